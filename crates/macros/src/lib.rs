@@ -0,0 +1,103 @@
+//! Compile-time lookup of Zero Trust palette colors.
+//!
+//! [`zero_trust_color!`] turns a palette or role name into a
+//! `zero_trust_palette::Color` constant. Unknown names fail the build, so a
+//! typo never reaches a running program:
+//!
+//! ```
+//! use zero_trust_macros::zero_trust_color;
+//! use zero_trust_palette::{accent, Color};
+//!
+//! const KEYWORD: Color = zero_trust_color!("syntax.keyword");
+//! assert_eq!(KEYWORD, accent::BLUE);
+//! assert_eq!(zero_trust_color!("accent.blue"), accent::BLUE);
+//! ```
+//!
+//! ```compile_fail
+//! let _ = zero_trust_macros::zero_trust_color!("accent.bleu");
+//! ```
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Palette, Swatch};
+
+/// Expands to the `Color` of a palette entry (`"accent.blue"`) or role
+/// (`"syntax.keyword"`).
+///
+/// Roles resolve against the shipped dark palette and default role map,
+/// including the role's alpha. The calling crate must depend on
+/// `zero-trust-palette`.
+#[proc_macro]
+pub fn zero_trust_color(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(tokens) => tokens,
+        Err((message, span)) => compile_error(&message, span),
+    }
+}
+
+fn expand(input: TokenStream) -> Result<TokenStream, (String, Span)> {
+    let mut tokens = input.into_iter();
+    let (literal, span) = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => {
+            let span = literal.span();
+            (literal.to_string(), span)
+        }
+        (Some(token), _) => return Err(("expected a single string literal".into(), token.span())),
+        (None, _) => return Err(("expected a color name".into(), Span::call_site())),
+    };
+    let name = literal
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| ("expected a string literal".to_string(), span))?;
+
+    let color = Swatch::from_name(name)
+        .map(Swatch::color)
+        .or_else(|| Role::from_name(name).map(|role| role.default_style().color(&Palette::BASE)))
+        .ok_or_else(|| (format!("`{name}` is not a palette entry or role"), span))?;
+    Ok(color_tokens(color, span))
+}
+
+/// `::zero_trust_palette::Color::rgba(r, g, b, a)`
+fn color_tokens(color: Color, span: Span) -> TokenStream {
+    let mut path = TokenStream::new();
+    for segment in ["zero_trust_palette", "Color", "rgba"] {
+        path.extend([
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new(segment, span)),
+        ]);
+    }
+    let mut args = TokenStream::new();
+    for (i, channel) in [color.r, color.g, color.b, color.a].into_iter().enumerate() {
+        if i > 0 {
+            args.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        args.extend([TokenTree::Literal(Literal::u8_suffixed(channel))]);
+    }
+    path.extend([TokenTree::Group(Group::new(Delimiter::Parenthesis, args))]);
+    path
+}
+
+/// `::core::compile_error!("message")`, pointing at `span`.
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    for segment in ["core", "compile_error"] {
+        tokens.extend([
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new(segment, span)),
+        ]);
+    }
+    let mut message = Literal::string(message);
+    message.set_span(span);
+    let mut group = Group::new(
+        Delimiter::Parenthesis,
+        TokenStream::from(TokenTree::Literal(message)),
+    );
+    group.set_span(span);
+    tokens.extend([
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(group),
+    ]);
+    tokens
+}
@@ -0,0 +1,43 @@
+//! Rainbow bracket colors.
+//!
+//! Bracket pair colorization needs a handful of colors that are clearly
+//! distinct from each other, yet sit at the same visual weight as the
+//! syntax colors. [`rainbow`] takes the average OKLCH lightness and chroma of
+//! the accents, spaces the hues evenly around the circle starting from
+//! `accent.gold`, and lifts each result to WCAG AA against the background.
+
+use crate::contrast::{ensure_contrast, AA};
+use crate::roles::Role;
+use crate::space::Oklch;
+use crate::{Color, Swatch, Theme};
+
+/// The number of bracket levels emitted by default.
+pub const DEFAULT_LEVELS: usize = 6;
+
+/// The `N` evenly spaced bracket hues for `theme` before any contrast
+/// adjustment, outermost pair first.
+pub fn hues<const N: usize>(theme: &Theme) -> [Oklch; N] {
+    let (mut l, mut c, mut count) = (0.0, 0.0, 0.0);
+    for swatch in Swatch::ALL
+        .iter()
+        .filter(|s| s.name().starts_with("accent."))
+    {
+        let lch = theme.palette[*swatch].to_oklch();
+        l += lch.l;
+        c += lch.c;
+        count += 1.0;
+    }
+    let (l, c) = (l / count, c / count);
+    let start = theme.palette[Swatch::AccentGold].to_oklch().h;
+    core::array::from_fn(|i| {
+        let h = start + 360.0 * i as f64 / N as f64;
+        Oklch { l, c, h: h % 360.0 }
+    })
+}
+
+/// `N` bracket colors for `theme`, outermost pair first: the [`hues`]
+/// lifted to WCAG AA against the background.
+pub fn rainbow<const N: usize>(theme: &Theme) -> [Color; N] {
+    let background = theme.opaque_color(Role::Background);
+    hues::<N>(theme).map(|lch| ensure_contrast(Color::from_oklch(lch), background, AA))
+}
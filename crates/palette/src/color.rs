@@ -0,0 +1,182 @@
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+/// An sRGB color with 8 bits per channel and straight (non-premultiplied) alpha.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    /// Opaque black.
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    /// Opaque white.
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    /// Creates an opaque color from its channels.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b, a: 255 }
+    }
+
+    /// Creates a color from its channels, including alpha.
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color { r, g, b, a }
+    }
+
+    /// Creates an opaque color from a packed `0xRRGGBB` value.
+    pub const fn from_u32(rgb: u32) -> Self {
+        Color::rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+    }
+
+    /// Returns `true` if the color is fully opaque.
+    pub const fn is_opaque(&self) -> bool {
+        self.a == 255
+    }
+
+    /// Parses `#RGB`, `#RRGGBB` or `#RRGGBBAA`. The leading `#` is optional.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseColorError::InvalidDigit);
+        }
+        let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).unwrap();
+        match digits.len() {
+            3 => {
+                let short = |i: usize| u8::from_str_radix(&digits[i..=i], 16).unwrap() * 0x11;
+                Ok(Color::rgb(short(0), short(1), short(2)))
+            }
+            6 => Ok(Color::rgb(channel(0), channel(2), channel(4))),
+            8 => Ok(Color::rgba(channel(0), channel(2), channel(4), channel(6))),
+            len => Err(ParseColorError::InvalidLength(len)),
+        }
+    }
+
+    /// Parses a hex color in a const context, panicking if it is malformed.
+    ///
+    /// Accepts the same forms as [`Color::from_hex`]; intended for building
+    /// style tables at compile time.
+    pub const fn hex(hex: &str) -> Color {
+        let bytes = hex.as_bytes();
+        let start = if !bytes.is_empty() && bytes[0] == b'#' {
+            1
+        } else {
+            0
+        };
+        const fn digit(b: u8) -> u8 {
+            match b {
+                b'0'..=b'9' => b - b'0',
+                b'a'..=b'f' => b - b'a' + 10,
+                b'A'..=b'F' => b - b'A' + 10,
+                _ => panic!("invalid hex digit in color"),
+            }
+        }
+        const fn pair(bytes: &[u8], i: usize) -> u8 {
+            digit(bytes[i]) * 16 + digit(bytes[i + 1])
+        }
+        let s = start;
+        match bytes.len() - start {
+            3 => Color::rgb(
+                digit(bytes[s]) * 0x11,
+                digit(bytes[s + 1]) * 0x11,
+                digit(bytes[s + 2]) * 0x11,
+            ),
+            6 => Color::rgb(pair(bytes, s), pair(bytes, s + 2), pair(bytes, s + 4)),
+            8 => Color::rgba(
+                pair(bytes, s),
+                pair(bytes, s + 2),
+                pair(bytes, s + 4),
+                pair(bytes, s + 6),
+            ),
+            _ => panic!("expected 3, 6 or 8 hex digits"),
+        }
+    }
+
+    /// Formats the color as four `0`–`1` fractions, `r g b a`, the encoding
+    /// Apple property lists such as Xcode themes store colors in.
+    pub const fn float_rgba(self) -> FloatRgba {
+        FloatRgba(self)
+    }
+
+    /// Formats the color as `#RRGGBB`, or `#RRGGBBAA` when it is not opaque.
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> alloc::string::String {
+        alloc::string::ToString::to_string(self)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b)?;
+        if !self.is_opaque() {
+            write!(f, "{:02X}", self.a)?;
+        }
+        Ok(())
+    }
+}
+
+/// A color displayed as `r g b a` fractions, from [`Color::float_rgba`].
+///
+/// Each channel has at most six decimals, enough to map back to the same
+/// 8-bit value, and no trailing zeros: opaque white is `1 1 1 1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FloatRgba(pub Color);
+
+impl fmt::Display for FloatRgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Color { r, g, b, a } = self.0;
+        for (i, channel) in [r, g, b, a].into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            // Integer math keeps the output the same with and without `std`.
+            let mut micros = (channel as u32 * 1_000_000 + 127) / 255;
+            match micros {
+                0 => f.write_str("0")?,
+                1_000_000 => f.write_str("1")?,
+                _ => {
+                    let mut digits = 6;
+                    while micros.is_multiple_of(10) {
+                        micros /= 10;
+                        digits -= 1;
+                    }
+                    write!(f, "0.{micros:0digits$}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::from_hex(s)
+    }
+}
+
+/// The error returned when a hex color string cannot be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The string contained a character that is not a hex digit.
+    InvalidDigit,
+    /// The string had a digit count other than 3, 6 or 8.
+    InvalidLength(usize),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::InvalidDigit => f.write_str("invalid hex digit in color"),
+            ParseColorError::InvalidLength(len) => {
+                write!(f, "expected 3, 6 or 8 hex digits, found {len}")
+            }
+        }
+    }
+}
+
+impl Error for ParseColorError {}
@@ -0,0 +1,56 @@
+//! Flattening translucent colors for targets that only accept opaque ones.
+
+use crate::math;
+use crate::roles::Role;
+use crate::{Color, Theme};
+
+impl Color {
+    /// Composites `self` over `backdrop` (source-over).
+    ///
+    /// Blending happens on gamma-encoded sRGB values, the way editors and
+    /// terminals render translucent colors, so the flattened result matches
+    /// what a target with alpha support would show.
+    pub fn over(self, backdrop: Color) -> Color {
+        let (sa, ba) = (self.alpha(), backdrop.alpha());
+        let a = sa + ba * (1.0 - sa);
+        if a == 0.0 {
+            return Color::rgba(0, 0, 0, 0);
+        }
+        let blend = |s: u8, b: u8| {
+            let v = (s as f64 * sa + b as f64 * ba * (1.0 - sa)) / a;
+            math::round(v) as u8
+        };
+        Color::rgb(
+            blend(self.r, backdrop.r),
+            blend(self.g, backdrop.g),
+            blend(self.b, backdrop.b),
+        )
+        .with_alpha(a)
+    }
+}
+
+impl Role {
+    /// The surface a role's color is drawn on, used to flatten translucency.
+    pub fn backdrop(self) -> Role {
+        if self.name().starts_with("terminal.") {
+            Role::TerminalBackground
+        } else {
+            Role::Background
+        }
+    }
+}
+
+impl Theme {
+    /// The color of `role`, composited over its backdrop so it is opaque.
+    ///
+    /// This is what a target writes whenever its format has no alpha
+    /// channel: the result is what an application blending the translucent
+    /// color itself would show on [`Role::backdrop`].
+    pub fn opaque_color(&self, role: Role) -> Color {
+        let color = self.color(role);
+        if color.is_opaque() || role == role.backdrop() {
+            return color;
+        }
+        color.over(self.opaque_color(role.backdrop()))
+    }
+}
@@ -0,0 +1,51 @@
+//! WCAG 2 contrast math.
+
+use crate::space::Oklch;
+use crate::Color;
+
+/// The WCAG AA minimum for normal text.
+pub const AA: f64 = 4.5;
+/// The WCAG AAA minimum for normal text.
+pub const AAA: f64 = 7.0;
+
+impl Color {
+    /// The WCAG relative luminance, in `0.0..=1.0`. Alpha is ignored.
+    pub fn relative_luminance(&self) -> f64 {
+        let rgb = crate::space::LinearRgb::from(*self);
+        0.2126 * rgb.r + 0.7152 * rgb.g + 0.0722 * rgb.b
+    }
+}
+
+/// The WCAG contrast ratio between two colors, in `1.0..=21.0`.
+pub fn contrast_ratio(a: Color, b: Color) -> f64 {
+    let (a, b) = (a.relative_luminance(), b.relative_luminance());
+    let (hi, lo) = if a > b { (a, b) } else { (b, a) };
+    (hi + 0.05) / (lo + 0.05)
+}
+
+/// Moves `fg` away from `bg` in OKLCH lightness until their contrast reaches
+/// `min`, keeping hue and as much chroma as the gamut allows.
+///
+/// Colors that already meet `min` are returned unchanged. If even the
+/// extreme lightness falls short, the extreme is returned.
+pub fn ensure_contrast(fg: Color, bg: Color, min: f64) -> Color {
+    if contrast_ratio(fg, bg) >= min {
+        return fg;
+    }
+    let lch = fg.to_oklch();
+    let at = |l: f64| fg.map_oklch(|lch| Oklch { l, ..lch });
+    let lighter = bg.relative_luminance() < 0.18;
+    let (mut near, mut far) = if lighter { (lch.l, 1.0) } else { (lch.l, 0.0) };
+    if contrast_ratio(at(far), bg) < min {
+        return at(far);
+    }
+    for _ in 0..24 {
+        let mid = (near + far) / 2.0;
+        if contrast_ratio(at(mid), bg) >= min {
+            far = mid;
+        } else {
+            near = mid;
+        }
+    }
+    at(far)
+}
@@ -0,0 +1,201 @@
+//! Perceptual color difference and near-duplicate detection.
+//!
+//! Two metrics are offered: Euclidean distance in OKLab ([`delta_e_ok`]),
+//! cheap and used throughout the crate, and CIEDE2000
+//! ([`delta_e_2000`]), the industry reference for "can a viewer tell these
+//! apart". [`DuplicateLint`] uses either to flag entries that are too close
+//! to carry distinct meaning.
+
+use crate::math;
+use crate::space::Lab;
+use crate::{Color, Palette, Swatch};
+
+/// Euclidean distance in OKLab, ignoring alpha.
+///
+/// Roughly `0.02` is the smallest difference noticeable side by side.
+pub fn delta_e_ok(a: Color, b: Color) -> f64 {
+    let (a, b) = (a.to_oklab(), b.to_oklab());
+    let (dl, da, db) = (a.l - b.l, a.a - b.a, a.b - b.b);
+    math::sqrt(dl * dl + da * da + db * db)
+}
+
+/// CIEDE2000 difference, ignoring alpha.
+///
+/// Around `2.3` is a just-noticeable difference.
+pub fn delta_e_2000(a: Color, b: Color) -> f64 {
+    a.to_lab().ciede2000(b.to_lab())
+}
+
+impl Lab {
+    /// The CIEDE2000 difference to `other`, with unit weighting factors.
+    pub fn ciede2000(self, other: Lab) -> f64 {
+        const POW25_7: f64 = 6_103_515_625.0;
+        let pow7 = |x: f64| x * x * x * x * x * x * x;
+        let hue = |b: f64, a: f64| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                math::rem_euclid(math::atan2(b, a).to_degrees(), 360.0)
+            }
+        };
+
+        let c_bar = (math::hypot(self.a, self.b) + math::hypot(other.a, other.b)) / 2.0;
+        let g = 0.5 * (1.0 - math::sqrt(pow7(c_bar) / (pow7(c_bar) + POW25_7)));
+        let (a1, a2) = ((1.0 + g) * self.a, (1.0 + g) * other.a);
+        let (c1, c2) = (math::hypot(a1, self.b), math::hypot(a2, other.b));
+        let (h1, h2) = (hue(self.b, a1), hue(other.b, a2));
+
+        let dl = other.l - self.l;
+        let dc = c2 - c1;
+        let dh = if c1 * c2 == 0.0 {
+            0.0
+        } else if math::abs(h2 - h1) <= 180.0 {
+            h2 - h1
+        } else if h2 - h1 > 180.0 {
+            h2 - h1 - 360.0
+        } else {
+            h2 - h1 + 360.0
+        };
+        let dh = 2.0 * math::sqrt(c1 * c2) * math::sin((dh / 2.0).to_radians());
+
+        let l_mean = (self.l + other.l) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if math::abs(h1 - h2) <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let cos = |deg: f64| math::cos(deg.to_radians());
+        let t = 1.0 - 0.17 * cos(h_mean - 30.0)
+            + 0.24 * cos(2.0 * h_mean)
+            + 0.32 * cos(3.0 * h_mean + 6.0)
+            - 0.20 * cos(4.0 * h_mean - 63.0);
+        let l50 = (l_mean - 50.0) * (l_mean - 50.0);
+        let sl = 1.0 + 0.015 * l50 / math::sqrt(20.0 + l50);
+        let sc = 1.0 + 0.045 * c_mean;
+        let sh = 1.0 + 0.015 * c_mean * t;
+        let rotation = 30.0 * math::exp(-((h_mean - 275.0) / 25.0) * ((h_mean - 275.0) / 25.0));
+        let rc = 2.0 * math::sqrt(pow7(c_mean) / (pow7(c_mean) + POW25_7));
+        let rt = -math::sin((2.0 * rotation).to_radians()) * rc;
+
+        let (l, c, h) = (dl / sl, dc / sc, dh / sh);
+        math::sqrt(l * l + c * c + h * h + rt * c * h)
+    }
+}
+
+/// How to measure color difference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Metric {
+    /// [`delta_e_ok`].
+    #[default]
+    Oklab,
+    /// [`delta_e_2000`].
+    Ciede2000,
+}
+
+impl Metric {
+    /// The difference between `a` and `b` under this metric.
+    pub fn distance(self, a: Color, b: Color) -> f64 {
+        match self {
+            Metric::Oklab => delta_e_ok(a, b),
+            Metric::Ciede2000 => delta_e_2000(a, b),
+        }
+    }
+
+    /// A threshold just above the metric's noticeable difference.
+    pub const fn default_threshold(self) -> f64 {
+        match self {
+            Metric::Oklab => 0.03,
+            Metric::Ciede2000 => 3.0,
+        }
+    }
+}
+
+/// Two entries closer than the lint threshold.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NearDuplicate<T> {
+    pub first: T,
+    pub second: T,
+    pub distance: f64,
+}
+
+/// Flags colors that are too close to tell apart.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct DuplicateLint {
+    pub metric: Metric,
+    /// Pairs strictly closer than this are reported.
+    pub threshold: f64,
+}
+
+impl Default for DuplicateLint {
+    fn default() -> Self {
+        DuplicateLint {
+            metric: Metric::Oklab,
+            threshold: Metric::Oklab.default_threshold(),
+        }
+    }
+}
+
+impl DuplicateLint {
+    /// Every pair of `entries` closer than the threshold, in input order.
+    pub fn check<'a, T: Copy>(
+        &'a self,
+        entries: &'a [(T, Color)],
+    ) -> impl Iterator<Item = NearDuplicate<T>> + 'a {
+        entries
+            .iter()
+            .enumerate()
+            .flat_map(move |(i, &(first, a))| {
+                entries[i + 1..].iter().filter_map(move |&(second, b)| {
+                    let distance = self.metric.distance(a, b);
+                    (distance < self.threshold).then_some(NearDuplicate {
+                        first,
+                        second,
+                        distance,
+                    })
+                })
+            })
+    }
+
+    /// Checks the `accent.*` entries of `palette`.
+    ///
+    /// Accents are the colors that must stay distinguishable from each
+    /// other; other groups repeat colors on purpose (`ansi.black` is
+    /// `bg.surface`, for instance).
+    pub fn check_accents<'a>(
+        &'a self,
+        palette: &'a Palette,
+    ) -> impl Iterator<Item = NearDuplicate<Swatch>> + 'a {
+        let accents = || {
+            Swatch::ALL
+                .iter()
+                .copied()
+                .filter(|s| s.name().starts_with("accent."))
+        };
+        accents().enumerate().flat_map(move |(i, first)| {
+            accents().skip(i + 1).filter_map(move |second| {
+                let distance = self.metric.distance(palette[first], palette[second]);
+                (distance < self.threshold).then_some(NearDuplicate {
+                    first,
+                    second,
+                    distance,
+                })
+            })
+        })
+    }
+}
@@ -0,0 +1,84 @@
+//! The Zero Trust color palette.
+//!
+//! This crate exposes every color of the Zero Trust theme as a typed
+//! [`Color`] constant, so tools can depend on the palette directly instead of
+//! copying hex strings out of the theme files.
+//!
+//! ```
+//! use zero_trust_palette::{accent, bg, Swatch};
+//!
+//! assert_eq!(bg::BASE.to_hex(), "#1E1E1E");
+//! assert_eq!(Swatch::from_name("accent.blue").map(Swatch::color), Some(accent::BLUE));
+//! ```
+//!
+//! Outputs should not pick swatches directly but go through [`roles`], which
+//! says what each color is *for*:
+//!
+//! ```
+//! use zero_trust_palette::{accent, roles::{Role, RoleMap}, Palette};
+//!
+//! let keyword = RoleMap::DEFAULT.color(Role::Keyword, &Palette::BASE);
+//! assert_eq!(keyword, accent::BLUE);
+//! ```
+//!
+//! Derived colors are computed with perceptual operations rather than
+//! hardcoded:
+//!
+//! ```
+//! use zero_trust_palette::{accent, bg, mix};
+//!
+//! let hover = bg::SURFACE.lighten(0.04);
+//! let inactive_tab = mix(bg::BASE, bg::SURFACE, 0.5);
+//! let selection = accent::SKY.with_alpha(0.3);
+//! assert_eq!(selection.a, 77);
+//! # let _ = (hover, inactive_tab);
+//! ```
+//!
+//! Every [`Variant`] is derived from the same base palette; a [`Theme`]
+//! bundles a variant's palette with the role map:
+//!
+//! ```
+//! use zero_trust_palette::{roles::Role, Appearance, Theme, Variant};
+//!
+//! let light = Theme::new(Variant::Light);
+//! assert_eq!(light.appearance(), Appearance::Light);
+//! assert!(light.color(Role::Background).to_oklch().l > 0.9);
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("zero-trust-palette needs either the `std` or the `libm` feature for float math");
+
+pub mod brackets;
+mod color;
+mod composite;
+pub mod contrast;
+pub mod difference;
+mod manip;
+mod math;
+mod palette;
+#[cfg(feature = "palette")]
+mod palette_interop;
+pub mod quantize;
+pub mod ramp;
+pub mod roles;
+pub mod scale;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod space;
+mod swatch;
+#[cfg(any(feature = "anstyle", feature = "ratatui"))]
+mod terminal_style;
+mod variant;
+
+pub use color::{Color, FloatRgba, ParseColorError};
+pub use manip::mix;
+pub use palette::Palette;
+pub use swatch::{accent, ansi, bg, fg, shade, state, tint, vcs, Swatch};
+pub use variant::{
+    Appearance, Dimmed, HighContrast, Inversion, Oled, Theme, Transforms, Tuning, Variant,
+};
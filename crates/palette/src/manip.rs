@@ -0,0 +1,86 @@
+//! Perceptual color manipulation.
+//!
+//! All operations work in OKLab / OKLCH, so equal amounts look like equal
+//! changes regardless of hue, and they preserve alpha unless stated otherwise.
+
+use crate::math;
+use crate::space::{Oklab, Oklch};
+use crate::Color;
+
+impl Color {
+    /// Applies `f` to the color in OKLCH, keeping alpha and fitting the
+    /// result back into the sRGB gamut.
+    pub fn map_oklch(self, f: impl FnOnce(Oklch) -> Oklch) -> Color {
+        Color {
+            a: self.a,
+            ..Color::from_oklch(f(self.to_oklch()))
+        }
+    }
+
+    /// Raises OKLCH lightness by `amount` (lightness spans `0.0..=1.0`).
+    pub fn lighten(self, amount: f64) -> Color {
+        self.map_oklch(|lch| Oklch {
+            l: lch.l + amount,
+            ..lch
+        })
+    }
+
+    /// Lowers OKLCH lightness by `amount`.
+    pub fn darken(self, amount: f64) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Scales OKLCH chroma by `1.0 + amount`.
+    pub fn saturate(self, amount: f64) -> Color {
+        self.map_oklch(|lch| Oklch {
+            c: (lch.c * (1.0 + amount)).max(0.0),
+            ..lch
+        })
+    }
+
+    /// Scales OKLCH chroma by `1.0 - amount`.
+    pub fn desaturate(self, amount: f64) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// Rotates the OKLCH hue by `degrees`.
+    pub fn rotate_hue(self, degrees: f64) -> Color {
+        self.map_oklch(|lch| Oklch {
+            h: math::rem_euclid(lch.h + degrees, 360.0),
+            ..lch
+        })
+    }
+
+    /// Replaces alpha with `alpha` in `0.0..=1.0`.
+    pub fn with_alpha(self, alpha: f64) -> Color {
+        Color {
+            a: math::round(alpha.clamp(0.0, 1.0) * 255.0) as u8,
+            ..self
+        }
+    }
+
+    /// Alpha as a fraction in `0.0..=1.0`.
+    pub fn alpha(&self) -> f64 {
+        self.a as f64 / 255.0
+    }
+
+    /// Interpolates towards `other`; see [`mix`].
+    pub fn mix(self, other: Color, t: f64) -> Color {
+        mix(self, other, t)
+    }
+}
+
+/// Interpolates between `a` (`t = 0.0`) and `b` (`t = 1.0`) in OKLab.
+///
+/// Alpha is interpolated linearly alongside.
+pub fn mix(a: Color, b: Color, t: f64) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let (x, y) = (a.to_oklab(), b.to_oklab());
+    let lerp = |p: f64, q: f64| p + (q - p) * t;
+    let mixed = Color::from_oklab(Oklab {
+        l: lerp(x.l, y.l),
+        a: lerp(x.a, y.a),
+        b: lerp(x.b, y.b),
+    });
+    mixed.with_alpha(lerp(a.alpha(), b.alpha()))
+}
@@ -0,0 +1,52 @@
+//! Float functions that live in `std`, with a `libm` fallback for `no_std`.
+//!
+//! `std` defers transcendental functions to the platform's math library,
+//! whose last-bit results differ between systems. Enabling the `libm`
+//! feature uses `libm` even with `std`, so derived colors are bit-identical
+//! everywhere.
+
+macro_rules! forward {
+    ($($name:ident($($arg:ident),*) => $libm:ident;)*) => {
+        $(
+            #[inline]
+            pub(crate) fn $name($($arg: f64),*) -> f64 {
+                #[cfg(all(feature = "std", not(feature = "libm")))]
+                {
+                    forward!(@std $name $($arg),*)
+                }
+                #[cfg(any(not(feature = "std"), feature = "libm"))]
+                {
+                    libm::$libm($($arg),*)
+                }
+            }
+        )*
+    };
+    (@std $name:ident $first:ident $(, $rest:ident)*) => {
+        $first.$name($($rest),*)
+    };
+}
+
+forward! {
+    powf(x, y) => pow;
+    cbrt(x) => cbrt;
+    sqrt(x) => sqrt;
+    atan2(y, x) => atan2;
+    hypot(x, y) => hypot;
+    sin(x) => sin;
+    cos(x) => cos;
+    exp(x) => exp;
+    round(x) => round;
+    trunc(x) => trunc;
+    abs(x) => fabs;
+}
+
+/// The non-negative remainder of `x / y`, like `f64::rem_euclid`.
+#[inline]
+pub(crate) fn rem_euclid(x: f64, y: f64) -> f64 {
+    let r = x - trunc(x / y) * y;
+    if r < 0.0 {
+        r + abs(y)
+    } else {
+        r
+    }
+}
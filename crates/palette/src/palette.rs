@@ -0,0 +1,69 @@
+use core::ops::{Index, IndexMut};
+
+use crate::{Color, Swatch};
+
+/// A complete set of colors, one for every [`Swatch`].
+///
+/// [`Palette::BASE`] holds the shipped dark palette; variants are derived from
+/// it by transforming its colors while keeping every swatch's identity.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Palette {
+    colors: [Color; Swatch::COUNT],
+}
+
+impl Palette {
+    /// The base Zero Trust palette.
+    pub const BASE: Palette = {
+        let mut colors = [Color::BLACK; Swatch::COUNT];
+        let mut i = 0;
+        while i < Swatch::COUNT {
+            colors[i] = Swatch::ALL[i].color();
+            i += 1;
+        }
+        Palette { colors }
+    };
+
+    /// Returns the color assigned to `swatch`.
+    pub const fn get(&self, swatch: Swatch) -> Color {
+        self.colors[swatch as usize]
+    }
+
+    /// Replaces the color assigned to `swatch`.
+    pub fn set(&mut self, swatch: Swatch, color: Color) {
+        self.colors[swatch as usize] = color;
+    }
+
+    /// Iterates over every swatch and its color, in palette order.
+    pub fn iter(&self) -> impl Iterator<Item = (Swatch, Color)> + '_ {
+        Swatch::ALL.iter().map(|&swatch| (swatch, self.get(swatch)))
+    }
+
+    /// Returns a new palette with `f` applied to every color.
+    pub fn map(&self, mut f: impl FnMut(Swatch, Color) -> Color) -> Palette {
+        let mut palette = self.clone();
+        for (swatch, color) in self.iter() {
+            palette.set(swatch, f(swatch, color));
+        }
+        palette
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::BASE
+    }
+}
+
+impl Index<Swatch> for Palette {
+    type Output = Color;
+
+    fn index(&self, swatch: Swatch) -> &Color {
+        &self.colors[swatch as usize]
+    }
+}
+
+impl IndexMut<Swatch> for Palette {
+    fn index_mut(&mut self, swatch: Swatch) -> &mut Color {
+        &mut self.colors[swatch as usize]
+    }
+}
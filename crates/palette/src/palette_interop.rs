@@ -0,0 +1,87 @@
+//! Conversions to and from the [`palette`](::palette) crate, behind the
+//! `palette` feature.
+//!
+//! Integer sRGB types convert losslessly. Float and OKLCH conversions go
+//! through this crate's own color math, so `Color -> Oklch -> Color` gives the
+//! same result whichever side of the bridge it happens on.
+
+use ::palette::{OklabHue, Oklch as PaletteOklch, Srgb, Srgba};
+
+use crate::space::Oklch;
+use crate::Color;
+
+impl From<Color> for Srgb<u8> {
+    fn from(color: Color) -> Self {
+        Srgb::new(color.r, color.g, color.b)
+    }
+}
+
+impl From<Srgb<u8>> for Color {
+    fn from(rgb: Srgb<u8>) -> Self {
+        Color::rgb(rgb.red, rgb.green, rgb.blue)
+    }
+}
+
+impl From<Color> for Srgba<u8> {
+    fn from(color: Color) -> Self {
+        Srgba::new(color.r, color.g, color.b, color.a)
+    }
+}
+
+impl From<Srgba<u8>> for Color {
+    fn from(rgba: Srgba<u8>) -> Self {
+        Color::rgba(rgba.red, rgba.green, rgba.blue, rgba.alpha)
+    }
+}
+
+impl From<Color> for Srgb<f32> {
+    fn from(color: Color) -> Self {
+        Srgb::<u8>::from(color).into_format()
+    }
+}
+
+impl From<Srgb<f32>> for Color {
+    /// Clamps out-of-range channels before quantizing to 8 bits.
+    fn from(rgb: Srgb<f32>) -> Self {
+        let clamped = Srgb::new(
+            rgb.red.clamp(0.0, 1.0),
+            rgb.green.clamp(0.0, 1.0),
+            rgb.blue.clamp(0.0, 1.0),
+        );
+        clamped.into_format::<u8>().into()
+    }
+}
+
+impl From<Oklch> for PaletteOklch<f32> {
+    fn from(lch: Oklch) -> Self {
+        PaletteOklch::new(
+            lch.l as f32,
+            lch.c as f32,
+            OklabHue::from_degrees(lch.h as f32),
+        )
+    }
+}
+
+impl From<PaletteOklch<f32>> for Oklch {
+    fn from(lch: PaletteOklch<f32>) -> Self {
+        Oklch {
+            l: lch.l as f64,
+            c: lch.chroma as f64,
+            h: lch.hue.into_positive_degrees() as f64,
+        }
+    }
+}
+
+impl From<Color> for PaletteOklch<f32> {
+    /// Ignores alpha.
+    fn from(color: Color) -> Self {
+        color.to_oklch().into()
+    }
+}
+
+impl From<PaletteOklch<f32>> for Color {
+    /// Reduces chroma to fit the sRGB gamut; the result is opaque.
+    fn from(lch: PaletteOklch<f32>) -> Self {
+        Color::from_oklch(lch.into())
+    }
+}
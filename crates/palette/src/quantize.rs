@@ -0,0 +1,90 @@
+//! Mapping colors onto the xterm 256- and 16-color palettes.
+//!
+//! For terminals and tools without truecolor. The nearest index is chosen by
+//! Euclidean distance in OKLab, which tracks perceived difference far better
+//! than distance in sRGB.
+
+use crate::difference::delta_e_ok;
+use crate::Color;
+
+/// The default xterm colors for indices `0..16`.
+pub const XTERM_16: [Color; 16] = [
+    Color::from_u32(0x000000),
+    Color::from_u32(0xCD0000),
+    Color::from_u32(0x00CD00),
+    Color::from_u32(0xCDCD00),
+    Color::from_u32(0x0000EE),
+    Color::from_u32(0xCD00CD),
+    Color::from_u32(0x00CDCD),
+    Color::from_u32(0xE5E5E5),
+    Color::from_u32(0x7F7F7F),
+    Color::from_u32(0xFF0000),
+    Color::from_u32(0x00FF00),
+    Color::from_u32(0xFFFF00),
+    Color::from_u32(0x5C5CFF),
+    Color::from_u32(0xFF00FF),
+    Color::from_u32(0x00FFFF),
+    Color::from_u32(0xFFFFFF),
+];
+
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The color xterm shows for a 256-color index.
+pub const fn ansi256_color(index: u8) -> Color {
+    match index {
+        0..=15 => XTERM_16[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            Color::rgb(
+                CUBE[(i / 36) as usize],
+                CUBE[(i / 6 % 6) as usize],
+                CUBE[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let v = 8 + (index - 232) * 10;
+            Color::rgb(v, v, v)
+        }
+    }
+}
+
+fn nearest(color: Color, candidates: impl Iterator<Item = u8>) -> u8 {
+    candidates
+        .min_by(|&x, &y| {
+            let dx = delta_e_ok(color, ansi256_color(x));
+            let dy = delta_e_ok(color, ansi256_color(y));
+            dx.total_cmp(&dy)
+        })
+        .expect("candidate set is never empty")
+}
+
+/// The nearest 256-color index.
+///
+/// Only the fixed cube and gray ramp (`16..=255`) are considered: indices
+/// `0..16` are redefined by most terminal themes, this one included, so
+/// their appearance cannot be relied on.
+pub fn to_ansi256(color: Color) -> u8 {
+    nearest(color, 16..=255)
+}
+
+/// The nearest of the 16 basic colors, judged by xterm's defaults.
+pub fn to_ansi16(color: Color) -> u8 {
+    nearest(color, 0..16)
+}
+
+/// A color together with its degraded fallbacks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Quantized {
+    pub truecolor: Color,
+    pub ansi256: u8,
+    pub ansi16: u8,
+}
+
+/// Computes both fallbacks for `color`.
+pub fn quantize(color: Color) -> Quantized {
+    Quantized {
+        truecolor: color,
+        ansi256: to_ansi256(color),
+        ansi16: to_ansi16(color),
+    }
+}
@@ -0,0 +1,155 @@
+//! Derived color ramps for diagnostic states.
+//!
+//! Each of success, warning, error and info gets four steps computed from one
+//! source color, so diagnostics, diff views and notifications share the same
+//! family of colors in every target and variant. The background wash is the
+//! exception: it is taken from the hand-picked tint the state used before it
+//! had a ramp. The steps live in the `state.*` palette entries; [`apply`]
+//! recomputes them after a variant's transform has run.
+
+use crate::contrast::{ensure_contrast, AA};
+use crate::{mix, Color, Palette, Swatch};
+
+/// A diagnostic state with its own ramp.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum State {
+    Success,
+    Warning,
+    Error,
+    Info,
+}
+
+impl State {
+    pub const ALL: [State; 4] = [State::Success, State::Warning, State::Error, State::Info];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            State::Success => "success",
+            State::Warning => "warning",
+            State::Error => "error",
+            State::Info => "info",
+        }
+    }
+
+    /// The palette entry the ramp is derived from.
+    pub const fn source(self) -> Swatch {
+        match self {
+            State::Success => Swatch::AnsiGreen,
+            State::Warning => Swatch::AnsiYellow,
+            State::Error => Swatch::AnsiRed,
+            State::Info => Swatch::AccentSky,
+        }
+    }
+
+    /// The palette entry the background wash is copied from.
+    pub const fn tint(self) -> Swatch {
+        match self {
+            State::Success => Swatch::TintGreen,
+            State::Warning => Swatch::TintYellow,
+            State::Error => Swatch::TintRed,
+            State::Info => Swatch::ShadeBlue,
+        }
+    }
+
+    /// The `state.*` entries holding the ramp, in [`Ramp`] field order.
+    pub const fn swatches(self) -> [Swatch; 4] {
+        use Swatch::*;
+        match self {
+            State::Success => [
+                StateSuccess,
+                StateSuccessEmphasized,
+                StateSuccessMuted,
+                StateSuccessTint,
+            ],
+            State::Warning => [
+                StateWarning,
+                StateWarningEmphasized,
+                StateWarningMuted,
+                StateWarningTint,
+            ],
+            State::Error => [
+                StateError,
+                StateErrorEmphasized,
+                StateErrorMuted,
+                StateErrorTint,
+            ],
+            State::Info => [
+                StateInfo,
+                StateInfoEmphasized,
+                StateInfoMuted,
+                StateInfoTint,
+            ],
+        }
+    }
+}
+
+/// The four steps of a state ramp.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ramp {
+    /// The state color itself, for icons and underlines.
+    pub base: Color,
+    /// Stronger than `base`, for text that must stand out; at least WCAG AA.
+    pub emphasized: Color,
+    /// Faded towards the background, for secondary markers.
+    pub muted: Color,
+    /// A background wash behind highlighted lines or banners.
+    pub tint: Color,
+}
+
+impl Ramp {
+    /// Lightness step from `base` to `emphasized`.
+    pub const EMPHASIS: f64 = 0.08;
+    /// How far `muted` is mixed towards the background.
+    pub const MUTE: f64 = 0.45;
+    /// How far `tint` is mixed from the background towards `base`.
+    pub const TINT: f64 = 0.2;
+
+    /// Derives a ramp from `base` for the given editor background.
+    pub fn derive(base: Color, background: Color) -> Ramp {
+        let dark = background.to_oklch().l < 0.5;
+        let emphasized = if dark {
+            base.lighten(Ramp::EMPHASIS)
+        } else {
+            base.darken(Ramp::EMPHASIS)
+        };
+        Ramp {
+            base,
+            emphasized: ensure_contrast(emphasized, background, AA),
+            muted: mix(base, background, Ramp::MUTE),
+            tint: mix(background, base, Ramp::TINT),
+        }
+    }
+
+    /// The ramp for `state` in `palette`, with the state's own tint.
+    ///
+    /// The derived steps keep their OKLab spacing, but the wash is pinned to
+    /// [`State::tint`]. A [`Ramp::TINT`] mix sits so close to the background
+    /// that it reads as gray beside the current-line highlight, while the
+    /// hand-picked tints keep their hue and still leave text readable. They
+    /// are also the entries selections and collaborator cursors use, so
+    /// pinning keeps an error banner the same red as the rest of the theme
+    /// and lets `[palette]` retune it by name.
+    pub fn for_state(palette: &Palette, state: State) -> Ramp {
+        Ramp {
+            tint: palette[state.tint()],
+            ..Ramp::derive(palette[state.source()], palette[Swatch::BgBase])
+        }
+    }
+
+    /// The steps in field order.
+    pub const fn steps(&self) -> [Color; 4] {
+        [self.base, self.emphasized, self.muted, self.tint]
+    }
+}
+
+/// Recomputes every `state.*` entry of `palette` from its source color.
+pub fn apply(palette: &Palette) -> Palette {
+    let mut palette = palette.clone();
+    for state in State::ALL {
+        let ramp = Ramp::for_state(&palette, state);
+        for (swatch, color) in state.swatches().into_iter().zip(ramp.steps()) {
+            palette.set(swatch, color);
+        }
+    }
+    palette
+}
@@ -0,0 +1,461 @@
+//! Semantic roles: what a color *means*, independent of any target.
+//!
+//! Emitters never pick palette entries themselves. They ask for a [`Role`]
+//! (`syntax.keyword`, `ui.statusline`, `terminal.red`, ...) and a [`RoleMap`]
+//! answers with a [`Style`] pointing into the palette. Retargeting the theme
+//! to a new editor is then a matter of mapping that editor's keys to roles.
+
+use core::ops::{Index, IndexMut};
+
+use crate::{Color, Palette, Swatch};
+
+/// How a role is painted: a palette entry plus optional font attributes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Style {
+    /// The palette entry providing the color.
+    pub swatch: Swatch,
+    /// Alpha applied on top of the swatch's color.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "opaque", skip_serializing_if = "is_opaque")
+    )]
+    pub alpha: u8,
+    /// Whether text in this role is rendered in italics.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
+    pub italic: bool,
+    /// An explicit font weight (CSS scale, e.g. `700` for bold).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub weight: Option<u16>,
+}
+
+#[cfg(feature = "serde")]
+fn opaque() -> u8 {
+    255
+}
+
+#[cfg(feature = "serde")]
+fn is_opaque(alpha: &u8) -> bool {
+    *alpha == 255
+}
+
+#[cfg(feature = "serde")]
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+impl Style {
+    /// An opaque, upright, default-weight style.
+    pub const fn new(swatch: Swatch) -> Self {
+        Style {
+            swatch,
+            alpha: 255,
+            italic: false,
+            weight: None,
+        }
+    }
+
+    /// Returns the style rendered in italics.
+    pub const fn italic(self) -> Self {
+        Style {
+            italic: true,
+            ..self
+        }
+    }
+
+    /// Returns the style with the given font weight.
+    pub const fn weight(self, weight: u16) -> Self {
+        Style {
+            weight: Some(weight),
+            ..self
+        }
+    }
+
+    /// Returns the style with the given alpha.
+    pub const fn with_alpha(self, alpha: u8) -> Self {
+        Style { alpha, ..self }
+    }
+
+    /// Returns `true` if the style asks for a bold weight (600 or more).
+    pub fn is_bold(&self) -> bool {
+        self.weight.is_some_and(|weight| weight >= 600)
+    }
+
+    /// Resolves the style's color against `palette`.
+    pub const fn color(&self, palette: &Palette) -> Color {
+        let color = palette.get(self.swatch);
+        Color {
+            a: (color.a as u16 * self.alpha as u16 / 255) as u8,
+            ..color
+        }
+    }
+}
+
+macro_rules! roles {
+    ($($(#[$doc:meta])* $variant:ident, $name:literal, $style:expr;)*) => {
+        /// A semantic color role.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum Role {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl Role {
+            /// Every role, in declaration order.
+            pub const ALL: &'static [Role] = &[$(Role::$variant,)*];
+
+            /// The number of roles.
+            pub const COUNT: usize = Role::ALL.len();
+
+            /// The dotted name of the role, e.g. `"syntax.keyword"`.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Role::$variant => $name,)*
+                }
+            }
+
+            /// Looks a role up by its dotted name.
+            pub fn from_name(name: &str) -> Option<Role> {
+                match name {
+                    $($name => Some(Role::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns `true` for `syntax.*` roles.
+            pub fn is_syntax(self) -> bool {
+                self.name().starts_with("syntax.")
+            }
+
+            /// The style the role has in the shipped theme.
+            pub const fn default_style(self) -> Style {
+                use Swatch::*;
+                match self {
+                    $(Role::$variant => $style,)*
+                }
+            }
+        }
+    };
+}
+
+impl Role {
+    /// Returns `true` for roles that fill an area (surfaces, highlights,
+    /// selections) rather than color text or lines.
+    pub fn is_surface(self) -> bool {
+        use Role::*;
+        matches!(
+            self,
+            Background
+                | Surface
+                | SurfaceElevated
+                | Panel
+                | Toolbar
+                | Titlebar
+                | TitlebarInactive
+                | Statusline
+                | Tabline
+                | TabActive
+                | TabInactive
+                | Gutter
+                | Subheader
+                | Cursorline
+                | LineHighlight
+                | Element
+                | ElementHover
+                | ElementActive
+                | ElementSelected
+                | ElementDisabled
+                | Ghost
+                | GhostHover
+                | GhostActive
+                | DropTarget
+                | Selection
+                | Highlight
+                | SearchMatch
+                | ScrollbarThumb
+                | ScrollbarThumbHover
+                | ScrollbarTrack
+                | ErrorBackground
+                | WarningBackground
+                | InfoBackground
+                | SuccessBackground
+                | HintBackground
+                | IgnoredBackground
+                | PredictiveBackground
+                | GitConflictOurs
+                | GitConflictTheirs
+                | TerminalBackground
+        )
+    }
+}
+
+impl core::fmt::Display for Role {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+roles! {
+    // Window chrome and editor surfaces.
+    Background, "ui.background", Style::new(BgBase);
+    Surface, "ui.surface", Style::new(BgSurface);
+    SurfaceElevated, "ui.surface.elevated", Style::new(BgSurface);
+    Panel, "ui.panel", Style::new(BgSurface);
+    Toolbar, "ui.toolbar", Style::new(BgRaised);
+    Titlebar, "ui.titlebar", Style::new(BgBase);
+    TitlebarInactive, "ui.titlebar.inactive", Style::new(BgRaised);
+    Statusline, "ui.statusline", Style::new(BgSurface);
+    Tabline, "ui.tabline", Style::new(BgSurface);
+    TabActive, "ui.tab.active", Style::new(BgBase);
+    TabInactive, "ui.tab.inactive", Style::new(BgSurface);
+    Gutter, "ui.gutter", Style::new(BgRaised);
+    Subheader, "ui.subheader", Style::new(BgSubtle);
+    Cursorline, "ui.cursorline", Style::new(BgSubtle);
+    LineHighlight, "ui.line.highlight", Style::new(BgLine);
+    LineNumber, "ui.line_number", Style::new(FgSubtle);
+    LineNumberActive, "ui.line_number.active", Style::new(FgBright);
+    LineNumberHover, "ui.line_number.hover", Style::new(FgBase);
+    Border, "ui.border", Style::new(BgOverlay);
+    BorderVariant, "ui.border.variant", Style::new(BgSubtle);
+    BorderFocused, "ui.border.focused", Style::new(AccentAzure);
+    BorderSelected, "ui.border.selected", Style::new(AccentAzure);
+    BorderDisabled, "ui.border.disabled", Style::new(BgActive);
+    Split, "ui.split", Style::new(BgBorder);
+    Element, "ui.element", Style::new(BgSurface);
+    ElementHover, "ui.element.hover", Style::new(BgOverlay);
+    ElementActive, "ui.element.active", Style::new(BgActive);
+    ElementSelected, "ui.element.selected", Style::new(TintBlue);
+    ElementDisabled, "ui.element.disabled", Style::new(BgSubtle);
+    Ghost, "ui.ghost", Style::new(BgRaised);
+    GhostHover, "ui.ghost.hover", Style::new(BgSurface);
+    GhostActive, "ui.ghost.active", Style::new(BgOverlay);
+    DropTarget, "ui.drop_target", Style::new(ShadeBlue);
+    Text, "ui.text", Style::new(FgBase);
+    TextMuted, "ui.text.muted", Style::new(FgMuted);
+    TextPlaceholder, "ui.text.placeholder", Style::new(FgSubtle);
+    TextDisabled, "ui.text.disabled", Style::new(BgActive);
+    Accent, "ui.accent", Style::new(AccentSky);
+    Link, "ui.link", Style::new(AccentPeriwinkle);
+    Icon, "ui.icon", Style::new(FgMuted);
+    IconMuted, "ui.icon.muted", Style::new(FgSubtle);
+    IconPlaceholder, "ui.icon.placeholder", Style::new(BgThumb);
+    Cursor, "ui.cursor", Style::new(AccentSky);
+    Selection, "ui.selection", Style::new(ShadeBlue);
+    Highlight, "ui.highlight", Style::new(BgHighlight);
+    SearchMatch, "ui.search.match", Style::new(BgHighlight);
+    Guide, "ui.guide", Style::new(BgBorder);
+    GuideActive, "ui.guide.active", Style::new(BgGuide);
+    GuideHover, "ui.guide.hover", Style::new(BgGuideHover);
+    Invisible, "ui.invisible", Style::new(BgBorder);
+    ScrollbarThumb, "ui.scrollbar.thumb", Style::new(BgThumb);
+    ScrollbarThumbHover, "ui.scrollbar.thumb.hover", Style::new(BgThumbHover);
+    ScrollbarTrack, "ui.scrollbar.track", Style::new(BgSubtle);
+    ScrollbarTrackBorder, "ui.scrollbar.track.border", Style::new(BgSurface);
+
+    // Diagnostics and file states.
+    Error, "status.error", Style::new(StateError);
+    ErrorEmphasized, "status.error.emphasized", Style::new(StateErrorEmphasized);
+    ErrorMuted, "status.error.muted", Style::new(StateErrorMuted);
+    ErrorBackground, "status.error.background", Style::new(StateErrorTint);
+    ErrorBorder, "status.error.border", Style::new(AnsiBrightRed);
+    Warning, "status.warning", Style::new(StateWarning);
+    WarningEmphasized, "status.warning.emphasized", Style::new(StateWarningEmphasized);
+    WarningMuted, "status.warning.muted", Style::new(StateWarningMuted);
+    WarningBackground, "status.warning.background", Style::new(StateWarningTint);
+    WarningBorder, "status.warning.border", Style::new(AnsiBrightYellow);
+    Info, "status.info", Style::new(StateInfo);
+    InfoEmphasized, "status.info.emphasized", Style::new(StateInfoEmphasized);
+    InfoMuted, "status.info.muted", Style::new(StateInfoMuted);
+    InfoBackground, "status.info.background", Style::new(StateInfoTint);
+    InfoBorder, "status.info.border", Style::new(AnsiBrightBlue);
+    Success, "status.success", Style::new(StateSuccess);
+    SuccessEmphasized, "status.success.emphasized", Style::new(StateSuccessEmphasized);
+    SuccessMuted, "status.success.muted", Style::new(StateSuccessMuted);
+    SuccessBackground, "status.success.background", Style::new(StateSuccessTint);
+    SuccessBorder, "status.success.border", Style::new(AnsiBrightGreen);
+    Hint, "status.hint", Style::new(FgMuted);
+    HintBackground, "status.hint.background", Style::new(BgSubtle);
+    HintBorder, "status.hint.border", Style::new(BgOverlay);
+    Ignored, "status.ignored", Style::new(FgSubtle);
+    IgnoredBackground, "status.ignored.background", Style::new(BgSubtle);
+    IgnoredBorder, "status.ignored.border", Style::new(BgOverlay);
+    Predictive, "status.predictive", Style::new(AnsiMagenta);
+    PredictiveBackground, "status.predictive.background", Style::new(TintPurple);
+    PredictiveBorder, "status.predictive.border", Style::new(AnsiBrightMagenta);
+
+    // Version control gutter markers.
+    GitAdded, "git.added", Style::new(VcsAdded);
+    GitModified, "git.modified", Style::new(VcsModified);
+    GitDeleted, "git.deleted", Style::new(VcsDeleted);
+    GitConflictOurs, "git.conflict.ours", Style::new(VcsAdded).with_alpha(0x1A);
+    GitConflictTheirs, "git.conflict.theirs", Style::new(AccentPeriwinkle).with_alpha(0x1A);
+
+    // The integrated terminal.
+    TerminalBackground, "terminal.background", Style::new(BgBase);
+    TerminalForeground, "terminal.foreground", Style::new(FgBase);
+    TerminalForegroundBright, "terminal.foreground.bright", Style::new(FgBright);
+    TerminalForegroundDim, "terminal.foreground.dim", Style::new(FgMuted);
+    TerminalBlack, "terminal.black", Style::new(AnsiBlack);
+    TerminalRed, "terminal.red", Style::new(AnsiRed);
+    TerminalGreen, "terminal.green", Style::new(AnsiGreen);
+    TerminalYellow, "terminal.yellow", Style::new(AnsiYellow);
+    TerminalBlue, "terminal.blue", Style::new(AnsiBlue);
+    TerminalMagenta, "terminal.magenta", Style::new(AnsiMagenta);
+    TerminalCyan, "terminal.cyan", Style::new(AnsiCyan);
+    TerminalWhite, "terminal.white", Style::new(AnsiWhite);
+    TerminalBrightBlack, "terminal.bright_black", Style::new(AnsiBrightBlack);
+    TerminalBrightRed, "terminal.bright_red", Style::new(AnsiBrightRed);
+    TerminalBrightGreen, "terminal.bright_green", Style::new(AnsiBrightGreen);
+    TerminalBrightYellow, "terminal.bright_yellow", Style::new(AnsiBrightYellow);
+    TerminalBrightBlue, "terminal.bright_blue", Style::new(AnsiBrightBlue);
+    TerminalBrightMagenta, "terminal.bright_magenta", Style::new(AnsiBrightMagenta);
+    TerminalBrightCyan, "terminal.bright_cyan", Style::new(AnsiBrightCyan);
+    TerminalBrightWhite, "terminal.bright_white", Style::new(AnsiBrightWhite);
+    TerminalDimBlack, "terminal.dim_black", Style::new(AnsiDimBlack);
+    TerminalDimRed, "terminal.dim_red", Style::new(AnsiDimRed);
+    TerminalDimGreen, "terminal.dim_green", Style::new(AnsiDimGreen);
+    TerminalDimYellow, "terminal.dim_yellow", Style::new(AnsiDimYellow);
+    TerminalDimBlue, "terminal.dim_blue", Style::new(AnsiDimBlue);
+    TerminalDimMagenta, "terminal.dim_magenta", Style::new(AnsiDimMagenta);
+    TerminalDimCyan, "terminal.dim_cyan", Style::new(AnsiDimCyan);
+    TerminalDimWhite, "terminal.dim_white", Style::new(AnsiDimWhite);
+
+    // Syntax highlighting.
+    Attribute, "syntax.attribute", Style::new(AccentMagenta);
+    Boolean, "syntax.boolean", Style::new(AccentBlue);
+    Comment, "syntax.comment", Style::new(AccentGreen).italic();
+    CommentDoc, "syntax.comment.doc", Style::new(AccentGreen).italic();
+    Constant, "syntax.constant", Style::new(AccentSky);
+    Constructor, "syntax.constructor", Style::new(AccentYellow);
+    Embedded, "syntax.embedded", Style::new(FgBase);
+    Emphasis, "syntax.emphasis", Style::new(AccentSky);
+    EmphasisStrong, "syntax.emphasis.strong", Style::new(AccentPeriwinkle).weight(700);
+    Enum, "syntax.enum", Style::new(AccentPink);
+    Function, "syntax.function", Style::new(AccentYellow);
+    SyntaxHint, "syntax.hint", Style::new(AccentLime).weight(700);
+    Keyword, "syntax.keyword", Style::new(AccentBlue).weight(600);
+    Label, "syntax.label", Style::new(AccentSky);
+    LinkText, "syntax.link_text", Style::new(AccentOrange);
+    LinkUri, "syntax.link_uri", Style::new(AccentOrange);
+    Namespace, "syntax.namespace", Style::new(FgBase);
+    Number, "syntax.number", Style::new(AccentSage);
+    Operator, "syntax.operator", Style::new(AccentCornflower);
+    SyntaxPredictive, "syntax.predictive", Style::new(AccentSlate).italic();
+    Preproc, "syntax.preproc", Style::new(FgBase);
+    Primary, "syntax.primary", Style::new(FgBase);
+    Property, "syntax.property", Style::new(AccentLightBlue);
+    Punctuation, "syntax.punctuation", Style::new(FgBase);
+    PunctuationBracket, "syntax.punctuation.bracket", Style::new(FgBase);
+    PunctuationDelimiter, "syntax.punctuation.delimiter", Style::new(FgBase);
+    PunctuationListMarker, "syntax.punctuation.list_marker", Style::new(AccentPink);
+    PunctuationSpecial, "syntax.punctuation.special", Style::new(AccentCrimson);
+    Selector, "syntax.selector", Style::new(AccentLime);
+    SelectorPseudo, "syntax.selector.pseudo", Style::new(AccentSky);
+    String, "syntax.string", Style::new(AccentOrange);
+    StringEscape, "syntax.string.escape", Style::new(AccentGold);
+    StringRegex, "syntax.string.regex", Style::new(AccentRed);
+    StringSpecial, "syntax.string.special", Style::new(AccentMagenta);
+    StringSpecialSymbol, "syntax.string.special.symbol", Style::new(AccentSky);
+    Tag, "syntax.tag", Style::new(AccentBlue);
+    TextLiteral, "syntax.text.literal", Style::new(AccentOrange);
+    Title, "syntax.title", Style::new(AccentMagenta).weight(600);
+    Type, "syntax.type", Style::new(AccentTeal);
+    Variable, "syntax.variable", Style::new(AccentLightBlue);
+    VariableSpecial, "syntax.variable.special", Style::new(AccentCoral);
+    Variant, "syntax.variant", Style::new(AccentSky);
+}
+
+/// The role-to-style assignment that every emitter reads from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RoleMap {
+    styles: [Style; Role::COUNT],
+}
+
+impl RoleMap {
+    /// The assignments of the shipped theme.
+    pub const DEFAULT: RoleMap = {
+        let mut styles = [Style::new(Swatch::BgBase); Role::COUNT];
+        let mut i = 0;
+        while i < Role::COUNT {
+            styles[i] = Role::ALL[i].default_style();
+            i += 1;
+        }
+        RoleMap { styles }
+    };
+
+    /// Returns the style assigned to `role`.
+    pub const fn get(&self, role: Role) -> Style {
+        self.styles[role as usize]
+    }
+
+    /// Replaces the style assigned to `role`.
+    pub fn set(&mut self, role: Role, style: Style) {
+        self.styles[role as usize] = style;
+    }
+
+    /// Resolves the color of `role` against `palette`.
+    pub const fn color(&self, role: Role, palette: &Palette) -> Color {
+        self.get(role).color(palette)
+    }
+
+    /// Iterates over every role and its style, in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = (Role, Style)> + '_ {
+        Role::ALL.iter().map(|&role| (role, self.get(role)))
+    }
+}
+
+impl Default for RoleMap {
+    fn default() -> Self {
+        RoleMap::DEFAULT
+    }
+}
+
+impl Index<Role> for RoleMap {
+    type Output = Style;
+
+    fn index(&self, role: Role) -> &Style {
+        &self.styles[role as usize]
+    }
+}
+
+impl IndexMut<Role> for RoleMap {
+    fn index_mut(&mut self, role: Role) -> &mut Style {
+        &mut self.styles[role as usize]
+    }
+}
+
+/// The cursor and selection colors of one collaborator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Player {
+    pub cursor: Swatch,
+    pub background: Swatch,
+    pub selection: Swatch,
+}
+
+/// Collaborator colors; the first entry is the local user.
+pub const PLAYERS: [Player; 8] = {
+    use Swatch::*;
+    const fn player(cursor: Swatch, background: Swatch, selection: Swatch) -> Player {
+        Player {
+            cursor,
+            background,
+            selection,
+        }
+    }
+    [
+        player(AccentSky, TintBlue, ShadeBlue),
+        player(AnsiGreen, TintGreen, ShadeGreen),
+        player(AnsiRed, TintRed, ShadeRed),
+        player(AnsiYellow, TintYellow, ShadeYellow),
+        player(AnsiMagenta, TintPurple, ShadePurple),
+        player(AnsiCyan, TintCyan, ShadeCyan),
+        player(FgMuted, BgOverlay, BgSubtle),
+        player(AccentSalmon, TintCrimson, ShadeCrimson),
+    ]
+};
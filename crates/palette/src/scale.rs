@@ -0,0 +1,97 @@
+//! Numbered surface scales derived from a background and foreground.
+//!
+//! Editors need many near-background shades for chrome (panels, hovers,
+//! borders). Instead of picking them by hand per target, [`Scale`] spaces
+//! them evenly in OKLab lightness between the background and foreground, so
+//! `surface2` sits at the same perceptual step in every variant.
+
+use core::cmp::Ordering;
+
+use crate::roles::Role;
+use crate::{mix, Color, Theme};
+
+/// `surface0..=surface5` and `overlay0..=overlay2`, from background upwards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Scale {
+    pub surfaces: [Color; 6],
+    pub overlays: [Color; 3],
+}
+
+impl Scale {
+    /// Fraction of the background-to-foreground distance covered by surfaces.
+    pub const SURFACE_SPAN: f64 = 0.25;
+    /// Where the overlays start and how far they reach, as fractions.
+    pub const OVERLAY_RANGE: (f64, f64) = (0.35, 0.55);
+
+    /// Generates the scale between `background` and `foreground`.
+    ///
+    /// `surface0` is the background itself. Near black, evenly spaced
+    /// lightness can round several steps onto the same 8-bit color; such a
+    /// step is moved one code further towards the foreground, so every step
+    /// stays distinguishable from the one below it.
+    pub fn generate(background: Color, foreground: Color) -> Scale {
+        let (start, end) = Scale::OVERLAY_RANGE;
+        let fraction = |i: usize| match i {
+            0..=5 => Scale::SURFACE_SPAN * i as f64 / 5.0,
+            _ => start + (end - start) * (i - 6) as f64 / 2.0,
+        };
+        let mut steps = [background; 9];
+        for i in 1..steps.len() {
+            let step = mix(background, foreground, fraction(i));
+            steps[i] = if step == steps[i - 1] {
+                nudge(step, foreground)
+            } else {
+                step
+            };
+        }
+        Scale {
+            surfaces: core::array::from_fn(|i| steps[i]),
+            overlays: core::array::from_fn(|i| steps[6 + i]),
+        }
+    }
+
+    /// Generates the scale for a theme's editor background and text.
+    pub fn for_theme(theme: &Theme) -> Scale {
+        Scale::generate(
+            theme.opaque_color(Role::Background),
+            theme.opaque_color(Role::Text),
+        )
+    }
+
+    /// `surfaceN`, for `n` in `0..=5`.
+    pub fn surface(&self, n: usize) -> Color {
+        self.surfaces[n]
+    }
+
+    /// `overlayN`, for `n` in `0..=2`.
+    pub fn overlay(&self, n: usize) -> Color {
+        self.overlays[n]
+    }
+
+    /// The names of the steps, in [`Scale::iter`] order.
+    pub const NAMES: [&'static str; 9] = [
+        "surface0", "surface1", "surface2", "surface3", "surface4", "surface5", "overlay0",
+        "overlay1", "overlay2",
+    ];
+
+    /// Every step with its name (`"surface0"`, ..., `"overlay2"`).
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Color)> + '_ {
+        let colors = self.surfaces.iter().chain(&self.overlays);
+        Scale::NAMES.into_iter().zip(colors.copied())
+    }
+}
+
+/// `color` with every channel one code closer to `target`.
+fn nudge(color: Color, target: Color) -> Color {
+    let step = |channel: u8, towards: u8| match channel.cmp(&towards) {
+        Ordering::Less => channel + 1,
+        Ordering::Greater => channel - 1,
+        Ordering::Equal => channel,
+    };
+    Color {
+        r: step(color.r, target.r),
+        g: step(color.g, target.g),
+        b: step(color.b, target.b),
+        a: color.a,
+    }
+}
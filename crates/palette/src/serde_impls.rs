@@ -0,0 +1,161 @@
+//! `serde` support, behind the `serde` feature.
+//!
+//! Colors serialize as hex strings, swatches, roles and variants as their
+//! names, and [`Palette`] / [`RoleMap`] as maps keyed by those names. Maps may
+//! be partial when deserializing: entries that are not listed keep their
+//! shipped value, which lets override files name only what they change.
+
+use core::fmt;
+
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::roles::{Role, RoleMap};
+use crate::{Appearance, Color, Palette, Swatch, Tuning, Variant};
+
+/// Deserializes a value from its name via `parse`.
+struct Named<T> {
+    expecting: &'static str,
+    parse: fn(&str) -> Option<T>,
+}
+
+impl<T> Visitor<'_> for Named<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        (self.parse)(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+macro_rules! by_name {
+    ($ty:ty, $expecting:literal, $to:expr, $from:expr) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str($to(*self))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(Named {
+                    expecting: $expecting,
+                    parse: $from,
+                })
+            }
+        }
+    };
+}
+
+by_name!(
+    Swatch,
+    "a palette entry name",
+    Swatch::name,
+    Swatch::from_name
+);
+by_name!(Role, "a role name", Role::name, Role::from_name);
+by_name!(Variant, "a variant name", Variant::slug, Variant::from_slug);
+by_name!(
+    Appearance,
+    "`dark` or `light`",
+    Appearance::name,
+    |s| match s {
+        "dark" => Some(Appearance::Dark),
+        "light" => Some(Appearance::Light),
+        _ => None,
+    }
+);
+
+/// A [`Tuning`] factor, rejected unless [`Tuning::is_valid_factor`].
+pub(crate) fn tuning_factor<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    let factor = f64::deserialize(deserializer)?;
+    if Tuning::is_valid_factor(factor) {
+        Ok(factor)
+    } else {
+        Err(de::Error::invalid_value(
+            de::Unexpected::Float(factor),
+            &"a finite factor greater than 0",
+        ))
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Named {
+            expecting: "a hex color such as `#1E1E1E`",
+            parse: |s| Color::from_hex(s).ok(),
+        })
+    }
+}
+
+/// Deserializes a partial map into a copy of the shipped table.
+struct Partial<T, K, V> {
+    base: T,
+    expecting: &'static str,
+    set: fn(&mut T, K, V),
+}
+
+impl<'de, T, K: Deserialize<'de>, V: Deserialize<'de>> Visitor<'de> for Partial<T, K, V> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<T, A::Error> {
+        while let Some((key, value)) = map.next_entry()? {
+            (self.set)(&mut self.base, key, value);
+        }
+        Ok(self.base)
+    }
+}
+
+impl Serialize for Palette {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(Swatch::COUNT))?;
+        for (swatch, color) in self.iter() {
+            map.serialize_entry(&swatch, &color)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Palette {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(Partial {
+            base: Palette::BASE,
+            expecting: "a map of palette entry names to colors",
+            set: Palette::set,
+        })
+    }
+}
+
+impl Serialize for RoleMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(Role::COUNT))?;
+        for (role, style) in self.iter() {
+            map.serialize_entry(&role, &style)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RoleMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(Partial {
+            base: RoleMap::DEFAULT,
+            expecting: "a map of role names to styles",
+            set: RoleMap::set,
+        })
+    }
+}
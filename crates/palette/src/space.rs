@@ -0,0 +1,288 @@
+//! Color spaces used for color math.
+//!
+//! [`Color`] (8-bit sRGB, with `#RRGGBB` hex as its text form) is the storage
+//! format. [`LinearRgb`] is used for luminance and compositing, [`Hsl`] for
+//! interop with tools that speak it, and everything that reasons about
+//! lightness, chroma or hue perceptually converts through [`Oklab`] /
+//! [`Oklch`]. CIE [`Lab`] exists only to feed the CIEDE2000 formula.
+//!
+//! Every conversion back to [`Color`] round-trips within one 8-bit step per
+//! channel.
+
+use crate::math;
+use crate::Color;
+
+/// Linear-light sRGB, each channel nominally in `0.0..=1.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+/// The OKLab perceptual color space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+/// OKLab in polar form: lightness, chroma and hue in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+/// Hue (degrees), saturation and lightness, the cylindrical form of sRGB.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+/// CIELAB under D65, with `l` in `0.0..=100.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+fn decode(channel: u8) -> f64 {
+    let c = channel as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        math::powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+fn encode(channel: f64) -> u8 {
+    let c = channel.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * math::powf(c, 1.0 / 2.4) - 0.055
+    };
+    math::round(c * 255.0) as u8
+}
+
+impl LinearRgb {
+    /// Returns `true` if every channel lies within the sRGB gamut.
+    pub fn in_gamut(&self) -> bool {
+        const EPS: f64 = 1e-6;
+        [self.r, self.g, self.b]
+            .iter()
+            .all(|c| (-EPS..=1.0 + EPS).contains(c))
+    }
+}
+
+impl From<Color> for LinearRgb {
+    fn from(color: Color) -> Self {
+        LinearRgb {
+            r: decode(color.r),
+            g: decode(color.g),
+            b: decode(color.b),
+        }
+    }
+}
+
+impl From<LinearRgb> for Color {
+    /// Encodes to 8-bit sRGB, clipping out-of-gamut channels. Alpha is opaque.
+    fn from(rgb: LinearRgb) -> Self {
+        Color::rgb(encode(rgb.r), encode(rgb.g), encode(rgb.b))
+    }
+}
+
+impl From<LinearRgb> for Oklab {
+    fn from(rgb: LinearRgb) -> Self {
+        let l = 0.4122214708 * rgb.r + 0.5363325363 * rgb.g + 0.0514459929 * rgb.b;
+        let m = 0.2119034982 * rgb.r + 0.6806995451 * rgb.g + 0.1073969566 * rgb.b;
+        let s = 0.0883024619 * rgb.r + 0.2817188376 * rgb.g + 0.6299787005 * rgb.b;
+        let (l, m, s) = (math::cbrt(l), math::cbrt(m), math::cbrt(s));
+        Oklab {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+}
+
+impl From<Oklab> for LinearRgb {
+    fn from(lab: Oklab) -> Self {
+        let l = lab.l + 0.3963377774 * lab.a + 0.2158037573 * lab.b;
+        let m = lab.l - 0.1055613458 * lab.a - 0.0638541728 * lab.b;
+        let s = lab.l - 0.0894841775 * lab.a - 1.2914855480 * lab.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+        LinearRgb {
+            r: 4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            g: -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            b: -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        let h = math::atan2(lab.b, lab.a).to_degrees();
+        Oklch {
+            l: lab.l,
+            c: math::hypot(lab.a, lab.b),
+            h: if h < 0.0 { h + 360.0 } else { h },
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        let h = lch.h.to_radians();
+        let (sin, cos) = (math::sin(h), math::cos(h));
+        Oklab {
+            l: lch.l,
+            a: lch.c * cos,
+            b: lch.c * sin,
+        }
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        let [r, g, b] = [color.r, color.g, color.b].map(|c| c as f64 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        if d == 0.0 {
+            return Hsl { h: 0.0, s: 0.0, l };
+        }
+        let s = d / (1.0 - math::abs(2.0 * l - 1.0));
+        let h = if max == r {
+            math::rem_euclid((g - b) / d, 6.0)
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        Hsl { h: h * 60.0, s, l }
+    }
+}
+
+impl From<Hsl> for Color {
+    /// Converts to an opaque color.
+    fn from(hsl: Hsl) -> Self {
+        let (s, l) = (hsl.s.clamp(0.0, 1.0), hsl.l.clamp(0.0, 1.0));
+        let c = (1.0 - math::abs(2.0 * l - 1.0)) * s;
+        let h = math::rem_euclid(hsl.h, 360.0) / 60.0;
+        let x = c * (1.0 - math::abs(math::rem_euclid(h, 2.0) - 1.0));
+        let (r, g, b) = match h as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c / 2.0;
+        let channel = |v: f64| math::round((v + m) * 255.0).clamp(0.0, 255.0) as u8;
+        Color::rgb(channel(r), channel(g), channel(b))
+    }
+}
+
+impl Oklch {
+    /// Reduces chroma, keeping lightness and hue, until the color fits sRGB.
+    pub fn into_gamut(self) -> Oklch {
+        let l = self.l.clamp(0.0, 1.0);
+        let fits = |c: f64| LinearRgb::from(Oklab::from(Oklch { l, c, h: self.h })).in_gamut();
+        if fits(self.c) {
+            return Oklch { l, ..self };
+        }
+        let (mut lo, mut hi) = (0.0, self.c);
+        for _ in 0..24 {
+            let mid = (lo + hi) / 2.0;
+            if fits(mid) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Oklch {
+            l,
+            c: lo,
+            h: self.h,
+        }
+    }
+}
+
+impl From<LinearRgb> for Lab {
+    fn from(rgb: LinearRgb) -> Self {
+        // sRGB to XYZ, divided by the D65 white point.
+        let x = (0.4124564 * rgb.r + 0.3575761 * rgb.g + 0.1804375 * rgb.b) / 0.95047;
+        let y = 0.2126729 * rgb.r + 0.7151522 * rgb.g + 0.0721750 * rgb.b;
+        let z = (0.0193339 * rgb.r + 0.1191920 * rgb.g + 0.9503041 * rgb.b) / 1.08883;
+        let f = |t: f64| {
+            const DELTA: f64 = 6.0 / 29.0;
+            if t > DELTA * DELTA * DELTA {
+                math::cbrt(t)
+            } else {
+                t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+            }
+        };
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+
+impl Color {
+    /// Converts to linear-light sRGB, ignoring alpha.
+    pub fn to_linear(&self) -> LinearRgb {
+        LinearRgb::from(*self)
+    }
+
+    /// Creates an opaque color from linear-light sRGB, clipping channels.
+    pub fn from_linear(rgb: LinearRgb) -> Color {
+        rgb.into()
+    }
+
+    /// Converts to HSL, ignoring alpha.
+    pub fn to_hsl(&self) -> Hsl {
+        Hsl::from(*self)
+    }
+
+    /// Creates an opaque color from HSL.
+    pub fn from_hsl(hsl: Hsl) -> Color {
+        hsl.into()
+    }
+
+    /// Converts to OKLab, ignoring alpha.
+    pub fn to_oklab(&self) -> Oklab {
+        LinearRgb::from(*self).into()
+    }
+
+    /// Converts to CIELAB, ignoring alpha.
+    pub fn to_lab(&self) -> Lab {
+        LinearRgb::from(*self).into()
+    }
+
+    /// Converts to OKLCH, ignoring alpha.
+    pub fn to_oklch(&self) -> Oklch {
+        self.to_oklab().into()
+    }
+
+    /// Creates an opaque color from OKLab, clipping out-of-gamut channels.
+    pub fn from_oklab(lab: Oklab) -> Color {
+        LinearRgb::from(lab).into()
+    }
+
+    /// Creates an opaque color from OKLCH, reducing chroma to fit the gamut.
+    pub fn from_oklch(lch: Oklch) -> Color {
+        Color::from_oklab(lch.into_gamut().into())
+    }
+}
@@ -0,0 +1,198 @@
+//! Every named entry of the Zero Trust palette.
+//!
+//! Entries are grouped by purpose into modules (`bg`, `fg`, `accent`, `tint`,
+//! `shade`, `vcs`, `state`, `ansi`) and are also enumerated by [`Swatch`], whose
+//! dotted names (`"accent.blue"`) are the stable identifiers used by theme
+//! sources and tooling.
+
+use crate::Color;
+
+macro_rules! swatches {
+    ($(
+        $(#[$group_doc:meta])*
+        $group:ident {
+            $($konst:ident => $variant:ident, $name:literal, $hex:literal;)*
+        }
+    )*) => {
+        $(
+            $(#[$group_doc])*
+            pub mod $group {
+                use crate::Color;
+                $(
+                    #[doc = concat!("`", $name, "`")]
+                    pub const $konst: Color = Color::from_u32($hex);
+                )*
+            }
+        )*
+
+        /// Identifies one entry of the palette.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum Swatch {
+            $($($variant,)*)*
+        }
+
+        impl Swatch {
+            /// Every swatch, in palette order.
+            pub const ALL: &'static [Swatch] = &[$($(Swatch::$variant,)*)*];
+
+            /// The number of swatches in the palette.
+            pub const COUNT: usize = Swatch::ALL.len();
+
+            /// The dotted name of the swatch, e.g. `"accent.blue"`.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $($(Swatch::$variant => $name,)*)*
+                }
+            }
+
+            /// The color of the swatch in the base (dark) palette.
+            pub const fn color(self) -> Color {
+                match self {
+                    $($(Swatch::$variant => $group::$konst,)*)*
+                }
+            }
+
+            /// Looks a swatch up by its dotted name.
+            pub fn from_name(name: &str) -> Option<Swatch> {
+                match name {
+                    $($($name => Some(Swatch::$variant),)*)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl core::fmt::Display for Swatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+swatches! {
+    /// Neutral background surfaces, from the editor canvas upwards.
+    bg {
+        BASE => BgBase, "bg.base", 0x1E1E1E;
+        RAISED => BgRaised, "bg.raised", 0x252525;
+        SUBTLE => BgSubtle, "bg.subtle", 0x2A2A2A;
+        SURFACE => BgSurface, "bg.surface", 0x2D2D2D;
+        OVERLAY => BgOverlay, "bg.overlay", 0x3A3A3A;
+        BORDER => BgBorder, "bg.border", 0x404040;
+        ACTIVE => BgActive, "bg.active", 0x4A4A4A;
+        HIGHLIGHT => BgHighlight, "bg.highlight", 0x515C6A;
+        THUMB => BgThumb, "bg.thumb", 0x5A5A5A;
+        GUIDE => BgGuide, "bg.guide", 0x606060;
+        THUMB_HOVER => BgThumbHover, "bg.thumb_hover", 0x6A6A6A;
+        GUIDE_HOVER => BgGuideHover, "bg.guide_hover", 0x707070;
+        LINE => BgLine, "bg.line", 0x3E3D32;
+    }
+
+    /// Text and icon foregrounds, from brightest to faintest.
+    fg {
+        BRIGHT => FgBright, "fg.bright", 0xFFFFFF;
+        BASE => FgBase, "fg.base", 0xCCCCCC;
+        MUTED => FgMuted, "fg.muted", 0x8B8B8B;
+        SUBTLE => FgSubtle, "fg.subtle", 0x6B6B6B;
+    }
+
+    /// Saturated colors used for syntax and UI highlights.
+    accent {
+        BLUE => AccentBlue, "accent.blue", 0x569CD6;
+        LIGHT_BLUE => AccentLightBlue, "accent.light_blue", 0x9CDCFE;
+        SKY => AccentSky, "accent.sky", 0x4FC1FF;
+        AZURE => AccentAzure, "accent.azure", 0x007ACC;
+        CORNFLOWER => AccentCornflower, "accent.cornflower", 0x79C0FF;
+        PERIWINKLE => AccentPeriwinkle, "accent.periwinkle", 0x74B7FF;
+        TEAL => AccentTeal, "accent.teal", 0x4EC9B0;
+        YELLOW => AccentYellow, "accent.yellow", 0xDCDCAA;
+        ORANGE => AccentOrange, "accent.orange", 0xCE9178;
+        GOLD => AccentGold, "accent.gold", 0xD7BA7D;
+        RED => AccentRed, "accent.red", 0xD16969;
+        CRIMSON => AccentCrimson, "accent.crimson", 0xF85149;
+        CORAL => AccentCoral, "accent.coral", 0xFF7B72;
+        SALMON => AccentSalmon, "accent.salmon", 0xFF8080;
+        PINK => AccentPink, "accent.pink", 0xFF6B9D;
+        MAGENTA => AccentMagenta, "accent.magenta", 0xC586C0;
+        GREEN => AccentGreen, "accent.green", 0x6A9955;
+        SAGE => AccentSage, "accent.sage", 0xB5CEA8;
+        LIME => AccentLime, "accent.lime", 0x7EE787;
+        SLATE => AccentSlate, "accent.slate", 0x8B949E;
+    }
+
+    /// Dark, strongly tinted backgrounds for highlighted regions.
+    tint {
+        BLUE => TintBlue, "tint.blue", 0x264F78;
+        GREEN => TintGreen, "tint.green", 0x3A5A3A;
+        RED => TintRed, "tint.red", 0x5A3A3A;
+        YELLOW => TintYellow, "tint.yellow", 0x5A5A2A;
+        PURPLE => TintPurple, "tint.purple", 0x4A3A5A;
+        CYAN => TintCyan, "tint.cyan", 0x2A5A5A;
+        CRIMSON => TintCrimson, "tint.crimson", 0x5A2A2A;
+    }
+
+    /// Darker, quieter versions of the tints, used for selections.
+    shade {
+        BLUE => ShadeBlue, "shade.blue", 0x1A4A6B;
+        GREEN => ShadeGreen, "shade.green", 0x2A4A2A;
+        RED => ShadeRed, "shade.red", 0x4A2A2A;
+        YELLOW => ShadeYellow, "shade.yellow", 0x4A4A1A;
+        PURPLE => ShadePurple, "shade.purple", 0x3A2A4A;
+        CYAN => ShadeCyan, "shade.cyan", 0x1A4A4A;
+        CRIMSON => ShadeCrimson, "shade.crimson", 0x4A1A1A;
+    }
+
+    /// Version control markers.
+    vcs {
+        ADDED => VcsAdded, "vcs.added", 0xA3BE8C;
+        MODIFIED => VcsModified, "vcs.modified", 0xEBCB8B;
+        DELETED => VcsDeleted, "vcs.deleted", 0xBF616A;
+    }
+
+    /// Diagnostic state ramps, derived by [`crate::ramp`] in every variant.
+    state {
+        SUCCESS => StateSuccess, "state.success", 0x90A959;
+        SUCCESS_EMPHASIZED => StateSuccessEmphasized, "state.success_emphasized", 0xA9C272;
+        SUCCESS_MUTED => StateSuccessMuted, "state.success_muted", 0x59663F;
+        SUCCESS_TINT => StateSuccessTint, "state.success_tint", 0x3A5A3A;
+        WARNING => StateWarning, "state.warning", 0xF4D03F;
+        WARNING_EMPHASIZED => StateWarningEmphasized, "state.warning_emphasized", 0xFFEDAA;
+        WARNING_MUTED => StateWarningMuted, "state.warning_muted", 0x8B7A39;
+        WARNING_TINT => StateWarningTint, "state.warning_tint", 0x5A5A2A;
+        ERROR => StateError, "state.error", 0xF48771;
+        ERROR_EMPHASIZED => StateErrorEmphasized, "state.error_emphasized", 0xFFAB99;
+        ERROR_MUTED => StateErrorMuted, "state.error_muted", 0x8D564A;
+        ERROR_TINT => StateErrorTint, "state.error_tint", 0x5A3A3A;
+        INFO => StateInfo, "state.info", 0x4FC1FF;
+        INFO_EMPHASIZED => StateInfoEmphasized, "state.info_emphasized", 0x98D8FF;
+        INFO_MUTED => StateInfoMuted, "state.info_muted", 0x3E7291;
+        INFO_TINT => StateInfoTint, "state.info_tint", 0x1A4A6B;
+    }
+
+    /// The terminal palette: the eight ANSI colors plus bright and dim forms.
+    ansi {
+        BLACK => AnsiBlack, "ansi.black", 0x2D2D2D;
+        RED => AnsiRed, "ansi.red", 0xF48771;
+        GREEN => AnsiGreen, "ansi.green", 0x90A959;
+        YELLOW => AnsiYellow, "ansi.yellow", 0xF4D03F;
+        BLUE => AnsiBlue, "ansi.blue", 0x4FC1FF;
+        MAGENTA => AnsiMagenta, "ansi.magenta", 0xB78CF2;
+        CYAN => AnsiCyan, "ansi.cyan", 0x7FDBCA;
+        WHITE => AnsiWhite, "ansi.white", 0xFFFFFF;
+        BRIGHT_BLACK => AnsiBrightBlack, "ansi.bright_black", 0x6B6B6B;
+        BRIGHT_RED => AnsiBrightRed, "ansi.bright_red", 0xFF6B6B;
+        BRIGHT_GREEN => AnsiBrightGreen, "ansi.bright_green", 0xA8D08D;
+        BRIGHT_YELLOW => AnsiBrightYellow, "ansi.bright_yellow", 0xFFE66D;
+        BRIGHT_BLUE => AnsiBrightBlue, "ansi.bright_blue", 0x7DC8FF;
+        BRIGHT_MAGENTA => AnsiBrightMagenta, "ansi.bright_magenta", 0xC9A8FF;
+        BRIGHT_CYAN => AnsiBrightCyan, "ansi.bright_cyan", 0x9FFFE0;
+        BRIGHT_WHITE => AnsiBrightWhite, "ansi.bright_white", 0xFFFFFF;
+        DIM_BLACK => AnsiDimBlack, "ansi.dim_black", 0x1E1E1E;
+        DIM_RED => AnsiDimRed, "ansi.dim_red", 0xD73A49;
+        DIM_GREEN => AnsiDimGreen, "ansi.dim_green", 0x28A745;
+        DIM_YELLOW => AnsiDimYellow, "ansi.dim_yellow", 0xF9C513;
+        DIM_BLUE => AnsiDimBlue, "ansi.dim_blue", 0x007ACC;
+        DIM_MAGENTA => AnsiDimMagenta, "ansi.dim_magenta", 0xB392F0;
+        DIM_CYAN => AnsiDimCyan, "ansi.dim_cyan", 0x39C5CF;
+        DIM_WHITE => AnsiDimWhite, "ansi.dim_white", 0xCCCCCC;
+    }
+}
@@ -0,0 +1,92 @@
+//! Ready-made styles for CLI and TUI crates, behind the `anstyle` and
+//! `ratatui` features.
+//!
+//! Surface roles set the background color, every other role the foreground.
+//! Translucent colors are flattened over their backdrop first, since
+//! terminals have no notion of alpha. The `From<Role>` impls use the dark
+//! theme; [`Theme`] methods cover the other variants.
+
+use crate::roles::Role;
+use crate::{Color, Theme, Variant};
+
+fn dark() -> Theme {
+    Theme::new(Variant::Dark)
+}
+
+#[cfg(feature = "anstyle")]
+mod anstyle_impls {
+    use super::*;
+    use anstyle::{Effects, RgbColor, Style};
+
+    impl From<Color> for anstyle::Color {
+        fn from(color: Color) -> Self {
+            anstyle::Color::Rgb(RgbColor(color.r, color.g, color.b))
+        }
+    }
+
+    impl Theme {
+        /// The `anstyle` style for `role` in this theme.
+        pub fn anstyle(&self, role: Role) -> Style {
+            let style = self.style(role);
+            let color = Some(self.opaque_color(role).into());
+            let mut effects = Effects::new();
+            if style.italic {
+                effects |= Effects::ITALIC;
+            }
+            if style.is_bold() {
+                effects |= Effects::BOLD;
+            }
+            let base = Style::new().effects(effects);
+            if role.is_surface() {
+                base.bg_color(color)
+            } else {
+                base.fg_color(color)
+            }
+        }
+    }
+
+    impl From<Role> for Style {
+        fn from(role: Role) -> Self {
+            dark().anstyle(role)
+        }
+    }
+}
+
+#[cfg(feature = "ratatui")]
+mod ratatui_impls {
+    use super::*;
+    use ratatui::style::{Modifier, Style};
+
+    impl From<Color> for ratatui::style::Color {
+        fn from(color: Color) -> Self {
+            ratatui::style::Color::Rgb(color.r, color.g, color.b)
+        }
+    }
+
+    impl Theme {
+        /// The `ratatui` style for `role` in this theme.
+        pub fn ratatui_style(&self, role: Role) -> Style {
+            let style = self.style(role);
+            let color = self.opaque_color(role);
+            let mut modifier = Modifier::empty();
+            if style.italic {
+                modifier |= Modifier::ITALIC;
+            }
+            if style.is_bold() {
+                modifier |= Modifier::BOLD;
+            }
+            let base = Style::new().add_modifier(modifier);
+            if role.is_surface() {
+                base.bg(color.into())
+            } else {
+                base.fg(color.into())
+            }
+        }
+    }
+
+    impl From<Role> for Style {
+        fn from(role: Role) -> Self {
+            dark().ratatui_style(role)
+        }
+    }
+}
@@ -0,0 +1,465 @@
+//! Theme variants derived from the base palette.
+//!
+//! Only the dark palette is designed by hand. Every other variant is computed
+//! from it, so a palette tweak propagates to all of them.
+
+use crate::contrast::{ensure_contrast, AA, AAA};
+use crate::math;
+use crate::ramp;
+use crate::roles::{Role, RoleMap, Style};
+use crate::space::Oklch;
+use crate::{Color, Palette, Swatch};
+
+/// Whether a theme is meant for a dark or a light background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Appearance {
+    Dark,
+    Light,
+}
+
+impl Appearance {
+    /// The lowercase name, as used by most theme formats.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Appearance::Dark => "dark",
+            Appearance::Light => "light",
+        }
+    }
+
+    /// The variant shown in this appearance when only dark and light are
+    /// told apart, e.g. by the operating system.
+    pub const fn variant(self) -> Variant {
+        match self {
+            Appearance::Dark => Variant::Dark,
+            Appearance::Light => Variant::Light,
+        }
+    }
+}
+
+/// One of the shipped theme variants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Variant {
+    Dark,
+    Light,
+    HighContrast,
+    Dimmed,
+    Oled,
+}
+
+impl Variant {
+    /// Every variant, in the order they are emitted.
+    pub const ALL: &'static [Variant] = &[
+        Variant::Dark,
+        Variant::Light,
+        Variant::HighContrast,
+        Variant::Dimmed,
+        Variant::Oled,
+    ];
+
+    /// The display name, e.g. `"Zero Trust Dark"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Variant::Dark => "Zero Trust Dark",
+            Variant::Light => "Zero Trust Light",
+            Variant::HighContrast => "Zero Trust High Contrast",
+            Variant::Dimmed => "Zero Trust Dimmed",
+            Variant::Oled => "Zero Trust OLED",
+        }
+    }
+
+    /// The part of the display name after the theme family, e.g. `"Dark"`.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Variant::Dark => "Dark",
+            Variant::Light => "Light",
+            Variant::HighContrast => "High Contrast",
+            Variant::Dimmed => "Dimmed",
+            Variant::Oled => "OLED",
+        }
+    }
+
+    /// The short identifier used in file names and on the command line.
+    pub const fn slug(self) -> &'static str {
+        match self {
+            Variant::Dark => "dark",
+            Variant::Light => "light",
+            Variant::HighContrast => "high-contrast",
+            Variant::Dimmed => "dimmed",
+            Variant::Oled => "oled",
+        }
+    }
+
+    /// Looks a variant up by its slug.
+    pub fn from_slug(slug: &str) -> Option<Variant> {
+        Variant::ALL.iter().copied().find(|v| v.slug() == slug)
+    }
+
+    pub const fn appearance(self) -> Appearance {
+        match self {
+            Variant::Dark => Appearance::Dark,
+            Variant::Light => Appearance::Light,
+            Variant::HighContrast | Variant::Dimmed | Variant::Oled => Appearance::Dark,
+        }
+    }
+
+    /// Derives the variant's palette and role map from the base ones.
+    pub fn derive(
+        self,
+        base: &Palette,
+        roles: &RoleMap,
+        transforms: &Transforms,
+    ) -> (Palette, RoleMap) {
+        let (palette, roles) = match self {
+            Variant::Dark => (base.clone(), roles.clone()),
+            Variant::Light => (transforms.light.apply(base, roles), roles.clone()),
+            Variant::HighContrast => (transforms.high_contrast.apply(base, roles), roles.clone()),
+            Variant::Dimmed => (transforms.dimmed.apply(base), roles.clone()),
+            Variant::Oled => transforms.oled.apply(base, roles),
+        };
+        (ramp::apply(&palette), roles)
+    }
+}
+
+/// The parameters of every derived variant.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Transforms {
+    pub light: Inversion,
+    pub high_contrast: HighContrast,
+    pub dimmed: Dimmed,
+    pub oled: Oled,
+}
+
+/// Turns a dark palette into a light one by remapping OKLCH lightness.
+///
+/// Lightness is mapped linearly so that `bg.base` lands on `background` and
+/// `fg.base` on `foreground`; neutral colors keep their position relative to
+/// those two anchors. Saturated colors sit higher in a dark palette than they
+/// can in a light one, so their distance from the background is scaled down
+/// by `chroma_compression` to keep them from collapsing into near-black.
+/// Hue is kept as is and chroma only drops where the new lightness cannot
+/// hold it inside sRGB. Accents that come out too pale for a light
+/// background are then darkened until every syntax role reaches `min_ratio`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Inversion {
+    /// Target lightness for the base background.
+    pub background: f64,
+    /// Target lightness for the base foreground.
+    pub foreground: f64,
+    /// How much less saturated colors move than neutrals, in `0.0..1.0`.
+    pub chroma_compression: f64,
+    /// The minimum WCAG contrast ratio for syntax roles.
+    pub min_ratio: f64,
+}
+
+impl Default for Inversion {
+    fn default() -> Self {
+        Inversion {
+            background: 0.985,
+            foreground: 0.30,
+            chroma_compression: 0.2,
+            min_ratio: AA,
+        }
+    }
+}
+
+impl Inversion {
+    pub fn apply(&self, palette: &Palette, roles: &RoleMap) -> Palette {
+        let bg = palette[Swatch::BgBase].to_oklch().l;
+        let fg = palette[Swatch::FgBase].to_oklch().l;
+        let slope = (self.foreground - self.background) / (fg - bg);
+        let mut light = palette.map(|_, color| {
+            color.map_oklch(|lch| {
+                let saturation = ((lch.c - 0.02) / 0.06).clamp(0.0, 1.0);
+                let slope = slope * (1.0 - self.chroma_compression * saturation);
+                let l = self.background + (lch.l - bg) * slope;
+                Oklch { l, ..lch }
+            })
+        });
+        // ANSI black and white are named for what they look like, not for
+        // their lightness rank, so black always takes the darker of the pair.
+        for (black, white) in [
+            (Swatch::AnsiBlack, Swatch::AnsiWhite),
+            (Swatch::AnsiBrightBlack, Swatch::AnsiBrightWhite),
+            (Swatch::AnsiDimBlack, Swatch::AnsiDimWhite),
+        ] {
+            if light[black].to_oklch().l > light[white].to_oklch().l {
+                let (b, w) = (light[black], light[white]);
+                light[black] = w;
+                light[white] = b;
+            }
+        }
+        let background = roles.color(Role::Background, &light);
+        for role in Role::ALL.iter().filter(|r| r.is_syntax()) {
+            let swatch = roles.get(*role).swatch;
+            light[swatch] = ensure_contrast(light[swatch], background, self.min_ratio);
+        }
+        light
+    }
+}
+
+/// Raises foreground/background contrast for low-vision users.
+///
+/// Background surfaces are deepened by scaling their OKLCH lightness, then
+/// every color used for syntax or body text is lifted until it reaches
+/// `min_ratio` against the editor background.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct HighContrast {
+    /// The minimum WCAG contrast ratio for text roles.
+    pub min_ratio: f64,
+    /// Factor applied to the lightness of `bg.*` swatches.
+    pub background_scale: f64,
+}
+
+impl Default for HighContrast {
+    fn default() -> Self {
+        HighContrast {
+            min_ratio: AAA,
+            background_scale: 0.6,
+        }
+    }
+}
+
+impl HighContrast {
+    pub fn apply(&self, palette: &Palette, roles: &RoleMap) -> Palette {
+        let mut palette = palette.map(|swatch, color| {
+            if !swatch.name().starts_with("bg.") {
+                return color;
+            }
+            color.map_oklch(|lch| Oklch {
+                l: lch.l * self.background_scale,
+                ..lch
+            })
+        });
+        let background = roles.color(Role::Background, &palette);
+        let text = [Role::Text, Role::TerminalForeground];
+        for role in Role::ALL.iter().filter(|r| r.is_syntax()).chain(&text) {
+            let swatch = roles.get(*role).swatch;
+            palette[swatch] = ensure_contrast(palette[swatch], background, self.min_ratio);
+        }
+        palette
+    }
+}
+
+/// Softens the palette for long sessions: less chroma, less contrast.
+///
+/// Chroma is multiplied by `chroma`, and lightness is pulled towards the
+/// midpoint between `bg.base` and `fg.base` by `contrast`, so the relative
+/// order of every color is kept.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Dimmed {
+    /// Factor applied to OKLCH chroma.
+    pub chroma: f64,
+    /// Factor applied to each color's lightness distance from the midpoint.
+    pub contrast: f64,
+}
+
+impl Default for Dimmed {
+    fn default() -> Self {
+        Dimmed {
+            chroma: 0.7,
+            contrast: 0.85,
+        }
+    }
+}
+
+impl Dimmed {
+    pub fn apply(&self, palette: &Palette) -> Palette {
+        let bg = palette[Swatch::BgBase].to_oklch().l;
+        let fg = palette[Swatch::FgBase].to_oklch().l;
+        let pivot = (bg + fg) / 2.0;
+        palette.map(|_, color| {
+            color.map_oklch(|lch| Oklch {
+                l: pivot + (lch.l - pivot) * self.contrast,
+                c: lch.c * self.chroma,
+                h: lch.h,
+            })
+        })
+    }
+}
+
+/// Collapses every background surface to true black for OLED screens.
+///
+/// Without lighter panels to tell surfaces apart, the separation moves to the
+/// borders: they become the base foreground at a low alpha, so they read as
+/// the same subtle line on every surface they sit on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Oled {
+    /// Alpha of the foreground used for primary borders, in `0.0..=1.0`.
+    pub border_strength: f64,
+}
+
+impl Default for Oled {
+    fn default() -> Self {
+        Oled {
+            border_strength: 0.18,
+        }
+    }
+}
+
+impl Oled {
+    /// The roles that paint a surface and turn black.
+    pub const SURFACES: &'static [Role] = &[
+        Role::Background,
+        Role::Surface,
+        Role::SurfaceElevated,
+        Role::Panel,
+        Role::Toolbar,
+        Role::Titlebar,
+        Role::TitlebarInactive,
+        Role::Statusline,
+        Role::Tabline,
+        Role::TabActive,
+        Role::TabInactive,
+        Role::Gutter,
+        Role::Subheader,
+        Role::Element,
+        Role::ElementDisabled,
+        Role::Ghost,
+        Role::ScrollbarTrack,
+        Role::TerminalBackground,
+    ];
+
+    pub fn apply(&self, palette: &Palette, roles: &RoleMap) -> (Palette, RoleMap) {
+        let mut palette = palette.clone();
+        let mut roles = roles.clone();
+        let black = roles.get(Role::Background).swatch;
+        palette[black] = Color::BLACK;
+        for &role in Oled::SURFACES {
+            roles[role].swatch = black;
+        }
+        let border = |strength: f64| {
+            let alpha = math::round(strength * 255.0).clamp(0.0, 255.0) as u8;
+            Style::new(Swatch::FgBase).with_alpha(alpha)
+        };
+        roles.set(Role::Border, border(self.border_strength));
+        roles.set(Role::Split, border(self.border_strength * 0.75));
+        roles.set(Role::BorderVariant, border(self.border_strength * 0.5));
+        roles.set(
+            Role::ScrollbarTrackBorder,
+            border(self.border_strength * 0.5),
+        );
+        (palette, roles)
+    }
+}
+
+/// User adjustments for bright or washed-out displays.
+///
+/// Applied to the base palette before any variant is derived. Both factors
+/// scale OKLCH components and leave hue alone, so colors get brighter or
+/// more vivid without drifting; `1.0` is a no-op.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Tuning {
+    /// Factor applied to OKLCH lightness.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serde_impls::tuning_factor")
+    )]
+    pub brightness: f64,
+    /// Factor applied to OKLCH chroma.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serde_impls::tuning_factor")
+    )]
+    pub saturation: f64,
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Tuning {
+            brightness: 1.0,
+            saturation: 1.0,
+        }
+    }
+}
+
+impl Tuning {
+    /// Whether `factor` can scale a component: finite and above zero, so
+    /// no color collapses to black or gray, or turns into NaN.
+    pub fn is_valid_factor(factor: f64) -> bool {
+        factor.is_finite() && factor > 0.0
+    }
+
+    /// Returns `true` if applying would change nothing.
+    pub fn is_identity(&self) -> bool {
+        *self == Tuning::default()
+    }
+
+    pub fn apply(&self, palette: &Palette) -> Palette {
+        if self.is_identity() {
+            return palette.clone();
+        }
+        palette.map(|_, color| {
+            color.map_oklch(|lch| Oklch {
+                l: (lch.l * self.brightness).clamp(0.0, 1.0),
+                c: lch.c * self.saturation,
+                h: lch.h,
+            })
+        })
+    }
+}
+
+/// A fully resolved theme: a variant's palette plus the role assignments.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    pub variant: Variant,
+    pub palette: Palette,
+    pub roles: RoleMap,
+}
+
+impl Theme {
+    /// Builds `variant` from the shipped palette, role map and transforms.
+    pub fn new(variant: Variant) -> Self {
+        Theme::from_parts(
+            variant,
+            &Palette::BASE,
+            RoleMap::DEFAULT,
+            &Transforms::default(),
+        )
+    }
+
+    /// Builds `variant` from a custom base palette, role map and transforms.
+    pub fn from_parts(
+        variant: Variant,
+        base: &Palette,
+        roles: RoleMap,
+        transforms: &Transforms,
+    ) -> Self {
+        let (palette, roles) = variant.derive(base, &roles, transforms);
+        Theme {
+            variant,
+            palette,
+            roles,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.variant.name()
+    }
+
+    pub fn appearance(&self) -> Appearance {
+        self.variant.appearance()
+    }
+
+    /// The style assigned to `role`.
+    pub fn style(&self, role: Role) -> Style {
+        self.roles.get(role)
+    }
+
+    /// The resolved color of `role`.
+    pub fn color(&self, role: Role) -> Color {
+        self.roles.color(role, &self.palette)
+    }
+}
@@ -0,0 +1,42 @@
+use zero_trust_palette::brackets::{self, DEFAULT_LEVELS};
+use zero_trust_palette::contrast::{contrast_ratio, AA};
+use zero_trust_palette::difference::delta_e_2000;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+/// The smallest CIEDE2000 difference allowed between two levels.
+const MIN_DISTANCE: f64 = 10.0;
+
+#[test]
+fn rainbow_levels_read_and_stay_apart() {
+    for &variant in Variant::ALL {
+        let theme = Theme::new(variant);
+        let background = theme.opaque_color(Role::Background);
+        let levels: [Color; DEFAULT_LEVELS] = brackets::rainbow(&theme);
+        for (i, &level) in levels.iter().enumerate() {
+            let ratio = contrast_ratio(level, background);
+            assert!(ratio >= AA, "level {i} of {variant:?} is {ratio:.2}:1");
+            for (j, &other) in levels.iter().enumerate().skip(i + 1) {
+                let distance = delta_e_2000(level, other);
+                assert!(
+                    distance >= MIN_DISTANCE,
+                    "levels {i} and {j} of {variant:?} are {distance:.1} apart"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn rainbow_hues_are_evenly_spaced() {
+    for &variant in Variant::ALL {
+        let hues: [_; DEFAULT_LEVELS] = brackets::hues(&Theme::new(variant));
+        let step = 360.0 / DEFAULT_LEVELS as f64;
+        for pair in hues.windows(2) {
+            let gap = (pair[1].h - pair[0].h).rem_euclid(360.0);
+            assert!((gap - step).abs() < 1e-9, "{variant:?}: gap of {gap}");
+        }
+        assert_eq!(hues[0].l, hues[1].l);
+        assert_eq!(hues[0].c, hues[1].c);
+    }
+}
@@ -0,0 +1,43 @@
+use zero_trust_palette::Color;
+
+const HALF: u8 = 0x80;
+
+#[test]
+fn half_white_over_black_is_middle_gray() {
+    let white = Color::rgba(255, 255, 255, HALF);
+    assert_eq!(white.over(Color::BLACK), Color::hex("#808080"));
+}
+
+#[test]
+fn transparent_colors_leave_the_backdrop_alone() {
+    let backdrop = Color::hex("#1E1E1E");
+    assert_eq!(Color::rgba(255, 0, 0, 0).over(backdrop), backdrop);
+    let translucent = Color::rgba(0x56, 0x9C, 0xD6, HALF);
+    assert_eq!(Color::rgba(255, 0, 0, 0).over(translucent), translucent);
+}
+
+#[test]
+fn stacked_layers_accumulate_alpha() {
+    let red = Color::rgba(255, 0, 0, HALF);
+    let blue = Color::rgba(0, 0, 255, HALF);
+    let layered = red.over(blue);
+    // 1 - (1 - a)² for two layers of alpha a.
+    let a = HALF as f64 / 255.0;
+    assert_eq!(
+        layered.a,
+        (255.0 * (1.0 - (1.0 - a) * (1.0 - a))).round() as u8
+    );
+    assert!(layered.r > layered.b, "the top layer dominates: {layered}");
+
+    // Flattening the pair first or layer by layer gives the same color.
+    let backdrop = Color::hex("#1E1E1E");
+    let (together, apart) = (layered.over(backdrop), red.over(blue.over(backdrop)));
+    for (x, y) in [
+        (together.r, apart.r),
+        (together.g, apart.g),
+        (together.b, apart.b),
+    ] {
+        assert!(x.abs_diff(y) <= 1, "{together} vs {apart}");
+    }
+    assert!(together.is_opaque());
+}
@@ -0,0 +1,56 @@
+use zero_trust_palette::difference::{DuplicateLint, Metric};
+use zero_trust_palette::space::Lab;
+use zero_trust_palette::{accent, Palette, Swatch};
+
+/// Reference pairs from Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference
+/// Formula: Implementation Notes, Supplementary Test Data".
+#[test]
+fn ciede2000_matches_reference_data() {
+    let cases = [
+        ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+        ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+        ((50.0, 2.49, -0.001), (50.0, -2.49, 0.0009), 7.1792),
+        ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+        (
+            (60.2574, -34.0099, 36.2677),
+            (60.4626, -34.1751, 39.4387),
+            1.2644,
+        ),
+        ((2.0776, 0.0795, -1.135), (0.9033, -0.0636, -0.5514), 0.9082),
+    ];
+    for ((l1, a1, b1), (l2, a2, b2), expected) in cases {
+        let first = Lab {
+            l: l1,
+            a: a1,
+            b: b1,
+        };
+        let second = Lab {
+            l: l2,
+            a: a2,
+            b: b2,
+        };
+        let actual = first.ciede2000(second);
+        assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+        assert!((second.ciede2000(first) - expected).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn lint_flags_only_pairs_below_threshold() {
+    let lint = DuplicateLint {
+        metric: Metric::Ciede2000,
+        threshold: 5.0,
+    };
+    let entries = [
+        ("coral", accent::CORAL),
+        ("salmon", accent::SALMON),
+        ("teal", accent::TEAL),
+    ];
+    let found: Vec<_> = lint.check(&entries).map(|d| (d.first, d.second)).collect();
+    assert_eq!(found, [("coral", "salmon")]);
+
+    let accents: Vec<_> = lint.check_accents(&Palette::BASE).collect();
+    assert!(accents
+        .iter()
+        .any(|d| d.first == Swatch::AccentCoral && d.second == Swatch::AccentSalmon));
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "palette")]
+
+use palette::{FromColor, Oklch as PaletteOklch, Srgb, Srgba};
+use zero_trust_palette::space::Oklch;
+use zero_trust_palette::{Color, Palette};
+
+/// Within one 8-bit step per channel.
+fn assert_close(expected: Color, actual: Color) {
+    let channels = |c: Color| [c.r, c.g, c.b];
+    for (e, a) in channels(expected).into_iter().zip(channels(actual)) {
+        assert!(e.abs_diff(a) <= 1, "{expected} round-tripped to {actual}");
+    }
+}
+
+#[test]
+fn srgb_round_trips() {
+    let color = Color::rgba(0x56, 0x9C, 0xD6, 0x80);
+    assert_eq!(Color::from(Srgba::<u8>::from(color)), color);
+    assert_eq!(
+        Color::from(Srgb::<u8>::from(color)),
+        Color::rgb(0x56, 0x9C, 0xD6)
+    );
+
+    let float = Srgb::<f32>::from(color);
+    assert!((float.red - 0x56 as f32 / 255.0).abs() < 1e-6);
+    assert_close(color, Color::from(float));
+    // Out-of-gamut channels are clamped rather than wrapped.
+    assert_eq!(
+        Color::from(Srgb::new(1.5, -0.5, 0.5)),
+        Color::rgb(255, 0, 128)
+    );
+}
+
+#[test]
+fn oklch_agrees_with_the_palette_crate() {
+    for (swatch, color) in Palette::BASE.iter() {
+        let ours = color.to_oklch();
+        let theirs = Oklch::from(PaletteOklch::from_color(Srgb::<f32>::from(color)));
+        assert!(
+            (ours.l - theirs.l).abs() < 1e-3,
+            "{swatch:?}: {ours:?} vs {theirs:?}"
+        );
+        assert!(
+            (ours.c - theirs.c).abs() < 1e-3,
+            "{swatch:?}: {ours:?} vs {theirs:?}"
+        );
+        // Hue is meaningless for grays.
+        if ours.c > 0.01 {
+            let turn = (ours.h - theirs.h).rem_euclid(360.0);
+            assert!(
+                turn.min(360.0 - turn) < 0.5,
+                "{swatch:?}: {ours:?} vs {theirs:?}"
+            );
+        }
+        assert_eq!(PaletteOklch::<f32>::from(color), ours.into());
+        assert_close(color, Color::from(PaletteOklch::from(color)));
+    }
+}
@@ -0,0 +1,45 @@
+use proptest::prelude::*;
+use zero_trust_palette::quantize::{ansi256_color, to_ansi16, to_ansi256, XTERM_16};
+use zero_trust_palette::Color;
+
+fn distance(a: Color, b: Color) -> f64 {
+    let (a, b) = (a.to_oklab(), b.to_oklab());
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+#[test]
+fn ansi256_picks() {
+    assert_eq!(to_ansi256(Color::from_u32(0xFFFFFF)), 231);
+    assert_eq!(to_ansi256(Color::from_u32(0x1E1E1E)), 234);
+    assert_eq!(to_ansi256(Color::from_u32(0x000000)), 16);
+    assert_eq!(ansi256_color(234), Color::from_u32(0x1C1C1C));
+}
+
+#[test]
+fn ansi16_picks_for_primaries() {
+    assert_eq!(to_ansi16(Color::from_u32(0xFF0000)), 9);
+    assert_eq!(to_ansi16(Color::from_u32(0x00FF00)), 10);
+    assert_eq!(to_ansi16(Color::from_u32(0x0000FF)), 4);
+    assert_eq!(to_ansi16(Color::from_u32(0xFFFFFF)), 15);
+    assert_eq!(to_ansi16(Color::from_u32(0x000000)), 0);
+}
+
+proptest! {
+    #[test]
+    fn ansi256_is_nearest(r: u8, g: u8, b: u8) {
+        let color = Color::rgb(r, g, b);
+        let chosen = distance(color, ansi256_color(to_ansi256(color)));
+        for index in 16..=255 {
+            prop_assert!(chosen <= distance(color, ansi256_color(index)) + 1e-9);
+        }
+    }
+
+    #[test]
+    fn ansi16_is_nearest(r: u8, g: u8, b: u8) {
+        let color = Color::rgb(r, g, b);
+        let chosen = distance(color, XTERM_16[to_ansi16(color) as usize]);
+        for candidate in XTERM_16 {
+            prop_assert!(chosen <= distance(color, candidate) + 1e-9);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use proptest::prelude::*;
+use zero_trust_palette::space::{Hsl, LinearRgb, Oklab, Oklch};
+use zero_trust_palette::Color;
+
+fn color() -> impl Strategy<Value = Color> {
+    any::<[u8; 4]>().prop_map(|[r, g, b, a]| Color::rgba(r, g, b, a))
+}
+
+fn assert_close(expected: Color, actual: Color) {
+    let channels = |c: Color| [c.r, c.g, c.b];
+    for (e, a) in channels(expected).into_iter().zip(channels(actual)) {
+        assert!(e.abs_diff(a) <= 1, "{expected} round-tripped to {actual}");
+    }
+}
+
+proptest! {
+    #[test]
+    fn hex(c in color()) {
+        prop_assert_eq!(Color::from_hex(&c.to_hex()), Ok(c));
+    }
+
+    #[test]
+    fn float_rgba(c in color()) {
+        let text = c.float_rgba().to_string();
+        let channels: Vec<u8> = text
+            .split(' ')
+            .map(|value| (value.parse::<f64>().unwrap() * 255.0).round() as u8)
+            .collect();
+        prop_assert_eq!(channels, vec![c.r, c.g, c.b, c.a]);
+    }
+
+    #[test]
+    fn linear_rgb(c in color()) {
+        assert_close(c, Color::from(LinearRgb::from(c)));
+    }
+
+    #[test]
+    fn hsl(c in color()) {
+        assert_close(c, Color::from(Hsl::from(c)));
+    }
+
+    #[test]
+    fn oklab(c in color()) {
+        assert_close(c, Color::from_oklab(Oklab::from(LinearRgb::from(c))));
+    }
+
+    #[test]
+    fn oklch(c in color()) {
+        assert_close(c, Color::from_oklch(Oklch::from(c.to_oklab())));
+    }
+
+    #[test]
+    fn hsl_keeps_hue_of_saturated_colors(h in 0.0..360.0f64) {
+        let round = Color::from_hsl(Hsl { h, s: 1.0, l: 0.5 }).to_hsl();
+        let diff = (round.h - h).abs();
+        prop_assert!(diff.min(360.0 - diff) < 1.0);
+    }
+}
+
+#[test]
+fn every_8bit_gray_survives_oklch() {
+    for v in 0..=255 {
+        let gray = Color::rgb(v, v, v);
+        assert_eq!(Color::from_oklch(gray.to_oklch()), gray);
+    }
+}
@@ -0,0 +1,55 @@
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::scale::Scale;
+use zero_trust_palette::{Color, Theme, Variant};
+
+fn lightness(color: Color) -> f64 {
+    color.to_oklab().l
+}
+
+/// How far 8-bit rounding can move `color`'s lightness: the span from one
+/// code darker to one code lighter. Near black that is far coarser than the
+/// spacing between steps.
+fn rounding(color: Color) -> f64 {
+    let shift = |by: i16| {
+        let channel = |c: u8| (c as i16 + by).clamp(0, 255) as u8;
+        Color::rgb(channel(color.r), channel(color.g), channel(color.b))
+    };
+    lightness(shift(1)) - lightness(shift(-1))
+}
+
+#[test]
+fn steps_are_evenly_spaced_in_oklab_lightness() {
+    for &variant in Variant::ALL {
+        let theme = Theme::new(variant);
+        let background = lightness(theme.opaque_color(Role::Background));
+        let text = lightness(theme.opaque_color(Role::Text));
+        let scale = Scale::for_theme(&theme);
+        assert_eq!(scale.surface(0), theme.opaque_color(Role::Background));
+
+        let (start, end) = Scale::OVERLAY_RANGE;
+        let fractions = (0..6)
+            .map(|i| Scale::SURFACE_SPAN * i as f64 / 5.0)
+            .chain((0..3).map(|i| start + (end - start) * i as f64 / 2.0));
+        let steps: Vec<(&str, Color)> = scale.iter().collect();
+        for ((name, color), t) in steps.iter().zip(fractions) {
+            let expected = background + (text - background) * t;
+            let l = lightness(*color);
+            assert!(
+                (l - expected).abs() <= rounding(*color),
+                "{variant:?} {name}: {l:.4}, expected {expected:.4}"
+            );
+        }
+
+        // Every step moves further from the background, towards the text.
+        let towards = (text - background).signum();
+        for pair in steps.windows(2) {
+            let [(below, lower), (name, upper)] = pair else {
+                unreachable!()
+            };
+            assert!(
+                (lightness(*upper) - lightness(*lower)) * towards > 0.0,
+                "{variant:?} {name} does not move on from {below}"
+            );
+        }
+    }
+}
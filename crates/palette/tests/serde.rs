@@ -0,0 +1,65 @@
+#![cfg(feature = "serde")]
+
+use zero_trust_palette::roles::{Role, RoleMap, Style};
+use zero_trust_palette::{Color, Palette, Swatch, Variant};
+
+#[test]
+fn tables_round_trip_through_their_names() {
+    let json = serde_json::to_value(Palette::BASE).unwrap();
+    assert_eq!(
+        json["accent.blue"],
+        Palette::BASE[Swatch::AccentBlue].to_hex()
+    );
+    assert_eq!(
+        serde_json::from_value::<Palette>(json).unwrap(),
+        Palette::BASE
+    );
+
+    let json = serde_json::to_value(RoleMap::DEFAULT).unwrap();
+    assert_eq!(
+        serde_json::from_value::<RoleMap>(json).unwrap(),
+        RoleMap::DEFAULT
+    );
+
+    for &variant in Variant::ALL {
+        let json = serde_json::to_string(&variant).unwrap();
+        assert_eq!(json, format!("\"{}\"", variant.slug()));
+        assert_eq!(serde_json::from_str::<Variant>(&json).unwrap(), variant);
+    }
+}
+
+#[test]
+fn partial_maps_keep_the_shipped_entries() {
+    let palette: Palette = serde_json::from_str(r##"{"accent.blue": "#112233"}"##).unwrap();
+    let mut expected = Palette::BASE;
+    expected.set(Swatch::AccentBlue, Color::rgb(0x11, 0x22, 0x33));
+    assert_eq!(palette, expected);
+
+    let roles: RoleMap =
+        serde_json::from_str(r#"{"syntax.keyword": {"swatch": "accent.coral", "italic": true}}"#)
+            .unwrap();
+    let keyword = Role::from_name("syntax.keyword").unwrap();
+    let mut expected = RoleMap::DEFAULT;
+    expected.set(
+        keyword,
+        Style {
+            italic: true,
+            ..Style::new(Swatch::AccentCoral)
+        },
+    );
+    assert_eq!(roles, expected);
+}
+
+#[test]
+fn unknown_keys_are_rejected() {
+    let err = serde_json::from_str::<Palette>(r##"{"acent.blue": "#112233"}"##).unwrap_err();
+    assert!(
+        err.to_string().contains("expected a palette entry name"),
+        "{err}"
+    );
+    let err = serde_json::from_str::<RoleMap>(r#"{"syntax.keywrd": {"swatch": "accent.blue"}}"#)
+        .unwrap_err();
+    assert!(err.to_string().contains("expected a role name"), "{err}");
+    let err = serde_json::from_str::<Palette>(r#"{"accent.blue": "blue"}"#).unwrap_err();
+    assert!(err.to_string().contains("expected a hex color"), "{err}");
+}
@@ -0,0 +1,101 @@
+#![cfg(any(feature = "anstyle", feature = "ratatui"))]
+
+use zero_trust_palette::roles::{Role, Style};
+use zero_trust_palette::{Color, Swatch, Theme, Variant};
+
+fn comment() -> Role {
+    Role::from_name("syntax.comment").unwrap()
+}
+
+/// The dark theme with comments bold and italic, translucent text in the
+/// editor and the terminal, and a terminal background of its own.
+fn theme() -> Theme {
+    let mut theme = Theme::new(Variant::Dark);
+    theme.roles.set(
+        comment(),
+        Style::new(Swatch::AccentGreen).italic().weight(700),
+    );
+    theme
+        .roles
+        .set(Role::Text, Style::new(Swatch::FgBase).with_alpha(0x80));
+    theme
+        .roles
+        .set(Role::TerminalBackground, Style::new(Swatch::BgRaised));
+    theme.roles.set(
+        Role::TerminalRed,
+        Style::new(Swatch::AccentRed).with_alpha(0x80),
+    );
+    theme
+}
+
+/// `role`'s own color at half alpha over `backdrop`'s.
+fn composited(theme: &Theme, role: Role, backdrop: Role) -> Color {
+    let color = theme.color(role);
+    assert!(!color.is_opaque());
+    color.over(theme.color(backdrop))
+}
+
+#[cfg(feature = "anstyle")]
+#[test]
+fn anstyle_styles() {
+    use anstyle::{Effects, RgbColor};
+
+    let rgb = |c: Color| Some(anstyle::Color::Rgb(RgbColor(c.r, c.g, c.b)));
+    let theme = theme();
+
+    let style = theme.anstyle(comment());
+    assert_eq!(style.get_effects(), Effects::BOLD | Effects::ITALIC);
+    assert_eq!(
+        style.get_fg_color(),
+        rgb(theme.palette[Swatch::AccentGreen])
+    );
+
+    // Terminals have no alpha: text is flattened over the editor
+    // background, terminal colors over the terminal background.
+    let text = theme.anstyle(Role::Text);
+    let expected = composited(&theme, Role::Text, Role::Background);
+    assert_eq!(text.get_fg_color(), rgb(expected));
+    let red = theme.anstyle(Role::TerminalRed);
+    let expected = composited(&theme, Role::TerminalRed, Role::TerminalBackground);
+    assert_eq!(red.get_fg_color(), rgb(expected));
+
+    let plain = anstyle::Style::from(Role::Text);
+    let dark = Theme::new(Variant::Dark);
+    assert_eq!(plain.get_effects(), Effects::new());
+    assert_eq!(plain.get_fg_color(), rgb(dark.opaque_color(Role::Text)));
+    assert_eq!(plain.get_bg_color(), None);
+    let background = anstyle::Style::from(Role::Background);
+    assert_eq!(background.get_fg_color(), None);
+    assert_eq!(background.get_bg_color(), rgb(dark.color(Role::Background)));
+}
+
+#[cfg(feature = "ratatui")]
+#[test]
+fn ratatui_styles() {
+    use ratatui::style::Modifier;
+
+    let rgb = |c: Color| Some(ratatui::style::Color::Rgb(c.r, c.g, c.b));
+    let theme = theme();
+
+    let style = theme.ratatui_style(comment());
+    assert_eq!(style.add_modifier, Modifier::BOLD | Modifier::ITALIC);
+    assert_eq!(style.fg, rgb(theme.palette[Swatch::AccentGreen]));
+
+    let text = theme.ratatui_style(Role::Text);
+    assert_eq!(
+        text.fg,
+        rgb(composited(&theme, Role::Text, Role::Background))
+    );
+    let red = theme.ratatui_style(Role::TerminalRed);
+    let expected = composited(&theme, Role::TerminalRed, Role::TerminalBackground);
+    assert_eq!(red.fg, rgb(expected));
+
+    let plain = ratatui::style::Style::from(Role::Text);
+    let dark = Theme::new(Variant::Dark);
+    assert_eq!(plain.add_modifier, Modifier::empty());
+    assert_eq!(plain.fg, rgb(dark.opaque_color(Role::Text)));
+    assert_eq!(plain.bg, None);
+    let background = ratatui::style::Style::from(Role::Background);
+    assert_eq!(background.fg, None);
+    assert_eq!(background.bg, rgb(dark.color(Role::Background)));
+}
@@ -0,0 +1,122 @@
+use zero_trust_palette::contrast::{contrast_ratio, AA, AAA};
+use zero_trust_palette::ramp::{Ramp, State};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::roles::RoleMap;
+use zero_trust_palette::{
+    shade, tint, Color, Dimmed, Oled, Palette, Swatch, Theme, Transforms, Variant,
+};
+
+/// Asserts that every syntax role of `theme` reaches `min` against the
+/// editor background.
+fn assert_syntax_contrast(theme: &Theme, min: f64) {
+    let background = theme.color(Role::Background);
+    for &role in Role::ALL.iter().filter(|r| r.is_syntax()) {
+        let ratio = contrast_ratio(theme.color(role), background);
+        assert!(
+            ratio >= min,
+            "{} in {} is {ratio:.2}:1",
+            role.name(),
+            theme.name()
+        );
+    }
+}
+
+#[test]
+fn light_syntax_meets_aa() {
+    assert_syntax_contrast(&Theme::new(Variant::Light), AA);
+}
+
+#[test]
+fn high_contrast_syntax_meets_aaa() {
+    assert_syntax_contrast(&Theme::new(Variant::HighContrast), AAA);
+}
+
+#[test]
+fn state_backgrounds_keep_their_hand_picked_tints() {
+    for &variant in Variant::ALL {
+        let theme = Theme::new(variant);
+        for state in State::ALL {
+            let [.., tint] = state.swatches();
+            assert_eq!(theme.palette[tint], theme.palette[state.tint()]);
+        }
+    }
+    let dark = Theme::new(Variant::Dark);
+    assert_eq!(dark.color(Role::ErrorBackground), tint::RED);
+    assert_eq!(dark.color(Role::InfoBackground), shade::BLUE);
+
+    // Retuning a tint moves the wash with it; the other steps still follow
+    // the state color.
+    let mut palette = Palette::BASE;
+    palette.set(Swatch::TintRed, Color::hex("#602020"));
+    let ramp = Ramp::for_state(&palette, State::Error);
+    assert_eq!(ramp.tint, Color::hex("#602020"));
+    let derived = Ramp::derive(palette[State::Error.source()], palette[Swatch::BgBase]);
+    assert_eq!(
+        [ramp.base, ramp.emphasized, ramp.muted],
+        [derived.base, derived.emphasized, derived.muted]
+    );
+}
+
+#[test]
+fn dimmed_lowers_chroma() {
+    let dimmed = Dimmed::default().apply(&Palette::BASE);
+    for &swatch in Swatch::ALL {
+        let base = Palette::BASE[swatch].to_oklch().c;
+        let c = dimmed[swatch].to_oklch().c;
+        assert!(
+            c <= base + 1e-3,
+            "{} went from {base:.3} to {c:.3}",
+            swatch.name()
+        );
+        if base > 0.05 {
+            assert!(c < base, "{} kept its chroma", swatch.name());
+        }
+    }
+    let (dark, dimmed) = (Theme::new(Variant::Dark), Theme::new(Variant::Dimmed));
+    for &role in Role::ALL.iter().filter(|r| r.is_syntax()) {
+        let base = dark.color(role).to_oklch().c;
+        if base > 0.05 {
+            assert!(dimmed.color(role).to_oklch().c < base, "{}", role.name());
+        }
+    }
+}
+
+#[test]
+fn dimmed_follows_its_transform() {
+    let dimmed = |chroma, contrast| {
+        let transforms = Transforms {
+            dimmed: Dimmed { chroma, contrast },
+            ..Transforms::default()
+        };
+        Theme::from_parts(
+            Variant::Dimmed,
+            &Palette::BASE,
+            RoleMap::DEFAULT,
+            &transforms,
+        )
+    };
+    let keyword = |theme: &Theme| theme.color(Role::Keyword).to_oklch().c;
+    assert!(keyword(&dimmed(0.4, 0.85)) < keyword(&Theme::new(Variant::Dimmed)));
+    let identity = dimmed(1.0, 1.0);
+    for &role in Role::ALL {
+        let dark = Theme::new(Variant::Dark).color(role).to_oklch();
+        let same = identity.color(role).to_oklch();
+        assert!((dark.c - same.c).abs() < 0.01, "{}", role.name());
+        assert!((dark.l - same.l).abs() < 0.01, "{}", role.name());
+    }
+}
+
+#[test]
+fn oled_surfaces_are_black_and_borders_stay_apart() {
+    let oled = Theme::new(Variant::Oled);
+    assert_eq!(oled.palette[Swatch::BgBase], Color::BLACK);
+    for &role in Oled::SURFACES {
+        assert_eq!(oled.opaque_color(role), Color::BLACK, "{}", role.name());
+    }
+    let borders =
+        [Role::Border, Role::Split, Role::BorderVariant].map(|role| oled.opaque_color(role));
+    for (i, border) in borders.iter().enumerate() {
+        assert_ne!(*border, Color::BLACK);
+        assert!(!borders[i + 1..].contains(border), "{border} is used twice");
+    }
+}
@@ -0,0 +1,33 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use zero_trust::model::Model;
+use zero_trust::targets::Registry;
+use zero_trust::{render_all, Source};
+use zero_trust_palette::Variant;
+
+fn build(c: &mut Criterion) {
+    let source = Source::default();
+    let targets: Vec<_> = Registry::builtin().iter().cloned().collect();
+
+    c.bench_function("derive every variant", |b| {
+        b.iter(|| {
+            Variant::ALL
+                .iter()
+                .map(|&v| source.theme(v))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("model every variant", |b| {
+        b.iter(|| {
+            Variant::ALL
+                .iter()
+                .map(|&v| Model::new(&source, &source.theme(v)))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("render every target and variant", |b| {
+        b.iter(|| render_all(&targets, &source, Variant::ALL).unwrap())
+    });
+}
+
+criterion_group!(benches, build);
+criterion_main!(benches);
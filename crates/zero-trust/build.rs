@@ -0,0 +1,53 @@
+//! Hashes the generator's sources into `ZERO_TRUST_SOURCE_HASH`, so the
+//! build cache goes stale whenever a built-in target changes, not only on a
+//! version bump.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+/// Every file below `dir`, sorted so the hash does not depend on the order
+/// the file system lists them in.
+fn files(dir: &Path, found: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            files(&path, found);
+        } else {
+            found.push(path);
+        }
+    }
+}
+
+fn main() {
+    let manifest = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    // The palette is a path dependency in the workspace; a published crate
+    // has only its own sources, which is fine since the version pins the
+    // palette there.
+    let roots = [manifest.join("src"), manifest.join("../palette/src")];
+    let mut hasher = Sha256::new();
+    for root in &roots {
+        println!("cargo:rerun-if-changed={}", root.display());
+        let mut found = Vec::new();
+        files(root, &mut found);
+        found.sort();
+        for path in found {
+            let name = path.strip_prefix(root).unwrap_or(&path);
+            let contents = fs::read(&path).unwrap_or_default();
+            for part in [name.to_string_lossy().as_bytes(), &contents] {
+                hasher.update((part.len() as u64).to_le_bytes());
+                hasher.update(part);
+            }
+        }
+    }
+    let hash: String = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    println!("cargo:rustc-env=ZERO_TRUST_SOURCE_HASH={hash}");
+}
@@ -0,0 +1,222 @@
+//! Following the operating system's dark or light appearance.
+//!
+//! `zero-trust auto` reads the appearance the desktop asks for and keeps a
+//! set of configured application files on the matching variant. The
+//! applications are listed in `~/.config/zero-trust-theme/auto.toml`:
+//!
+//! ```toml
+//! [[apps]]
+//! name = "alacritty"
+//! path = "~/.config/alacritty/zero-trust.toml"
+//! dark = "~/src/zero-trust-theme/extras/alacritty/zero-trust-dark.toml"
+//! light = "~/src/zero-trust-theme/extras/alacritty/zero-trust-light.toml"
+//!
+//! [[apps]]
+//! name = "kitty"
+//! path = "~/.config/kitty/zero-trust.conf"
+//! dark = "~/src/zero-trust-theme/extras/kitty/zero-trust-dark.conf"
+//! light = "~/src/zero-trust-theme/extras/kitty/zero-trust-light.conf"
+//! reload = ["pkill", "-USR1", "kitty"]
+//! ```
+//!
+//! On a switch, each `path` is overwritten with the `dark` or `light` file
+//! and then the `reload` command, if any, runs, so applications that do not
+//! watch their configuration can be signalled to reread it.
+//!
+//! The appearance comes from the `org.freedesktop.appearance color-scheme`
+//! setting of the XDG desktop portal on Linux (via `gdbus`), the global
+//! `AppleInterfaceStyle` default on macOS and the `AppsUseLightTheme`
+//! registry value on Windows. Only the portal announces changes; on macOS
+//! and Windows the setting is polled, every two seconds unless `--interval`
+//! says otherwise.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Deserialize;
+use zero_trust_palette::Appearance;
+
+use crate::install::Dirs;
+use crate::Error;
+
+/// The configuration file, below the configuration directory.
+pub const CONFIG_FILE: &str = "zero-trust-theme/auto.toml";
+
+/// Where the configuration lives for the current user.
+pub fn config_file(dirs: &Dirs) -> PathBuf {
+    dirs.config.join(CONFIG_FILE)
+}
+
+/// Reads the output of the portal's `Settings.Read` call for
+/// `color-scheme`, e.g. `(<<uint32 1>>,)`: 1 prefers dark, 2 light and 0
+/// states no preference, which reads as light.
+pub fn parse_portal(output: &str) -> Option<Appearance> {
+    let value = output.split("uint32 ").nth(1)?;
+    let digits: String = value.chars().take_while(char::is_ascii_digit).collect();
+    match digits.parse::<u32>().ok()? {
+        1 => Some(Appearance::Dark),
+        0 | 2 => Some(Appearance::Light),
+        _ => None,
+    }
+}
+
+/// Reads the output of `defaults read -g AppleInterfaceStyle`, which is
+/// `Dark` in dark mode and fails, printing nothing, in light mode.
+pub fn parse_defaults(output: &str) -> Appearance {
+    if output.trim() == "Dark" {
+        Appearance::Dark
+    } else {
+        Appearance::Light
+    }
+}
+
+/// Reads the output of `reg query` for `AppsUseLightTheme`, e.g.
+/// `AppsUseLightTheme    REG_DWORD    0x0`.
+pub fn parse_registry(output: &str) -> Option<Appearance> {
+    let line = output
+        .lines()
+        .find(|line| line.contains("AppsUseLightTheme"))?;
+    match line.split_whitespace().last()? {
+        "0x0" => Some(Appearance::Dark),
+        "0x1" => Some(Appearance::Light),
+        _ => None,
+    }
+}
+
+fn stdout(program: &str, args: &[&str]) -> Option<(bool, String)> {
+    let output = Command::new(program).args(args).output().ok()?;
+    Some((
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    ))
+}
+
+/// The current appearance, or `None` where it cannot be read.
+pub fn detect() -> Option<Appearance> {
+    if cfg!(target_os = "macos") {
+        let (_, output) = stdout("defaults", &["read", "-g", "AppleInterfaceStyle"])?;
+        Some(parse_defaults(&output))
+    } else if cfg!(windows) {
+        let (ok, output) = stdout(
+            "reg",
+            &[
+                "query",
+                r"HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+                "/v",
+                "AppsUseLightTheme",
+            ],
+        )?;
+        ok.then(|| parse_registry(&output)).flatten()
+    } else {
+        let (ok, output) = stdout(
+            "gdbus",
+            &[
+                "call",
+                "--session",
+                "--dest",
+                "org.freedesktop.portal.Desktop",
+                "--object-path",
+                "/org/freedesktop/portal/desktop",
+                "--method",
+                "org.freedesktop.portal.Settings.Read",
+                "org.freedesktop.appearance",
+                "color-scheme",
+            ],
+        )?;
+        ok.then(|| parse_portal(&output)).flatten()
+    }
+}
+
+/// One application kept on the current appearance.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct App {
+    /// A name for messages.
+    pub name: String,
+    /// The file the application reads its theme from.
+    pub path: PathBuf,
+    /// The file copied to `path` in dark mode.
+    pub dark: PathBuf,
+    /// The file copied to `path` in light mode.
+    pub light: PathBuf,
+    /// A command run after `path` changed, e.g. to signal the application.
+    #[serde(default)]
+    pub reload: Vec<String>,
+}
+
+/// The applications to keep on the current appearance.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub apps: Vec<App>,
+}
+
+/// `path` with a leading `~/` replaced by the home directory and, if still
+/// relative, taken relative to `base`.
+fn resolve(path: &Path, base: &Path, home: &Path) -> PathBuf {
+    match path.strip_prefix("~") {
+        Ok(rest) => home.join(rest),
+        Err(_) => base.join(path),
+    }
+}
+
+impl Config {
+    /// Parses a configuration from TOML text; `path` is used in errors.
+    pub fn parse(text: &str, path: &Path) -> Result<Config, Error> {
+        toml::from_str(text).map_err(|source| Error::Parse {
+            path: path.to_owned(),
+            text: text.to_owned(),
+            source: Box::new(source),
+        })
+    }
+
+    /// Reads the configuration at `path`, resolving `~/` against `home` and
+    /// relative paths against the configuration's directory.
+    pub fn load(path: &Path, home: &Path) -> Result<Config, Error> {
+        let text = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        let mut config = Config::parse(&text, path)?;
+        let base = path.parent().unwrap_or(Path::new("."));
+        for app in &mut config.apps {
+            for path in [&mut app.path, &mut app.dark, &mut app.light] {
+                *path = resolve(path, base, home);
+            }
+        }
+        Ok(config)
+    }
+}
+
+impl App {
+    /// The file to copy to `path` in `appearance`.
+    pub fn file(&self, appearance: Appearance) -> &Path {
+        match appearance {
+            Appearance::Dark => &self.dark,
+            Appearance::Light => &self.light,
+        }
+    }
+
+    /// Puts the application on `appearance`, running its reload command if
+    /// that changed its file. Returns whether it did.
+    pub fn apply(&self, appearance: Appearance) -> Result<bool, Error> {
+        let from = self.file(appearance);
+        let contents = fs::read(from).map_err(|e| Error::io(from, e))?;
+        if fs::read(&self.path).is_ok_and(|current| current == contents) {
+            return Ok(false);
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+        }
+        fs::write(&self.path, contents).map_err(|e| Error::io(&self.path, e))?;
+        if let [program, args @ ..] = self.reload.as_slice() {
+            let status = Command::new(program)
+                .args(args)
+                .status()
+                .map_err(|e| Error::io(program, e))?;
+            // `pkill` fails when the application is not running, which is fine.
+            if !status.success() {
+                tracing::debug!(name = %self.name, %status, "reload command failed");
+            }
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,117 @@
+//! Skipping targets whose inputs have not changed since the last build.
+//!
+//! A target's fingerprint is a SHA-256 hash of everything its output
+//! depends on: the generator version and a hash of its sources, taken by
+//! the build script so an edited built-in target counts as a new one, the
+//! target's own definition (a template's text, a plugin's module) and the
+//! resolved [`Model`] of every variant being rendered. The cache records
+//! the fingerprint and the hash of every file written for it; a target is
+//! skipped only when its fingerprint matches and those files are still on
+//! disk unchanged.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::model::Model;
+use crate::{Error, Format, Output, Target};
+
+/// The cache file, kept in the output directory.
+pub const FILE_NAME: &str = ".zero-trust-cache.json";
+
+/// The lowercase hex SHA-256 digest of `data`.
+pub fn sha256(data: &[u8]) -> String {
+    hex(&Sha256::digest(data))
+}
+
+fn hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// The fingerprint of `target` rendering `models` laid out in `format`.
+pub fn fingerprint(target: &dyn Target, models: &[Model], format: Format) -> String {
+    let format: &[u8] = match format {
+        Format::Pretty => b"pretty",
+        Format::Minified => b"minified",
+    };
+    let mut hasher = Sha256::new();
+    for part in [
+        env!("CARGO_PKG_VERSION").as_bytes(),
+        env!("ZERO_TRUST_SOURCE_HASH").as_bytes(),
+        target.name().as_bytes(),
+        target.fingerprint(),
+        format,
+    ] {
+        // Length-prefixed so adjacent parts cannot run into each other.
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part);
+    }
+    hasher.update(serde_json::to_vec(models).expect("the model always serializes"));
+    hex(&hasher.finalize())
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    fingerprint: String,
+    /// The SHA-256 of every file written, by path below the output directory.
+    outputs: BTreeMap<PathBuf, String>,
+}
+
+/// Fingerprints of the last build, by target name.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cache {
+    targets: BTreeMap<String, Entry>,
+}
+
+impl Cache {
+    /// Reads the cache in `out_dir`.
+    ///
+    /// A missing or unreadable cache is empty, so the next build renders
+    /// everything.
+    pub fn load(out_dir: &Path) -> Cache {
+        fs::read(out_dir.join(FILE_NAME))
+            .ok()
+            .and_then(|data| serde_json::from_slice(&data).ok())
+            .unwrap_or_default()
+    }
+
+    /// Writes the cache into `out_dir`.
+    pub fn save(&self, out_dir: &Path) -> Result<(), Error> {
+        let path = out_dir.join(FILE_NAME);
+        let mut text =
+            serde_json::to_string_pretty(self).map_err(|e| Error::io(&path, e.into()))?;
+        text.push('\n');
+        fs::create_dir_all(out_dir).map_err(|e| Error::io(out_dir, e))?;
+        fs::write(&path, text).map_err(|e| Error::io(&path, e))
+    }
+
+    /// Whether `target` last rendered with `fingerprint` and its files in
+    /// `out_dir` are as it left them.
+    pub fn is_fresh(&self, target: &str, fingerprint: &str, out_dir: &Path) -> bool {
+        let Some(entry) = self.targets.get(target) else {
+            return false;
+        };
+        entry.fingerprint == fingerprint
+            && entry.outputs.iter().all(|(path, hash)| {
+                fs::read(out_dir.join(path)).is_ok_and(|data| sha256(&data) == *hash)
+            })
+    }
+
+    /// Records that `target` rendered `outputs` with `fingerprint`.
+    pub fn record(&mut self, target: &str, fingerprint: String, outputs: &[Output]) {
+        let outputs = outputs
+            .iter()
+            .map(|output| (output.path.clone(), sha256(output.contents.as_bytes())))
+            .collect();
+        self.targets.insert(
+            target.to_owned(),
+            Entry {
+                fingerprint,
+                outputs,
+            },
+        );
+    }
+}
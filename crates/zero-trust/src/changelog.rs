@@ -0,0 +1,199 @@
+//! Summarizing palette and role changes for release notes.
+//!
+//! [`changes`] compares two theme sources entry by entry and describes each
+//! difference the way a reader thinks of it: a palette color that was
+//! "darkened and desaturated" rather than two hex strings, a role that "now
+//! uses `fg.muted`". [`at_revision`] reads a source out of git history, so
+//! two tags can be compared without checking either out.
+
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+
+use zero_trust_palette::roles::{Role, Style};
+use zero_trust_palette::{Color, Swatch};
+
+use crate::{Error, Source};
+
+/// OKLCH lightness or chroma differences below this are not called out.
+const THRESHOLD: f64 = 0.005;
+
+/// Hue shifts below this many degrees are not called out.
+const HUE_THRESHOLD: f64 = 3.0;
+
+/// Below this chroma a color is close enough to gray that its hue is noise.
+const GRAY_CHROMA: f64 = 0.02;
+
+/// One difference between two theme sources.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    Swatch {
+        swatch: Swatch,
+        old: Color,
+        new: Color,
+    },
+    Role {
+        role: Role,
+        old: Style,
+        new: Style,
+    },
+}
+
+/// Every palette entry and role that differs from `old` to `new`, palette
+/// first, each in declaration order.
+pub fn changes(old: &Source, new: &Source) -> Vec<Change> {
+    let swatches = Swatch::ALL
+        .iter()
+        .filter(|&&swatch| old.palette[swatch] != new.palette[swatch])
+        .map(|&swatch| Change::Swatch {
+            swatch,
+            old: old.palette[swatch],
+            new: new.palette[swatch],
+        });
+    let roles = Role::ALL
+        .iter()
+        .filter(|&&role| old.roles[role] != new.roles[role])
+        .map(|&role| Change::Role {
+            role,
+            old: old.roles[role],
+            new: new.roles[role],
+        });
+    swatches.chain(roles).collect()
+}
+
+/// How `new` differs perceptually from `old`, e.g. `darkened and desaturated`.
+pub fn describe_color(old: Color, new: Color) -> String {
+    let (a, b) = (old.to_oklch(), new.to_oklch());
+    let mut words = Vec::new();
+    if b.l - a.l > THRESHOLD {
+        words.push("lightened");
+    } else if a.l - b.l > THRESHOLD {
+        words.push("darkened");
+    }
+    if b.c - a.c > THRESHOLD {
+        words.push("saturated");
+    } else if a.c - b.c > THRESHOLD {
+        words.push("desaturated");
+    }
+    let hue = (b.h - a.h).rem_euclid(360.0);
+    if a.c.min(b.c) > GRAY_CHROMA && hue.min(360.0 - hue) > HUE_THRESHOLD {
+        words.push("shifted in hue");
+    }
+    if new.a > old.a {
+        words.push("made more opaque");
+    } else if new.a < old.a {
+        words.push("made more transparent");
+    }
+    match words.as_slice() {
+        [] => "adjusted".into(),
+        [word] => (*word).into(),
+        [init @ .., last] => format!("{} and {last}", init.join(", ")),
+    }
+}
+
+fn weight(weight: Option<u16>) -> String {
+    weight.map_or("the default weight".into(), |weight| {
+        format!("weight {weight}")
+    })
+}
+
+fn percent(alpha: u8) -> String {
+    format!("{}%", (u32::from(alpha) * 100 + 127) / 255)
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Change::Swatch { swatch, old, new } => write!(
+                f,
+                "`{}` {} from {old} to {new}",
+                swatch.name(),
+                describe_color(old, new)
+            ),
+            Change::Role { role, old, new } => {
+                let mut parts = Vec::new();
+                if old.swatch != new.swatch {
+                    parts.push(format!(
+                        "now uses `{}` instead of `{}`",
+                        new.swatch.name(),
+                        old.swatch.name()
+                    ));
+                }
+                if old.alpha != new.alpha {
+                    parts.push(format!(
+                        "opacity {} instead of {}",
+                        percent(new.alpha),
+                        percent(old.alpha)
+                    ));
+                }
+                if old.italic != new.italic {
+                    parts.push(
+                        if new.italic {
+                            "now italic"
+                        } else {
+                            "no longer italic"
+                        }
+                        .into(),
+                    );
+                }
+                if old.weight != new.weight {
+                    parts.push(format!(
+                        "{} instead of {}",
+                        weight(new.weight),
+                        weight(old.weight)
+                    ));
+                }
+                write!(f, "`{}` {}", role.name(), parts.join(", "))
+            }
+        }
+    }
+}
+
+/// A Markdown summary of `changes` for release notes.
+pub fn to_markdown(changes: &[Change]) -> String {
+    let (palette, roles): (Vec<&Change>, Vec<&Change>) = changes
+        .iter()
+        .partition(|change| matches!(change, Change::Swatch { .. }));
+    let mut text = String::new();
+    for (heading, changes) in [("Palette", palette), ("Roles", roles)] {
+        if changes.is_empty() {
+            continue;
+        }
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&format!("### {heading}\n\n"));
+        for change in changes {
+            text.push_str(&format!("- {change}\n"));
+        }
+    }
+    if text.is_empty() {
+        text.push_str("No palette or role changes.\n");
+    }
+    text
+}
+
+/// Parses the theme source at `path` as of the git `revision`.
+pub fn at_revision(path: &Path, revision: &str) -> Result<Source, Error> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let file_name = path.file_name().unwrap_or(path.as_os_str());
+    // `./` makes git resolve the path against `dir` rather than the repository root.
+    let spec = format!("{revision}:./{}", file_name.to_string_lossy());
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["show", &spec])
+        .output()
+        .map_err(|e| Error::io("git", e))?;
+    if !output.status.success() {
+        return Err(Error::Git {
+            revision: revision.to_owned(),
+            message: String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+        });
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    Source::parse(&text, Path::new(&spec))
+}
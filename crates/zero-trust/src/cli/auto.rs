@@ -0,0 +1,112 @@
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use clap::Args;
+use zero_trust::appearance::{self, Config};
+use zero_trust::install::Dirs;
+use zero_trust::Error;
+use zero_trust_palette::Appearance;
+
+#[derive(Debug, Args)]
+pub struct AutoArgs {
+    /// The applications to switch [default: ~/.config/zero-trust-theme/auto.toml]
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+    /// Switch once to the current appearance and exit, e.g. from a login script.
+    #[arg(long)]
+    once: bool,
+    /// Seconds between appearance checks on macOS and Windows, and on Linux
+    /// when the desktop portal cannot be monitored.
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        default_value_t = 2,
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    interval: u64,
+}
+
+/// Puts every application on `appearance`, reporting but surviving failures
+/// so one missing file does not stop the others from switching.
+fn switch(config: &Config, appearance: Appearance) {
+    for app in &config.apps {
+        match app.apply(appearance) {
+            Ok(true) => println!("switched {} to {}", app.name, appearance.variant().slug()),
+            Ok(false) => {
+                tracing::debug!(name = %app.name, "already on the current variant")
+            }
+            Err(err) => super::report(&err),
+        }
+    }
+}
+
+/// Calls `changed` on every `SettingChanged` signal for `color-scheme` from
+/// the desktop portal, until the monitor exits. Fails if `gdbus` cannot run.
+fn monitor_portal(mut changed: impl FnMut()) -> io::Result<()> {
+    let mut child = Command::new("gdbus")
+        .args([
+            "monitor",
+            "--session",
+            "--dest",
+            "org.freedesktop.portal.Desktop",
+            "--object-path",
+            "/org/freedesktop/portal/desktop",
+        ])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+    let stdout = child.stdout.take().expect("stdout is piped");
+    for line in BufReader::new(stdout).lines() {
+        let line = line?;
+        if line.contains("SettingChanged") && line.contains("color-scheme") {
+            changed();
+        }
+    }
+    child.wait()?;
+    Ok(())
+}
+
+pub fn run(args: AutoArgs) -> Result<(), Error> {
+    let dirs = Dirs::from_env().ok_or(Error::NoHome)?;
+    let path = args
+        .config
+        .unwrap_or_else(|| appearance::config_file(&dirs));
+    let config = Config::load(&path, &dirs.home)?;
+    if config.apps.is_empty() {
+        println!(
+            "no applications configured in {}; add [[apps]] tables to switch",
+            path.display()
+        );
+        return Ok(());
+    }
+    let mut current = appearance::detect().ok_or(Error::UnknownAppearance)?;
+    switch(&config, current);
+    if args.once {
+        return Ok(());
+    }
+    let mut check = || {
+        if let Some(appearance) = appearance::detect().filter(|&a| a != current) {
+            current = appearance;
+            switch(&config, current);
+        }
+    };
+    if cfg!(all(unix, not(target_os = "macos"))) {
+        println!("following the desktop portal's color scheme; press Ctrl-C to stop");
+        match monitor_portal(&mut check) {
+            Ok(()) => tracing::debug!("portal monitor exited; polling instead"),
+            Err(err) => tracing::debug!(%err, "cannot monitor the portal; polling instead"),
+        }
+    } else {
+        println!(
+            "checking the system appearance every {}s; press Ctrl-C to stop",
+            args.interval
+        );
+    }
+    loop {
+        thread::sleep(Duration::from_secs(args.interval));
+        check();
+    }
+}
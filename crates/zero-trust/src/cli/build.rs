@@ -0,0 +1,229 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use clap::Args;
+use rayon::prelude::*;
+use zero_trust::cache::{self, Cache};
+use zero_trust::install::Dirs;
+use zero_trust::model::Model;
+use zero_trust::overrides;
+use zero_trust::targets::Registry;
+use zero_trust::{templates, write_outputs, Error, Format, Output, Source, Target};
+use zero_trust_palette::Variant;
+
+use super::{source_dir, SourceArgs};
+
+#[derive(Debug, Args)]
+pub struct BuildArgs {
+    #[command(flatten)]
+    source: SourceArgs,
+    /// Targets to render, comma-separated [default: all]
+    #[arg(long = "target", value_name = "NAME", value_delimiter = ',')]
+    targets: Vec<String>,
+    /// Variants to render, comma-separated, or `all` [default: dark,light]
+    #[arg(long = "variant", value_name = "NAME", value_delimiter = ',')]
+    variants: Vec<String>,
+    /// Where to write the files [default: next to the theme source]
+    #[arg(long, value_name = "DIR")]
+    out_dir: Option<PathBuf>,
+    /// Directory of `*.tera` templates for custom targets [default: `templates` next to the theme source]
+    #[arg(long, value_name = "DIR")]
+    templates: Option<PathBuf>,
+    /// Directory of `*.wasm` plugins for custom targets [default: `plugins` next to the theme source]
+    #[cfg(feature = "wasm")]
+    #[arg(long, value_name = "DIR")]
+    plugins: Option<PathBuf>,
+    /// Directory of `<target>.toml` override layers [default: `overrides` next to the theme source]
+    #[arg(long, value_name = "DIR")]
+    overrides: Option<PathBuf>,
+    /// Ignore the personal overrides in ~/.config/zero-trust-theme/user.toml.
+    #[arg(long)]
+    no_user: bool,
+    /// Write JSON without indentation, e.g. for packaging.
+    #[arg(long)]
+    minify: bool,
+}
+
+/// A resolved build: what to render and where.
+pub struct Plan {
+    pub source_path: PathBuf,
+    pub templates: PathBuf,
+    #[cfg(feature = "wasm")]
+    pub plugins: PathBuf,
+    pub overrides: PathBuf,
+    /// The user's override file, when it is applied.
+    pub user: Option<PathBuf>,
+    pub targets: Vec<Arc<dyn Target>>,
+    pub variants: Vec<Variant>,
+    pub format: Format,
+    pub out_dir: PathBuf,
+}
+
+impl BuildArgs {
+    /// Plans a build for this machine, with the user's overrides unless
+    /// `--no-user` is given.
+    pub fn plan(&self) -> Result<(Plan, Source), Error> {
+        self.plan_with(!self.no_user)
+    }
+
+    /// Plans a build of the published theme, never personalized by the
+    /// user's overrides: for checking, packaging, releasing and diffing
+    /// against the output directory.
+    pub fn plan_published(&self) -> Result<(Plan, Source), Error> {
+        self.plan_with(false)
+    }
+
+    fn plan_with(&self, user: bool) -> Result<(Plan, Source), Error> {
+        let (source_path, source) = self.source.load()?;
+        let templates = self
+            .templates
+            .clone()
+            .unwrap_or_else(|| source_dir(&source_path).join(templates::DEFAULT_DIR));
+        let mut registry = Registry::with_templates(&templates)?;
+        #[cfg(feature = "wasm")]
+        let plugins = self
+            .plugins
+            .clone()
+            .unwrap_or_else(|| source_dir(&source_path).join(zero_trust::plugins::DEFAULT_DIR));
+        #[cfg(feature = "wasm")]
+        registry.load_plugins(&plugins)?;
+        let overrides = self
+            .overrides
+            .clone()
+            .unwrap_or_else(|| source_dir(&source_path).join(overrides::DEFAULT_DIR));
+        let user = user
+            .then(|| Dirs::from_env().map(|dirs| overrides::user_file(&dirs)))
+            .flatten()
+            .filter(|path| path.is_file());
+        registry.apply_overrides(&overrides, user.as_deref())?;
+        if let Some(path) = &user {
+            tracing::info!(path = %path.display(), "applying user overrides");
+        }
+        let plan = Plan {
+            targets: registry.select(&self.targets)?,
+            templates,
+            #[cfg(feature = "wasm")]
+            plugins,
+            overrides,
+            user,
+            variants: zero_trust::select_variants(&self.variants)?,
+            format: if self.minify {
+                Format::Minified
+            } else {
+                Format::Pretty
+            },
+            out_dir: self
+                .out_dir
+                .clone()
+                .unwrap_or_else(|| source_dir(&source_path)),
+            source_path,
+        };
+        Ok((plan, source))
+    }
+}
+
+impl Plan {
+    /// Renders every selected target.
+    pub fn render(&self, source: &Source) -> Result<Vec<Output>, Error> {
+        let outputs = zero_trust::render_all(&self.targets, source, &self.variants)?;
+        Ok(self.format(outputs))
+    }
+
+    /// Renders one target.
+    pub fn render_target(
+        &self,
+        target: &dyn Target,
+        source: &Source,
+    ) -> Result<Vec<Output>, Error> {
+        Ok(self.format(target.render(source, &self.variants)?))
+    }
+
+    fn format(&self, outputs: Vec<Output>) -> Vec<Output> {
+        outputs
+            .into_iter()
+            .map(|output| output.format(self.format))
+            .collect()
+    }
+
+    /// Writes `outputs` and reports each file.
+    pub fn write(&self, outputs: &[Output]) -> Result<(), Error> {
+        write_outputs(&self.out_dir, outputs)?;
+        for output in outputs {
+            println!("wrote {}", self.out_dir.join(&output.path).display());
+        }
+        Ok(())
+    }
+}
+
+/// Reports the accent pairs the source's `[lint]` settings flag: as
+/// warnings, or as an error when the lint denies them.
+pub fn lint(source: &Source) -> Result<(), Error> {
+    let pairs = source.near_duplicates();
+    if source.lint.deny && !pairs.is_empty() {
+        return Err(Error::NearDuplicates {
+            threshold: source.lint.duplicates().threshold,
+            pairs,
+        });
+    }
+    for pair in pairs {
+        tracing::warn!(
+            "{} and {} are only {:.4} apart; separate them or lower the `[lint]` threshold",
+            pair.first.name(),
+            pair.second.name(),
+            pair.distance
+        );
+    }
+    Ok(())
+}
+
+#[derive(Debug, Args)]
+pub struct BuildCommand {
+    #[command(flatten)]
+    build: BuildArgs,
+    /// Render every target, even those whose inputs have not changed.
+    #[arg(long)]
+    force: bool,
+}
+
+pub fn run(args: BuildCommand) -> Result<(), Error> {
+    let (plan, source) = args.build.plan()?;
+    lint(&source)?;
+    let mut cache = Cache::load(&plan.out_dir);
+    let models: Vec<Model> = plan
+        .variants
+        .iter()
+        .map(|&variant| Model::new(&source, &source.theme(variant)))
+        .collect();
+    // Render in parallel, then write in target order so the log is stable.
+    let rendered: Vec<(String, Option<Vec<Output>>)> = plan
+        .targets
+        .par_iter()
+        .map(|target| {
+            let fingerprint = cache::fingerprint(target.as_ref(), &models, plan.format);
+            if !args.force && cache.is_fresh(target.name(), &fingerprint, &plan.out_dir) {
+                tracing::debug!(name = target.name(), %fingerprint, "skipping target, inputs unchanged");
+                return Ok((fingerprint, None));
+            }
+            let start = Instant::now();
+            let outputs = plan.render_target(target.as_ref(), &source)?;
+            tracing::debug!(
+                name = target.name(),
+                files = outputs.len(),
+                elapsed = ?start.elapsed(),
+                "rendered target"
+            );
+            Ok((fingerprint, Some(outputs)))
+        })
+        .collect::<Result<_, Error>>()?;
+    for (target, (fingerprint, outputs)) in plan.targets.iter().zip(rendered) {
+        match outputs {
+            Some(outputs) => {
+                plan.write(&outputs)?;
+                cache.record(target.name(), fingerprint, &outputs);
+            }
+            None => println!("{}: up to date", target.name()),
+        }
+    }
+    cache.save(&plan.out_dir)
+}
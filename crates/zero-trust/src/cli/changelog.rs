@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use clap::Args;
+use zero_trust::{changelog, Error, Source};
+
+use super::current_dir;
+
+#[derive(Debug, Args)]
+pub struct ChangelogArgs {
+    /// The earlier revision, e.g. the previous release tag.
+    from: String,
+    /// The later revision.
+    #[arg(default_value = "HEAD")]
+    to: String,
+    /// The theme source [default: the nearest theme.toml]
+    #[arg(long, value_name = "PATH")]
+    source: Option<PathBuf>,
+}
+
+pub fn run(args: ChangelogArgs) -> Result<(), Error> {
+    let path = match args.source {
+        Some(path) => path,
+        None => Source::discover(&current_dir()?)?,
+    };
+    let old = changelog::at_revision(&path, &args.from)?;
+    let new = changelog::at_revision(&path, &args.to)?;
+    print!(
+        "{}",
+        changelog::to_markdown(&changelog::changes(&old, &new))
+    );
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use clap::Args;
+use zero_trust::{stale_outputs, Error};
+
+use super::build::{self, BuildArgs};
+
+#[derive(Debug, Args)]
+pub struct CheckArgs {
+    #[command(flatten)]
+    build: BuildArgs,
+}
+
+pub fn run(args: CheckArgs) -> Result<(), Error> {
+    let (plan, source) = args.build.plan_published()?;
+    build::lint(&source)?;
+    let outputs = plan.render(&source)?;
+    let paths: Vec<_> = stale_outputs(&plan.out_dir, &outputs)
+        .into_iter()
+        .map(|output| plan.out_dir.join(&output.path))
+        .collect();
+    if !paths.is_empty() {
+        return Err(Error::Drift { paths });
+    }
+    println!("{} generated file(s) up to date", outputs.len());
+    Ok(())
+}
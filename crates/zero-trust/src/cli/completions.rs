@@ -0,0 +1,58 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use clap::{Args, CommandFactory};
+use clap_complete::Shell;
+use zero_trust::Error;
+
+use super::Cli;
+
+const BIN: &str = "zero-trust";
+
+#[derive(Debug, Args)]
+pub struct CompletionsArgs {
+    /// The shell to print completions for.
+    #[arg(value_enum, required_unless_present_any = ["man", "out_dir"])]
+    shell: Option<Shell>,
+    /// Print the man page instead.
+    #[arg(long, conflicts_with = "shell")]
+    man: bool,
+    /// Write completions for every shell and a man page for every subcommand into this directory.
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["shell", "man"])]
+    out_dir: Option<PathBuf>,
+}
+
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> Error + '_ {
+    |source| Error::Io {
+        path: path.to_owned(),
+        source,
+    }
+}
+
+pub fn run(args: CompletionsArgs) -> Result<(), Error> {
+    let mut command = Cli::command();
+    if let Some(dir) = args.out_dir {
+        fs::create_dir_all(&dir).map_err(io_error(&dir))?;
+        for shell in [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Elvish,
+        ] {
+            let path = clap_complete::generate_to(shell, &mut command, BIN, &dir)
+                .map_err(io_error(&dir))?;
+            println!("wrote {}", path.display());
+        }
+        clap_mangen::generate_to(Cli::command(), &dir).map_err(io_error(&dir))?;
+        println!("wrote man pages to {}", dir.display());
+    } else if args.man {
+        clap_mangen::Man::new(command)
+            .render(&mut io::stdout())
+            .map_err(io_error(Path::new("-")))?;
+    } else if let Some(shell) = args.shell {
+        clap_complete::generate(shell, &mut command, BIN, &mut io::stdout());
+    }
+    Ok(())
+}
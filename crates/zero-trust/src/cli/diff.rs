@@ -0,0 +1,123 @@
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+
+use anstyle::Style;
+use clap::{Args, ValueEnum};
+use zero_trust::diff::{diff, Change};
+use zero_trust::install::{self, Dirs};
+use zero_trust::Error;
+use zero_trust_palette::roles::Role;
+
+use super::build::BuildArgs;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Color output for terminals unless `NO_COLOR` is set.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Auto => {
+                io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+            }
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct DiffArgs {
+    #[command(flatten)]
+    build: BuildArgs,
+    /// Compare against the installed files instead of the output directory,
+    /// with `user.toml` applied as `install` does.
+    #[arg(long)]
+    installed: bool,
+    #[arg(long, value_enum, default_value_t)]
+    color: ColorChoice,
+}
+
+struct Painter {
+    enabled: bool,
+}
+
+impl Painter {
+    fn paint(&self, role: Role, text: &str) -> String {
+        if self.enabled {
+            let style = Style::from(role);
+            format!("{style}{text}{style:#}")
+        } else {
+            text.to_owned()
+        }
+    }
+}
+
+pub fn run(args: DiffArgs) -> Result<(), Error> {
+    // The output directory holds the published theme and the installed
+    // files the personalized one, so each is compared against its own.
+    let (plan, source) = if args.installed {
+        args.build.plan()?
+    } else {
+        args.build.plan_published()?
+    };
+    let painter = Painter {
+        enabled: args.color.enabled(),
+    };
+    let dirs = Dirs::from_env();
+    let mut clean = true;
+    for target in &plan.targets {
+        let base = if args.installed {
+            match dirs
+                .as_ref()
+                .and_then(|dirs| install::detect(target.as_ref(), dirs, &source.meta))
+            {
+                Some(dir) => dir.themes,
+                None => continue,
+            }
+        } else {
+            plan.out_dir.clone()
+        };
+        for output in plan.render_target(target.as_ref(), &source)? {
+            let path: PathBuf = if args.installed {
+                base.join(output.path.file_name().expect("outputs are files"))
+            } else {
+                base.join(&output.path)
+            };
+            let old = fs::read_to_string(&path).ok();
+            let changes = diff(&output.path, old.as_deref(), &output.contents);
+            if changes.is_empty() {
+                continue;
+            }
+            clean = false;
+            println!(
+                "{}",
+                painter.paint(Role::Accent, &path.display().to_string())
+            );
+            for change in changes {
+                let line = match change {
+                    Change::Added { at, new } => {
+                        painter.paint(Role::Success, &format!("  + {at}: {new}"))
+                    }
+                    Change::Removed { at, old } => {
+                        painter.paint(Role::Error, &format!("  - {at}: {old}"))
+                    }
+                    Change::Changed { at, old, new } => {
+                        painter.paint(Role::Warning, &format!("  ~ {at}: {old} -> {new}"))
+                    }
+                };
+                println!("{line}");
+            }
+        }
+    }
+    if clean {
+        println!("no differences");
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+
+use clap::Args;
+use zero_trust::scaffold::{self, Project};
+use zero_trust::{write_outputs, Error};
+
+#[derive(Debug, Args)]
+pub struct InitArgs {
+    /// The directory to create the project in; its name names the theme.
+    dir: PathBuf,
+    /// The theme's display name [default: the directory name, title-cased]
+    #[arg(long)]
+    name: Option<String>,
+    /// The theme's author [default: git's user.name]
+    #[arg(long)]
+    author: Option<String>,
+    /// Scaffold into a directory that is not empty, overwriting files of the same name.
+    #[arg(long)]
+    force: bool,
+}
+
+/// The author git would record, falling back to the login name.
+fn default_author() -> String {
+    Command::new("git")
+        .args(["config", "user.name"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+        .filter(|name| !name.is_empty())
+        .or_else(|| env::var("USER").ok())
+        .unwrap_or_default()
+}
+
+pub fn run(args: InitArgs) -> Result<(), Error> {
+    let not_empty = fs::read_dir(&args.dir).is_ok_and(|mut entries| entries.next().is_some());
+    if not_empty && !args.force {
+        return Err(Error::Io {
+            path: args.dir,
+            source: io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "directory is not empty; pass --force to scaffold into it anyway",
+            ),
+        });
+    }
+    let dir_name = args
+        .dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "my-theme".into());
+    let author = args.author.unwrap_or_else(default_author);
+    let mut project = Project::from_dir_name(&dir_name, author);
+    if let Some(name) = args.name {
+        project.name = name;
+    }
+    let outputs = scaffold::files(&project);
+    write_outputs(&args.dir, &outputs)?;
+    for output in &outputs {
+        println!("wrote {}", args.dir.join(&output.path).display());
+    }
+    println!(
+        "\n{} is ready; edit theme.toml, then run:\n\n    cd {} && zero-trust build",
+        project.name,
+        args.dir.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use std::fs;
+
+use clap::Args;
+use zero_trust::install::{self, Action, Dirs, Mode};
+use zero_trust::targets::Registry;
+use zero_trust::{Error, Source};
+use zero_trust_palette::Variant;
+
+use super::build::BuildArgs;
+
+#[derive(Debug, Args)]
+pub struct InstallArgs {
+    #[command(flatten)]
+    build: BuildArgs,
+    /// Link to the built files instead of copying them, so rebuilding updates
+    /// the installed theme.
+    #[arg(long)]
+    symlink: bool,
+    /// Print what would be done without touching any file.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct UninstallArgs {
+    /// Targets to uninstall, comma-separated [default: all]
+    #[arg(long = "target", value_name = "NAME", value_delimiter = ',')]
+    targets: Vec<String>,
+    /// Print what would be done without touching any file.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+fn dirs() -> Result<Dirs, Error> {
+    Dirs::from_env().ok_or(Error::NoHome)
+}
+
+fn perform(actions: &[Action], dry_run: bool) -> Result<(), Error> {
+    for action in actions {
+        if dry_run {
+            println!("would {action}");
+        } else {
+            action.apply()?;
+            println!("{action}");
+        }
+    }
+    Ok(())
+}
+
+pub fn run_install(args: InstallArgs) -> Result<(), Error> {
+    let dirs = dirs()?;
+    let (mut plan, source) = args.build.plan()?;
+    let mode = if args.symlink {
+        Mode::Symlink
+    } else {
+        Mode::Copy
+    };
+    let mut found = false;
+    for target in &plan.targets.clone() {
+        let Some(dir) = install::detect(target.as_ref(), &dirs, &source.meta) else {
+            continue;
+        };
+        found = true;
+        let outputs = plan.render_target(target.as_ref(), &source)?;
+        if mode == Mode::Symlink && !args.dry_run {
+            plan.write(&outputs)?;
+            plan.out_dir = fs::canonicalize(&plan.out_dir).map_err(|source| Error::Io {
+                path: plan.out_dir.clone(),
+                source,
+            })?;
+        }
+        println!("{}: {}", target.name(), dir.app.display());
+        let mut actions = install::install_actions(&dir, &outputs, mode, &plan.out_dir);
+        actions.extend(install::refresh_action(target.as_ref()));
+        perform(&actions, args.dry_run)?;
+    }
+    if !found {
+        println!("no supported application found");
+    }
+    Ok(())
+}
+
+pub fn run_uninstall(args: UninstallArgs) -> Result<(), Error> {
+    let dirs = dirs()?;
+    // Render every variant so files from any earlier install are found,
+    // named after the nearest theme source if there is one.
+    let source = match Source::discover(&super::current_dir()?) {
+        Ok(path) => Source::load(&path)?,
+        Err(_) => Source::default(),
+    };
+    for target in Registry::builtin().select(&args.targets)? {
+        let Some(dir) = install::detect(target.as_ref(), &dirs, &source.meta) else {
+            continue;
+        };
+        let outputs = target.render(&source, Variant::ALL)?;
+        let mut actions = install::uninstall_actions(&dir, &outputs);
+        if actions.is_empty() {
+            continue;
+        }
+        println!("{}: {}", target.name(), dir.app.display());
+        actions.extend(install::refresh_action(target.as_ref()));
+        perform(&actions, args.dry_run)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,175 @@
+//! Command-line interface of the `zero-trust` binary.
+
+use std::env;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+use clap::{ArgAction, Args, Parser, Subcommand};
+use tracing::Level;
+use zero_trust::{Error, Source};
+use zero_trust_palette::Tuning;
+
+mod auto;
+mod build;
+mod changelog;
+mod check;
+mod completions;
+mod diff;
+mod init;
+mod install;
+mod package;
+mod release;
+mod schema;
+mod watch;
+
+/// Generate and manage the Zero Trust theme files.
+#[derive(Debug, Parser)]
+#[command(name = "zero-trust", version, about)]
+pub struct Cli {
+    /// Log what the generator does to standard error; repeat for more detail.
+    #[arg(long, short, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Render theme files for the selected targets and variants.
+    Build(build::BuildCommand),
+    /// Rebuild whenever the theme source changes.
+    Watch(watch::WatchArgs),
+    /// Install theme files into the applications found on this machine.
+    Install(install::InstallArgs),
+    /// Remove installed theme files.
+    Uninstall(install::UninstallArgs),
+    /// Switch installed themes between dark and light with the system appearance.
+    Auto(auto::AutoArgs),
+    /// Show how regenerating would change the files on disk.
+    Diff(diff::DiffArgs),
+    /// Fail if any generated file on disk is out of date.
+    Check(check::CheckArgs),
+    /// Bundle generated themes for distribution.
+    #[command(subcommand)]
+    Package(package::PackageCommand),
+    /// Write a SHA-256 manifest of the generated files, optionally signed.
+    Release(release::ReleaseArgs),
+    /// Summarize palette and role changes between two git revisions.
+    Changelog(changelog::ChangelogArgs),
+    /// Print shell completions or the man page, for packagers.
+    Completions(completions::CompletionsArgs),
+    /// Scaffold a new theme project derived from Zero Trust.
+    Init(init::InitArgs),
+    /// Print a JSON Schema for theme.toml, for editor completion and validation.
+    Schema(schema::SchemaArgs),
+}
+
+impl Cli {
+    pub fn run(self) -> Result<(), Error> {
+        init_logging(self.verbose);
+        match self.command {
+            Command::Build(args) => build::run(args),
+            Command::Watch(args) => watch::run(args),
+            Command::Install(args) => install::run_install(args),
+            Command::Uninstall(args) => install::run_uninstall(args),
+            Command::Auto(args) => auto::run(args),
+            Command::Diff(args) => diff::run(args),
+            Command::Check(args) => check::run(args),
+            Command::Package(command) => package::run(command),
+            Command::Release(args) => release::run(args),
+            Command::Changelog(args) => changelog::run(args),
+            Command::Completions(args) => completions::run(args),
+            Command::Init(args) => init::run(args),
+            Command::Schema(args) => schema::run(args),
+        }
+    }
+}
+
+/// Logs to standard error: warnings only by default, which targets were
+/// built or skipped, override layers and render timings with `-v`, and
+/// everything with `-vv`.
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => Level::WARN,
+        1 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .with_target(false)
+        .without_time()
+        .init();
+}
+
+/// Prints `err` to standard error, as an annotated snippet when it points
+/// into the theme source.
+pub fn report(err: &Error) {
+    match err.diagnostic() {
+        Some(diagnostic) => eprintln!("{:?}", miette::Report::new(diagnostic)),
+        None => eprintln!("error: {err}"),
+    }
+}
+
+/// Locating and adjusting the theme source, shared by every subcommand.
+#[derive(Debug, Args)]
+struct SourceArgs {
+    /// The theme source [default: the nearest theme.toml]
+    #[arg(long, value_name = "PATH")]
+    source: Option<PathBuf>,
+    /// Multiply the lightness of every palette color, e.g. 0.9 for a bright display.
+    #[arg(long, value_name = "FACTOR", value_parser = tuning_factor)]
+    brightness: Option<f64>,
+    /// Multiply the chroma of every palette color, e.g. 1.1 for a washed-out display.
+    #[arg(long, value_name = "FACTOR", value_parser = tuning_factor)]
+    saturation: Option<f64>,
+}
+
+/// Parses a `--brightness` or `--saturation` factor.
+fn tuning_factor(arg: &str) -> Result<f64, String> {
+    let factor = arg.parse::<f64>().map_err(|e| e.to_string())?;
+    if Tuning::is_valid_factor(factor) {
+        Ok(factor)
+    } else {
+        Err("expected a finite factor greater than 0".into())
+    }
+}
+
+impl SourceArgs {
+    /// Loads the source and applies the command-line tuning over its own.
+    fn load(&self) -> Result<(PathBuf, Source), Error> {
+        let path = match &self.source {
+            Some(path) => path.clone(),
+            None => Source::discover(&current_dir()?)?,
+        };
+        Ok((path.clone(), self.load_from(&path)?))
+    }
+
+    /// Loads the source at `path` and applies the command-line tuning.
+    fn load_from(&self, path: &Path) -> Result<Source, Error> {
+        let mut source = Source::load(path)?;
+        if let Some(brightness) = self.brightness {
+            source.tuning.brightness = brightness;
+        }
+        if let Some(saturation) = self.saturation {
+            source.tuning.saturation = saturation;
+        }
+        Ok(source)
+    }
+}
+
+fn current_dir() -> Result<PathBuf, Error> {
+    env::current_dir().map_err(|source| Error::Io {
+        path: ".".into(),
+        source,
+    })
+}
+
+/// The directory holding `path`, where outputs go unless told otherwise.
+fn source_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_owned(),
+        _ => PathBuf::from("."),
+    }
+}
@@ -0,0 +1,101 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Subcommand};
+use zero_trust::package::{self, Extension, Vsix};
+use zero_trust::Error;
+
+use super::build::BuildArgs;
+use super::source_dir;
+
+#[derive(Debug, Subcommand)]
+pub enum PackageCommand {
+    /// Build a VS Code extension (.vsix) from the `vscode` target.
+    Vscode(VscodeArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct VscodeArgs {
+    #[command(flatten)]
+    build: BuildArgs,
+    /// The marketplace publisher [default: the repository owner in extension.toml]
+    #[arg(long, value_name = "NAME")]
+    publisher: Option<String>,
+    /// A PNG icon of at least 128x128 pixels.
+    #[arg(long, value_name = "PATH")]
+    icon: Option<PathBuf>,
+    /// Where to write the archive [default: <id>-<version>.vsix in the output directory]
+    #[arg(long, short, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, Error> {
+    fs::read(path).map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// Reads `path` if it exists.
+fn read_optional(path: &Path) -> Result<Option<String>, Error> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(Error::Io {
+            path: path.to_owned(),
+            source,
+        }),
+    }
+}
+
+pub fn run(command: PackageCommand) -> Result<(), Error> {
+    match command {
+        PackageCommand::Vscode(args) => vscode(args),
+    }
+}
+
+fn vscode(args: VscodeArgs) -> Result<(), Error> {
+    let (plan, source) = args.build.plan_published()?;
+    let dir = source_dir(&plan.source_path);
+    let extension = Extension::load(&dir.join(package::MANIFEST))?;
+    let target = plan
+        .targets
+        .iter()
+        .find(|target| target.name() == "vscode")
+        .ok_or_else(|| Error::UnknownTarget {
+            name: "vscode".into(),
+            available: plan.targets.iter().map(|t| t.name().to_owned()).collect(),
+        })?;
+    let publisher = match args.publisher {
+        Some(publisher) => publisher,
+        None => extension
+            .repository_owner()
+            .ok_or_else(|| Error::Io {
+                path: dir.join(package::MANIFEST),
+                source: std::io::Error::other(
+                    "no repository to take the publisher from; pass --publisher",
+                ),
+            })?
+            .to_owned(),
+    };
+    let vsix = Vsix {
+        themes: Vsix::themes(target.as_ref(), &source, &plan.variants, plan.format)?,
+        publisher,
+        license: read_optional(&dir.join("LICENSE"))?,
+        readme: read_optional(&dir.join("README.md"))?,
+        icon: args.icon.as_deref().map(read).transpose()?,
+        extension,
+    };
+    let output = args.output.unwrap_or_else(|| {
+        plan.out_dir.join(format!(
+            "{}-{}.vsix",
+            vsix.extension.id, vsix.extension.version
+        ))
+    });
+    fs::write(&output, vsix.to_bytes()).map_err(|source| Error::Io {
+        path: output.clone(),
+        source,
+    })?;
+    println!("wrote {} ({} themes)", output.display(), vsix.themes.len());
+    Ok(())
+}
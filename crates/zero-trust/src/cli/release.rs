@@ -0,0 +1,104 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Args;
+use zero_trust::release::{self, Manifest};
+use zero_trust::Error;
+
+use super::build::BuildArgs;
+
+/// Read as the secret key's password, so signing can run unattended.
+const PASSWORD_VAR: &str = "ZERO_TRUST_MINISIGN_PASSWORD";
+
+#[derive(Debug, Args)]
+pub struct ReleaseArgs {
+    #[command(flatten)]
+    build: BuildArgs,
+    /// Further artifacts to list, relative to and inside the output directory, e.g. a .vsix
+    #[arg(long, value_name = "PATH")]
+    include: Vec<PathBuf>,
+    /// Sign the manifest with this minisign secret key [password: $ZERO_TRUST_MINISIGN_PASSWORD or a prompt]
+    #[arg(long, value_name = "KEY", conflicts_with = "verify")]
+    sign: Option<PathBuf>,
+    /// Check the files against an existing manifest instead of writing one.
+    #[arg(long)]
+    verify: bool,
+    /// With --verify, also check the manifest's signature against this minisign public key.
+    #[arg(long, value_name = "KEY", requires = "verify")]
+    public_key: Option<PathBuf>,
+}
+
+fn read(path: PathBuf) -> Result<String, Error> {
+    fs::read_to_string(&path).map_err(|source| Error::Io { path, source })
+}
+
+pub fn run(args: ReleaseArgs) -> Result<(), Error> {
+    let (plan, source) = args.build.plan_published()?;
+    let manifest_path = plan.out_dir.join(release::MANIFEST);
+    let signature_path = plan.out_dir.join(release::SIGNATURE);
+
+    if args.verify {
+        let text = read(manifest_path.clone())?;
+        if let Some(key_path) = args.public_key {
+            let key = read(key_path)?;
+            let signature = read(signature_path.clone())?;
+            release::verify_signature(&text, &signature, &key).map_err(|e| Error::Signature {
+                path: signature_path.clone(),
+                message: e.to_string(),
+            })?;
+            println!("{}: good signature", signature_path.display());
+        }
+        let manifest = Manifest::from_text(&text, &manifest_path)?;
+        let paths = manifest.verify(&plan.out_dir);
+        if !paths.is_empty() {
+            return Err(Error::Signature {
+                path: manifest_path,
+                message: format!(
+                    "{} file(s) missing or modified:\n  {}",
+                    paths.len(),
+                    paths
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join("\n  ")
+                ),
+            });
+        }
+        println!(
+            "{}: {} files verified",
+            manifest_path.display(),
+            manifest.len()
+        );
+        return Ok(());
+    }
+
+    let outputs = plan.render(&source)?;
+    plan.write(&outputs)?;
+    let mut manifest = Manifest::default();
+    for output in &outputs {
+        manifest.insert(&output.path, output.contents.as_bytes());
+    }
+    for path in &args.include {
+        manifest.include(&plan.out_dir, path)?;
+    }
+    let text = manifest.to_string();
+    fs::write(&manifest_path, &text).map_err(|source| Error::Io {
+        path: manifest_path.clone(),
+        source,
+    })?;
+    println!(
+        "wrote {} ({} files)",
+        manifest_path.display(),
+        manifest.len()
+    );
+    if let Some(key_path) = args.sign {
+        let signature = release::sign_file(&text, &key_path, env::var(PASSWORD_VAR).ok())?;
+        fs::write(&signature_path, signature).map_err(|source| Error::Io {
+            path: signature_path.clone(),
+            source,
+        })?;
+        println!("wrote {}", signature_path.display());
+    }
+    Ok(())
+}
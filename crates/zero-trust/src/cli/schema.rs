@@ -0,0 +1,27 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Args;
+use zero_trust::{schema, Error};
+
+#[derive(Debug, Args)]
+pub struct SchemaArgs {
+    /// Write the schema to this file instead of standard output.
+    #[arg(long, short, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+pub fn run(args: SchemaArgs) -> Result<(), Error> {
+    let text = schema::to_string();
+    match args.output {
+        Some(path) => {
+            fs::write(&path, text).map_err(|source| Error::Io {
+                path: path.clone(),
+                source,
+            })?;
+            println!("wrote {}", path.display());
+        }
+        None => print!("{text}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, SystemTime};
+
+use clap::Args;
+use notify::{RecursiveMode, Watcher};
+use zero_trust::{Error, Output};
+
+use super::build::{BuildArgs, Plan};
+
+/// How long to wait for an editor's burst of save events to settle.
+const DEBOUNCE: Duration = Duration::from_millis(150);
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    #[command(flatten)]
+    build: BuildArgs,
+    /// Bump the modification time of this file after every rebuild, e.g. an
+    /// editor's settings file, to make it reload the theme (repeatable).
+    #[arg(long, value_name = "PATH")]
+    touch: Vec<PathBuf>,
+}
+
+fn watch_error(path: &Path, err: notify::Error) -> Error {
+    Error::Io {
+        path: path.to_owned(),
+        source: io::Error::other(err),
+    }
+}
+
+fn touch(path: &Path) -> Result<(), Error> {
+    let file = File::options()
+        .append(true)
+        .open(path)
+        .map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+    file.set_modified(SystemTime::now())
+        .map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+}
+
+/// Keeps the last rendered contents so only changed files are rewritten.
+#[derive(Default)]
+struct Rebuilder {
+    written: HashMap<PathBuf, String>,
+}
+
+impl Rebuilder {
+    /// Re-plans from scratch, so edited and newly added templates and
+    /// overrides are seen.
+    fn rebuild(&mut self, args: &WatchArgs) -> Result<Plan, Error> {
+        let (plan, source) = args.build.plan()?;
+        let changed: Vec<Output> = plan
+            .render(&source)?
+            .into_iter()
+            .filter(|output| self.written.get(&output.path) != Some(&output.contents))
+            .collect();
+        if changed.is_empty() {
+            println!("no changes");
+            return Ok(plan);
+        }
+        plan.write(&changed)?;
+        for output in changed {
+            self.written.insert(output.path, output.contents);
+        }
+        for path in &args.touch {
+            touch(path)?;
+        }
+        Ok(plan)
+    }
+}
+
+pub fn run(args: WatchArgs) -> Result<(), Error> {
+    let mut rebuilder = Rebuilder::default();
+    let plan = rebuilder.rebuild(&args)?;
+    let source_path = plan.source_path.clone();
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).map_err(|e| watch_error(&source_path, e))?;
+    // Editors often save by renaming a temporary file over the original, so
+    // watch the directory rather than the file itself.
+    let dir = super::source_dir(&source_path);
+    watcher
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .map_err(|e| watch_error(&dir, e))?;
+    println!("watching {}", source_path.display());
+    #[cfg(feature = "wasm")]
+    let extra_dirs = [&plan.templates, &plan.plugins, &plan.overrides];
+    #[cfg(not(feature = "wasm"))]
+    let extra_dirs = [&plan.templates, &plan.overrides];
+    let user_dir = plan.user.as_deref().and_then(Path::parent);
+    for dir in extra_dirs
+        .into_iter()
+        .map(PathBuf::as_path)
+        .chain(user_dir)
+        .filter(|dir| dir.is_dir())
+    {
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(|e| watch_error(dir, e))?;
+        println!("watching {}", dir.display());
+    }
+
+    let file_name = source_path.file_name();
+    let is_relevant = |event: &notify::Result<notify::Event>| match event {
+        Ok(event) => event.paths.iter().any(|path| {
+            path.file_name() == file_name
+                || path
+                    .extension()
+                    .is_some_and(|ext| ext == "tera" || ext == "wasm" || ext == "toml")
+        }),
+        Err(_) => true,
+    };
+    while let Ok(event) = rx.recv() {
+        if !is_relevant(&event) {
+            continue;
+        }
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
+        if let Err(err) = event {
+            eprintln!("error: {}", watch_error(&source_path, err));
+            continue;
+        }
+        // A broken file mid-edit should not end the session.
+        if let Err(err) = rebuilder.rebuild(&args) {
+            super::report(&err);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! Pointing theme source errors at the offending text.
+//!
+//! The TOML parser reports where a value failed to deserialize; this adds
+//! the file contents so the error can be rendered as an annotated snippet,
+//! plus a hint for the mistakes people actually make: a misspelled role or
+//! palette entry name, or a malformed hex color.
+
+use std::fmt;
+use std::path::Path;
+
+use miette::{LabeledSpan, NamedSource, SourceSpan};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Swatch};
+
+/// Names further than this many edits from every candidate get no hint.
+const MAX_EDITS: usize = 3;
+
+/// A theme source error with its location in the file.
+#[derive(Debug)]
+pub struct Diagnostic {
+    message: String,
+    source_code: NamedSource<String>,
+    span: Option<SourceSpan>,
+    help: Option<String>,
+}
+
+/// The candidate closest to `name`, if it is close enough to be a typo.
+pub fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .map(|candidate| (strsim::damerau_levenshtein(name, candidate), candidate))
+        .filter(|&(edits, _)| edits <= MAX_EDITS)
+        .min_by_key(|&(edits, _)| edits)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The backquoted names in a serde "unknown field" message.
+fn expected_names(message: &str) -> Vec<&str> {
+    let Some((_, expected)) = message.split_once("expected") else {
+        return Vec::new();
+    };
+    expected.split('`').skip(1).step_by(2).collect()
+}
+
+/// A hint for the value `found` that `message` rejected.
+fn help(message: &str, found: &str) -> Option<String> {
+    let suggest =
+        |names: Vec<&str>| did_you_mean(found, names).map(|name| format!("did you mean `{name}`?"));
+    if message.contains("expected a syntax role name") {
+        suggest(
+            Role::ALL
+                .iter()
+                .filter(|role| role.is_syntax())
+                .map(|role| role.name())
+                .collect(),
+        )
+    } else if message.contains("expected a role name") {
+        suggest(Role::ALL.iter().map(|role| role.name()).collect())
+    } else if message.contains("expected a palette entry name") {
+        suggest(Swatch::ALL.iter().map(|swatch| swatch.name()).collect())
+    } else if message.contains("expected a hex color") {
+        let reason = Color::from_hex(found).err()?;
+        Some(format!(
+            "{reason}; colors are written as `#RGB`, `#RRGGBB` or `#RRGGBBAA`"
+        ))
+    } else if message.starts_with("unknown field") {
+        let field = message.split('`').nth(1)?;
+        did_you_mean(field, expected_names(message)).map(|name| format!("did you mean `{name}`?"))
+    } else {
+        None
+    }
+}
+
+impl Diagnostic {
+    pub(crate) fn new(path: &Path, text: &str, err: &toml::de::Error) -> Diagnostic {
+        let message = err.message().trim_end().to_owned();
+        let span = err.span();
+        let found = span
+            .clone()
+            .and_then(|span| text.get(span))
+            .map(|found| found.trim().trim_matches(['"', '\'']))
+            .unwrap_or_default();
+        Diagnostic {
+            help: help(&message, found),
+            source_code: NamedSource::new(path.display().to_string(), text.to_owned())
+                .with_language("TOML"),
+            span: span.map(SourceSpan::from),
+            message,
+        }
+    }
+
+    /// The hint shown below the snippet, if any.
+    pub fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+impl miette::Diagnostic for Diagnostic {
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        Some(&self.source_code)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let span = self.span?;
+        Some(Box::new(std::iter::once(LabeledSpan::new_with_span(
+            Some("here".into()),
+            span,
+        ))))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn fmt::Display>)
+    }
+}
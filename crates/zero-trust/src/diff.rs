@@ -0,0 +1,176 @@
+//! Comparing generated files with the ones on disk.
+//!
+//! JSON and TOML outputs are compared as data, so a reviewer sees
+//! `themes[Zero Trust Dark].style.background` changing rather than a shifted
+//! line; every other format falls back to a line diff.
+
+use std::fmt;
+use std::path::Path;
+
+use serde_json::Value;
+use similar::{ChangeTag, TextDiff};
+
+/// One difference between the old and new version of a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    Added {
+        at: String,
+        new: String,
+    },
+    Removed {
+        at: String,
+        old: String,
+    },
+    Changed {
+        at: String,
+        old: String,
+        new: String,
+    },
+}
+
+/// A structural key path such as `style."editor.background"`.
+#[derive(Clone, Debug, Default)]
+struct KeyPath(String);
+
+impl KeyPath {
+    fn key(&self, key: &str) -> KeyPath {
+        let simple = !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+        let segment = if simple {
+            key.to_owned()
+        } else {
+            format!("{key:?}")
+        };
+        if self.0.is_empty() {
+            KeyPath(segment)
+        } else {
+            KeyPath(format!("{}.{segment}", self.0))
+        }
+    }
+
+    fn index(&self, label: &str) -> KeyPath {
+        KeyPath(format!("{}[{label}]", self.0))
+    }
+}
+
+impl fmt::Display for KeyPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.0.is_empty() { "(root)" } else { &self.0 })
+    }
+}
+
+fn name(item: &Value) -> Option<&str> {
+    item.get("name").and_then(Value::as_str)
+}
+
+/// Whether no two elements of `items` share a `name`.
+fn unique_names(items: &[Value]) -> bool {
+    items
+        .iter()
+        .enumerate()
+        .all(|(i, item)| name(item).is_none() || !items[..i].iter().any(|o| name(o) == name(item)))
+}
+
+/// Array elements are labelled by their `name` when they have one, so
+/// reordering a theme family does not show up as every theme changing.
+/// When either side repeats a name, the names no longer say which element
+/// is which, and both sides are labelled by index instead.
+fn labels(old: &[Value], new: &[Value]) -> (Vec<String>, Vec<String>) {
+    let by_name = unique_names(old) && unique_names(new);
+    let label = |items: &[Value]| -> Vec<String> {
+        items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| match name(item) {
+                Some(name) if by_name => name.to_owned(),
+                _ => i.to_string(),
+            })
+            .collect()
+    };
+    (label(old), label(new))
+}
+
+fn walk(at: KeyPath, old: Option<&Value>, new: Option<&Value>, changes: &mut Vec<Change>) {
+    match (old, new) {
+        (Some(Value::Object(old)), Some(Value::Object(new))) => {
+            for (key, value) in new {
+                walk(at.key(key), old.get(key), Some(value), changes);
+            }
+            for (key, value) in old.iter().filter(|(key, _)| !new.contains_key(*key)) {
+                walk(at.key(key), Some(value), None, changes);
+            }
+        }
+        (Some(Value::Array(old)), Some(Value::Array(new))) => {
+            let (old_labels, new_labels) = labels(old, new);
+            for (label, value) in new_labels.iter().zip(new) {
+                let previous = old_labels.iter().position(|l| l == label).map(|i| &old[i]);
+                walk(at.index(label), previous, Some(value), changes);
+            }
+            for (label, value) in old_labels.iter().zip(old) {
+                if !new_labels.contains(label) {
+                    walk(at.index(label), Some(value), None, changes);
+                }
+            }
+        }
+        (Some(old), Some(new)) if old != new => changes.push(Change::Changed {
+            at: at.to_string(),
+            old: old.to_string(),
+            new: new.to_string(),
+        }),
+        (None, Some(new)) => changes.push(Change::Added {
+            at: at.to_string(),
+            new: new.to_string(),
+        }),
+        (Some(old), None) => changes.push(Change::Removed {
+            at: at.to_string(),
+            old: old.to_string(),
+        }),
+        _ => {}
+    }
+}
+
+/// Parses `text` as structured data if `path`'s extension is a known format.
+fn parse(path: &Path, text: &str) -> Option<Value> {
+    match path.extension()?.to_str()? {
+        "json" => serde_json::from_str(text).ok(),
+        "toml" => toml::from_str::<toml::Value>(text)
+            .ok()
+            .and_then(|value| serde_json::to_value(value).ok()),
+        _ => None,
+    }
+}
+
+/// The changes turning `old` into `new`; `old` is `None` for a new file.
+pub fn diff(path: &Path, old: Option<&str>, new: &str) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let Some(old) = old else {
+        changes.push(Change::Added {
+            at: "(file)".into(),
+            new: format!("{} lines", new.lines().count()),
+        });
+        return changes;
+    };
+    if old == new {
+        return changes;
+    }
+    if let (Some(old), Some(new)) = (parse(path, old), parse(path, new)) {
+        walk(KeyPath::default(), Some(&old), Some(&new), &mut changes);
+        return changes;
+    }
+    let lines = TextDiff::from_lines(old, new);
+    for change in lines.iter_all_changes() {
+        let at = format!(
+            "line {}",
+            change.new_index().or(change.old_index()).unwrap_or(0) + 1
+        );
+        let text = change.value().trim_end_matches('\n').to_owned();
+        match change.tag() {
+            ChangeTag::Insert => changes.push(Change::Added { at, new: text }),
+            ChangeTag::Delete => changes.push(Change::Removed { at, old: text }),
+            ChangeTag::Equal => {}
+        }
+    }
+    changes
+}
@@ -0,0 +1,160 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+use zero_trust_palette::difference::NearDuplicate;
+use zero_trust_palette::{Swatch, Variant};
+
+use crate::diagnostic::Diagnostic;
+
+/// Errors from loading a theme source or writing outputs.
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// The theme source is not valid TOML or does not match the schema.
+    Parse {
+        path: PathBuf,
+        /// The file contents, for pointing at the error.
+        text: String,
+        source: Box<toml::de::Error>,
+    },
+    /// No `theme.toml` was found in the directory or any parent.
+    NotFound { start: PathBuf },
+    /// A target name that is neither built in nor a loaded template.
+    UnknownTarget {
+        name: String,
+        available: Vec<String>,
+    },
+    /// A template failed to parse or render.
+    Template { path: PathBuf, source: tera::Error },
+    /// A WebAssembly plugin failed to load or run, or returned bad output.
+    Plugin { path: PathBuf, message: String },
+    /// A variant name that is neither a variant slug nor `all`.
+    UnknownVariant(String),
+    /// Generated files on disk no longer match the theme source.
+    Drift { paths: Vec<PathBuf> },
+    /// A release manifest could not be signed, or failed verification.
+    Signature { path: PathBuf, message: String },
+    /// A revision of the theme source could not be read from git.
+    Git { revision: String, message: String },
+    /// The home directory could not be determined.
+    NoHome,
+    /// The desktop's dark or light appearance could not be read.
+    UnknownAppearance,
+    /// Accents closer than the `[lint]` threshold, with `deny = true`.
+    NearDuplicates {
+        threshold: f64,
+        pairs: Vec<NearDuplicate<Swatch>>,
+    },
+}
+
+impl Error {
+    /// The error as an annotated snippet of the theme source, for errors
+    /// that point into one.
+    pub fn diagnostic(&self) -> Option<Diagnostic> {
+        match self {
+            Error::Parse { path, text, source } => Some(Diagnostic::new(path, text, source)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Error::Io {
+            path: path.into(),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::Parse { path, source, .. } => write!(f, "{}: {source}", path.display()),
+            Error::NotFound { start } => write!(
+                f,
+                "no {} found in {} or any parent directory",
+                crate::Source::FILE_NAME,
+                start.display()
+            ),
+            Error::UnknownTarget { name, available } => write!(
+                f,
+                "unknown target `{name}` (available: {})",
+                available.join(", ")
+            ),
+            Error::Template { path, source } => {
+                // Tera keeps the useful message in the error's source chain.
+                write!(f, "{}: {source}", path.display())?;
+                let mut cause = std::error::Error::source(source);
+                while let Some(err) = cause {
+                    write!(f, ": {err}")?;
+                    cause = err.source();
+                }
+                Ok(())
+            }
+            Error::Plugin { path, message } => write!(f, "{}: {message}", path.display()),
+            Error::UnknownVariant(name) => {
+                write!(f, "unknown variant `{name}` (available: all")?;
+                for variant in Variant::ALL {
+                    write!(f, ", {}", variant.slug())?;
+                }
+                f.write_str(")")
+            }
+            Error::Drift { paths } => {
+                write!(
+                    f,
+                    "{} generated file(s) out of date with the theme source; run `zero-trust build`:",
+                    paths.len()
+                )?;
+                for path in paths {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            Error::Signature { path, message } => write!(f, "{}: {message}", path.display()),
+            Error::Git { revision, message } => write!(f, "git revision `{revision}`: {message}"),
+            Error::NoHome => f.write_str("cannot determine the home directory"),
+            Error::UnknownAppearance => {
+                f.write_str("could not read the dark or light appearance of this desktop")
+            }
+            Error::NearDuplicates { threshold, pairs } => {
+                write!(
+                    f,
+                    "{} accent pair(s) closer than the lint threshold of {threshold}:",
+                    pairs.len()
+                )?;
+                for pair in pairs {
+                    write!(
+                        f,
+                        "\n  {} and {} ({:.4})",
+                        pair.first.name(),
+                        pair.second.name(),
+                        pair.distance
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { source, .. } => Some(source),
+            Error::Template { source, .. } => Some(source),
+            Error::NotFound { .. }
+            | Error::UnknownTarget { .. }
+            | Error::Plugin { .. }
+            | Error::UnknownVariant(_)
+            | Error::Drift { .. }
+            | Error::Signature { .. }
+            | Error::Git { .. }
+            | Error::NoHome
+            | Error::UnknownAppearance
+            | Error::NearDuplicates { .. } => None,
+        }
+    }
+}
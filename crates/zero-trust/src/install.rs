@@ -0,0 +1,240 @@
+//! Installing generated files into local applications.
+//!
+//! Each [`Target`] may say where its application looks for themes through
+//! [`Target::install_dir`]. Installation only happens for applications whose
+//! configuration directory already exists, so nothing is created for tools
+//! the user does not have. Targets whose application caches its themes
+//! also name a [`Target::refresh_command`], run after the files change.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::{Error, Meta, Output, Target};
+
+/// The per-user base directories install locations are built from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dirs {
+    pub home: PathBuf,
+    /// `$XDG_CONFIG_HOME`, `~/.config` by default. Also used on macOS, where
+    /// most terminal tools follow XDG rather than `Application Support`.
+    pub config: PathBuf,
+    /// `~/Library/Application Support` on macOS, `%APPDATA%` on Windows and
+    /// the same as `config` elsewhere.
+    pub app_data: PathBuf,
+}
+
+impl Dirs {
+    /// Reads the directories from the environment of the current user.
+    pub fn from_env() -> Option<Dirs> {
+        let home = env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(PathBuf::from)?;
+        let config = env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home.join(".config"));
+        let app_data = if cfg!(target_os = "macos") {
+            home.join("Library/Application Support")
+        } else if cfg!(windows) {
+            env::var_os("APPDATA")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| config.clone())
+        } else {
+            config.clone()
+        };
+        Some(Dirs {
+            home,
+            config,
+            app_data,
+        })
+    }
+
+    /// Qt's and GLib's generic data location: `~/.local/share` (without
+    /// reading `$XDG_DATA_HOME`), `app_data` on macOS and the local
+    /// application data folder on Windows.
+    pub fn data(&self) -> PathBuf {
+        if cfg!(target_os = "macos") {
+            self.app_data.clone()
+        } else if cfg!(windows) {
+            self.home.join("AppData/Local")
+        } else {
+            self.home.join(".local/share")
+        }
+    }
+}
+
+/// Where an application keeps its configuration and its themes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstallDir {
+    /// The application's configuration directory; its existence is taken as
+    /// proof that the application is installed.
+    pub app: PathBuf,
+    /// The directory the target's files are placed in.
+    pub themes: PathBuf,
+}
+
+/// How installed files refer to the generated ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Write a copy of each file.
+    #[default]
+    Copy,
+    /// Link to the file in the build output directory, so later builds are
+    /// picked up without reinstalling.
+    Symlink,
+}
+
+/// One file operation of an install or uninstall.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    Copy {
+        to: PathBuf,
+        contents: String,
+    },
+    Symlink {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    Remove {
+        path: PathBuf,
+    },
+    /// Runs a target's refresh command, `program` followed by `args`.
+    Run {
+        program: String,
+        args: Vec<String>,
+    },
+}
+
+impl Action {
+    /// Carries out the operation.
+    pub fn apply(&self) -> Result<(), Error> {
+        match self {
+            Action::Copy { to, contents } => {
+                create_parent(to)?;
+                fs::write(to, contents).map_err(|e| Error::io(to, e))
+            }
+            Action::Symlink { from, to } => {
+                create_parent(to)?;
+                match fs::remove_file(to) {
+                    Ok(()) => {}
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                    Err(e) => return Err(Error::io(to, e)),
+                }
+                symlink(from, to).map_err(|e| Error::io(to, e))
+            }
+            Action::Remove { path } => fs::remove_file(path).map_err(|e| Error::io(path, e)),
+            Action::Run { program, args } => {
+                // The files are in place either way; the application only
+                // picks them up once the command has been run by hand.
+                match Command::new(program).args(args).status() {
+                    Ok(status) if !status.success() => {
+                        tracing::warn!(%program, %status, "refresh command failed");
+                    }
+                    Ok(_) => {}
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                        tracing::warn!(%program, "refresh command not found");
+                    }
+                    Err(e) => return Err(Error::io(program, e)),
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Copy { to, .. } => write!(f, "copy {}", to.display()),
+            Action::Symlink { from, to } => {
+                write!(f, "link {} -> {}", to.display(), from.display())
+            }
+            Action::Remove { path } => write!(f, "remove {}", path.display()),
+            Action::Run { program, args } => {
+                write!(f, "run {program}")?;
+                args.iter().try_for_each(|arg| write!(f, " {arg}"))
+            }
+        }
+    }
+}
+
+fn create_parent(path: &Path) -> Result<(), Error> {
+    match path.parent() {
+        Some(parent) => fs::create_dir_all(parent).map_err(|e| Error::io(parent, e)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(unix)]
+fn symlink(from: &Path, to: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(from, to)
+}
+
+#[cfg(windows)]
+fn symlink(from: &Path, to: &Path) -> io::Result<()> {
+    std::os::windows::fs::symlink_file(from, to)
+}
+
+/// Returns the install directory of `target` if its application is present.
+pub fn detect(target: &dyn Target, dirs: &Dirs, meta: &Meta) -> Option<InstallDir> {
+    target
+        .install_dir(dirs, meta)
+        .filter(|dir| dir.app.is_dir())
+}
+
+fn destination(dir: &InstallDir, output: &Output) -> PathBuf {
+    let name = output.path.file_name().expect("outputs are files");
+    dir.themes.join(name)
+}
+
+/// The actions installing `outputs` into `dir`.
+///
+/// `out_dir` is where the build wrote `outputs`; it is only used for
+/// symlinks and must be absolute for them to resolve.
+pub fn install_actions(
+    dir: &InstallDir,
+    outputs: &[Output],
+    mode: Mode,
+    out_dir: &Path,
+) -> Vec<Action> {
+    outputs
+        .iter()
+        .map(|output| {
+            let to = destination(dir, output);
+            match mode {
+                Mode::Copy => Action::Copy {
+                    to,
+                    contents: output.contents.clone(),
+                },
+                Mode::Symlink => Action::Symlink {
+                    from: out_dir.join(&output.path),
+                    to,
+                },
+            }
+        })
+        .collect()
+}
+
+/// The action running the refresh command of `target`, if it has one.
+pub fn refresh_action(target: &dyn Target) -> Option<Action> {
+    let (program, args) = target.refresh_command().split_first()?;
+    Some(Action::Run {
+        program: (*program).to_owned(),
+        args: args.iter().map(|&arg| arg.to_owned()).collect(),
+    })
+}
+
+/// The actions removing previously installed `outputs` from `dir`.
+///
+/// Only files that exist are listed; symlinks count even when dangling.
+pub fn uninstall_actions(dir: &InstallDir, outputs: &[Output]) -> Vec<Action> {
+    outputs
+        .iter()
+        .map(|output| destination(dir, output))
+        .filter(|path| path.symlink_metadata().is_ok())
+        .map(|path| Action::Remove { path })
+        .collect()
+}
@@ -0,0 +1,136 @@
+//! Syntax colors specialized for one language.
+//!
+//! The `[languages]` tables of a theme source restyle syntax roles inside a
+//! single language, keyed by its usual identifier:
+//!
+//! ```toml
+//! [languages.rust]
+//! "syntax.label"     = { swatch = "accent.coral", italic = true } # lifetimes
+//! "syntax.attribute" = { swatch = "accent.gold" }
+//!
+//! [languages.markdown]
+//! "syntax.title"     = { swatch = "accent.blue", weight = 700 }
+//! ```
+//!
+//! Each language resolves like its own small theme: the base roles with the
+//! language's styles on top, derived for every variant, so a language rule
+//! follows the variant transforms like any other role. Targets whose
+//! highlighting can be scoped by language emit the [`rules`] as extra
+//! scoped entries; the rest ignore them.
+
+use std::collections::BTreeMap;
+
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+use zero_trust_palette::roles::{Role, Style};
+use zero_trust_palette::{Color, Theme, Variant};
+
+use crate::Source;
+
+/// Syntax role styles by language identifier.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Languages(BTreeMap<String, BTreeMap<Role, Style>>);
+
+/// A role name that must be a `syntax.*` role.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct SyntaxRole(Role);
+
+impl<'de> Deserialize<'de> for SyntaxRole {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        match Role::from_name(&name) {
+            Some(role) if role.is_syntax() => Ok(SyntaxRole(role)),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&name),
+                &"a syntax role name",
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Languages {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let languages = BTreeMap::<String, BTreeMap<SyntaxRole, Style>>::deserialize(deserializer)?;
+        Ok(Languages(
+            languages
+                .into_iter()
+                .map(|(language, styles)| {
+                    let styles = styles
+                        .into_iter()
+                        .map(|(SyntaxRole(role), style)| (role, style))
+                        .collect();
+                    (language, styles)
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Languages {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The restyled roles of `language`, if it has any.
+    pub fn get(&self, language: &str) -> Option<&BTreeMap<Role, Style>> {
+        self.0.get(language)
+    }
+
+    /// Every language and its restyled roles, sorted by language.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &BTreeMap<Role, Style>)> {
+        self.0
+            .iter()
+            .map(|(language, styles)| (language.as_str(), styles))
+    }
+
+    /// Restyles `role` inside `language`.
+    pub fn set(&mut self, language: &str, role: Role, style: Style) {
+        self.0
+            .entry(language.to_owned())
+            .or_default()
+            .insert(role, style);
+    }
+}
+
+/// One syntax role restyled for one language, resolved for a variant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    /// The language identifier, e.g. `rust`.
+    pub language: String,
+    pub role: Role,
+    pub style: Style,
+    pub color: Color,
+    /// The color composited over the background, for targets without alpha.
+    pub opaque: Color,
+}
+
+impl Source {
+    /// Resolves `variant` with the roles of `language` restyled.
+    pub fn language_theme(&self, variant: Variant, language: &str) -> Theme {
+        let mut roles = self.roles.clone();
+        for (&role, &style) in self.languages.get(language).into_iter().flatten() {
+            roles.set(role, style);
+        }
+        let base = self.tuning.apply(&self.palette);
+        Theme::from_parts(variant, &base, roles, &self.transforms)
+    }
+}
+
+/// Every language rule of `source` resolved for `variant`, by language and
+/// then role.
+pub fn rules(source: &Source, variant: Variant) -> Vec<Rule> {
+    let mut rules = Vec::new();
+    for (language, styles) in source.languages.iter() {
+        let theme = source.language_theme(variant, language);
+        for &role in styles.keys() {
+            rules.push(Rule {
+                language: language.to_owned(),
+                role,
+                style: theme.style(role),
+                color: theme.color(role),
+                opaque: theme.opaque_color(role),
+            });
+        }
+    }
+    rules
+}
@@ -0,0 +1,74 @@
+//! The Zero Trust theme generator.
+//!
+//! Every theme file is rendered from one [`Source`], normally the
+//! `theme.toml` at the repository root. A [`Target`] turns the resolved
+//! themes into the files one editor or tool expects; a
+//! [`Registry`](targets::Registry) holds the built-in targets plus any
+//! [templates] or WebAssembly plugins the user provides.
+//!
+//! ```no_run
+//! use zero_trust::{targets::Registry, Source, DEFAULT_VARIANTS};
+//!
+//! let source = Source::load("theme.toml".as_ref())?;
+//! for target in Registry::with_templates("templates".as_ref())?.iter() {
+//!     for output in target.render(&source, DEFAULT_VARIANTS)? {
+//!         println!("{}", output.path.display());
+//!     }
+//! }
+//! # Ok::<(), zero_trust::Error>(())
+//! ```
+
+pub mod appearance;
+pub mod cache;
+pub mod changelog;
+pub mod diagnostic;
+pub mod diff;
+mod error;
+pub mod install;
+pub mod languages;
+pub mod model;
+pub mod overrides;
+pub mod package;
+#[cfg(feature = "wasm")]
+pub mod plugins;
+pub mod release;
+pub mod scaffold;
+pub mod schema;
+mod source;
+mod target;
+pub mod targets;
+pub mod templates;
+
+pub use error::Error;
+pub use source::{Lint, Meta, Source};
+pub use target::{render_all, stale_outputs, write_outputs, Format, Output, Target};
+
+use zero_trust_palette::Variant;
+
+/// The variants generated when none are requested explicitly.
+pub const DEFAULT_VARIANTS: &[Variant] = &[Variant::Dark, Variant::Light];
+
+/// Resolves command-line variant names: slugs such as `dark` or
+/// `high-contrast`, or `all`. An empty list selects [`DEFAULT_VARIANTS`].
+pub fn select_variants(names: &[String]) -> Result<Vec<Variant>, Error> {
+    if names.is_empty() {
+        return Ok(DEFAULT_VARIANTS.to_vec());
+    }
+    let mut variants = Vec::new();
+    for name in names {
+        if name == "all" {
+            variants.extend_from_slice(Variant::ALL);
+        } else {
+            let variant =
+                Variant::from_slug(name).ok_or_else(|| Error::UnknownVariant(name.clone()))?;
+            variants.push(variant);
+        }
+    }
+    let mut seen = Vec::new();
+    variants.retain(|variant| {
+        let first = !seen.contains(variant);
+        seen.push(*variant);
+        first
+    });
+    Ok(variants)
+}
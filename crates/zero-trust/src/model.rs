@@ -0,0 +1,150 @@
+//! The serializable theme model handed to templates.
+//!
+//! Everything a custom target could need about one variant, flattened into
+//! plain data: colors in several spellings so template languages without
+//! string functions can still emit `#RRGGBB`, `RRGGBB` or `r,g,b`.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use zero_trust_palette::brackets::{self, DEFAULT_LEVELS};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::scale::Scale;
+use zero_trust_palette::{Appearance, Color, Theme};
+
+use crate::{Meta, Source};
+
+/// A color in every spelling targets ask for.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ColorModel {
+    /// `#RRGGBB`, or `#RRGGBBAA` when translucent.
+    pub hex: String,
+    /// `RRGGBB` without the `#` and without alpha.
+    pub bare: String,
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl From<Color> for ColorModel {
+    fn from(color: Color) -> Self {
+        ColorModel {
+            hex: color.to_string(),
+            bare: format!("{:02X}{:02X}{:02X}", color.r, color.g, color.b),
+            r: color.r,
+            g: color.g,
+            b: color.b,
+            a: color.a,
+        }
+    }
+}
+
+/// A role's resolved color and font attributes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RoleModel {
+    #[serde(flatten)]
+    pub color: ColorModel,
+    /// The color composited over its backdrop, for targets without alpha.
+    pub opaque: ColorModel,
+    pub italic: bool,
+    pub bold: bool,
+    pub weight: Option<u16>,
+}
+
+/// One resolved variant.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Model {
+    pub meta: Meta,
+    /// The display name, e.g. `Zero Trust Dark`.
+    pub name: String,
+    /// The variant slug, e.g. `dark`.
+    pub slug: &'static str,
+    pub appearance: Appearance,
+    /// Palette entries by dotted name (`accent.blue`).
+    pub palette: BTreeMap<&'static str, ColorModel>,
+    /// Roles by dotted name (`syntax.keyword`).
+    pub roles: BTreeMap<&'static str, RoleModel>,
+    /// The 16 terminal colors in ANSI order, opaque.
+    pub ansi: Vec<ColorModel>,
+    /// Rainbow bracket colors, outermost first.
+    pub brackets: Vec<ColorModel>,
+    /// Chrome shades by name (`surface0`..`surface5`, `overlay0`..`overlay2`),
+    /// evenly spaced in lightness from the background towards the text.
+    pub scale: BTreeMap<&'static str, ColorModel>,
+    /// Syntax roles restyled for one language, by language and role name;
+    /// only the roles a language changes are listed.
+    pub languages: BTreeMap<String, BTreeMap<&'static str, RoleModel>>,
+}
+
+/// The terminal roles for ANSI indices `0..16`.
+pub const ANSI_ROLES: [Role; 16] = [
+    Role::TerminalBlack,
+    Role::TerminalRed,
+    Role::TerminalGreen,
+    Role::TerminalYellow,
+    Role::TerminalBlue,
+    Role::TerminalMagenta,
+    Role::TerminalCyan,
+    Role::TerminalWhite,
+    Role::TerminalBrightBlack,
+    Role::TerminalBrightRed,
+    Role::TerminalBrightGreen,
+    Role::TerminalBrightYellow,
+    Role::TerminalBrightBlue,
+    Role::TerminalBrightMagenta,
+    Role::TerminalBrightCyan,
+    Role::TerminalBrightWhite,
+];
+
+impl RoleModel {
+    fn new(theme: &Theme, role: Role) -> RoleModel {
+        let style = theme.style(role);
+        RoleModel {
+            color: theme.color(role).into(),
+            opaque: theme.opaque_color(role).into(),
+            italic: style.italic,
+            bold: style.is_bold(),
+            weight: style.weight,
+        }
+    }
+}
+
+impl Model {
+    pub fn new(source: &Source, theme: &Theme) -> Model {
+        let roles = Role::ALL
+            .iter()
+            .map(|&role| (role.name(), RoleModel::new(theme, role)));
+        let languages = source.languages.iter().map(|(language, styles)| {
+            let theme = source.language_theme(theme.variant, language);
+            let roles = styles
+                .keys()
+                .map(|&role| (role.name(), RoleModel::new(&theme, role)))
+                .collect();
+            (language.to_owned(), roles)
+        });
+        let brackets: [Color; DEFAULT_LEVELS] = brackets::rainbow(theme);
+        Model {
+            meta: source.meta.clone(),
+            name: source.meta.theme_name(theme.variant),
+            slug: theme.variant.slug(),
+            appearance: theme.appearance(),
+            palette: theme
+                .palette
+                .iter()
+                .map(|(swatch, color)| (swatch.name(), color.into()))
+                .collect(),
+            roles: roles.collect(),
+            ansi: ANSI_ROLES
+                .iter()
+                .map(|&role| theme.opaque_color(role).into())
+                .collect(),
+            brackets: brackets.into_iter().map(ColorModel::from).collect(),
+            scale: Scale::for_theme(theme)
+                .iter()
+                .map(|(name, color)| (name, color.into()))
+                .collect(),
+            languages: languages.collect(),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Per-target override layers.
+//!
+//! `overrides/<target>.toml` next to `theme.toml` adjusts palette entries and
+//! roles for one target only, e.g. a lighter gutter just in JetBrains:
+//!
+//! ```toml
+//! [roles]
+//! "ui.gutter" = { swatch = "bg.surface" }
+//! ```
+//!
+//! End users can keep personal tweaks in `~/.config/zero-trust-theme/user.toml`,
+//! in the same format, which applies to every target they build or install.
+//!
+//! Layers are partial and apply in order on top of the theme source, so a
+//! later layer wins where two name the same entry: `theme.toml`, then the
+//! target's override file, then the user's file. Roles keep pointing at
+//! palette entries, so a palette override also recolors every role using
+//! that entry in that target.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use zero_trust_palette::roles::{Role, Style};
+use zero_trust_palette::{Color, Swatch, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+/// The override directory used when none is given.
+pub const DEFAULT_DIR: &str = "overrides";
+
+/// The user's own layer, below the configuration directory.
+pub const USER_FILE: &str = "zero-trust-theme/user.toml";
+
+/// Where the user's layer lives for the current user.
+pub fn user_file(dirs: &Dirs) -> PathBuf {
+    dirs.config.join(USER_FILE)
+}
+
+/// Palette entries and roles to replace, as read from an override file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Layer {
+    pub palette: BTreeMap<Swatch, Color>,
+    pub roles: BTreeMap<Role, Style>,
+}
+
+impl Layer {
+    /// Parses an override layer from TOML text; `path` is used in errors.
+    pub fn parse(text: &str, path: &Path) -> Result<Layer, Error> {
+        toml::from_str(text).map_err(|source| Error::Parse {
+            path: path.to_owned(),
+            text: text.to_owned(),
+            source: Box::new(source),
+        })
+    }
+
+    /// Reads and parses the override layer at `path`.
+    pub fn load(path: &Path) -> Result<Layer, Error> {
+        let text = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        Layer::parse(&text, path)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.palette.is_empty() && self.roles.is_empty()
+    }
+
+    /// Writes the layer's entries over `source`.
+    pub fn apply_to(&self, source: &mut Source) {
+        for (&swatch, &color) in &self.palette {
+            source.palette.set(swatch, color);
+        }
+        for (&role, &style) in &self.roles {
+            source.roles.set(role, style);
+        }
+    }
+}
+
+/// A target rendered from the theme source with override layers on top.
+pub struct Overridden {
+    target: Arc<dyn Target>,
+    layers: Vec<(PathBuf, Layer)>,
+    fingerprint: Vec<u8>,
+}
+
+impl Overridden {
+    /// Wraps `target` so it renders with `layers` applied in order, each
+    /// with the file it was read from.
+    pub fn new(target: Arc<dyn Target>, layers: Vec<(PathBuf, Layer)>) -> Overridden {
+        let mut fingerprint = target.fingerprint().to_vec();
+        for (_, layer) in &layers {
+            serde_json::to_writer(&mut fingerprint, layer).expect("layers always serialize");
+        }
+        Overridden {
+            target,
+            layers,
+            fingerprint,
+        }
+    }
+
+    /// The override files applied, in order.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.layers.iter().map(|(path, _)| path.as_path())
+    }
+}
+
+impl Target for Overridden {
+    fn name(&self) -> &str {
+        self.target.name()
+    }
+
+    fn fingerprint(&self) -> &[u8] {
+        &self.fingerprint
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let mut source = source.clone();
+        for (path, layer) in &self.layers {
+            tracing::debug!(
+                name = self.name(),
+                layer = %path.display(),
+                palette = layer.palette.len(),
+                roles = layer.roles.len(),
+                "applying override layer"
+            );
+            layer.apply_to(&mut source);
+        }
+        self.target.render(&source, variants)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, meta: &Meta) -> Option<InstallDir> {
+        self.target.install_dir(dirs, meta)
+    }
+
+    fn refresh_command(&self) -> &[&str] {
+        self.target.refresh_command()
+    }
+}
+
+/// The `*.toml` files in `dir` by target name, sorted.
+///
+/// A missing directory holds no overrides.
+pub fn list_dir(dir: &Path) -> Result<Vec<(String, PathBuf)>, Error> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(Error::io(dir, e)),
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| Error::io(dir, e))?.path();
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                files.push((name.to_owned(), path.clone()));
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,284 @@
+//! Packaging generated themes for distribution.
+//!
+//! [`Vsix`] assembles a VS Code extension archive directly, so releases
+//! need neither Node nor `vsce`. The archive holds the theme files rendered
+//! by the `vscode` target, a `package.json` contributing one theme per
+//! variant, the license, the readme and an optional icon, plus the
+//! `extension.vsixmanifest` and `[Content_Types].xml` the marketplace reads.
+//! Entries carry a fixed timestamp, so the same inputs produce the same
+//! archive byte for byte.
+
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::Path;
+
+use serde::Deserialize;
+use serde_json::json;
+use zero_trust_palette::{Appearance, Variant};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, DateTime, ZipWriter};
+
+use crate::{Error, Format, Source, Target};
+
+/// The extension manifest read for package metadata, next to `theme.toml`.
+pub const MANIFEST: &str = "extension.toml";
+
+/// The oldest VS Code release the package claims to support.
+pub(crate) const VSCODE_ENGINE: &str = "^1.70.0";
+
+/// Package identity, read from the extension manifest.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Extension {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub version: String,
+    #[serde(default)]
+    pub repository: Option<String>,
+}
+
+impl Extension {
+    /// Reads the extension manifest at `path`.
+    pub fn load(path: &Path) -> Result<Extension, Error> {
+        let text = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        toml::from_str(&text).map_err(|source| Error::Parse {
+            path: path.to_owned(),
+            text,
+            source: Box::new(source),
+        })
+    }
+
+    /// The owner of a GitHub-style repository URL, the usual publisher name.
+    pub fn repository_owner(&self) -> Option<&str> {
+        url_owner(self.repository.as_deref()?)
+    }
+}
+
+/// The owner of a GitHub-style repository URL, its first path segment.
+pub(crate) fn url_owner(url: &str) -> Option<&str> {
+    let path = url.split_once("://").map_or(url, |(_, rest)| rest);
+    path.split('/').nth(1).filter(|owner| !owner.is_empty())
+}
+
+/// One theme contributed by the extension.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VsixTheme {
+    /// The name shown in the theme picker, e.g. `Zero Trust Dark`.
+    pub label: String,
+    /// `vs`, `vs-dark`, `hc-black` or `hc-light`.
+    pub ui_theme: &'static str,
+    /// The file name below `themes/`.
+    pub file_name: String,
+    pub contents: String,
+}
+
+/// The base theme VS Code falls back to for colors a theme leaves out.
+pub fn ui_theme(variant: Variant) -> &'static str {
+    match (variant, variant.appearance()) {
+        (Variant::HighContrast, Appearance::Dark) => "hc-black",
+        (Variant::HighContrast, Appearance::Light) => "hc-light",
+        (_, Appearance::Dark) => "vs-dark",
+        (_, Appearance::Light) => "vs",
+    }
+}
+
+/// A VS Code extension ready to be written as a `.vsix`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vsix {
+    pub extension: Extension,
+    pub publisher: String,
+    pub themes: Vec<VsixTheme>,
+    pub license: Option<String>,
+    pub readme: Option<String>,
+    /// A PNG icon, at least 128×128 pixels.
+    pub icon: Option<Vec<u8>>,
+}
+
+pub(crate) fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl Vsix {
+    /// Renders `target` once per variant, so every theme file can be matched
+    /// to the variant it belongs to.
+    pub fn themes(
+        target: &dyn Target,
+        source: &Source,
+        variants: &[Variant],
+        format: Format,
+    ) -> Result<Vec<VsixTheme>, Error> {
+        let mut themes = Vec::new();
+        for &variant in variants {
+            // The manifest the target writes for unpacked installs is
+            // replaced by the one generated here.
+            let outputs = target.render(source, &[variant])?;
+            for output in outputs
+                .into_iter()
+                .filter(|o| !o.path.ends_with("package.json"))
+            {
+                let file_name = output
+                    .path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .unwrap_or_default()
+                    .to_owned();
+                themes.push(VsixTheme {
+                    label: source.meta.theme_name(variant),
+                    ui_theme: ui_theme(variant),
+                    file_name,
+                    contents: output.format(format).contents,
+                });
+            }
+        }
+        Ok(themes)
+    }
+
+    /// The extension's `package.json`.
+    pub fn package_json(&self) -> String {
+        let extension = &self.extension;
+        let themes: Vec<_> = self
+            .themes
+            .iter()
+            .map(|theme| {
+                json!({
+                    "label": theme.label,
+                    "uiTheme": theme.ui_theme,
+                    "path": format!("./themes/{}", theme.file_name),
+                })
+            })
+            .collect();
+        let mut package = json!({
+            "name": extension.id,
+            "displayName": extension.name,
+            "description": extension.description,
+            "version": extension.version,
+            "publisher": self.publisher,
+            "engines": { "vscode": VSCODE_ENGINE },
+            "categories": ["Themes"],
+            "keywords": ["theme", "color-theme"],
+            "contributes": { "themes": themes },
+        });
+        if self.license.is_some() {
+            package["license"] = "SEE LICENSE IN LICENSE.txt".into();
+        }
+        if let Some(repository) = &extension.repository {
+            package["repository"] = json!({ "type": "git", "url": repository });
+        }
+        if self.icon.is_some() {
+            package["icon"] = "icon.png".into();
+        }
+        let mut text =
+            serde_json::to_string_pretty(&package).expect("JSON values always serialize");
+        text.push('\n');
+        text
+    }
+
+    /// The `extension.vsixmanifest` describing the package to the marketplace.
+    pub fn vsixmanifest(&self) -> String {
+        let extension = &self.extension;
+        let mut properties = format!(
+            "      <Property Id=\"Microsoft.VisualStudio.Code.Engine\" Value=\"{}\" />\n",
+            escape_xml(VSCODE_ENGINE)
+        );
+        let mut assets = String::from(
+            "    <Asset Type=\"Microsoft.VisualStudio.Code.Manifest\" Path=\"extension/package.json\" Addressable=\"true\" />\n",
+        );
+        let mut metadata = String::new();
+        if let Some(repository) = &extension.repository {
+            properties.push_str(&format!(
+                "      <Property Id=\"Microsoft.VisualStudio.Services.Links.Source\" Value=\"{}\" />\n",
+                escape_xml(repository)
+            ));
+        }
+        if self.readme.is_some() {
+            assets.push_str("    <Asset Type=\"Microsoft.VisualStudio.Services.Content.Details\" Path=\"extension/README.md\" Addressable=\"true\" />\n");
+        }
+        if self.license.is_some() {
+            metadata.push_str("    <License>extension/LICENSE.txt</License>\n");
+            assets.push_str("    <Asset Type=\"Microsoft.VisualStudio.Services.Content.License\" Path=\"extension/LICENSE.txt\" Addressable=\"true\" />\n");
+        }
+        if self.icon.is_some() {
+            metadata.push_str("    <Icon>extension/icon.png</Icon>\n");
+            assets.push_str("    <Asset Type=\"Microsoft.VisualStudio.Services.Icons.Default\" Path=\"extension/icon.png\" Addressable=\"true\" />\n");
+        }
+        format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<PackageManifest Version="2.0.0" xmlns="http://schemas.microsoft.com/developer/vsx-schema/2011" xmlns:d="http://schemas.microsoft.com/developer/vsx-schema-design/2011">
+  <Metadata>
+    <Identity Language="en-US" Id="{id}" Version="{version}" Publisher="{publisher}" />
+    <DisplayName>{name}</DisplayName>
+    <Description xml:space="preserve">{description}</Description>
+    <Tags>theme,color-theme</Tags>
+    <Categories>Themes</Categories>
+    <GalleryFlags>Public</GalleryFlags>
+    <Properties>
+{properties}    </Properties>
+{metadata}  </Metadata>
+  <Installation>
+    <InstallationTarget Id="Microsoft.VisualStudio.Code" />
+  </Installation>
+  <Dependencies />
+  <Assets>
+{assets}  </Assets>
+</PackageManifest>
+"#,
+            id = escape_xml(&extension.id),
+            version = escape_xml(&extension.version),
+            publisher = escape_xml(&self.publisher),
+            name = escape_xml(&extension.name),
+            description = escape_xml(&extension.description),
+        )
+    }
+
+    /// The `.vsix` archive.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension=".json" ContentType="application/json" />
+  <Default Extension=".vsixmanifest" ContentType="text/xml" />
+  <Default Extension=".md" ContentType="text/markdown" />
+  <Default Extension=".txt" ContentType="text/plain" />
+  <Default Extension=".png" ContentType="image/png" />
+</Types>
+"#;
+        let mut files: Vec<(String, &[u8])> = Vec::new();
+        let manifest = self.vsixmanifest();
+        let package = self.package_json();
+        files.push(("[Content_Types].xml".into(), CONTENT_TYPES.as_bytes()));
+        files.push(("extension.vsixmanifest".into(), manifest.as_bytes()));
+        files.push(("extension/package.json".into(), package.as_bytes()));
+        if let Some(readme) = &self.readme {
+            files.push(("extension/README.md".into(), readme.as_bytes()));
+        }
+        if let Some(license) = &self.license {
+            files.push(("extension/LICENSE.txt".into(), license.as_bytes()));
+        }
+        if let Some(icon) = &self.icon {
+            files.push(("extension/icon.png".into(), icon));
+        }
+        for theme in &self.themes {
+            files.push((
+                format!("extension/themes/{}", theme.file_name),
+                theme.contents.as_bytes(),
+            ));
+        }
+
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .last_modified_time(DateTime::default())
+            .unix_permissions(0o644);
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, data) in files {
+            zip.start_file(name, options)
+                .expect("writing to memory cannot fail");
+            zip.write_all(data).expect("writing to memory cannot fail");
+        }
+        zip.finish()
+            .expect("writing to memory cannot fail")
+            .into_inner()
+    }
+}
@@ -0,0 +1,234 @@
+//! Third-party targets compiled to WebAssembly.
+//!
+//! Templates cover tools whose theme file is a fill-in-the-blanks format;
+//! a plugin is for emitters that need real logic. Every `*.wasm` file in a
+//! plugin directory becomes a target named after the file stem, so
+//! `mytool.wasm` is the target `mytool`.
+//!
+//! # Interface
+//!
+//! A plugin is a core WebAssembly module that imports nothing and exports:
+//!
+//! - `memory`, its linear memory;
+//! - `zt_alloc(len: i32) -> i32`, returning a buffer of `len` bytes for the
+//!   host to write the request into;
+//! - `zt_render(ptr: i32, len: i32) -> i64`, called with the request and
+//!   returning the response as `ptr << 32 | len`.
+//!
+//! The request is UTF-8 JSON holding the interface version and the
+//! [`Model`] of every variant being rendered:
+//!
+//! ```json
+//! { "api": 1, "target": "mytool", "variants": [{ "name": "Zero Trust Dark", ... }] }
+//! ```
+//!
+//! The response lists the files to write, or an error to report:
+//!
+//! ```json
+//! { "files": [{ "path": "dark.conf", "contents": "..." }] }
+//! { "error": "no terminal colors in this theme" }
+//! ```
+//!
+//! File paths are relative to `extras/<target>/` and may not leave it.
+//! Plugins run sandboxed: they get no host functions, so no file system,
+//! network or clock, and a fuel and memory budget stops runaway code.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use wasmi::{Config, Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+use zero_trust_palette::Variant;
+
+use crate::model::Model;
+use crate::target::is_contained;
+use crate::{Error, Output, Source, Target};
+
+/// The plugin directory used when none is given.
+pub const DEFAULT_DIR: &str = "plugins";
+
+/// The interface version sent in every request.
+pub const API_VERSION: u32 = 1;
+
+/// Instructions a plugin may execute per render.
+const FUEL: u64 = 10_000_000_000;
+/// Linear memory a plugin may grow to, in bytes.
+const MEMORY: usize = 256 << 20;
+
+#[derive(Serialize)]
+struct Request<'a> {
+    api: u32,
+    target: &'a str,
+    variants: Vec<Model>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Response {
+    Files { files: Vec<File> },
+    Error { error: String },
+}
+
+#[derive(Deserialize)]
+struct File {
+    path: PathBuf,
+    contents: String,
+}
+
+/// A target implemented by a WebAssembly module.
+pub struct PluginTarget {
+    name: String,
+    path: PathBuf,
+    wasm: Vec<u8>,
+    engine: Engine,
+    module: Module,
+}
+
+fn plugin_error(path: &Path, message: impl ToString) -> Error {
+    Error::Plugin {
+        path: path.to_owned(),
+        message: message.to_string(),
+    }
+}
+
+impl PluginTarget {
+    /// Compiles the module at `path`.
+    pub fn load(path: &Path) -> Result<PluginTarget, Error> {
+        let wasm = fs::read(path).map_err(|e| Error::io(path, e))?;
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_owned();
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, &wasm).map_err(|e| plugin_error(path, e))?;
+        Ok(PluginTarget {
+            name,
+            path: path.to_owned(),
+            wasm,
+            engine,
+            module,
+        })
+    }
+
+    /// The module file this target was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Runs the plugin on one serialized request.
+    fn call(&self, request: &[u8]) -> Result<Vec<u8>, Error> {
+        let fail = |e: wasmi::Error| plugin_error(&self.path, e);
+        let limits: StoreLimits = StoreLimitsBuilder::new().memory_size(MEMORY).build();
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(FUEL).map_err(fail)?;
+        let instance = Linker::new(&self.engine)
+            .instantiate_and_start(&mut store, &self.module)
+            .map_err(fail)?;
+        let memory = instance
+            .get_export(&store, "memory")
+            .and_then(|export| export.into_memory())
+            .ok_or_else(|| plugin_error(&self.path, "missing `memory` export"))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&store, "zt_alloc")
+            .map_err(fail)?;
+        let render = instance
+            .get_typed_func::<(i32, i32), i64>(&store, "zt_render")
+            .map_err(fail)?;
+
+        let len = i32::try_from(request.len())
+            .map_err(|_| plugin_error(&self.path, "request too large"))?;
+        let ptr = alloc.call(&mut store, len).map_err(fail)?;
+        memory
+            .write(&mut store, ptr as u32 as usize, request)
+            .map_err(|e| plugin_error(&self.path, e))?;
+        let start = Instant::now();
+        let packed = render.call(&mut store, (ptr, len)).map_err(fail)? as u64;
+        tracing::debug!(
+            plugin = %self.path.display(),
+            elapsed = ?start.elapsed(),
+            fuel = FUEL - store.get_fuel().unwrap_or(0),
+            "ran plugin"
+        );
+        let (ptr, len) = ((packed >> 32) as usize, (packed & 0xFFFF_FFFF) as usize);
+        let mut response = vec![0; len];
+        memory
+            .read(&store, ptr, &mut response)
+            .map_err(|e| plugin_error(&self.path, e))?;
+        Ok(response)
+    }
+}
+
+impl Target for PluginTarget {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn fingerprint(&self) -> &[u8] {
+        &self.wasm
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let request = Request {
+            api: API_VERSION,
+            target: &self.name,
+            variants: variants
+                .iter()
+                .map(|&variant| Model::new(source, &source.theme(variant)))
+                .collect(),
+        };
+        let request = serde_json::to_vec(&request).expect("the model always serializes");
+        let response = self.call(&request)?;
+        let files = match serde_json::from_slice(&response) {
+            Ok(Response::Files { files }) => files,
+            Ok(Response::Error { error }) => return Err(plugin_error(&self.path, error)),
+            Err(e) => {
+                return Err(plugin_error(
+                    &self.path,
+                    format_args!("malformed response: {e}"),
+                ))
+            }
+        };
+        let dir = Path::new("extras").join(&self.name);
+        files
+            .into_iter()
+            .map(|file| {
+                if !is_contained(&file.path) {
+                    return Err(plugin_error(
+                        &self.path,
+                        format_args!(
+                            "refusing to write outside {}: {}",
+                            dir.display(),
+                            file.path.display()
+                        ),
+                    ));
+                }
+                Ok(Output::new(dir.join(file.path), file.contents))
+            })
+            .collect()
+    }
+}
+
+/// Loads every `*.wasm` file in `dir`, sorted by file name.
+///
+/// A missing directory yields no targets.
+pub fn load_dir(dir: &Path) -> Result<Vec<PluginTarget>, Error> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(Error::io(dir, e)),
+    };
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| Error::io(dir, e))?.path();
+        if path.extension().is_some_and(|ext| ext == "wasm") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    paths.iter().map(|path| PluginTarget::load(path)).collect()
+}
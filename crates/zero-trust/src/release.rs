@@ -0,0 +1,200 @@
+//! Checksums and signatures for release artifacts.
+//!
+//! A release ships a [`Manifest`] of the SHA-256 of every generated file, in
+//! the `SHA256SUMS` format `sha256sum -c` reads, and optionally a detached
+//! minisign (ed25519) signature of the manifest, so users can check that the
+//! theme files they installed are the ones that were published.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::Cursor;
+use std::path::{Component, Path, PathBuf};
+
+use minisign::{PublicKey, PublicKeyBox, SecretKeyBox, SignatureBox};
+
+use crate::cache::sha256;
+use crate::target::is_contained;
+use crate::Error;
+
+/// The manifest file, written to the output directory.
+pub const MANIFEST: &str = "SHA256SUMS";
+
+/// The detached signature of the manifest, next to it.
+pub const SIGNATURE: &str = "SHA256SUMS.minisig";
+
+/// The SHA-256 of every artifact, by path below the output directory.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    files: BTreeMap<String, String>,
+}
+
+/// `path` with `/` separators, as manifests write it on every platform.
+fn manifest_path(path: &Path) -> String {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+impl Manifest {
+    /// Records the checksum of `data`, published at `path`.
+    pub fn insert(&mut self, path: &Path, data: &[u8]) {
+        self.files.insert(manifest_path(path), sha256(data));
+    }
+
+    /// Reads and records the artifact at `path` below `dir`, refusing paths
+    /// that leave `dir`, absolute or through `..`, which [`Manifest::verify`]
+    /// would reject anyway.
+    pub fn include(&mut self, dir: &Path, path: &Path) -> Result<(), Error> {
+        if !is_contained(path) {
+            return Err(signature_error(
+                path,
+                format_args!("not a path inside {}", dir.display()),
+            ));
+        }
+        let full = dir.join(path);
+        let data = fs::read(&full).map_err(|e| Error::io(&full, e))?;
+        self.insert(path, &data);
+        Ok(())
+    }
+
+    /// The recorded paths and checksums, sorted by path.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.files
+            .iter()
+            .map(|(path, hash)| (path.as_str(), hash.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Reads the manifest at `path`.
+    pub fn load(path: &Path) -> Result<Manifest, Error> {
+        let text = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        Manifest::from_text(&text, path)
+    }
+
+    /// Parses `text`, read from `path`, for callers that already hold the
+    /// manifest, e.g. after checking its signature.
+    pub fn from_text(text: &str, path: &Path) -> Result<Manifest, Error> {
+        Manifest::parse(text).ok_or_else(|| Error::Signature {
+            path: path.to_owned(),
+            message: "not a SHA256SUMS manifest".into(),
+        })
+    }
+
+    /// Parses `<hash>  <path>` lines; `None` if any line is malformed.
+    pub fn parse(text: &str) -> Option<Manifest> {
+        let mut files = BTreeMap::new();
+        for line in text.lines().filter(|line| !line.is_empty()) {
+            let (hash, path) = line.split_once("  ")?;
+            let valid = hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit());
+            if !valid || path.is_empty() {
+                return None;
+            }
+            files.insert(path.to_owned(), hash.to_ascii_lowercase());
+        }
+        Some(Manifest { files })
+    }
+
+    /// The recorded files below `dir` that are missing or no longer match
+    /// their checksum. Paths leaving `dir`, absolute or through `..`, are
+    /// never read and always fail.
+    pub fn verify(&self, dir: &Path) -> Vec<PathBuf> {
+        self.files
+            .iter()
+            .filter(|(path, hash)| {
+                !is_contained(Path::new(path))
+                    || !fs::read(dir.join(path)).is_ok_and(|data| sha256(&data) == **hash)
+            })
+            .map(|(path, _)| dir.join(path))
+            .collect()
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (path, hash) in &self.files {
+            writeln!(f, "{hash}  {path}")?;
+        }
+        Ok(())
+    }
+}
+
+fn signature_error(path: &Path, err: impl fmt::Display) -> Error {
+    Error::Signature {
+        path: path.to_owned(),
+        message: err.to_string(),
+    }
+}
+
+/// Signs `manifest` with the minisign secret key in `key`, returning the
+/// contents of the `.minisig` file.
+///
+/// An encrypted key is decrypted with `password`, or with a password read
+/// from the terminal when none is given; an unencrypted key needs neither.
+pub fn sign(
+    manifest: &str,
+    key: &str,
+    password: Option<String>,
+) -> Result<String, minisign::PError> {
+    let secret_key = SecretKeyBox::from_string(key)?;
+    // Keys made with `minisign -G -W` carry no password.
+    let secret_key = match secret_key.clone().into_unencrypted_secret_key() {
+        Ok(secret_key) => secret_key,
+        Err(_) => secret_key.into_secret_key(password)?,
+    };
+    let trusted_comment = format!(
+        "file:{MANIFEST} generator:zero-trust {}",
+        env!("CARGO_PKG_VERSION")
+    );
+    let signature = minisign::sign(
+        None,
+        &secret_key,
+        Cursor::new(manifest.as_bytes()),
+        Some(&trusted_comment),
+        Some("signature from zero-trust release"),
+    )?;
+    Ok(signature.into_string())
+}
+
+/// Signs the manifest text with the secret key file at `key_path`.
+pub fn sign_file(
+    manifest: &str,
+    key_path: &Path,
+    password: Option<String>,
+) -> Result<String, Error> {
+    let key = fs::read_to_string(key_path).map_err(|e| Error::io(key_path, e))?;
+    sign(manifest, &key, password).map_err(|e| signature_error(key_path, e))
+}
+
+/// Checks the detached `signature` of `manifest` against the minisign
+/// `public_key`, either a key file's contents or its bare base64 line.
+pub fn verify_signature(
+    manifest: &str,
+    signature: &str,
+    public_key: &str,
+) -> Result<(), minisign::PError> {
+    let public_key = match public_key.trim() {
+        line if !line.contains('\n') => PublicKey::from_base64(line)?,
+        _ => PublicKeyBox::from_string(public_key)?.into_public_key()?,
+    };
+    let signature = SignatureBox::from_string(signature)?;
+    minisign::verify(
+        &public_key,
+        &signature,
+        Cursor::new(manifest.as_bytes()),
+        true,
+        false,
+        false,
+    )
+}
@@ -0,0 +1,146 @@
+//! Scaffolding a derivative theme project.
+//!
+//! `zero-trust init` lays out a directory that the generator builds like this
+//! repository: a copy of the Zero Trust `theme.toml` under the new name, the
+//! JSON Schema it references, `extension.toml` for packaging, and the
+//! `templates/` and `overrides/` directories, each with a short README.
+
+use std::path::Path;
+
+use crate::{cache, overrides, package, schema, templates, Output, Source};
+
+/// The Zero Trust theme source, the starting point of every fork.
+const THEME: &str = include_str!("../../../theme.toml");
+
+/// Where the copied source's own metadata ends and the palette begins.
+const PALETTE_START: &str = "# Palette entries by dotted name.";
+
+/// The identity of a new theme.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Project {
+    /// The display name, e.g. `My Fork`.
+    pub name: String,
+    pub author: String,
+    /// The package identifier, e.g. `my-fork`.
+    pub id: String,
+}
+
+impl Project {
+    /// A project called after its directory name: `my-fork` becomes `My Fork`.
+    pub fn from_dir_name(dir_name: &str, author: String) -> Project {
+        let id: String = dir_name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        let id = id.trim_matches('-').to_owned();
+        let name = id
+            .split('-')
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        Project { name, author, id }
+    }
+}
+
+fn toml_string(value: &str) -> String {
+    toml::Value::String(value.to_owned()).to_string()
+}
+
+fn theme_toml(project: &Project) -> String {
+    let palette = THEME
+        .find(PALETTE_START)
+        .map_or(THEME, |start| &THEME[start..]);
+    format!(
+        "#:schema ./{schema}
+
+# The single source of the {name} theme, derived from Zero Trust.
+#
+# Every theme file is generated from this file by the `zero-trust`
+# generator. Edit colors here, then run `zero-trust build` in this
+# directory to regenerate the outputs. Only the dark palette is defined by
+# hand; the other variants and the `state.*` ramps are derived from it.
+
+# Metadata stamped into every generated file whose format has a place for
+# it. `version` defaults to the version of the generator.
+[meta]
+name = {name_value}
+author = {author}
+homepage = \"\"
+license = \"MIT\"
+
+{palette}",
+        schema = schema::FILE_NAME,
+        name = project.name,
+        name_value = toml_string(&project.name),
+        author = toml_string(&project.author),
+    )
+}
+
+fn extension_toml(project: &Project) -> String {
+    format!(
+        "id = {id}
+name = {name}
+description = {description}
+version = \"0.1.0\"
+authors = [{author}]
+# repository = \"https://github.com/you/{raw_id}\"
+",
+        id = toml_string(&project.id),
+        name = toml_string(&project.name),
+        description = toml_string(&format!("{}, derived from Zero Trust", project.name)),
+        author = toml_string(&project.author),
+        raw_id = project.id,
+    )
+}
+
+const TEMPLATES_README: &str = "# Templates
+
+Every `<tool>.<ext>.tera` file here becomes the build target `<tool>`,
+rendered once per variant into `extras/<tool>/<theme>-<variant>.<ext>`,
+`<theme>` being the theme name from `theme.toml` in kebab case.
+Templates see the resolved variant as `meta`, `palette`, `roles`, `ansi`,
+`brackets` and `languages`.
+";
+
+const OVERRIDES_README: &str = "# Overrides
+
+A `<target>.toml` here replaces palette entries and roles for that target
+only, on top of `theme.toml`:
+
+```toml
+#:schema ../theme.schema.json
+[roles]
+\"ui.gutter\" = { swatch = \"bg.surface\" }
+```
+";
+
+/// The files of a new project, relative to its directory.
+pub fn files(project: &Project) -> Vec<Output> {
+    vec![
+        Output::new(Source::FILE_NAME, theme_toml(project)),
+        Output::new(schema::FILE_NAME, schema::to_string()),
+        Output::new(package::MANIFEST, extension_toml(project)),
+        Output::new(
+            Path::new(templates::DEFAULT_DIR).join("README.md"),
+            TEMPLATES_README,
+        ),
+        Output::new(
+            Path::new(overrides::DEFAULT_DIR).join("README.md"),
+            OVERRIDES_README,
+        ),
+        Output::new(".gitignore", format!("{}\n", cache::FILE_NAME)),
+    ]
+}
@@ -0,0 +1,402 @@
+//! A JSON Schema for theme sources.
+//!
+//! Editors with a TOML language server (Taplo, Even Better TOML) use it for
+//! completion and validation of `theme.toml` and override files. The schema
+//! is generated from the same tables the parser uses, so every palette entry
+//! and role is listed with its shipped value as the default.
+
+use serde_json::{json, Map, Value};
+use zero_trust_palette::roles::{Role, RoleMap};
+use zero_trust_palette::{Palette, Swatch};
+
+use crate::Source;
+
+/// The file name the schema is committed under, next to `theme.toml`.
+pub const FILE_NAME: &str = "theme.schema.json";
+
+const HEX: &str = "^#?([0-9A-Fa-f]{3}|[0-9A-Fa-f]{6}|[0-9A-Fa-f]{8})$";
+
+/// What each palette group is for, by name prefix.
+const SWATCH_GROUPS: &[(&str, &str)] = &[
+    ("bg.", "Neutral background surface."),
+    ("fg.", "Text and icon foreground."),
+    ("accent.", "Saturated color for syntax and UI highlights."),
+    (
+        "tint.",
+        "Strongly tinted background for highlighted regions.",
+    ),
+    ("shade.", "Quieter tint, used for selections."),
+    ("vcs.", "Version control marker."),
+    (
+        "state.",
+        "Derived diagnostic ramp step; recomputed from its source color for every variant.",
+    ),
+    ("ansi.", "Terminal color."),
+];
+
+/// What each role group paints, by name prefix.
+const ROLE_GROUPS: &[(&str, &str)] = &[
+    ("ui.", "Editor chrome."),
+    ("status.", "Diagnostics and file states."),
+    ("git.", "Version control markers."),
+    ("terminal.", "The integrated terminal."),
+    ("syntax.", "Syntax highlighting."),
+];
+
+fn describe(name: &str, groups: &[(&str, &'static str)]) -> &'static str {
+    groups
+        .iter()
+        .find(|(prefix, _)| name.starts_with(prefix))
+        .map_or("", |&(_, description)| description)
+}
+
+fn color(description: &str, default: impl ToString) -> Value {
+    json!({
+        "description": description,
+        "type": "string",
+        "pattern": HEX,
+        "default": default.to_string(),
+    })
+}
+
+fn number(description: &str, default: f64, minimum: f64, maximum: Option<f64>) -> Value {
+    let mut schema = json!({
+        "description": description,
+        "type": "number",
+        "minimum": minimum,
+        "default": default,
+    });
+    if let Some(maximum) = maximum {
+        schema["maximum"] = maximum.into();
+    }
+    schema
+}
+
+fn object(description: &str, properties: Map<String, Value>) -> Value {
+    json!({
+        "description": description,
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    })
+}
+
+fn palette() -> Value {
+    let properties = Swatch::ALL
+        .iter()
+        .map(|&swatch| {
+            let name = swatch.name();
+            let schema = color(describe(name, SWATCH_GROUPS), Palette::BASE[swatch]);
+            (name.to_owned(), schema)
+        })
+        .collect();
+    object(
+        "Palette entries by dotted name; unlisted entries keep their shipped value.",
+        properties,
+    )
+}
+
+/// The schema of one role style; `default` is the shipped style, if any.
+fn style(description: &str, default: Option<Value>) -> Value {
+    let swatches: Vec<&str> = Swatch::ALL.iter().map(|swatch| swatch.name()).collect();
+    let mut schema = json!({
+        "description": description,
+        "type": "object",
+        "properties": {
+            "swatch": {
+                "description": "The palette entry providing the color.",
+                "enum": swatches,
+            },
+            "alpha": {
+                "description": "Alpha applied on top of the swatch's color.",
+                "type": "integer",
+                "minimum": 0,
+                "maximum": 255,
+                "default": 255,
+            },
+            "italic": {
+                "description": "Whether text in this role is rendered in italics.",
+                "type": "boolean",
+                "default": false,
+            },
+            "weight": {
+                "description": "An explicit font weight on the CSS scale, e.g. 700 for bold.",
+                "type": "integer",
+                "minimum": 100,
+                "maximum": 900,
+            },
+        },
+        "required": ["swatch"],
+        "additionalProperties": false,
+    });
+    if let Some(default) = default {
+        schema["default"] = default;
+    }
+    schema
+}
+
+fn roles() -> Value {
+    let properties = Role::ALL
+        .iter()
+        .map(|&role| {
+            let name = role.name();
+            let default =
+                serde_json::to_value(RoleMap::DEFAULT[role]).expect("styles always serialize");
+            let schema = style(describe(name, ROLE_GROUPS), Some(default));
+            (name.to_owned(), schema)
+        })
+        .collect();
+    object(
+        "Role assignments by dotted name; unlisted roles keep their shipped style.",
+        properties,
+    )
+}
+
+fn languages() -> Value {
+    let properties = Role::ALL
+        .iter()
+        .filter(|role| role.is_syntax())
+        .map(|&role| {
+            let schema = json!({
+                "description": "The role's style inside this language.",
+                "$ref": "#/$defs/style",
+            });
+            (role.name().to_owned(), schema)
+        })
+        .collect();
+    json!({
+        "description": "Syntax roles restyled inside one language, by language identifier such as `rust`.",
+        "type": "object",
+        "additionalProperties": object(
+            "Syntax roles by dotted name; unlisted roles keep their theme style.",
+            properties,
+        ),
+    })
+}
+
+fn tuning(source: &Source) -> Value {
+    let tuning = &source.tuning;
+    // A factor of zero would flatten the palette, so unlike other numbers
+    // the bound itself is excluded.
+    let factor = |description: &str, default: f64| {
+        let mut schema = number(description, default, 0.0, None);
+        if let Some(schema) = schema.as_object_mut() {
+            schema.remove("minimum");
+            schema.insert("exclusiveMinimum".into(), 0.0.into());
+        }
+        schema
+    };
+    let mut properties = Map::new();
+    properties.insert(
+        "brightness".into(),
+        factor("Factor applied to OKLCH lightness.", tuning.brightness),
+    );
+    properties.insert(
+        "saturation".into(),
+        factor("Factor applied to OKLCH chroma.", tuning.saturation),
+    );
+    object(
+        "Display tuning, applied to the palette before any variant is derived.",
+        properties,
+    )
+}
+
+fn transforms(source: &Source) -> Value {
+    let transforms = &source.transforms;
+    let section = |description: &str, fields: &[(&str, &str, f64, Option<f64>)]| {
+        let properties = fields
+            .iter()
+            .map(|&(key, description, default, maximum)| {
+                (key.to_owned(), number(description, default, 0.0, maximum))
+            })
+            .collect();
+        object(description, properties)
+    };
+    let light = &transforms.light;
+    let high_contrast = &transforms.high_contrast;
+    let dimmed = &transforms.dimmed;
+    let oled = &transforms.oled;
+    let mut properties = Map::new();
+    properties.insert(
+        "light".into(),
+        section(
+            "The light variant, derived by remapping OKLCH lightness.",
+            &[
+                (
+                    "background",
+                    "Target lightness for the base background.",
+                    light.background,
+                    Some(1.0),
+                ),
+                (
+                    "foreground",
+                    "Target lightness for the base foreground.",
+                    light.foreground,
+                    Some(1.0),
+                ),
+                (
+                    "chroma_compression",
+                    "How much less saturated colors move than neutrals.",
+                    light.chroma_compression,
+                    Some(1.0),
+                ),
+                (
+                    "min_ratio",
+                    "The minimum WCAG contrast ratio for syntax roles.",
+                    light.min_ratio,
+                    Some(21.0),
+                ),
+            ],
+        ),
+    );
+    properties.insert(
+        "high_contrast".into(),
+        section(
+            "The high-contrast variant.",
+            &[
+                (
+                    "min_ratio",
+                    "The minimum WCAG contrast ratio for text roles.",
+                    high_contrast.min_ratio,
+                    Some(21.0),
+                ),
+                (
+                    "background_scale",
+                    "Factor applied to the lightness of `bg.*` entries.",
+                    high_contrast.background_scale,
+                    None,
+                ),
+            ],
+        ),
+    );
+    properties.insert(
+        "dimmed".into(),
+        section(
+            "The dimmed variant.",
+            &[
+                (
+                    "chroma",
+                    "Factor applied to OKLCH chroma.",
+                    dimmed.chroma,
+                    None,
+                ),
+                (
+                    "contrast",
+                    "Factor applied to each color's lightness distance from the midpoint.",
+                    dimmed.contrast,
+                    None,
+                ),
+            ],
+        ),
+    );
+    properties.insert(
+        "oled".into(),
+        section(
+            "The OLED variant with true black surfaces.",
+            &[(
+                "border_strength",
+                "Alpha of the foreground used for primary borders.",
+                oled.border_strength,
+                Some(1.0),
+            )],
+        ),
+    );
+    object("Parameters of the derived variants.", properties)
+}
+
+fn lint(source: &Source) -> Value {
+    let lint = &source.lint;
+    let mut properties = Map::new();
+    properties.insert(
+        "metric".into(),
+        json!({
+            "description": "How color difference is measured.",
+            "enum": ["oklab", "ciede2000"],
+            "default": lint.metric,
+        }),
+    );
+    properties.insert(
+        "threshold".into(),
+        json!({
+            "description": "Accent pairs closer than this are reported; 0.03 for `oklab` and 3.0 for `ciede2000` when left out.",
+            "type": "number",
+            "minimum": 0.0,
+        }),
+    );
+    properties.insert(
+        "deny".into(),
+        json!({ "description": "Fail instead of warning.", "type": "boolean", "default": lint.deny }),
+    );
+    let swatches: Vec<&str> = Swatch::ALL.iter().map(|swatch| swatch.name()).collect();
+    properties.insert(
+        "allow".into(),
+        json!({
+            "description": "Accent pairs that are close on purpose and never reported, in either order.",
+            "type": "array",
+            "items": {
+                "type": "array",
+                "items": { "enum": swatches },
+                "minItems": 2,
+                "maxItems": 2,
+            },
+            "default": lint.allow,
+        }),
+    );
+    object(
+        "The near-duplicate accent check run by `build` and `check`.",
+        properties,
+    )
+}
+
+/// The schema for a theme source.
+pub fn schema() -> Value {
+    let source = Source::default();
+    let mut meta = Map::new();
+    meta.insert(
+        "name".into(),
+        json!({ "description": "The theme family name.", "type": "string", "default": source.meta.name }),
+    );
+    meta.insert(
+        "author".into(),
+        json!({ "description": "The author written into generated files.", "type": "string", "default": source.meta.author }),
+    );
+    meta.insert(
+        "version".into(),
+        json!({ "description": "The release version; the generator's version when left out.", "type": "string", "default": source.meta.version }),
+    );
+    meta.insert(
+        "homepage".into(),
+        json!({ "description": "Where the theme is published.", "type": "string", "default": source.meta.homepage }),
+    );
+    meta.insert(
+        "license".into(),
+        json!({ "description": "An SPDX license expression.", "type": "string", "default": source.meta.license }),
+    );
+    let mut properties = Map::new();
+    properties.insert(
+        "meta".into(),
+        object("Metadata stamped into generated files.", meta),
+    );
+    properties.insert("palette".into(), palette());
+    properties.insert("roles".into(), roles());
+    properties.insert("tuning".into(), tuning(&source));
+    properties.insert("transforms".into(), transforms(&source));
+    properties.insert("languages".into(), languages());
+    properties.insert("lint".into(), lint(&source));
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Zero Trust theme source",
+        "description": "A theme.toml for the zero-trust generator. Every section is optional and every table may be partial.",
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+        "$defs": { "style": style("A role's color and font attributes.", None) },
+    })
+}
+
+/// [`schema`] as pretty JSON with a trailing newline.
+pub fn to_string() -> String {
+    let mut text = serde_json::to_string_pretty(&schema()).expect("JSON values always serialize");
+    text.push('\n');
+    text
+}
@@ -0,0 +1,241 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use zero_trust_palette::difference::{DuplicateLint, Metric, NearDuplicate};
+use zero_trust_palette::roles::RoleMap;
+use zero_trust_palette::{Palette, Swatch, Theme, Transforms, Tuning, Variant};
+
+use crate::cache::sha256;
+use crate::languages::Languages;
+use crate::Error;
+
+/// Theme metadata, stamped into every generated file whose format has a
+/// place for it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Meta {
+    pub name: String,
+    pub author: String,
+    /// The release version, the generator's own by default.
+    pub version: String,
+    pub homepage: String,
+    /// An SPDX license expression.
+    pub license: String,
+}
+
+impl Default for Meta {
+    fn default() -> Self {
+        Meta {
+            name: "Zero Trust Theme".into(),
+            author: "Yannick Boog".into(),
+            version: env!("CARGO_PKG_VERSION").into(),
+            homepage: env!("CARGO_PKG_REPOSITORY").into(),
+            license: env!("CARGO_PKG_LICENSE").into(),
+        }
+    }
+}
+
+/// `text` in lowercase, with every run of other characters than ASCII
+/// letters and digits turned into one dash.
+fn kebab(text: &str) -> String {
+    let text: String = text
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    text.split('-')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+impl Meta {
+    /// The header comment lines for a file holding the theme `title`, for
+    /// formats that allow comments. Emitters prefix each line with their
+    /// comment marker.
+    pub fn header(&self, title: &str) -> Vec<String> {
+        vec![
+            format!("{title} {}", self.version),
+            format!("Author: {}", self.author),
+            format!("Homepage: {}", self.homepage),
+            format!("License: {}", self.license),
+            format!(
+                "Generated by zero-trust from {}; do not edit.",
+                Source::FILE_NAME
+            ),
+        ]
+    }
+
+    /// The family the variants are named after: `name` without a trailing
+    /// ` Theme`, so `Zero Trust Theme` names its variants `Zero Trust Dark`
+    /// and so on.
+    pub fn family(&self) -> &str {
+        self.name.strip_suffix(" Theme").unwrap_or(&self.name)
+    }
+
+    /// The display name of `variant`, e.g. `Zero Trust Dark`.
+    pub fn theme_name(&self, variant: Variant) -> String {
+        format!("{} {}", self.family(), variant.label())
+    }
+
+    /// The identifier generated file names start with: the family in
+    /// lowercase with dashes, e.g. `zero-trust`.
+    pub fn stem(&self) -> String {
+        kebab(self.family())
+    }
+
+    /// The whole name in lowercase with dashes, e.g. `zero-trust-theme`, for
+    /// formats that identify a package by name.
+    pub fn id(&self) -> String {
+        kebab(&self.name)
+    }
+
+    /// The file stem of `variant`, e.g. `zero-trust-dark`.
+    pub fn file_stem(&self, variant: Variant) -> String {
+        format!("{}-{}", self.stem(), variant.slug())
+    }
+
+    /// A UUID derived from the theme name and `key`, e.g. a variant slug, for
+    /// formats that identify themes by UUID. It stays the same across
+    /// releases, so editors keep treating an updated theme as the same one.
+    pub fn uuid(&self, key: &str) -> String {
+        let hash = sha256(format!("{}\0{key}", self.name).as_bytes());
+        let mut digits: Vec<char> = hash.chars().take(32).collect();
+        // Version 8 (custom) with the RFC 4122 variant bits.
+        digits[12] = '8';
+        let variant = digits[16].to_digit(16).unwrap_or(0) & 0x3 | 0x8;
+        digits[16] = char::from_digit(variant, 16).unwrap_or('8');
+        let digits: String = digits.into_iter().collect();
+        format!(
+            "{}-{}-{}-{}-{}",
+            &digits[..8],
+            &digits[8..12],
+            &digits[12..16],
+            &digits[16..20],
+            &digits[20..]
+        )
+    }
+}
+
+/// The near-duplicate accent check run by `build` and `check`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Lint {
+    pub metric: Metric,
+    /// Accent pairs strictly closer than this are reported; the metric's
+    /// [default threshold](Metric::default_threshold) when left out.
+    pub threshold: Option<f64>,
+    /// Fail instead of warning.
+    pub deny: bool,
+    /// Accent pairs that are close on purpose and never reported, in either
+    /// order.
+    pub allow: Vec<[Swatch; 2]>,
+}
+
+impl Lint {
+    /// Whether `first` and `second` may be as close as they like.
+    pub fn allows(&self, first: Swatch, second: Swatch) -> bool {
+        self.allow
+            .iter()
+            .any(|&pair| pair == [first, second] || pair == [second, first])
+    }
+
+    /// The palette lint this configures.
+    pub fn duplicates(&self) -> DuplicateLint {
+        DuplicateLint {
+            metric: self.metric,
+            threshold: self
+                .threshold
+                .unwrap_or_else(|| self.metric.default_threshold()),
+        }
+    }
+}
+
+/// The contents of a `theme.toml`.
+///
+/// Every section is optional and every table may be partial: whatever is
+/// left out keeps the value shipped in `zero-trust-palette`.
+/// [`Source::default`] is the `theme.toml` at the root of this repository.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Source {
+    #[serde(default)]
+    pub meta: Meta,
+    #[serde(default)]
+    pub palette: Palette,
+    #[serde(default)]
+    pub roles: RoleMap,
+    #[serde(default)]
+    pub tuning: Tuning,
+    #[serde(default)]
+    pub transforms: Transforms,
+    /// Syntax roles restyled inside single languages.
+    #[serde(default)]
+    pub languages: Languages,
+    #[serde(default)]
+    pub lint: Lint,
+}
+
+/// The `theme.toml` published with the generator.
+const SHIPPED: &str = include_str!("../../../theme.toml");
+
+impl Default for Source {
+    fn default() -> Self {
+        Source::parse(SHIPPED, Path::new(Source::FILE_NAME))
+            .expect("the shipped theme.toml is valid")
+    }
+}
+
+impl Source {
+    /// The conventional file name of a theme source.
+    pub const FILE_NAME: &'static str = "theme.toml";
+
+    /// Parses a theme source from TOML text; `path` is used in errors.
+    pub fn parse(text: &str, path: &Path) -> Result<Source, Error> {
+        toml::from_str(text).map_err(|source| Error::Parse {
+            path: path.to_owned(),
+            text: text.to_owned(),
+            source: Box::new(source),
+        })
+    }
+
+    /// Reads and parses the theme source at `path`.
+    pub fn load(path: &Path) -> Result<Source, Error> {
+        let text = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        tracing::debug!(path = %path.display(), "loading theme source");
+        Source::parse(&text, path)
+    }
+
+    /// Finds `theme.toml` in `start` or the nearest parent directory.
+    pub fn discover(start: &Path) -> Result<PathBuf, Error> {
+        start
+            .ancestors()
+            .map(|dir| dir.join(Source::FILE_NAME))
+            .find(|path| path.is_file())
+            .ok_or_else(|| Error::NotFound {
+                start: start.to_owned(),
+            })
+    }
+
+    /// The accent pairs of the palette that the `[lint]` settings flag as
+    /// too close to tell apart, leaving out the allowed ones.
+    pub fn near_duplicates(&self) -> Vec<NearDuplicate<Swatch>> {
+        self.lint
+            .duplicates()
+            .check_accents(&self.palette)
+            .filter(|pair| !self.lint.allows(pair.first, pair.second))
+            .collect()
+    }
+
+    /// Resolves `variant`, applying the tuning before it is derived.
+    pub fn theme(&self, variant: Variant) -> Theme {
+        let base = self.tuning.apply(&self.palette);
+        Theme::from_parts(variant, &base, self.roles.clone(), &self.transforms)
+    }
+}
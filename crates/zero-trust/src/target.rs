@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use zero_trust_palette::Variant;
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Source};
+
+/// One generated file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Output {
+    /// Where the file goes, relative to the output directory.
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+impl Output {
+    /// Line endings are normalized to LF, so a template checked out with
+    /// CRLF or a plugin built on Windows renders the same bytes everywhere.
+    pub fn new(path: impl Into<PathBuf>, contents: impl Into<String>) -> Self {
+        let mut contents = contents.into();
+        if contents.contains('\r') {
+            contents = contents.replace("\r\n", "\n");
+        }
+        Output {
+            path: path.into(),
+            contents,
+        }
+    }
+}
+
+/// How structured outputs are laid out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Indented, for reviewable diffs.
+    #[default]
+    Pretty,
+    /// Without insignificant whitespace, for smaller packages and faster
+    /// loading.
+    Minified,
+}
+
+impl Output {
+    /// A JSON file, pretty-printed with a trailing newline.
+    ///
+    /// Targets always emit this form; [`Output::format`] minifies it.
+    pub fn json(path: impl Into<PathBuf>, value: &serde_json::Value) -> Self {
+        let mut contents =
+            serde_json::to_string_pretty(value).expect("JSON values always serialize");
+        contents.push('\n');
+        Output::new(path, contents)
+    }
+
+    /// Lays the file out in `format`. Only JSON files have a minified form;
+    /// anything else, or a file that does not parse, is returned as is.
+    pub fn format(self, format: Format) -> Self {
+        let is_json = self.path.extension().is_some_and(|ext| ext == "json");
+        if format == Format::Pretty || !is_json {
+            return self;
+        }
+        match serde_json::from_str::<serde_json::Value>(&self.contents) {
+            Ok(value) => {
+                let mut contents = value.to_string();
+                contents.push('\n');
+                Output { contents, ..self }
+            }
+            Err(_) => self,
+        }
+    }
+}
+
+/// An editor or tool the theme is generated for.
+pub trait Target: Send + Sync {
+    /// The identifier used on the command line, e.g. `zed`.
+    fn name(&self) -> &str;
+
+    /// Renders the files for `variants`.
+    ///
+    /// Targets with theme families put every variant into one file; the
+    /// others emit one file per variant.
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error>;
+
+    /// Bytes identifying the target's own definition, hashed into its
+    /// [cache](crate::cache) fingerprint next to the theme.
+    ///
+    /// Built-in targets are covered by the hash of the generator's sources
+    /// the cache adds anyway; targets loaded from files return the file
+    /// contents.
+    fn fingerprint(&self) -> &[u8] {
+        &[]
+    }
+
+    /// Where the application reads themes from, for `zero-trust install`.
+    ///
+    /// `None` for targets without a fixed per-user location. Targets whose
+    /// directory is named after the theme take the name from `meta`.
+    fn install_dir(&self, dirs: &Dirs, meta: &Meta) -> Option<InstallDir> {
+        let _ = (dirs, meta);
+        None
+    }
+
+    /// The command to run after installing or uninstalling, for
+    /// applications that read themes from a cache of their own, e.g.
+    /// `["bat", "cache", "--build"]`. Empty for most targets.
+    fn refresh_command(&self) -> &[&str] {
+        &[]
+    }
+}
+
+/// Renders every target in parallel, keeping the outputs in target order.
+pub fn render_all(
+    targets: &[Arc<dyn Target>],
+    source: &Source,
+    variants: &[Variant],
+) -> Result<Vec<Output>, Error> {
+    let rendered: Vec<Vec<Output>> = targets
+        .par_iter()
+        .map(|target| target.render(source, variants))
+        .collect::<Result<_, _>>()?;
+    Ok(rendered.into_iter().flatten().collect())
+}
+
+/// The outputs whose file below `out_dir` is missing or has other contents.
+pub fn stale_outputs<'a>(out_dir: &Path, outputs: &'a [Output]) -> Vec<&'a Output> {
+    outputs
+        .iter()
+        .filter(|output| {
+            fs::read_to_string(out_dir.join(&output.path))
+                .ok()
+                .as_deref()
+                != Some(output.contents.as_str())
+        })
+        .collect()
+}
+
+/// Whether `path` stays inside the directory it is joined to.
+pub(crate) fn is_contained(path: &Path) -> bool {
+    path.components().next().is_some()
+        && path.components().all(|c| matches!(c, Component::Normal(_)))
+}
+
+/// Writes `outputs` below `out_dir`, creating directories as needed.
+pub fn write_outputs(out_dir: &Path, outputs: &[Output]) -> Result<(), Error> {
+    for output in outputs {
+        let path = out_dir.join(&output.path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+        }
+        fs::write(&path, &output.contents).map_err(|e| Error::io(&path, e))?;
+    }
+    Ok(())
+}
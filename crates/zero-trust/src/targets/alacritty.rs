@@ -0,0 +1,136 @@
+//! Alacritty: one `colors` snippet per variant, imported from the user's
+//! `alacritty.toml`.
+//!
+//! Selected text keeps its own colors, with only the selection background
+//! set.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::{ANSI_NAMES, TERMINAL, TERMINAL_DIM};
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Alacritty;
+
+/// Tables other than the ANSI colors, each with its keys and roles.
+const TABLES: &[(&str, &[(&str, Role)])] = &[
+    (
+        "primary",
+        &[
+            ("background", Role::TerminalBackground),
+            ("foreground", Role::TerminalForeground),
+            ("dim_foreground", Role::TerminalForegroundDim),
+            ("bright_foreground", Role::TerminalForegroundBright),
+        ],
+    ),
+    (
+        "cursor",
+        &[("text", Role::TerminalBackground), ("cursor", Role::Cursor)],
+    ),
+    (
+        "vi_mode_cursor",
+        &[("text", Role::TerminalBackground), ("cursor", Role::Accent)],
+    ),
+    ("selection", &[("background", Role::Selection)]),
+    (
+        "search.matches",
+        &[
+            ("foreground", Role::TerminalForeground),
+            ("background", Role::SearchMatch),
+        ],
+    ),
+    (
+        "search.focused_match",
+        &[
+            ("foreground", Role::TerminalBackground),
+            ("background", Role::Accent),
+        ],
+    ),
+    (
+        "hints.start",
+        &[
+            ("foreground", Role::TerminalBackground),
+            ("background", Role::Warning),
+        ],
+    ),
+    (
+        "hints.end",
+        &[
+            ("foreground", Role::TerminalBackground),
+            ("background", Role::WarningMuted),
+        ],
+    ),
+    (
+        "footer_bar",
+        &[("foreground", Role::Text), ("background", Role::Statusline)],
+    ),
+    (
+        "line_indicator",
+        &[
+            ("foreground", Role::TerminalBackground),
+            ("background", Role::TextMuted),
+        ],
+    ),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    let hex = |role| theme.opaque_color(role);
+    for &(table, keys) in TABLES {
+        let _ = writeln!(text, "\n[colors.{table}]");
+        if table == "selection" {
+            text.push_str("text = \"CellForeground\"\n");
+        }
+        for &(key, role) in keys {
+            let _ = writeln!(text, "{key} = \"{}\"", hex(role));
+        }
+    }
+    let ansi: [(&str, &[Role]); 3] = [
+        ("normal", &TERMINAL[..8]),
+        ("bright", &TERMINAL[8..]),
+        ("dim", &TERMINAL_DIM),
+    ];
+    for (table, roles) in ansi {
+        let _ = writeln!(text, "\n[colors.{table}]");
+        for (name, &role) in ANSI_NAMES.iter().zip(roles) {
+            let _ = writeln!(text, "{name} = \"{}\"", hex(role));
+        }
+    }
+    text
+}
+
+impl Target for Alacritty {
+    fn name(&self) -> &str {
+        "alacritty"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "toml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = if cfg!(windows) {
+            dirs.app_data.join("alacritty")
+        } else {
+            dirs.config.join("alacritty")
+        };
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
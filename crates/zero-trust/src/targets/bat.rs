@@ -0,0 +1,78 @@
+//! bat: the `.tmTheme` of each variant, plus `zero-trust.tmTheme` with the
+//! dark colors for `--theme=zero-trust`.
+//!
+//! bat names a theme after its file stem and only reads themes from its
+//! cache, so installing rebuilds the cache afterwards. The files are the
+//! ones the tmtheme target writes.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use zero_trust_palette::Variant;
+
+use super::tmtheme;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Bat;
+
+/// The directory `bat --config-dir` reports, if bat runs.
+fn config_dir() -> Option<PathBuf> {
+    let output = Command::new("bat").arg("--config-dir").output().ok()?;
+    let dir = String::from_utf8(output.stdout).ok()?;
+    let dir = dir.trim();
+    (output.status.success() && !dir.is_empty()).then(|| PathBuf::from(dir))
+}
+
+impl Target for Bat {
+    fn name(&self) -> &str {
+        "bat"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "tmTheme"),
+                    tmtheme::render(source, &source.theme(variant)),
+                )
+            })
+            .collect();
+        if variants.contains(&Variant::Dark) {
+            outputs.push(Output::new(
+                Path::new("extras")
+                    .join(self.name())
+                    .join(format!("{}.tmTheme", source.meta.stem())),
+                tmtheme::render(source, &source.theme(Variant::Dark)),
+            ));
+        }
+        Ok(outputs)
+    }
+
+    /// bat's configuration directory: `BAT_CONFIG_DIR` when set, otherwise
+    /// what `bat --config-dir` prints, and bat's default where bat is not on
+    /// `PATH`.
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = env::var_os("BAT_CONFIG_DIR")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(config_dir)
+            .unwrap_or_else(|| {
+                if cfg!(windows) {
+                    dirs.app_data.join("bat")
+                } else {
+                    dirs.config.join("bat")
+                }
+            });
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+
+    fn refresh_command(&self) -> &[&str] {
+        &["bat", "cache", "--build"]
+    }
+}
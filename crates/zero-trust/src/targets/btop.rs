@@ -0,0 +1,99 @@
+//! btop: one `.theme` file per variant, also read by bpytop.
+//!
+//! Every graph gradient is a ramp derived from one color the way the state
+//! ramps are: the muted step for low values, the color itself in the
+//! middle and the emphasized step at the top, so the meters brighten as
+//! they fill in dark variants and deepen in light ones.
+
+use std::fmt::Write;
+
+use zero_trust_palette::ramp::Ramp;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Btop;
+
+/// Single-color theme keys and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("main_bg", Role::Background),
+    ("main_fg", Role::Text),
+    ("title", Role::Text),
+    ("hi_fg", Role::Accent),
+    ("selected_bg", Role::ElementSelected),
+    ("selected_fg", Role::Text),
+    ("inactive_fg", Role::TextDisabled),
+    ("graph_text", Role::TextMuted),
+    ("meter_bg", Role::ElementActive),
+    ("proc_misc", Role::Info),
+    ("cpu_box", Role::Border),
+    ("mem_box", Role::Border),
+    ("net_box", Role::Border),
+    ("proc_box", Role::Border),
+    ("div_line", Role::BorderVariant),
+];
+
+/// Graphs and the roles their gradients are derived from.
+const GRADIENTS: &[(&str, Role)] = &[
+    ("temp", Role::Warning),
+    ("cpu", Role::Accent),
+    ("free", Role::Success),
+    ("cached", Role::Info),
+    ("available", Role::TerminalCyan),
+    ("used", Role::Error),
+    ("download", Role::TerminalBlue),
+    ("upload", Role::TerminalMagenta),
+    ("process", Role::Accent),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    for &(key, role) in COLORS {
+        let _ = writeln!(text, "theme[{key}]=\"{}\"", theme.opaque_color(role));
+    }
+    let background = theme.opaque_color(Role::Background);
+    for &(graph, role) in GRADIENTS {
+        let ramp = Ramp::derive(theme.opaque_color(role), background);
+        let steps = [
+            ("start", ramp.muted),
+            ("mid", ramp.base),
+            ("end", ramp.emphasized),
+        ];
+        for (step, color) in steps {
+            let _ = writeln!(text, "theme[{graph}_{step}]=\"{color}\"");
+        }
+    }
+    text
+}
+
+impl Target for Btop {
+    fn name(&self) -> &str {
+        "btop"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "theme"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("btop");
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
@@ -0,0 +1,100 @@
+//! delta: one gitconfig include per variant with a `[delta]` section setting
+//! the syntax theme and the diff, hunk header and line number styles.
+//!
+//! The syntax theme is the variant's bat theme, so the bat target has to be
+//! installed for it to resolve. Line backgrounds come from the success and
+//! error ramps: added and removed lines get the ramp's tint, and changed
+//! words a stronger mix of the same two colors. The include is pulled in
+//! from the user's own gitconfig.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{mix, Color, Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Delta;
+
+/// How far changed words are mixed from the background towards the state
+/// color, further than the ramp's tint behind whole lines.
+const EMPHASIS: f64 = 0.35;
+
+/// Styles of a single color, with the attributes written before it.
+const STYLES: &[(&str, &str, Role)] = &[
+    ("hunk-header-decoration-style", "", Role::Border),
+    ("hunk-header-file-style", "bold", Role::Accent),
+    ("hunk-header-line-number-style", "", Role::LineNumberActive),
+    ("file-style", "bold", Role::Text),
+    ("file-decoration-style", "ul", Role::Border),
+    ("commit-style", "bold", Role::Warning),
+    ("commit-decoration-style", "box", Role::Border),
+    ("line-numbers-zero-style", "", Role::LineNumber),
+    ("line-numbers-minus-style", "", Role::GitDeleted),
+    ("line-numbers-plus-style", "", Role::GitAdded),
+    ("line-numbers-left-style", "", Role::Border),
+    ("line-numbers-right-style", "", Role::Border),
+    (
+        "merge-conflict-ours-diff-header-style",
+        "bold",
+        Role::GitAdded,
+    ),
+    (
+        "merge-conflict-theirs-diff-header-style",
+        "bold",
+        Role::Info,
+    ),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    let background = theme.opaque_color(Role::Background);
+    let emphasis = |role| mix(background, theme.opaque_color(role), EMPHASIS);
+    let _ = writeln!(
+        text,
+        "\n[delta]\n\tsyntax-theme = {}\n\t{} = true",
+        source.meta.file_stem(theme.variant),
+        theme.appearance().name()
+    );
+    let lines: [(&str, Color); 4] = [
+        ("minus-style", theme.opaque_color(Role::ErrorBackground)),
+        ("minus-emph-style", emphasis(Role::Error)),
+        ("plus-style", theme.opaque_color(Role::SuccessBackground)),
+        ("plus-emph-style", emphasis(Role::Success)),
+    ];
+    for (key, color) in lines {
+        let _ = writeln!(text, "\t{key} = syntax \"{color}\"");
+    }
+    text.push_str("\tzero-style = syntax\n\thunk-header-style = file line-number syntax\n");
+    for &(key, attributes, role) in STYLES {
+        let color = theme.opaque_color(role);
+        let value = if attributes.is_empty() {
+            format!("\"{color}\"")
+        } else {
+            format!("{attributes} \"{color}\"")
+        };
+        let _ = writeln!(text, "\t{key} = {value}");
+    }
+    text
+}
+
+impl Target for Delta {
+    fn name(&self) -> &str {
+        "delta"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "gitconfig"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,201 @@
+//! dircolors: one `dircolors` database per variant coloring file types and
+//! common extensions, plus a shell snippet exporting the same colors as
+//! `LS_COLORS`.
+//!
+//! `ls` and the tools reading `LS_COLORS` take SGR sequences, so colors are
+//! written as the quantizer's 256-color picks, which every terminal with a
+//! 256-color palette shows alike. The snippet holds the value `dircolors -b`
+//! computes from the database, for machines without GNU coreutils. Either
+//! file is read from the user's shell profile.
+
+use std::fmt::Write;
+
+use zero_trust_palette::quantize::to_ansi256;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Dircolors;
+
+/// How a file type or extension is painted.
+#[derive(Clone, Copy)]
+struct Paint {
+    fg: Role,
+    bg: Option<Role>,
+    bold: bool,
+}
+
+impl Paint {
+    const fn fg(role: Role) -> Paint {
+        Paint {
+            fg: role,
+            bg: None,
+            bold: false,
+        }
+    }
+
+    const fn on(fg: Role, bg: Role) -> Paint {
+        Paint {
+            fg,
+            bg: Some(bg),
+            bold: false,
+        }
+    }
+
+    const fn bold(self) -> Paint {
+        Paint { bold: true, ..self }
+    }
+
+    /// The SGR parameters of the paint in `theme`.
+    fn sgr(self, theme: &Theme) -> String {
+        let index = |role| to_ansi256(theme.opaque_color(role));
+        let mut sgr = if self.bold { "01;" } else { "" }.to_owned();
+        let _ = write!(sgr, "38;5;{}", index(self.fg));
+        if let Some(bg) = self.bg {
+            let _ = write!(sgr, ";48;5;{}", index(bg));
+        }
+        sgr
+    }
+}
+
+/// File types: the database keyword, the `LS_COLORS` code and the paint.
+const TYPES: &[(&str, &str, Paint)] = &[
+    ("DIR", "di", Paint::fg(Role::Accent).bold()),
+    ("LINK", "ln", Paint::fg(Role::Info)),
+    ("ORPHAN", "or", Paint::fg(Role::Error).bold()),
+    ("MISSING", "mi", Paint::fg(Role::Error)),
+    ("FIFO", "pi", Paint::fg(Role::Warning)),
+    ("SOCK", "so", Paint::fg(Role::Keyword)),
+    ("DOOR", "do", Paint::fg(Role::Keyword)),
+    ("BLK", "bd", Paint::fg(Role::Warning).bold()),
+    ("CHR", "cd", Paint::fg(Role::Warning).bold()),
+    ("EXEC", "ex", Paint::fg(Role::Success).bold()),
+    ("SETUID", "su", Paint::on(Role::Background, Role::Error)),
+    ("SETGID", "sg", Paint::on(Role::Background, Role::Warning)),
+    (
+        "STICKY_OTHER_WRITABLE",
+        "tw",
+        Paint::on(Role::Background, Role::Success),
+    ),
+    (
+        "OTHER_WRITABLE",
+        "ow",
+        Paint::on(Role::Accent, Role::Element),
+    ),
+    ("STICKY", "st", Paint::on(Role::Text, Role::Element)),
+];
+
+/// Groups of extensions, each with a heading and the role that paints it;
+/// yazi's file type rules are built from the same groups.
+pub(super) const EXTENSIONS: &[(&str, Role, &[&str])] = &[
+    (
+        "Archives",
+        Role::Warning,
+        &[
+            "tar", "tgz", "gz", "bz2", "xz", "zst", "lz4", "zip", "7z", "rar", "jar", "deb", "rpm",
+            "apk", "dmg", "iso",
+        ],
+    ),
+    (
+        "Images",
+        Role::Keyword,
+        &[
+            "png", "jpg", "jpeg", "gif", "webp", "avif", "bmp", "ico", "svg", "tif", "tiff", "heic",
+        ],
+    ),
+    (
+        "Audio and video",
+        Role::Constant,
+        &[
+            "mp3", "flac", "ogg", "opus", "wav", "m4a", "mp4", "mkv", "webm", "mov", "avi",
+        ],
+    ),
+    (
+        "Documents",
+        Role::String,
+        &[
+            "pdf", "epub", "md", "rst", "txt", "doc", "docx", "odt", "xls", "xlsx", "ods",
+        ],
+    ),
+    (
+        "Data and configuration",
+        Role::Property,
+        &[
+            "json", "toml", "yaml", "yml", "xml", "ini", "conf", "csv", "sql", "lock",
+        ],
+    ),
+    (
+        "Source code",
+        Role::Function,
+        &[
+            "rs", "c", "h", "cpp", "hpp", "go", "py", "rb", "js", "ts", "java", "kt", "swift",
+            "sh", "lua",
+        ],
+    ),
+    (
+        "Temporary files",
+        Role::Comment,
+        &["bak", "tmp", "swp", "orig", "log", "part"],
+    ),
+];
+
+fn header(text: &mut String, source: &Source, theme: &Theme) {
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+}
+
+fn database(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    header(&mut text, source, theme);
+    text.push('\n');
+    for &(keyword, _, paint) in TYPES {
+        let _ = writeln!(text, "{keyword} {}", paint.sgr(theme));
+    }
+    for &(heading, role, extensions) in EXTENSIONS {
+        let sgr = Paint::fg(role).sgr(theme);
+        let _ = writeln!(text, "\n# {heading}");
+        for extension in extensions {
+            let _ = writeln!(text, ".{extension} {sgr}");
+        }
+    }
+    text
+}
+
+fn snippet(source: &Source, theme: &Theme) -> String {
+    let mut entries = Vec::new();
+    for &(_, code, paint) in TYPES {
+        entries.push(format!("{code}={}", paint.sgr(theme)));
+    }
+    for &(_, role, extensions) in EXTENSIONS {
+        let sgr = Paint::fg(role).sgr(theme);
+        entries.extend(extensions.iter().map(|ext| format!("*.{ext}={sgr}")));
+    }
+    let mut text = String::new();
+    header(&mut text, source, theme);
+    let _ = writeln!(text, "\nexport LS_COLORS='{}'", entries.join(":"));
+    text
+}
+
+impl Target for Dircolors {
+    fn name(&self) -> &str {
+        "dircolors"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let mut outputs = Vec::new();
+        for &variant in variants {
+            let theme = source.theme(variant);
+            outputs.push(Output::new(
+                super::extras_path(&source.meta, self.name(), variant, "dircolors"),
+                database(source, &theme),
+            ));
+            outputs.push(Output::new(
+                super::extras_path(&source.meta, self.name(), variant, "sh"),
+                snippet(source, &theme),
+            ));
+        }
+        Ok(outputs)
+    }
+}
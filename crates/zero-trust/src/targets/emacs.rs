@@ -0,0 +1,506 @@
+//! Emacs: one `deftheme` file per variant, plus `zero-trust-theme.el`
+//! defining the dark variant as plain `zero-trust`.
+//!
+//! Every face has three display specs: the exact colors for graphical
+//! frames and truecolor terminals, the nearest `color-N` of the 256-color
+//! palette, and the nearest of the 16 basic colors, so `emacs -nw` over
+//! SSH still gets a sensible approximation. Emacs scopes faces by mode
+//! rather than by language.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::quantize::{to_ansi16, to_ansi256};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use super::TERMINAL;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Emacs;
+
+/// The names Emacs gives the 16 basic terminal colors, by ANSI index.
+const ANSI16: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "brightblack",
+    "brightred",
+    "brightgreen",
+    "brightyellow",
+    "brightblue",
+    "brightmagenta",
+    "brightcyan",
+    "brightwhite",
+];
+
+/// The `ansi-color-*` faces, by ANSI index.
+const ANSI_FACES: [&str; 16] = [
+    "ansi-color-black",
+    "ansi-color-red",
+    "ansi-color-green",
+    "ansi-color-yellow",
+    "ansi-color-blue",
+    "ansi-color-magenta",
+    "ansi-color-cyan",
+    "ansi-color-white",
+    "ansi-color-bright-black",
+    "ansi-color-bright-red",
+    "ansi-color-bright-green",
+    "ansi-color-bright-yellow",
+    "ansi-color-bright-blue",
+    "ansi-color-bright-magenta",
+    "ansi-color-bright-cyan",
+    "ansi-color-bright-white",
+];
+
+/// Font-lock and markup faces and the roles that paint them. Bold and
+/// italics follow the role's style.
+const SYNTAX: &[(&str, Role)] = &[
+    ("font-lock-comment-face", Role::Comment),
+    ("font-lock-comment-delimiter-face", Role::Comment),
+    ("font-lock-doc-face", Role::CommentDoc),
+    ("font-lock-doc-markup-face", Role::CommentDoc),
+    ("font-lock-string-face", Role::String),
+    ("font-lock-escape-face", Role::StringEscape),
+    ("font-lock-regexp-face", Role::StringRegex),
+    ("font-lock-regexp-grouping-backslash", Role::StringEscape),
+    ("font-lock-regexp-grouping-construct", Role::StringEscape),
+    ("font-lock-number-face", Role::Number),
+    ("font-lock-constant-face", Role::Constant),
+    ("font-lock-builtin-face", Role::VariableSpecial),
+    ("font-lock-keyword-face", Role::Keyword),
+    ("font-lock-operator-face", Role::Operator),
+    ("font-lock-negation-char-face", Role::Operator),
+    ("font-lock-function-name-face", Role::Function),
+    ("font-lock-function-call-face", Role::Function),
+    ("font-lock-type-face", Role::Type),
+    ("font-lock-variable-name-face", Role::Variable),
+    ("font-lock-variable-use-face", Role::Variable),
+    ("font-lock-property-name-face", Role::Property),
+    ("font-lock-property-use-face", Role::Property),
+    ("font-lock-preprocessor-face", Role::Preproc),
+    ("font-lock-punctuation-face", Role::Punctuation),
+    ("font-lock-bracket-face", Role::PunctuationBracket),
+    ("font-lock-delimiter-face", Role::PunctuationDelimiter),
+    ("font-lock-misc-punctuation-face", Role::PunctuationSpecial),
+    ("org-document-title", Role::Title),
+    ("org-level-1", Role::Title),
+    ("org-level-2", Role::Title),
+    ("org-level-3", Role::Title),
+    ("org-level-4", Role::Title),
+    ("org-level-5", Role::Title),
+    ("org-level-6", Role::Title),
+    ("org-level-7", Role::Title),
+    ("org-level-8", Role::Title),
+    ("org-code", Role::TextLiteral),
+    ("org-verbatim", Role::TextLiteral),
+    ("org-list-dt", Role::PunctuationListMarker),
+    ("org-meta-line", Role::Comment),
+    ("org-drawer", Role::Comment),
+    ("org-tag", Role::Label),
+    ("bold", Role::EmphasisStrong),
+    ("italic", Role::Emphasis),
+];
+
+/// How a face is painted.
+#[derive(Clone, Copy)]
+struct Face {
+    fg: Option<Role>,
+    bg: Option<Role>,
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    /// A wavy underline in this color, for diagnostics.
+    wave: Option<Role>,
+    strike: bool,
+}
+
+impl Face {
+    const NONE: Face = Face {
+        fg: None,
+        bg: None,
+        bold: false,
+        italic: false,
+        underline: false,
+        wave: None,
+        strike: false,
+    };
+
+    const fn fg(role: Role) -> Face {
+        Face {
+            fg: Some(role),
+            ..Face::NONE
+        }
+    }
+
+    const fn bg(role: Role) -> Face {
+        Face {
+            bg: Some(role),
+            ..Face::NONE
+        }
+    }
+
+    const fn fg_bg(fg: Role, bg: Role) -> Face {
+        Face {
+            fg: Some(fg),
+            bg: Some(bg),
+            ..Face::NONE
+        }
+    }
+
+    const fn wave(role: Role) -> Face {
+        Face {
+            wave: Some(role),
+            ..Face::NONE
+        }
+    }
+
+    const fn bold(self) -> Face {
+        Face { bold: true, ..self }
+    }
+
+    const fn underline(self) -> Face {
+        Face {
+            underline: true,
+            ..self
+        }
+    }
+
+    const fn strike(self) -> Face {
+        Face {
+            strike: true,
+            ..self
+        }
+    }
+}
+
+/// Interface faces and those of magit, company, corfu and the
+/// diagnostics packages.
+const FACES: &[(&str, Face)] = &[
+    ("default", Face::fg_bg(Role::Text, Role::Background)),
+    ("cursor", Face::bg(Role::Cursor)),
+    ("region", Face::bg(Role::Selection)),
+    ("secondary-selection", Face::bg(Role::ElementSelected)),
+    ("hl-line", Face::bg(Role::Cursorline)),
+    ("highlight", Face::bg(Role::Highlight)),
+    ("isearch", Face::fg_bg(Role::Text, Role::SearchMatch).bold()),
+    ("lazy-highlight", Face::bg(Role::Highlight)),
+    (
+        "isearch-fail",
+        Face::fg_bg(Role::Error, Role::ErrorBackground),
+    ),
+    ("match", Face::bg(Role::SearchMatch)),
+    ("fringe", Face::bg(Role::Gutter)),
+    ("line-number", Face::fg_bg(Role::LineNumber, Role::Gutter)),
+    (
+        "line-number-current-line",
+        Face::fg_bg(Role::LineNumberActive, Role::Gutter).bold(),
+    ),
+    ("vertical-border", Face::fg(Role::Split)),
+    ("window-divider", Face::fg(Role::Split)),
+    ("fill-column-indicator", Face::fg(Role::Guide)),
+    ("minibuffer-prompt", Face::fg(Role::Accent).bold()),
+    ("link", Face::fg(Role::Link).underline()),
+    ("link-visited", Face::fg(Role::Link).underline()),
+    ("shadow", Face::fg(Role::TextMuted)),
+    ("tooltip", Face::fg_bg(Role::Text, Role::SurfaceElevated)),
+    ("show-paren-match", Face::bg(Role::Highlight).bold()),
+    (
+        "show-paren-mismatch",
+        Face::fg_bg(Role::Error, Role::ErrorBackground),
+    ),
+    ("trailing-whitespace", Face::bg(Role::ErrorBackground)),
+    ("whitespace-space", Face::fg(Role::Invisible)),
+    ("whitespace-tab", Face::fg(Role::Invisible)),
+    ("whitespace-newline", Face::fg(Role::Invisible)),
+    ("error", Face::fg(Role::Error).bold()),
+    ("warning", Face::fg(Role::Warning).bold()),
+    ("success", Face::fg(Role::Success).bold()),
+    ("font-lock-warning-face", Face::fg(Role::Warning).bold()),
+    ("mode-line", Face::fg_bg(Role::Text, Role::Statusline)),
+    (
+        "mode-line-inactive",
+        Face::fg_bg(Role::TextMuted, Role::Statusline),
+    ),
+    ("mode-line-buffer-id", Face::NONE.bold()),
+    ("mode-line-emphasis", Face::fg(Role::Accent).bold()),
+    ("mode-line-highlight", Face::bg(Role::ElementHover)),
+    ("header-line", Face::fg_bg(Role::TextMuted, Role::Tabline)),
+    ("tab-bar", Face::fg_bg(Role::TextMuted, Role::Tabline)),
+    ("tab-bar-tab", Face::fg_bg(Role::Text, Role::TabActive)),
+    (
+        "tab-bar-tab-inactive",
+        Face::fg_bg(Role::TextMuted, Role::TabInactive),
+    ),
+    ("completions-common-part", Face::fg(Role::Accent)),
+    ("completions-first-difference", Face::NONE.bold()),
+    ("flymake-error", Face::wave(Role::Error)),
+    ("flymake-warning", Face::wave(Role::Warning)),
+    ("flymake-note", Face::wave(Role::Info)),
+    ("flycheck-error", Face::wave(Role::Error)),
+    ("flycheck-warning", Face::wave(Role::Warning)),
+    ("flycheck-info", Face::wave(Role::Info)),
+    ("flyspell-incorrect", Face::wave(Role::Error)),
+    ("flyspell-duplicate", Face::wave(Role::Warning)),
+    ("org-todo", Face::fg(Role::Warning).bold()),
+    ("org-done", Face::fg(Role::Success).bold()),
+    ("org-headline-done", Face::fg(Role::TextMuted)),
+    ("org-date", Face::fg(Role::Link).underline()),
+    ("org-link", Face::fg(Role::Link).underline()),
+    ("org-block", Face::bg(Role::Surface)),
+    (
+        "org-block-begin-line",
+        Face::fg_bg(Role::Comment, Role::Surface),
+    ),
+    (
+        "org-block-end-line",
+        Face::fg_bg(Role::Comment, Role::Surface),
+    ),
+    ("org-table", Face::fg(Role::TextMuted)),
+    (
+        "diff-added",
+        Face::fg_bg(Role::GitAdded, Role::SuccessBackground),
+    ),
+    (
+        "diff-removed",
+        Face::fg_bg(Role::GitDeleted, Role::ErrorBackground),
+    ),
+    (
+        "diff-changed",
+        Face::fg_bg(Role::GitModified, Role::InfoBackground),
+    ),
+    ("diff-header", Face::fg_bg(Role::TextMuted, Role::Surface)),
+    (
+        "diff-file-header",
+        Face::fg_bg(Role::Text, Role::Surface).bold(),
+    ),
+    ("magit-section-heading", Face::fg(Role::Accent).bold()),
+    ("magit-section-highlight", Face::bg(Role::Cursorline)),
+    ("magit-branch-local", Face::fg(Role::Link)),
+    ("magit-branch-remote", Face::fg(Role::GitAdded)),
+    ("magit-branch-current", Face::fg(Role::Link).bold()),
+    ("magit-hash", Face::fg(Role::TextMuted)),
+    ("magit-tag", Face::fg(Role::Label)),
+    ("magit-dimmed", Face::fg(Role::TextMuted)),
+    (
+        "magit-diff-added",
+        Face::fg_bg(Role::GitAdded, Role::SuccessBackground),
+    ),
+    (
+        "magit-diff-added-highlight",
+        Face::fg_bg(Role::GitAdded, Role::SuccessBackground),
+    ),
+    (
+        "magit-diff-removed",
+        Face::fg_bg(Role::GitDeleted, Role::ErrorBackground),
+    ),
+    (
+        "magit-diff-removed-highlight",
+        Face::fg_bg(Role::GitDeleted, Role::ErrorBackground),
+    ),
+    ("magit-diff-context", Face::fg(Role::TextMuted)),
+    (
+        "magit-diff-context-highlight",
+        Face::fg_bg(Role::Text, Role::Cursorline),
+    ),
+    (
+        "magit-diff-hunk-heading",
+        Face::fg_bg(Role::TextMuted, Role::Surface),
+    ),
+    (
+        "magit-diff-hunk-heading-highlight",
+        Face::fg_bg(Role::Text, Role::ElementSelected),
+    ),
+    ("magit-diff-file-heading", Face::NONE.bold()),
+    ("magit-diffstat-added", Face::fg(Role::GitAdded)),
+    ("magit-diffstat-removed", Face::fg(Role::GitDeleted)),
+    ("magit-log-author", Face::fg(Role::Accent)),
+    ("magit-log-date", Face::fg(Role::TextMuted)),
+    ("magit-process-ok", Face::fg(Role::Success).bold()),
+    ("magit-process-ng", Face::fg(Role::Error).bold()),
+    (
+        "company-tooltip",
+        Face::fg_bg(Role::Text, Role::SurfaceElevated),
+    ),
+    ("company-tooltip-selection", Face::bg(Role::ElementSelected)),
+    ("company-tooltip-common", Face::fg(Role::Accent).bold()),
+    ("company-tooltip-annotation", Face::fg(Role::TextMuted)),
+    ("company-scrollbar-bg", Face::bg(Role::ScrollbarTrack)),
+    ("company-scrollbar-fg", Face::bg(Role::ScrollbarThumb)),
+    (
+        "company-tooltip-scrollbar-track",
+        Face::bg(Role::ScrollbarTrack),
+    ),
+    (
+        "company-tooltip-scrollbar-thumb",
+        Face::bg(Role::ScrollbarThumb),
+    ),
+    ("company-preview", Face::fg(Role::Predictive)),
+    ("company-preview-common", Face::fg(Role::Predictive)),
+    (
+        "corfu-default",
+        Face::fg_bg(Role::Text, Role::SurfaceElevated),
+    ),
+    (
+        "corfu-current",
+        Face::fg_bg(Role::Text, Role::ElementSelected),
+    ),
+    ("corfu-border", Face::bg(Role::Border)),
+    ("corfu-bar", Face::bg(Role::ScrollbarThumb)),
+    ("corfu-annotations", Face::fg(Role::TextMuted)),
+    ("corfu-deprecated", Face::fg(Role::TextMuted).strike()),
+];
+
+/// A color in the three display classes: exact, 256-color and 16-color.
+fn colors(color: Color) -> [String; 3] {
+    [
+        color.to_string(),
+        format!("color-{}", to_ansi256(color)),
+        ANSI16[usize::from(to_ansi16(color))].to_owned(),
+    ]
+}
+
+/// The attribute plist of `face` in display class `class`.
+fn plist(theme: &Theme, face: Face, class: usize) -> String {
+    let color = |role| colors(theme.opaque_color(role))[class].clone();
+    let mut attrs = Vec::new();
+    if let Some(fg) = face.fg {
+        attrs.push(format!(":foreground \"{}\"", color(fg)));
+    }
+    if let Some(bg) = face.bg {
+        attrs.push(format!(":background \"{}\"", color(bg)));
+    }
+    if face.bold {
+        attrs.push(":weight bold".to_owned());
+    }
+    if face.italic {
+        attrs.push(":slant italic".to_owned());
+    }
+    if face.underline {
+        attrs.push(":underline t".to_owned());
+    }
+    if let Some(wave) = face.wave {
+        attrs.push(format!(
+            ":underline (:style wave :color \"{}\")",
+            color(wave)
+        ));
+    }
+    if face.strike {
+        attrs.push(":strike-through t".to_owned());
+    }
+    format!("({})", attrs.join(" "))
+}
+
+fn set_face(text: &mut String, theme: &Theme, name: &str, face: Face) {
+    let specs = ["((min-colors 16777216))", "((min-colors 256))", "t"];
+    let _ = write!(text, " '({name} (");
+    for (class, display) in specs.iter().enumerate() {
+        if class > 0 {
+            text.push(' ');
+        }
+        let _ = write!(text, "({display} {})", plist(theme, face, class));
+    }
+    text.push_str("))\n");
+}
+
+fn render(source: &Source, theme: &Theme, name: &str) -> String {
+    let file = format!("{name}-theme.el");
+    let mut text = format!(
+        ";;; {file} --- {} -*- lexical-binding: t -*-\n",
+        source.meta.theme_name(theme.variant)
+    );
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, ";; {line}");
+    }
+    let _ = writeln!(
+        text,
+        "\n;;; Code:\n\n(deftheme {name} \"{}.\")\n\n(custom-theme-set-faces\n '{name}",
+        source.meta.theme_name(theme.variant)
+    );
+    for &(face, spec) in FACES {
+        set_face(&mut text, theme, face, spec);
+    }
+    for &(face, role) in SYNTAX {
+        let style = theme.style(role);
+        let spec = Face {
+            bold: style.is_bold(),
+            italic: style.italic,
+            ..Face::fg(role)
+        };
+        set_face(&mut text, theme, face, spec);
+    }
+    for (face, &role) in ANSI_FACES.iter().zip(&TERMINAL) {
+        set_face(&mut text, theme, face, Face::fg_bg(role, role));
+    }
+    text.truncate(text.len() - 1);
+    let _ = writeln!(
+        text,
+        ")
+
+;;;###autoload
+(when load-file-name
+  (add-to-list 'custom-theme-load-path
+               (file-name-as-directory (file-name-directory load-file-name))))
+
+(provide-theme '{name})
+
+;;; {file} ends here"
+    );
+    text
+}
+
+/// The theme name of `variant`, its file being `<name>-theme.el`.
+fn theme_name(meta: &Meta, variant: Variant) -> String {
+    meta.file_stem(variant)
+}
+
+impl Target for Emacs {
+    fn name(&self) -> &str {
+        "emacs"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name());
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                let name = theme_name(&source.meta, variant);
+                Output::new(
+                    dir.join(format!("{name}-theme.el")),
+                    render(source, &source.theme(variant), &name),
+                )
+            })
+            .collect();
+        if variants.contains(&Variant::Dark) {
+            outputs.push(Output::new(
+                dir.join(format!("{}-theme.el", source.meta.stem())),
+                render(source, &source.theme(Variant::Dark), &source.meta.stem()),
+            ));
+        }
+        Ok(outputs)
+    }
+
+    /// `~/.emacs.d`, or `~/.config/emacs` when only that exists, the same
+    /// order Emacs looks in. Themes go straight into it, the default
+    /// `custom-theme-directory`.
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let legacy = dirs.home.join(".emacs.d");
+        let xdg = dirs.config.join("emacs");
+        let app = if !legacy.is_dir() && xdg.is_dir() {
+            xdg
+        } else {
+            legacy
+        };
+        Some(InstallDir {
+            themes: app.clone(),
+            app,
+        })
+    }
+}
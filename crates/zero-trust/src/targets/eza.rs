@@ -0,0 +1,186 @@
+//! eza: one `theme.yml` per variant, coloring file kinds and types,
+//! permissions, sizes, owners, dates and git status.
+//!
+//! File types are painted like the extension groups of the dircolors
+//! target, and sizes brighten from muted bytes to error-colored huge files.
+//! eza reads a single `theme.yml` from its config directory, so picking a
+//! variant means linking its file there.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Eza;
+
+/// A style: the key, the role of its foreground and whether it is bold.
+type Style = (&'static str, Role, bool);
+
+/// Sections of styles.
+const SECTIONS: &[(&str, &[Style])] = &[
+    (
+        "filekinds",
+        &[
+            ("normal", Role::Text, false),
+            ("directory", Role::Accent, true),
+            ("symlink", Role::Info, false),
+            ("pipe", Role::Warning, false),
+            ("block_device", Role::Warning, true),
+            ("char_device", Role::Warning, true),
+            ("socket", Role::Keyword, false),
+            ("special", Role::Keyword, false),
+            ("executable", Role::Success, true),
+            ("mount_point", Role::Accent, true),
+        ],
+    ),
+    (
+        "perms",
+        &[
+            ("user_read", Role::Warning, true),
+            ("user_write", Role::Error, true),
+            ("user_execute_file", Role::Success, true),
+            ("user_execute_other", Role::Success, true),
+            ("group_read", Role::Warning, false),
+            ("group_write", Role::Error, false),
+            ("group_execute", Role::Success, false),
+            ("other_read", Role::Warning, false),
+            ("other_write", Role::Error, false),
+            ("other_execute", Role::Success, false),
+            ("special_user_file", Role::Keyword, false),
+            ("special_other", Role::Keyword, false),
+            ("attribute", Role::TextMuted, false),
+        ],
+    ),
+    (
+        "size",
+        &[
+            ("major", Role::Constant, false),
+            ("minor", Role::Constant, false),
+            ("number_byte", Role::TextMuted, false),
+            ("number_kilo", Role::Text, false),
+            ("number_mega", Role::Info, false),
+            ("number_giga", Role::Warning, false),
+            ("number_huge", Role::Error, false),
+            ("unit_byte", Role::TextMuted, false),
+            ("unit_kilo", Role::Text, false),
+            ("unit_mega", Role::Info, false),
+            ("unit_giga", Role::Warning, false),
+            ("unit_huge", Role::Error, false),
+        ],
+    ),
+    (
+        "users",
+        &[
+            ("user_you", Role::Warning, true),
+            ("user_root", Role::Error, true),
+            ("user_other", Role::Text, false),
+            ("group_yours", Role::Warning, false),
+            ("group_root", Role::Error, false),
+            ("group_other", Role::TextMuted, false),
+        ],
+    ),
+    (
+        "links",
+        &[
+            ("normal", Role::Info, false),
+            ("multi_link_file", Role::Warning, false),
+        ],
+    ),
+    (
+        "git",
+        &[
+            ("new", Role::GitAdded, false),
+            ("modified", Role::GitModified, false),
+            ("deleted", Role::GitDeleted, false),
+            ("renamed", Role::Info, false),
+            ("typechange", Role::Keyword, false),
+            ("ignored", Role::TextDisabled, false),
+            ("conflicted", Role::Error, false),
+        ],
+    ),
+    (
+        "git_repo",
+        &[
+            ("branch_main", Role::Success, false),
+            ("branch_other", Role::Warning, false),
+            ("git_clean", Role::Success, false),
+            ("git_dirty", Role::GitModified, false),
+        ],
+    ),
+    (
+        "file_type",
+        &[
+            ("image", Role::Keyword, false),
+            ("video", Role::Constant, false),
+            ("music", Role::Constant, false),
+            ("lossless", Role::Constant, false),
+            ("crypto", Role::Success, false),
+            ("document", Role::String, false),
+            ("compressed", Role::Warning, false),
+            ("temp", Role::Comment, false),
+            ("compiled", Role::TextMuted, false),
+            ("build", Role::Function, true),
+            ("source", Role::Function, false),
+        ],
+    ),
+];
+
+/// Styles at the top level.
+const TOP: &[Style] = &[
+    ("punctuation", Role::TextDisabled, false),
+    ("date", Role::Info, false),
+    ("inode", Role::TextMuted, false),
+    ("blocks", Role::TextMuted, false),
+    ("header", Role::Text, true),
+    ("octal", Role::Keyword, false),
+    ("flags", Role::Constant, false),
+    ("symlink_path", Role::Info, false),
+    ("control_char", Role::Error, false),
+    ("broken_symlink", Role::Error, false),
+];
+
+fn style(theme: &Theme, role: Role, bold: bool) -> String {
+    format!(
+        "{{foreground: \"{}\", is_bold: {bold}}}",
+        theme.opaque_color(role)
+    )
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push_str("\ncolourful: true\n");
+    for &(section, styles) in SECTIONS {
+        let _ = writeln!(text, "\n{section}:");
+        for &(key, role, bold) in styles {
+            let _ = writeln!(text, "  {key}: {}", style(theme, role, bold));
+        }
+    }
+    text.push('\n');
+    for &(key, role, bold) in TOP {
+        let _ = writeln!(text, "{key}: {}", style(theme, role, bold));
+    }
+    text
+}
+
+impl Target for Eza {
+    fn name(&self) -> &str {
+        "eza"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "yml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,135 @@
+//! fish: one theme file per variant for `fish_config theme`, setting the
+//! command line's syntax colors, the prompt helpers' colors and the
+//! completion pager.
+//!
+//! Syntax colors follow the editor's roles as the zsh target does, and
+//! autosuggestions take the predictive color of inline completions. Themes
+//! are picked from `~/.config/fish/themes` with
+//! `fish_config theme choose zero-trust-dark`.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Fish;
+
+/// Color variables: the foreground role, the background role and flags.
+const COLORS: &[(&str, Option<Role>, Option<Role>, &str)] = &[
+    ("fish_color_normal", Some(Role::Text), None, ""),
+    ("fish_color_command", Some(Role::Function), None, ""),
+    ("fish_color_keyword", Some(Role::Keyword), None, ""),
+    ("fish_color_quote", Some(Role::String), None, ""),
+    ("fish_color_redirection", Some(Role::Operator), None, ""),
+    ("fish_color_end", Some(Role::PunctuationDelimiter), None, ""),
+    ("fish_color_error", Some(Role::Error), None, ""),
+    ("fish_color_param", Some(Role::Variable), None, ""),
+    ("fish_color_valid_path", None, None, "--underline"),
+    ("fish_color_option", Some(Role::Property), None, ""),
+    ("fish_color_comment", Some(Role::Comment), None, ""),
+    (
+        "fish_color_selection",
+        Some(Role::Text),
+        Some(Role::Selection),
+        "",
+    ),
+    ("fish_color_operator", Some(Role::Operator), None, ""),
+    ("fish_color_escape", Some(Role::StringEscape), None, ""),
+    (
+        "fish_color_autosuggestion",
+        Some(Role::Predictive),
+        None,
+        "",
+    ),
+    ("fish_color_cwd", Some(Role::Accent), None, ""),
+    ("fish_color_cwd_root", Some(Role::Error), None, ""),
+    ("fish_color_user", Some(Role::Success), None, ""),
+    ("fish_color_host", Some(Role::Text), None, ""),
+    ("fish_color_host_remote", Some(Role::Warning), None, ""),
+    ("fish_color_status", Some(Role::Error), None, ""),
+    ("fish_color_cancel", Some(Role::Error), None, "--reverse"),
+    ("fish_color_search_match", None, Some(Role::SearchMatch), ""),
+    (
+        "fish_color_history_current",
+        Some(Role::Accent),
+        None,
+        "--bold",
+    ),
+    (
+        "fish_pager_color_progress",
+        Some(Role::Background),
+        Some(Role::Accent),
+        "",
+    ),
+    (
+        "fish_pager_color_prefix",
+        Some(Role::Accent),
+        None,
+        "--bold",
+    ),
+    ("fish_pager_color_completion", Some(Role::Text), None, ""),
+    (
+        "fish_pager_color_description",
+        Some(Role::TextMuted),
+        None,
+        "",
+    ),
+    (
+        "fish_pager_color_selected_background",
+        None,
+        Some(Role::ElementSelected),
+        "",
+    ),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let hex = |role| theme.opaque_color(role).to_string()[1..].to_lowercase();
+    let mut text = format!(
+        "# name: '{}'\n# preferred_background: {}\n#\n",
+        source.meta.theme_name(theme.variant),
+        hex(Role::Background)
+    );
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    for &(variable, fg, bg, flags) in COLORS {
+        let mut value = Vec::new();
+        value.extend(fg.map(hex));
+        value.extend(bg.map(|role| format!("--background={}", hex(role))));
+        if !flags.is_empty() {
+            value.push(flags.to_owned());
+        }
+        let _ = writeln!(text, "{variable} {}", value.join(" "));
+    }
+    text
+}
+
+impl Target for Fish {
+    fn name(&self) -> &str {
+        "fish"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "theme"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("fish");
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
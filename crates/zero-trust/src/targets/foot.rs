@@ -0,0 +1,110 @@
+//! foot: one `[colors]` include per variant, pulled into `foot.ini` with
+//! `include=`.
+//!
+//! foot only blends the background, by the `alpha` setting, so that takes
+//! the terminal background's alpha and every other color is composited
+//! over its backdrop. foot writes colors as bare `RRGGBB`.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use super::{TERMINAL, TERMINAL_DIM};
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Foot;
+
+/// Settings of one color and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("foreground", Role::TerminalForeground),
+    ("selection-foreground", Role::TerminalForeground),
+    ("selection-background", Role::Selection),
+    ("urls", Role::Link),
+];
+
+/// Settings of a foreground and a background color, with their roles.
+const PAIRS: &[(&str, Role, Role)] = &[
+    ("cursor", Role::TerminalBackground, Role::Cursor),
+    ("jump-labels", Role::TerminalBackground, Role::Warning),
+    (
+        "scrollback-indicator",
+        Role::TerminalBackground,
+        Role::TextMuted,
+    ),
+    (
+        "search-box-no-match",
+        Role::TerminalForeground,
+        Role::ErrorBackground,
+    ),
+    (
+        "search-box-match",
+        Role::TerminalForeground,
+        Role::SearchMatch,
+    ),
+];
+
+/// `color` as foot's bare `RRGGBB`.
+fn hex(color: Color) -> String {
+    color.to_string()[1..7].to_owned()
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    let background = theme.color(Role::TerminalBackground);
+    let _ = writeln!(
+        text,
+        "\n[colors]\nalpha={:.2}\nbackground={}",
+        f64::from(background.a) / 255.0,
+        hex(background)
+    );
+    let color = |role| hex(theme.opaque_color(role));
+    for &(key, role) in COLORS {
+        let _ = writeln!(text, "{key}={}", color(role));
+    }
+    for &(key, fg, bg) in PAIRS {
+        let _ = writeln!(text, "{key}={} {}", color(fg), color(bg));
+    }
+    let palettes: [(&str, &[Role]); 3] = [
+        ("regular", &TERMINAL[..8]),
+        ("bright", &TERMINAL[8..]),
+        ("dim", &TERMINAL_DIM),
+    ];
+    for (prefix, roles) in palettes {
+        text.push('\n');
+        for (index, &role) in roles.iter().enumerate() {
+            let _ = writeln!(text, "{prefix}{index}={}", color(role));
+        }
+    }
+    text
+}
+
+impl Target for Foot {
+    fn name(&self) -> &str {
+        "foot"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "ini"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("foot");
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
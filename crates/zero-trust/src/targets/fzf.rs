@@ -0,0 +1,108 @@
+//! fzf: the `--color` option of each variant, in truecolor and for
+//! 256-color terminals, plus a shell snippet adding the right one to
+//! `FZF_DEFAULT_OPTS`.
+//!
+//! The `.opts` files hold nothing but the option, not even the generated
+//! header, so they also work as `FZF_DEFAULT_OPTS_FILE`. The snippet picks
+//! truecolor when `COLORTERM` says the terminal has it, and appends to any
+//! options already set rather than replacing them. Both are meant to be
+//! pulled in from the user's shell profile.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::Depth;
+use crate::{Error, Output, Source, Target};
+
+pub struct Fzf;
+
+/// Color names and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("fg", Role::Text),
+    ("bg", Role::Background),
+    ("hl", Role::Accent),
+    ("fg+", Role::Text),
+    ("bg+", Role::ElementSelected),
+    ("hl+", Role::Accent),
+    ("selected-bg", Role::ElementActive),
+    ("info", Role::TextMuted),
+    ("prompt", Role::Accent),
+    ("pointer", Role::Accent),
+    ("marker", Role::Success),
+    ("spinner", Role::Info),
+    ("header", Role::Comment),
+    ("query", Role::Text),
+    ("disabled", Role::TextDisabled),
+    ("border", Role::Border),
+    ("label", Role::TextMuted),
+    ("gutter", Role::Background),
+    ("scrollbar", Role::ScrollbarThumb),
+    ("separator", Role::Border),
+    ("preview-fg", Role::Text),
+    ("preview-bg", Role::Background),
+];
+
+/// The `--color` option of `theme`.
+fn option(theme: &Theme, depth: Depth) -> String {
+    let colors: Vec<String> = COLORS
+        .iter()
+        .map(|&(name, role)| format!("{name}:{}", depth.color(theme.opaque_color(role))))
+        .collect();
+    format!("--color={}", colors.join(","))
+}
+
+fn snippet(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push_str("\ncase \"${COLORTERM:-}\" in\n");
+    let _ = writeln!(
+        text,
+        "    truecolor | 24bit) zero_trust_fzf='{}' ;;",
+        option(theme, Depth::Truecolor)
+    );
+    let _ = writeln!(
+        text,
+        "    *) zero_trust_fzf='{}' ;;",
+        option(theme, Depth::Ansi256)
+    );
+    text.push_str(
+        "esac\n\
+         export FZF_DEFAULT_OPTS=\"${FZF_DEFAULT_OPTS:+$FZF_DEFAULT_OPTS }$zero_trust_fzf\"\n\
+         unset zero_trust_fzf\n",
+    );
+    text
+}
+
+impl Target for Fzf {
+    fn name(&self) -> &str {
+        "fzf"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name());
+        let mut outputs = Vec::new();
+        for &variant in variants {
+            let theme = source.theme(variant);
+            for depth in [Depth::Truecolor, Depth::Ansi256] {
+                outputs.push(Output::new(
+                    dir.join(format!(
+                        "{}{}.opts",
+                        source.meta.file_stem(variant),
+                        depth.suffix()
+                    )),
+                    format!("{}\n", option(&theme, depth)),
+                ));
+            }
+            outputs.push(Output::new(
+                super::extras_path(&source.meta, self.name(), variant, "sh"),
+                snippet(source, &theme),
+            ));
+        }
+        Ok(outputs)
+    }
+}
@@ -0,0 +1,77 @@
+//! Ghostty: one theme file per variant, plus `zero-trust` with the dark
+//! colors for `theme = zero-trust`.
+//!
+//! Ghostty names a theme after its file, which has no extension.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::TERMINAL;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Ghostty;
+
+/// Settings other than the palette and the roles that paint them.
+const SETTINGS: &[(&str, Role)] = &[
+    ("background", Role::TerminalBackground),
+    ("foreground", Role::TerminalForeground),
+    ("cursor-color", Role::Cursor),
+    ("cursor-text", Role::TerminalBackground),
+    ("selection-background", Role::Selection),
+    ("selection-foreground", Role::TerminalForeground),
+    ("split-divider-color", Role::Split),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    for (index, &role) in TERMINAL.iter().enumerate() {
+        let _ = writeln!(text, "palette = {index}={}", theme.opaque_color(role));
+    }
+    text.push('\n');
+    for &(key, role) in SETTINGS {
+        let _ = writeln!(text, "{key} = {}", theme.opaque_color(role));
+    }
+    text
+}
+
+impl Target for Ghostty {
+    fn name(&self) -> &str {
+        "ghostty"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name());
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    dir.join(source.meta.file_stem(variant)),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect();
+        if variants.contains(&Variant::Dark) {
+            outputs.push(Output::new(
+                dir.join(source.meta.stem()),
+                render(source, &source.theme(Variant::Dark)),
+            ));
+        }
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("ghostty");
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
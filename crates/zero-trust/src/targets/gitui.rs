@@ -0,0 +1,92 @@
+//! gitui: two themes per variant, one in truecolor and one for 256-color
+//! terminals, picked with `gitui --theme`.
+//!
+//! gitui reads colors as hex or as palette indices; the `-256` themes carry
+//! the quantizer's picks.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::Depth;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Gitui;
+
+/// Theme fields and the roles that paint them.
+const FIELDS: &[(&str, Role)] = &[
+    ("selected_tab", Role::Accent),
+    ("command_fg", Role::Text),
+    ("selection_bg", Role::ElementSelected),
+    ("selection_fg", Role::Text),
+    ("cmdbar_bg", Role::Statusline),
+    ("cmdbar_extra_lines_bg", Role::Panel),
+    ("disabled_fg", Role::TextDisabled),
+    ("diff_line_add", Role::GitAdded),
+    ("diff_line_delete", Role::GitDeleted),
+    ("diff_file_added", Role::GitAdded),
+    ("diff_file_removed", Role::GitDeleted),
+    ("diff_file_moved", Role::Info),
+    ("diff_file_modified", Role::GitModified),
+    ("commit_hash", Role::Warning),
+    ("commit_time", Role::Info),
+    ("commit_author", Role::Success),
+    ("danger_fg", Role::Error),
+    ("push_gauge_bg", Role::Accent),
+    ("push_gauge_fg", Role::Background),
+    ("tag_fg", Role::Constant),
+    ("branch_fg", Role::Type),
+];
+
+fn render(source: &Source, theme: &Theme, depth: Depth) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "// {line}");
+    }
+    text.push_str("(\n");
+    for &(field, role) in FIELDS {
+        let _ = writeln!(
+            text,
+            "    {field}: Some(\"{}\"),",
+            depth.color(theme.opaque_color(role))
+        );
+    }
+    text.push_str(")\n");
+    text
+}
+
+impl Target for Gitui {
+    fn name(&self) -> &str {
+        "gitui"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name());
+        let mut outputs = Vec::new();
+        for &variant in variants {
+            let theme = source.theme(variant);
+            for depth in [Depth::Truecolor, Depth::Ansi256] {
+                outputs.push(Output::new(
+                    dir.join(format!(
+                        "{}{}.ron",
+                        source.meta.file_stem(variant),
+                        depth.suffix()
+                    )),
+                    render(source, &theme, depth),
+                ));
+            }
+        }
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.app_data.join("gitui");
+        Some(InstallDir {
+            themes: app.clone(),
+            app,
+        })
+    }
+}
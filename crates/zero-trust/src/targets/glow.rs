@@ -0,0 +1,135 @@
+//! glow: one glamour style per variant, used for headings, emphasis, code,
+//! links, lists, tables and the Chroma code block highlighting.
+//!
+//! Headings take the markup title color, with the top level set as a
+//! banner on it, inline code takes the literal color on a raised surface,
+//! and code blocks take the syntax roles. Other tools rendering Markdown
+//! with glamour read the same file through `GLAMOUR_STYLE`. mdcat has no
+//! style settings: it colors its output with the terminal's ANSI colors, so
+//! it already follows the terminal targets, and there is no file for it.
+
+use serde_json::{json, Map, Value};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Glow;
+
+/// Chroma token types and the roles that paint them.
+const CHROMA: &[(&str, Role)] = &[
+    ("text", Role::Text),
+    ("error", Role::Error),
+    ("comment", Role::Comment),
+    ("comment_preproc", Role::Preproc),
+    ("keyword", Role::Keyword),
+    ("keyword_reserved", Role::Keyword),
+    ("keyword_namespace", Role::Namespace),
+    ("keyword_type", Role::Type),
+    ("operator", Role::Operator),
+    ("punctuation", Role::Punctuation),
+    ("name", Role::Variable),
+    ("name_builtin", Role::Function),
+    ("name_tag", Role::Tag),
+    ("name_attribute", Role::Attribute),
+    ("name_class", Role::Type),
+    ("name_constant", Role::Constant),
+    ("name_decorator", Role::Attribute),
+    ("name_exception", Role::Type),
+    ("name_function", Role::Function),
+    ("name_other", Role::Variable),
+    ("literal", Role::Constant),
+    ("literal_number", Role::Number),
+    ("literal_date", Role::Number),
+    ("literal_string", Role::String),
+    ("literal_string_escape", Role::StringEscape),
+    ("generic_deleted", Role::GitDeleted),
+    ("generic_inserted", Role::GitAdded),
+    ("generic_subheading", Role::Title),
+];
+
+fn render(theme: &Theme) -> Value {
+    let hex = |role| theme.opaque_color(role).to_string();
+    let mut chroma: Map<String, Value> = CHROMA
+        .iter()
+        .map(|&(token, role)| (token.to_owned(), json!({ "color": hex(role) })))
+        .collect();
+    chroma.insert("generic_emph".to_owned(), json!({ "italic": true }));
+    chroma.insert("generic_strong".to_owned(), json!({ "bold": true }));
+    chroma.insert(
+        "background".to_owned(),
+        json!({ "background_color": hex(Role::SurfaceElevated) }),
+    );
+    let heading = |prefix: &str| json!({ "prefix": prefix });
+    json!({
+        "document": {
+            "block_prefix": "\n",
+            "block_suffix": "\n",
+            "color": hex(Role::Text),
+            "margin": 2
+        },
+        "block_quote": { "indent": 1, "indent_token": "│ ", "color": hex(Role::TextMuted) },
+        "paragraph": {},
+        "list": { "level_indent": 2, "color": hex(Role::Text) },
+        "heading": { "block_suffix": "\n", "color": hex(Role::Title), "bold": true },
+        "h1": {
+            "prefix": " ",
+            "suffix": " ",
+            "color": hex(Role::Background),
+            "background_color": hex(Role::Title),
+            "bold": true
+        },
+        "h2": heading("## "),
+        "h3": heading("### "),
+        "h4": heading("#### "),
+        "h5": heading("##### "),
+        "h6": { "prefix": "###### ", "bold": false },
+        "text": {},
+        "strikethrough": { "crossed_out": true },
+        "emph": { "italic": true, "color": hex(Role::Emphasis) },
+        "strong": { "bold": true, "color": hex(Role::EmphasisStrong) },
+        "hr": { "color": hex(Role::Border), "format": "\n--------\n" },
+        "item": { "block_prefix": "• " },
+        "enumeration": { "block_prefix": ". ", "color": hex(Role::PunctuationListMarker) },
+        "task": { "ticked": "[✓] ", "unticked": "[ ] " },
+        "link": { "color": hex(Role::LinkUri), "underline": true },
+        "link_text": { "color": hex(Role::LinkText), "bold": true },
+        "image": { "color": hex(Role::LinkUri), "underline": true },
+        "image_text": { "color": hex(Role::LinkText), "format": "Image: {{.text}} →" },
+        "code": {
+            "prefix": " ",
+            "suffix": " ",
+            "color": hex(Role::TextLiteral),
+            "background_color": hex(Role::SurfaceElevated)
+        },
+        "code_block": {
+            "color": hex(Role::Text),
+            "margin": 2,
+            "chroma": chroma
+        },
+        "table": { "color": hex(Role::Text) },
+        "definition_list": {},
+        "definition_term": { "bold": true },
+        "definition_description": { "block_prefix": "\n→ " },
+        "html_block": { "color": hex(Role::TextMuted) },
+        "html_span": { "color": hex(Role::TextMuted) }
+    })
+}
+
+impl Target for Glow {
+    fn name(&self) -> &str {
+        "glow"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::json(
+                    super::extras_path(&source.meta, self.name(), variant, "json"),
+                    &render(&source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,98 @@
+//! GNOME Terminal: one shell script per variant that adds a profile with
+//! the theme's colors through `gsettings`.
+//!
+//! GNOME Terminal keeps profiles in dconf and has no theme file to install,
+//! so the script is the theme: running it again updates the same profile,
+//! which is keyed by a stable UUID.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::TERMINAL;
+use crate::{Error, Output, Source, Target};
+
+pub struct GnomeTerminal;
+
+/// Profile color keys and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("background-color", Role::TerminalBackground),
+    ("foreground-color", Role::TerminalForeground),
+    ("bold-color", Role::TerminalForegroundBright),
+    ("cursor-background-color", Role::Cursor),
+    ("cursor-foreground-color", Role::TerminalBackground),
+    ("highlight-background-color", Role::Selection),
+    ("highlight-foreground-color", Role::TerminalForeground),
+];
+
+/// Profile switches that make GNOME Terminal use the colors above.
+const SWITCHES: &[(&str, bool)] = &[
+    ("use-theme-colors", false),
+    ("bold-color-same-as-fg", false),
+    ("cursor-colors-set", true),
+    ("highlight-colors-set", true),
+];
+
+/// The schema and path of the profile with the UUID in `$uuid`.
+const PROFILE_PATH: &str =
+    "org.gnome.Terminal.Legacy.Profile:/org/gnome/terminal/legacy/profiles:/:$uuid/";
+
+/// Adds `$uuid` to the profile list unless it is there already.
+const REGISTER: &str = r#"list=$(gsettings get org.gnome.Terminal.ProfilesList list)
+case "$list" in
+    *"$uuid"*) ;;
+    "@as []") gsettings set org.gnome.Terminal.ProfilesList list "['$uuid']" ;;
+    *) gsettings set org.gnome.Terminal.ProfilesList list "${list%]}, '$uuid']" ;;
+esac
+"#;
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::from("#!/bin/sh\n");
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    let uuid = source.meta.uuid(theme.variant.slug()).to_lowercase();
+    let _ = writeln!(text, "\nset -eu\n\nuuid={uuid}\nprofile=\"{PROFILE_PATH}\"");
+    text.push_str(REGISTER);
+    let _ = writeln!(
+        text,
+        "\ngsettings set \"$profile\" visible-name \"'{}'\"",
+        source.meta.theme_name(theme.variant)
+    );
+    for &(key, value) in SWITCHES {
+        let _ = writeln!(text, "gsettings set \"$profile\" {key} {value}");
+    }
+    let hex = |role| theme.opaque_color(role);
+    for &(key, role) in COLORS {
+        let _ = writeln!(text, "gsettings set \"$profile\" {key} \"'{}'\"", hex(role));
+    }
+    let palette: Vec<String> = TERMINAL
+        .iter()
+        .map(|&role| format!("'{}'", hex(role)))
+        .collect();
+    let _ = writeln!(
+        text,
+        "gsettings set \"$profile\" palette \"[{}]\"",
+        palette.join(", ")
+    );
+    text
+}
+
+impl Target for GnomeTerminal {
+    fn name(&self) -> &str {
+        "gnometerminal"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "sh"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,255 @@
+//! GtkSourceView 4 and 5, for gedit, GNOME Text Editor and GNOME Builder:
+//! one style scheme per variant, with the editor styles and the `def:*`
+//! styles every language definition falls back to.
+//!
+//! Colors are opaque even for GtkSourceView 5, since GTK 3 cannot parse an
+//! alpha channel. Language styles are named after the items of each
+//! language definition rather than roles. Which directory the styles belong
+//! in depends on the application and its GtkSourceView version.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::package::escape_xml;
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct GtkSourceView;
+
+/// How a style is painted.
+#[derive(Clone, Copy)]
+struct Paint {
+    fg: Option<Role>,
+    bg: Option<Role>,
+    bold: bool,
+    /// A squiggly underline in this color, for spelling and diagnostics.
+    error: Option<Role>,
+}
+
+impl Paint {
+    const NONE: Paint = Paint {
+        fg: None,
+        bg: None,
+        bold: false,
+        error: None,
+    };
+
+    const fn fg(role: Role) -> Paint {
+        Paint {
+            fg: Some(role),
+            ..Paint::NONE
+        }
+    }
+
+    const fn bg(role: Role) -> Paint {
+        Paint {
+            bg: Some(role),
+            ..Paint::NONE
+        }
+    }
+
+    const fn fg_bg(fg: Role, bg: Role) -> Paint {
+        Paint {
+            fg: Some(fg),
+            bg: Some(bg),
+            ..Paint::NONE
+        }
+    }
+
+    const fn error(role: Role) -> Paint {
+        Paint {
+            error: Some(role),
+            ..Paint::NONE
+        }
+    }
+
+    const fn bold(self) -> Paint {
+        Paint { bold: true, ..self }
+    }
+}
+
+/// Editor styles and how they are painted.
+const EDITOR: &[(&str, Paint)] = &[
+    ("text", Paint::fg_bg(Role::Text, Role::Background)),
+    ("selection", Paint::bg(Role::Selection)),
+    ("selection-unfocused", Paint::bg(Role::ElementSelected)),
+    ("cursor", Paint::fg(Role::Cursor)),
+    ("secondary-cursor", Paint::fg(Role::TextMuted)),
+    ("current-line", Paint::bg(Role::Cursorline)),
+    ("line-numbers", Paint::fg_bg(Role::LineNumber, Role::Gutter)),
+    (
+        "current-line-number",
+        Paint::fg_bg(Role::LineNumberActive, Role::Cursorline).bold(),
+    ),
+    ("bracket-match", Paint::bg(Role::Highlight).bold()),
+    (
+        "bracket-mismatch",
+        Paint::fg_bg(Role::Error, Role::ErrorBackground),
+    ),
+    ("right-margin", Paint::fg_bg(Role::Guide, Role::Surface)),
+    ("draw-spaces", Paint::fg(Role::Invisible)),
+    ("background-pattern", Paint::bg(Role::Cursorline)),
+    ("search-match", Paint::bg(Role::SearchMatch)),
+    ("map-overlay", Paint::bg(Role::ScrollbarThumb)),
+    ("snippet-focus", Paint::bg(Role::Highlight)),
+    ("def:error", Paint::error(Role::Error)),
+    ("def:warning", Paint::error(Role::Warning)),
+    ("def:note", Paint::fg(Role::Info).bold()),
+    ("def:net-address", Paint::fg(Role::Link)),
+    ("def:underlined", Paint::fg(Role::Link)),
+    (
+        "def:insert",
+        Paint::fg_bg(Role::GitAdded, Role::SuccessBackground),
+    ),
+    (
+        "def:delete",
+        Paint::fg_bg(Role::GitDeleted, Role::ErrorBackground),
+    ),
+    ("def:addition", Paint::fg(Role::GitAdded)),
+    ("def:deletion", Paint::fg(Role::GitDeleted)),
+    ("diff:changed-line", Paint::fg(Role::GitModified)),
+];
+
+/// The `def:*` syntax styles and the roles that paint them. Bold and
+/// italics follow the role's style.
+const SYNTAX: &[(&str, Role)] = &[
+    ("def:comment", Role::Comment),
+    ("def:shebang", Role::Preproc),
+    ("def:doc-comment", Role::CommentDoc),
+    ("def:doc-comment-element", Role::CommentDoc),
+    ("def:constant", Role::Constant),
+    ("def:special-constant", Role::Constant),
+    ("def:character", Role::String),
+    ("def:string", Role::String),
+    ("def:special-char", Role::StringEscape),
+    ("def:number", Role::Number),
+    ("def:floating-point", Role::Number),
+    ("def:decimal", Role::Number),
+    ("def:base-n-integer", Role::Number),
+    ("def:boolean", Role::Boolean),
+    ("def:identifier", Role::Variable),
+    ("def:function", Role::Function),
+    ("def:builtin", Role::VariableSpecial),
+    ("def:statement", Role::Keyword),
+    ("def:keyword", Role::Keyword),
+    ("def:reserved", Role::Keyword),
+    ("def:operator", Role::Operator),
+    ("def:type", Role::Type),
+    ("def:preprocessor", Role::Preproc),
+    ("def:emphasis", Role::Emphasis),
+    ("def:strong-emphasis", Role::EmphasisStrong),
+    ("def:heading", Role::Title),
+    ("def:link-text", Role::LinkText),
+    ("def:link-destination", Role::LinkUri),
+    ("def:inline-code", Role::TextLiteral),
+    ("def:preformatted-section", Role::TextLiteral),
+    ("def:list-marker", Role::PunctuationListMarker),
+];
+
+/// The style scheme id of `variant`, also its file stem.
+fn scheme_id(meta: &Meta, variant: Variant) -> String {
+    meta.file_stem(variant)
+}
+
+fn style(text: &mut String, theme: &Theme, name: &str, paint: Paint, italic: bool) {
+    let hex = |role| theme.opaque_color(role);
+    let _ = write!(text, "  <style name=\"{name}\"");
+    if let Some(fg) = paint.fg {
+        let _ = write!(text, " foreground=\"{}\"", hex(fg));
+    }
+    if let Some(bg) = paint.bg {
+        let _ = write!(text, " background=\"{}\"", hex(bg));
+    }
+    if paint.bold {
+        text.push_str(" bold=\"true\"");
+    }
+    if italic {
+        text.push_str(" italic=\"true\"");
+    }
+    if let Some(error) = paint.error {
+        let _ = write!(
+            text,
+            " underline=\"error\" underline-color=\"{}\"",
+            hex(error)
+        );
+    }
+    text.push_str("/>\n");
+}
+
+fn render(source: &Source, theme: &Theme, counterpart: Option<Variant>) -> String {
+    let mut text = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!--\n");
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "  {}", escape_xml(&line));
+    }
+    let _ = writeln!(
+        text,
+        "-->\n<style-scheme id=\"{}\" name=\"{}\" version=\"1.0\">",
+        scheme_id(&source.meta, theme.variant),
+        escape_xml(&source.meta.theme_name(theme.variant))
+    );
+    let _ = writeln!(
+        text,
+        "  <author>{}</author>",
+        escape_xml(&source.meta.author)
+    );
+    let _ = writeln!(
+        text,
+        "  <description>{} color scheme.</description>",
+        escape_xml(&source.meta.theme_name(theme.variant))
+    );
+    // GtkSourceView 5 switches to the counterpart when the desktop's
+    // appearance changes; version 4 ignores the metadata.
+    let appearance = theme.appearance().name();
+    let _ = writeln!(
+        text,
+        "  <metadata>\n    <property name=\"variant\">{appearance}</property>"
+    );
+    if let Some(other) = counterpart {
+        let _ = writeln!(
+            text,
+            "    <property name=\"{}-variant\">{}</property>",
+            other.appearance().name(),
+            scheme_id(&source.meta, other)
+        );
+    }
+    text.push_str("  </metadata>\n\n");
+    for &(name, paint) in EDITOR {
+        style(&mut text, theme, name, paint, false);
+    }
+    text.push('\n');
+    for &(name, role) in SYNTAX {
+        let role_style = theme.style(role);
+        let paint = Paint {
+            bold: role_style.is_bold(),
+            ..Paint::fg(role)
+        };
+        style(&mut text, theme, name, paint, role_style.italic);
+    }
+    text.push_str("</style-scheme>\n");
+    text
+}
+
+impl Target for GtkSourceView {
+    fn name(&self) -> &str {
+        "gtksourceview"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let both = variants.contains(&Variant::Dark) && variants.contains(&Variant::Light);
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                let counterpart = match variant {
+                    Variant::Dark if both => Some(Variant::Light),
+                    Variant::Light if both => Some(Variant::Dark),
+                    _ => None,
+                };
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "xml"),
+                    render(source, &source.theme(variant), counterpart),
+                )
+            })
+            .collect())
+    }
+}
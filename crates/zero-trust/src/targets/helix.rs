@@ -0,0 +1,267 @@
+//! Helix: one theme file per variant, plus `zero_trust.toml` inheriting
+//! the dark variant so `theme = "zero_trust"` works as documented.
+//!
+//! Helix cannot scope highlights to one language.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::textmate;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Helix;
+
+/// How a Helix key is painted.
+#[derive(Clone, Copy)]
+pub(super) enum Paint {
+    Fg(Role),
+    Bg(Role),
+    FgBg(Role, Role),
+    /// A curly underline, for diagnostics.
+    Undercurl(Role),
+}
+
+/// Syntax scopes and the roles that paint them. Italics and bold follow
+/// the role's style. Kakoune looks its faces up here too.
+pub(super) const SYNTAX: &[(&str, Role)] = &[
+    ("attribute", Role::Attribute),
+    ("type", Role::Type),
+    ("type.builtin", Role::Type),
+    ("type.enum", Role::Enum),
+    ("type.enum.variant", Role::Variant),
+    ("constructor", Role::Constructor),
+    ("constant", Role::Constant),
+    ("constant.builtin.boolean", Role::Boolean),
+    ("constant.character.escape", Role::StringEscape),
+    ("constant.numeric", Role::Number),
+    ("string", Role::String),
+    ("string.regexp", Role::StringRegex),
+    ("string.special", Role::StringSpecial),
+    ("string.special.symbol", Role::StringSpecialSymbol),
+    ("string.special.url", Role::LinkUri),
+    ("comment", Role::Comment),
+    ("comment.line.documentation", Role::CommentDoc),
+    ("comment.block.documentation", Role::CommentDoc),
+    ("variable", Role::Variable),
+    ("variable.builtin", Role::VariableSpecial),
+    ("variable.parameter", Role::Variable),
+    ("variable.other.member", Role::Property),
+    ("label", Role::Label),
+    ("punctuation", Role::Punctuation),
+    ("punctuation.delimiter", Role::PunctuationDelimiter),
+    ("punctuation.bracket", Role::PunctuationBracket),
+    ("punctuation.special", Role::PunctuationSpecial),
+    ("keyword", Role::Keyword),
+    ("keyword.operator", Role::Operator),
+    ("keyword.directive", Role::Preproc),
+    ("operator", Role::Operator),
+    ("function", Role::Function),
+    ("function.macro", Role::Function),
+    ("tag", Role::Tag),
+    ("namespace", Role::Namespace),
+    ("special", Role::PunctuationSpecial),
+    ("markup.heading", Role::Title),
+    ("markup.list", Role::PunctuationListMarker),
+    ("markup.bold", Role::EmphasisStrong),
+    ("markup.italic", Role::Emphasis),
+    ("markup.link.url", Role::LinkUri),
+    ("markup.link.text", Role::LinkText),
+    ("markup.quote", Role::Comment),
+    ("markup.raw", Role::TextLiteral),
+    ("diff.plus", Role::GitAdded),
+    ("diff.minus", Role::GitDeleted),
+    ("diff.delta", Role::GitModified),
+];
+
+/// Editor interface and diagnostic keys, also looked up by Kakoune.
+pub(super) const UI: &[(&str, Paint)] = &[
+    ("ui.background", Paint::Bg(Role::Background)),
+    ("ui.text", Paint::Fg(Role::Text)),
+    (
+        "ui.text.focus",
+        Paint::FgBg(Role::Text, Role::ElementSelected),
+    ),
+    ("ui.text.inactive", Paint::Fg(Role::TextMuted)),
+    ("ui.text.directory", Paint::Fg(Role::Link)),
+    ("ui.cursor", Paint::FgBg(Role::Background, Role::Cursor)),
+    ("ui.cursor.match", Paint::Bg(Role::Highlight)),
+    ("ui.selection", Paint::Bg(Role::Selection)),
+    ("ui.cursorline.primary", Paint::Bg(Role::Cursorline)),
+    ("ui.linenr", Paint::Fg(Role::LineNumber)),
+    ("ui.linenr.selected", Paint::Fg(Role::LineNumberActive)),
+    ("ui.gutter", Paint::Bg(Role::Gutter)),
+    (
+        "ui.statusline",
+        Paint::FgBg(Role::TextMuted, Role::Statusline),
+    ),
+    (
+        "ui.statusline.inactive",
+        Paint::FgBg(Role::TextPlaceholder, Role::Statusline),
+    ),
+    (
+        "ui.statusline.normal",
+        Paint::FgBg(Role::Background, Role::Accent),
+    ),
+    (
+        "ui.statusline.insert",
+        Paint::FgBg(Role::Background, Role::GitAdded),
+    ),
+    (
+        "ui.statusline.select",
+        Paint::FgBg(Role::Background, Role::Predictive),
+    ),
+    ("ui.bufferline", Paint::FgBg(Role::TextMuted, Role::Tabline)),
+    (
+        "ui.bufferline.active",
+        Paint::FgBg(Role::Text, Role::TabActive),
+    ),
+    ("ui.bufferline.background", Paint::Bg(Role::Tabline)),
+    ("ui.popup", Paint::FgBg(Role::Text, Role::SurfaceElevated)),
+    ("ui.window", Paint::Fg(Role::Split)),
+    ("ui.help", Paint::FgBg(Role::Text, Role::SurfaceElevated)),
+    ("ui.menu", Paint::FgBg(Role::Text, Role::SurfaceElevated)),
+    ("ui.menu.selected", Paint::Bg(Role::ElementSelected)),
+    (
+        "ui.menu.scroll",
+        Paint::FgBg(Role::ScrollbarThumb, Role::ScrollbarTrack),
+    ),
+    ("ui.highlight", Paint::Bg(Role::Highlight)),
+    ("ui.virtual.whitespace", Paint::Fg(Role::Invisible)),
+    ("ui.virtual.indent-guide", Paint::Fg(Role::Guide)),
+    ("ui.virtual.ruler", Paint::Bg(Role::Cursorline)),
+    (
+        "ui.virtual.inlay-hint",
+        Paint::FgBg(Role::Hint, Role::HintBackground),
+    ),
+    ("ui.virtual.jump-label", Paint::Fg(Role::Accent)),
+    ("error", Paint::Fg(Role::Error)),
+    ("warning", Paint::Fg(Role::Warning)),
+    ("info", Paint::Fg(Role::Info)),
+    ("hint", Paint::Fg(Role::Hint)),
+    ("diagnostic.error", Paint::Undercurl(Role::Error)),
+    ("diagnostic.warning", Paint::Undercurl(Role::Warning)),
+    ("diagnostic.info", Paint::Undercurl(Role::Info)),
+    ("diagnostic.hint", Paint::Undercurl(Role::Hint)),
+    ("diagnostic.unnecessary", Paint::Fg(Role::TextMuted)),
+];
+
+/// Helix's 16 named terminal colors, for themes and plugins that refer to
+/// them by name.
+const PALETTE: &[(&str, Role)] = &[
+    ("black", Role::TerminalBlack),
+    ("red", Role::TerminalRed),
+    ("green", Role::TerminalGreen),
+    ("yellow", Role::TerminalYellow),
+    ("blue", Role::TerminalBlue),
+    ("magenta", Role::TerminalMagenta),
+    ("cyan", Role::TerminalCyan),
+    ("gray", Role::TerminalBrightBlack),
+    ("light-red", Role::TerminalBrightRed),
+    ("light-green", Role::TerminalBrightGreen),
+    ("light-yellow", Role::TerminalBrightYellow),
+    ("light-blue", Role::TerminalBrightBlue),
+    ("light-magenta", Role::TerminalBrightMagenta),
+    ("light-cyan", Role::TerminalBrightCyan),
+    ("light-gray", Role::TerminalWhite),
+    ("white", Role::TerminalBrightWhite),
+];
+
+/// The Helix theme name of `variant`, also its file stem.
+fn theme_name(meta: &Meta, variant: Variant) -> String {
+    meta.file_stem(variant).replace('-', "_")
+}
+
+fn paint(theme: &Theme, paint: Paint) -> String {
+    let hex = |role| theme.opaque_color(role);
+    match paint {
+        Paint::Fg(fg) => format!("{{ fg = \"{}\" }}", hex(fg)),
+        Paint::Bg(bg) => format!("{{ bg = \"{}\" }}", hex(bg)),
+        Paint::FgBg(fg, bg) => format!("{{ fg = \"{}\", bg = \"{}\" }}", hex(fg), hex(bg)),
+        Paint::Undercurl(color) => format!(
+            "{{ underline = {{ color = \"{}\", style = \"curl\" }} }}",
+            hex(color)
+        ),
+    }
+}
+
+fn syntax(theme: &Theme, role: Role) -> String {
+    let modifiers: Vec<String> = textmate::font_style(theme.style(role))
+        .split_whitespace()
+        .map(|modifier| format!("\"{modifier}\""))
+        .collect();
+    if modifiers.is_empty() {
+        format!("{{ fg = \"{}\" }}", theme.opaque_color(role))
+    } else {
+        format!(
+            "{{ fg = \"{}\", modifiers = [{}] }}",
+            theme.opaque_color(role),
+            modifiers.join(", ")
+        )
+    }
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    for &(key, role) in SYNTAX {
+        let _ = writeln!(text, "\"{key}\" = {}", syntax(theme, role));
+    }
+    text.push('\n');
+    for &(key, p) in UI {
+        let _ = writeln!(text, "\"{key}\" = {}", paint(theme, p));
+    }
+    text.push_str("\n[palette]\n");
+    for &(name, role) in PALETTE {
+        let _ = writeln!(text, "{name} = \"{}\"", theme.opaque_color(role));
+    }
+    text
+}
+
+impl Target for Helix {
+    fn name(&self) -> &str {
+        "helix"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name());
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                let theme = source.theme(variant);
+                Output::new(
+                    dir.join(format!("{}.toml", theme_name(&source.meta, variant))),
+                    render(source, &theme),
+                )
+            })
+            .collect();
+        if variants.contains(&Variant::Dark) {
+            outputs.push(Output::new(
+                dir.join(format!("{}.toml", source.meta.stem().replace('-', "_"))),
+                format!(
+                    "inherits = \"{}\"\n",
+                    theme_name(&source.meta, Variant::Dark)
+                ),
+            ));
+        }
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = if cfg!(windows) {
+            dirs.app_data.join("helix")
+        } else {
+            dirs.config.join("helix")
+        };
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
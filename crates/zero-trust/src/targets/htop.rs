@@ -0,0 +1,54 @@
+//! htop: one `htoprc` fragment per variant selecting the built-in color
+//! scheme that suits it.
+//!
+//! htop has no custom colors: its schemes are compiled in and paint with
+//! the eight ANSI colors and bold, so on a terminal running one of the
+//! terminal targets they already draw from the palette. What is left to
+//! choose is the scheme whose foreground and background pairs read on the
+//! variant's backdrop. htop rewrites `htoprc` whenever it exits, so the
+//! line is merged in by hand.
+
+use std::fmt::Write;
+
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Htop;
+
+/// The `color_scheme` value and name of the built-in scheme for `variant`.
+fn scheme(variant: Variant) -> (u8, &'static str) {
+    match variant {
+        Variant::Dark | Variant::Dimmed => (0, "Default"),
+        Variant::Light => (3, "Light Terminal"),
+        Variant::HighContrast | Variant::Oled => (5, "Black Night"),
+    }
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    let (number, name) = scheme(theme.variant);
+    let _ = writeln!(text, "\n# {name}\ncolor_scheme={number}");
+    text
+}
+
+impl Target for Htop {
+    fn name(&self) -> &str {
+        "htop"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "htoprc"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
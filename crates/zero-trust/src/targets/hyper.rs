@@ -0,0 +1,127 @@
+//! Hyper: a local plugin whose `decorateConfig` sets the terminal colors of
+//! one variant, the dark one unless `zeroTrust.variant` in `.hyper.js`
+//! names another.
+//!
+//! Hyper draws the selection with CSS, so that keeps its alpha.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Map, Value};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::TERMINAL;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Hyper;
+
+/// The plugin's package name, also its directory below `local/`.
+fn plugin(meta: &Meta) -> String {
+    format!("hyper-{}", meta.stem())
+}
+
+/// Config keys other than `colors` and the roles that paint them.
+const CONFIG: &[(&str, Role)] = &[
+    ("foregroundColor", Role::TerminalForeground),
+    ("backgroundColor", Role::TerminalBackground),
+    ("borderColor", Role::Border),
+    ("cursorColor", Role::Cursor),
+    ("cursorAccentColor", Role::TerminalBackground),
+];
+
+/// The keys of Hyper's `colors`, in `TERMINAL` order.
+const COLORS: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "lightBlack",
+    "lightRed",
+    "lightGreen",
+    "lightYellow",
+    "lightBlue",
+    "lightMagenta",
+    "lightCyan",
+    "lightWhite",
+];
+
+/// The `decorateConfig` source, with the variants' config in `themes`.
+const DECORATE: &str = r#"
+exports.decorateConfig = (config) => {
+  const variant = (config.zeroTrust && config.zeroTrust.variant) || "dark";
+  const theme = themes[variant] || themes[Object.keys(themes)[0]];
+  return Object.assign({}, config, theme, {
+    colors: Object.assign({}, config.colors, theme.colors),
+  });
+};
+"#;
+
+fn config(theme: &Theme) -> Value {
+    let hex = |role| Value::from(theme.opaque_color(role).to_string());
+    let mut config: Map<String, Value> = CONFIG
+        .iter()
+        .map(|&(key, role)| (key.to_owned(), hex(role)))
+        .collect();
+    config.insert(
+        "selectionColor".into(),
+        theme.color(Role::Selection).to_string().into(),
+    );
+    let colors: Map<String, Value> = COLORS
+        .into_iter()
+        .zip(TERMINAL)
+        .map(|(key, role)| (key.to_owned(), hex(role)))
+        .collect();
+    config.insert("colors".into(), colors.into());
+    config.into()
+}
+
+impl Target for Hyper {
+    fn name(&self) -> &str {
+        "hyper"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let meta = &source.meta;
+        let dir: PathBuf = Path::new("extras").join(self.name()).join(plugin(meta));
+        let themes: Map<String, Value> = variants
+            .iter()
+            .map(|&variant| (variant.slug().to_owned(), config(&source.theme(variant))))
+            .collect();
+        let themes = serde_json::to_string_pretty(&themes).expect("JSON values always serialize");
+        let mut index = String::from("/*\n");
+        for line in meta.header(&meta.name) {
+            let _ = writeln!(index, " * {line}");
+        }
+        let _ = write!(index, " */\n\nconst themes = {themes};\n{DECORATE}");
+        Ok(vec![
+            Output::new(dir.join("index.js"), index),
+            Output::json(
+                dir.join("package.json"),
+                &json!({
+                    "name": plugin(meta),
+                    "version": meta.version,
+                    "description": format!("{} for Hyper.", meta.name),
+                    "main": "index.js",
+                    "keywords": ["hyper", "hyper-theme"],
+                    "author": meta.author,
+                    "license": meta.license,
+                    "homepage": meta.homepage,
+                }),
+            ),
+        ])
+    }
+
+    fn install_dir(&self, dirs: &Dirs, meta: &Meta) -> Option<InstallDir> {
+        let app = dirs.home.join(".hyper_plugins");
+        Some(InstallDir {
+            themes: app.join("local").join(plugin(meta)),
+            app,
+        })
+    }
+}
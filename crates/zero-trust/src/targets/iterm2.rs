@@ -0,0 +1,112 @@
+//! iTerm2: one `.itermcolors` color preset per variant.
+//!
+//! iTerm2 names an imported preset after its file, so the files are named
+//! after the themes. Colors are dictionaries of `0`–`1` components in the
+//! sRGB color space. The cursor guide and badge keep their alpha, which
+//! iTerm2 blends itself.
+//! Presets are imported from iTerm2's settings or by opening the file.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use super::TERMINAL;
+use crate::package::escape_xml;
+use crate::{Error, Output, Source, Target};
+
+pub struct ITerm2;
+
+/// Colors other than the ANSI ones and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("Background Color", Role::TerminalBackground),
+    ("Foreground Color", Role::TerminalForeground),
+    ("Bold Color", Role::TerminalForegroundBright),
+    ("Cursor Color", Role::Cursor),
+    ("Cursor Text Color", Role::TerminalBackground),
+    ("Selection Color", Role::Selection),
+    ("Selected Text Color", Role::TerminalForeground),
+    ("Link Color", Role::Link),
+    ("Underline Color", Role::Accent),
+    ("Tab Color", Role::Tabline),
+];
+
+/// Colors iTerm2 draws translucent over the terminal.
+const BLENDED: &[(&str, Role)] = &[
+    ("Cursor Guide Color", Role::Cursorline),
+    ("Badge Color", Role::TextMuted),
+];
+
+/// The component keys of a color, in the order iTerm2 writes them, with
+/// their index into `float_rgba`'s fractions.
+const COMPONENTS: [(&str, usize); 4] = [
+    ("Alpha Component", 3),
+    ("Blue Component", 2),
+    ("Green Component", 1),
+    ("Red Component", 0),
+];
+
+fn color(text: &mut String, key: &str, color: Color) {
+    let fractions = color.float_rgba().to_string();
+    let fractions: Vec<&str> = fractions.split(' ').collect();
+    let _ = writeln!(text, " <key>{key}</key>\n <dict>");
+    for (component, index) in COMPONENTS {
+        let _ = writeln!(
+            text,
+            "  <key>{component}</key>\n  <real>{}</real>",
+            fractions[index]
+        );
+    }
+    text.push_str("  <key>Color Space</key>\n  <string>sRGB</string>\n </dict>\n");
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+         \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n<!--\n",
+    );
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "  {}", escape_xml(&line));
+    }
+    text.push_str("-->\n<plist version=\"1.0\">\n<dict>\n");
+    for (index, &role) in TERMINAL.iter().enumerate() {
+        color(
+            &mut text,
+            &format!("Ansi {index} Color"),
+            theme.opaque_color(role),
+        );
+    }
+    for &(key, role) in COLORS {
+        color(&mut text, key, theme.opaque_color(role));
+    }
+    for &(key, role) in BLENDED {
+        color(&mut text, key, theme.color(role));
+    }
+    text.push_str("</dict>\n</plist>\n");
+    text
+}
+
+impl Target for ITerm2 {
+    fn name(&self) -> &str {
+        "iterm2"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name());
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                let theme = source.theme(variant);
+                Output::new(
+                    dir.join(format!(
+                        "{}.itermcolors",
+                        source.meta.theme_name(theme.variant)
+                    )),
+                    render(source, &theme),
+                )
+            })
+            .collect())
+    }
+}
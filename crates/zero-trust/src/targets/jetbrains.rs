@@ -0,0 +1,513 @@
+//! JetBrains IDEs: one `.icls` editor color scheme per variant, a
+//! `.theme.json` UI theme pointing at it, and the `META-INF/plugin.xml`
+//! descriptor that turns `extras/jetbrains/` into a theme plugin.
+//!
+//! Attribute keys are per language and rarely line up with the syntax roles
+//! one to one.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use serde_json::{json, Map, Value};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Appearance, Color, Theme, Variant};
+
+use crate::package::escape_xml;
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct JetBrains;
+
+/// The `.icls` format version the IDEs read since 2014.
+const SCHEME_VERSION: u32 = 142;
+
+/// The oldest IDE build the plugin claims to support.
+const SINCE_BUILD: &str = "222";
+
+/// Editor color keys and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("CARET_COLOR", Role::Cursor),
+    ("CARET_ROW_COLOR", Role::Cursorline),
+    ("SELECTION_BACKGROUND", Role::Selection),
+    ("LINE_NUMBERS_COLOR", Role::LineNumber),
+    ("LINE_NUMBER_ON_CARET_ROW_COLOR", Role::LineNumberActive),
+    ("GUTTER_BACKGROUND", Role::Gutter),
+    ("INDENT_GUIDE", Role::Guide),
+    ("SELECTED_INDENT_GUIDE", Role::GuideActive),
+    ("VISUAL_INDENT_GUIDE", Role::Guide),
+    ("RIGHT_MARGIN_COLOR", Role::Guide),
+    ("WHITESPACES", Role::Invisible),
+    ("SOFT_WRAP_SIGN_COLOR", Role::Invisible),
+    ("METHOD_SEPARATORS_COLOR", Role::Border),
+    ("TEARLINE_COLOR", Role::Border),
+    ("SELECTED_TEARLINE_COLOR", Role::BorderFocused),
+    ("FOLDED_TEXT_BORDER_COLOR", Role::Border),
+    ("DOCUMENTATION_COLOR", Role::SurfaceElevated),
+    ("LOOKUP_COLOR", Role::SurfaceElevated),
+    ("NOTIFICATION_BACKGROUND", Role::SurfaceElevated),
+    ("CONSOLE_BACKGROUND_KEY", Role::TerminalBackground),
+    ("ADDED_LINES_COLOR", Role::GitAdded),
+    ("MODIFIED_LINES_COLOR", Role::GitModified),
+    ("DELETED_LINES_COLOR", Role::GitDeleted),
+    ("WHITESPACES_MODIFIED_LINES_COLOR", Role::GitModified),
+    ("FILESTATUS_ADDED", Role::GitAdded),
+    ("FILESTATUS_MODIFIED", Role::GitModified),
+    ("FILESTATUS_DELETED", Role::GitDeleted),
+    ("FILESTATUS_IDEA_FILESTATUS_IGNORED", Role::Ignored),
+];
+
+/// Syntax attribute keys and the roles that paint them. Bold and italics
+/// follow the role's style.
+const SYNTAX: &[(&str, Role)] = &[
+    ("DEFAULT_KEYWORD", Role::Keyword),
+    ("DEFAULT_IDENTIFIER", Role::Variable),
+    ("DEFAULT_NUMBER", Role::Number),
+    ("DEFAULT_STRING", Role::String),
+    ("DEFAULT_VALID_STRING_ESCAPE", Role::StringEscape),
+    ("DEFAULT_LINE_COMMENT", Role::Comment),
+    ("DEFAULT_BLOCK_COMMENT", Role::Comment),
+    ("DEFAULT_DOC_COMMENT", Role::CommentDoc),
+    ("DEFAULT_DOC_COMMENT_TAG", Role::CommentDoc),
+    ("DEFAULT_DOC_MARKUP", Role::CommentDoc),
+    ("DEFAULT_SEMICOLON", Role::PunctuationDelimiter),
+    ("DEFAULT_COMMA", Role::PunctuationDelimiter),
+    ("DEFAULT_DOT", Role::PunctuationDelimiter),
+    ("DEFAULT_PARENTHS", Role::PunctuationBracket),
+    ("DEFAULT_BRACKETS", Role::PunctuationBracket),
+    ("DEFAULT_BRACES", Role::PunctuationBracket),
+    ("DEFAULT_OPERATION_SIGN", Role::Operator),
+    ("DEFAULT_CONSTANT", Role::Constant),
+    ("DEFAULT_FUNCTION_DECLARATION", Role::Function),
+    ("DEFAULT_FUNCTION_CALL", Role::Function),
+    ("DEFAULT_STATIC_METHOD", Role::Function),
+    ("DEFAULT_INSTANCE_METHOD", Role::Function),
+    ("DEFAULT_CLASS_NAME", Role::Type),
+    ("DEFAULT_CLASS_REFERENCE", Role::Type),
+    ("DEFAULT_INTERFACE_NAME", Role::Type),
+    ("DEFAULT_INSTANCE_FIELD", Role::Property),
+    ("DEFAULT_STATIC_FIELD", Role::Property),
+    ("DEFAULT_LOCAL_VARIABLE", Role::Variable),
+    ("DEFAULT_GLOBAL_VARIABLE", Role::Variable),
+    ("DEFAULT_PARAMETER", Role::Variable),
+    ("DEFAULT_REASSIGNED_LOCAL_VARIABLE", Role::Variable),
+    ("DEFAULT_PREDEFINED_SYMBOL", Role::VariableSpecial),
+    ("DEFAULT_METADATA", Role::Attribute),
+    ("DEFAULT_LABEL", Role::Label),
+    ("DEFAULT_MARKUP_TAG", Role::Tag),
+    ("DEFAULT_MARKUP_ATTRIBUTE", Role::Attribute),
+    ("DEFAULT_MARKUP_ENTITY", Role::StringEscape),
+    ("ENUM_CONST", Role::Variant),
+    ("ANNOTATION_NAME_ATTRIBUTES", Role::Attribute),
+    ("TYPE_PARAMETER_NAME_ATTRIBUTES", Role::Type),
+    ("org.rust.ENUM", Role::Enum),
+    ("org.rust.ENUM_VARIANT", Role::Variant),
+    ("org.rust.LIFETIME", Role::Label),
+    ("org.rust.MACRO", Role::Function),
+    ("org.rust.MODULE", Role::Namespace),
+    ("org.rust.CRATE", Role::Namespace),
+    ("org.rust.SELF_PARAMETER", Role::VariableSpecial),
+    ("org.rust.ATTRIBUTE", Role::Attribute),
+    ("MARKDOWN_HEADER_LEVEL_1", Role::Title),
+    ("MARKDOWN_HEADER_LEVEL_2", Role::Title),
+    ("MARKDOWN_HEADER_LEVEL_3", Role::Title),
+    ("MARKDOWN_BOLD", Role::EmphasisStrong),
+    ("MARKDOWN_ITALIC", Role::Emphasis),
+    ("MARKDOWN_LINK_TEXT", Role::LinkText),
+    ("MARKDOWN_LINK_DESTINATION", Role::LinkUri),
+    ("MARKDOWN_CODE_SPAN", Role::TextLiteral),
+    ("MARKDOWN_LIST_ITEM", Role::PunctuationListMarker),
+];
+
+/// A text decoration, by its `EFFECT_TYPE` number.
+#[derive(Clone, Copy)]
+enum Effect {
+    Underline = 1,
+    Wave = 2,
+    Strikeout = 3,
+}
+
+/// How an editor attribute key is painted.
+#[derive(Clone, Copy)]
+struct Paint {
+    fg: Option<Role>,
+    bg: Option<Role>,
+    effect: Option<(Effect, Role)>,
+    /// The mark in the scrollbar next to the editor.
+    stripe: Option<Role>,
+}
+
+impl Paint {
+    const NONE: Paint = Paint {
+        fg: None,
+        bg: None,
+        effect: None,
+        stripe: None,
+    };
+
+    const fn fg(role: Role) -> Paint {
+        Paint {
+            fg: Some(role),
+            ..Paint::NONE
+        }
+    }
+
+    const fn bg(role: Role) -> Paint {
+        Paint {
+            bg: Some(role),
+            ..Paint::NONE
+        }
+    }
+
+    const fn fg_bg(fg: Role, bg: Role) -> Paint {
+        Paint {
+            fg: Some(fg),
+            bg: Some(bg),
+            ..Paint::NONE
+        }
+    }
+
+    /// A wavy underline with a matching scrollbar mark, for diagnostics.
+    const fn wave(role: Role) -> Paint {
+        Paint {
+            effect: Some((Effect::Wave, role)),
+            stripe: Some(role),
+            ..Paint::NONE
+        }
+    }
+
+    const fn effect(self, effect: Effect, role: Role) -> Paint {
+        Paint {
+            effect: Some((effect, role)),
+            ..self
+        }
+    }
+
+    const fn stripe(self, role: Role) -> Paint {
+        Paint {
+            stripe: Some(role),
+            ..self
+        }
+    }
+}
+
+/// Editor interface and diagnostic attribute keys.
+const UI: &[(&str, Paint)] = &[
+    ("TEXT", Paint::fg_bg(Role::Text, Role::Background)),
+    (
+        "FOLDED_TEXT_ATTRIBUTES",
+        Paint::fg_bg(Role::TextMuted, Role::Cursorline),
+    ),
+    (
+        "SEARCH_RESULT_ATTRIBUTES",
+        Paint::bg(Role::SearchMatch).stripe(Role::SearchMatch),
+    ),
+    (
+        "TEXT_SEARCH_RESULT_ATTRIBUTES",
+        Paint::bg(Role::SearchMatch).stripe(Role::SearchMatch),
+    ),
+    ("WRITE_SEARCH_RESULT_ATTRIBUTES", Paint::bg(Role::Highlight)),
+    (
+        "IDENTIFIER_UNDER_CARET_ATTRIBUTES",
+        Paint::bg(Role::Highlight),
+    ),
+    (
+        "WRITE_IDENTIFIER_UNDER_CARET_ATTRIBUTES",
+        Paint::bg(Role::Highlight),
+    ),
+    ("MATCHED_BRACE_ATTRIBUTES", Paint::bg(Role::Highlight)),
+    (
+        "UNMATCHED_BRACE_ATTRIBUTES",
+        Paint::bg(Role::ErrorBackground),
+    ),
+    ("ERRORS_ATTRIBUTES", Paint::wave(Role::Error)),
+    ("WRONG_REFERENCES_ATTRIBUTES", Paint::fg(Role::Error)),
+    ("WARNING_ATTRIBUTES", Paint::wave(Role::Warning)),
+    ("WEAK_WARNING_ATTRIBUTES", Paint::wave(Role::Hint)),
+    ("INFO_ATTRIBUTES", Paint::wave(Role::Info)),
+    ("TYPO", Paint::wave(Role::Hint)),
+    ("DEFAULT_INVALID_STRING_ESCAPE", Paint::wave(Role::Error)),
+    ("NOT_USED_ELEMENT_ATTRIBUTES", Paint::fg(Role::TextMuted)),
+    (
+        "DEPRECATED_ATTRIBUTES",
+        Paint::NONE.effect(Effect::Strikeout, Role::TextMuted),
+    ),
+    (
+        "MARKED_FOR_REMOVAL_ATTRIBUTES",
+        Paint::NONE.effect(Effect::Strikeout, Role::Error),
+    ),
+    (
+        "HYPERLINK_ATTRIBUTES",
+        Paint::fg(Role::Link).effect(Effect::Underline, Role::Link),
+    ),
+    (
+        "FOLLOWED_HYPERLINK_ATTRIBUTES",
+        Paint::fg(Role::Link).effect(Effect::Underline, Role::Link),
+    ),
+    (
+        "INLAY_DEFAULT",
+        Paint::fg_bg(Role::Hint, Role::HintBackground),
+    ),
+    (
+        "INLINE_PARAMETER_HINT",
+        Paint::fg_bg(Role::Hint, Role::HintBackground),
+    ),
+    (
+        "DIFF_INSERTED",
+        Paint::bg(Role::SuccessBackground).stripe(Role::GitAdded),
+    ),
+    (
+        "DIFF_MODIFIED",
+        Paint::bg(Role::InfoBackground).stripe(Role::GitModified),
+    ),
+    (
+        "DIFF_DELETED",
+        Paint::bg(Role::ErrorBackground).stripe(Role::GitDeleted),
+    ),
+    (
+        "DIFF_CONFLICT",
+        Paint::bg(Role::WarningBackground).stripe(Role::Warning),
+    ),
+    ("CONSOLE_NORMAL_OUTPUT", Paint::fg(Role::TerminalForeground)),
+    ("CONSOLE_ERROR_OUTPUT", Paint::fg(Role::Error)),
+    ("CONSOLE_SYSTEM_OUTPUT", Paint::fg(Role::TextMuted)),
+    ("CONSOLE_USER_INPUT", Paint::fg(Role::TerminalForeground)),
+    ("CONSOLE_BLACK_OUTPUT", Paint::fg(Role::TerminalBlack)),
+    ("CONSOLE_RED_OUTPUT", Paint::fg(Role::TerminalRed)),
+    ("CONSOLE_GREEN_OUTPUT", Paint::fg(Role::TerminalGreen)),
+    ("CONSOLE_YELLOW_OUTPUT", Paint::fg(Role::TerminalYellow)),
+    ("CONSOLE_BLUE_OUTPUT", Paint::fg(Role::TerminalBlue)),
+    ("CONSOLE_MAGENTA_OUTPUT", Paint::fg(Role::TerminalMagenta)),
+    ("CONSOLE_CYAN_OUTPUT", Paint::fg(Role::TerminalCyan)),
+    ("CONSOLE_GRAY_OUTPUT", Paint::fg(Role::TerminalWhite)),
+    (
+        "CONSOLE_DARKGRAY_OUTPUT",
+        Paint::fg(Role::TerminalBrightBlack),
+    ),
+    (
+        "CONSOLE_RED_BRIGHT_OUTPUT",
+        Paint::fg(Role::TerminalBrightRed),
+    ),
+    (
+        "CONSOLE_GREEN_BRIGHT_OUTPUT",
+        Paint::fg(Role::TerminalBrightGreen),
+    ),
+    (
+        "CONSOLE_YELLOW_BRIGHT_OUTPUT",
+        Paint::fg(Role::TerminalBrightYellow),
+    ),
+    (
+        "CONSOLE_BLUE_BRIGHT_OUTPUT",
+        Paint::fg(Role::TerminalBrightBlue),
+    ),
+    (
+        "CONSOLE_MAGENTA_BRIGHT_OUTPUT",
+        Paint::fg(Role::TerminalBrightMagenta),
+    ),
+    (
+        "CONSOLE_CYAN_BRIGHT_OUTPUT",
+        Paint::fg(Role::TerminalBrightCyan),
+    ),
+    ("CONSOLE_WHITE_OUTPUT", Paint::fg(Role::TerminalBrightWhite)),
+];
+
+/// UI theme keys and the roles that paint them. `*` sets the defaults
+/// every component inherits.
+const UI_THEME: &[(&str, &str, Role)] = &[
+    ("*", "background", Role::Surface),
+    ("*", "foreground", Role::Text),
+    ("*", "infoForeground", Role::TextMuted),
+    ("*", "disabledForeground", Role::TextDisabled),
+    ("*", "selectionBackground", Role::ElementSelected),
+    ("*", "selectionForeground", Role::Text),
+    ("*", "selectionInactiveBackground", Role::ElementActive),
+    ("*", "hoverBackground", Role::ElementHover),
+    ("*", "borderColor", Role::Border),
+    ("*", "separatorColor", Role::Border),
+    ("*", "focusColor", Role::BorderFocused),
+    ("*", "accentColor", Role::Accent),
+    ("Component", "focusedBorderColor", Role::BorderFocused),
+    ("Link", "activeForeground", Role::Link),
+    ("EditorTabs", "background", Role::Tabline),
+    ("EditorTabs", "underlinedTabBackground", Role::TabActive),
+    ("EditorTabs", "underlineColor", Role::Accent),
+    ("MainWindow", "background", Role::Titlebar),
+    ("StatusBar", "background", Role::Statusline),
+    ("ToolWindow", "background", Role::Panel),
+    ("Popup", "background", Role::SurfaceElevated),
+    ("ScrollBar", "thumbColor", Role::ScrollbarThumb),
+    ("ScrollBar", "hoverThumbColor", Role::ScrollbarThumbHover),
+    ("ScrollBar", "trackColor", Role::ScrollbarTrack),
+];
+
+/// A color as the `.icls` format writes it: lowercase hex without `#`.
+fn hex(color: Color) -> String {
+    color.to_string()[1..7].to_lowercase()
+}
+
+/// The file stem of `variant`, shared by its scheme and UI theme.
+fn stem(meta: &Meta, variant: Variant) -> String {
+    meta.file_stem(variant)
+}
+
+fn option(text: &mut String, indent: usize, name: &str, value: impl std::fmt::Display) {
+    let _ = writeln!(
+        text,
+        "{:indent$}<option name=\"{name}\" value=\"{value}\" />",
+        ""
+    );
+}
+
+fn attribute(text: &mut String, theme: &Theme, key: &str, paint: Paint, font_type: u8) {
+    let _ = writeln!(text, "    <option name=\"{}\">", escape_xml(key));
+    text.push_str("      <value>\n");
+    if let Some(fg) = paint.fg {
+        option(text, 8, "FOREGROUND", hex(theme.opaque_color(fg)));
+    }
+    if let Some(bg) = paint.bg {
+        option(text, 8, "BACKGROUND", hex(theme.opaque_color(bg)));
+    }
+    if font_type != 0 {
+        option(text, 8, "FONT_TYPE", font_type);
+    }
+    if let Some((effect, role)) = paint.effect {
+        option(text, 8, "EFFECT_COLOR", hex(theme.opaque_color(role)));
+        option(text, 8, "EFFECT_TYPE", effect as u8);
+    }
+    if let Some(stripe) = paint.stripe {
+        option(
+            text,
+            8,
+            "ERROR_STRIPE_COLOR",
+            hex(theme.opaque_color(stripe)),
+        );
+    }
+    text.push_str("      </value>\n    </option>\n");
+}
+
+/// The `FONT_TYPE` of `role`: 1 for bold, 2 for italic, 3 for both.
+fn font_type(theme: &Theme, role: Role) -> u8 {
+    let style = theme.style(role);
+    let bold = style.is_bold();
+    u8::from(bold) | u8::from(style.italic) << 1
+}
+
+fn comment(text: &mut String, source: &Source, title: &str) {
+    text.push_str("<!--\n");
+    for line in source.meta.header(title) {
+        let _ = writeln!(text, "  {}", escape_xml(&line));
+    }
+    text.push_str("-->\n");
+}
+
+fn scheme(source: &Source, theme: &Theme) -> String {
+    let parent = match theme.appearance() {
+        Appearance::Dark => "Darcula",
+        Appearance::Light => "Default",
+    };
+    let mut text = String::new();
+    comment(&mut text, source, &source.meta.theme_name(theme.variant));
+    let _ = writeln!(
+        text,
+        "<scheme name=\"{}\" version=\"{SCHEME_VERSION}\" parent_scheme=\"{parent}\">",
+        escape_xml(&source.meta.theme_name(theme.variant))
+    );
+    text.push_str("  <colors>\n");
+    for &(key, role) in COLORS {
+        option(&mut text, 4, key, hex(theme.opaque_color(role)));
+    }
+    text.push_str("  </colors>\n  <attributes>\n");
+    for &(key, paint) in UI {
+        attribute(&mut text, theme, key, paint, 0);
+    }
+    for &(key, role) in SYNTAX {
+        attribute(
+            &mut text,
+            theme,
+            key,
+            Paint::fg(role),
+            font_type(theme, role),
+        );
+    }
+    text.push_str("  </attributes>\n</scheme>\n");
+    text
+}
+
+fn ui_theme(source: &Source, theme: &Theme) -> Value {
+    let mut ui: Map<String, Value> = Map::new();
+    for &(component, key, role) in UI_THEME {
+        let component = ui
+            .entry(component)
+            .or_insert_with(|| Value::Object(Map::new()));
+        component[key] = Value::String(theme.opaque_color(role).to_string());
+    }
+    json!({
+        "name": source.meta.theme_name(theme.variant),
+        "dark": theme.appearance() == Appearance::Dark,
+        "author": source.meta.author,
+        "editorScheme": format!("/{}.icls", stem(&source.meta, theme.variant)),
+        "ui": ui,
+    })
+}
+
+fn plugin(source: &Source, variants: &[Variant]) -> String {
+    let meta = &source.meta;
+    let id = meta.name.to_lowercase().replace(' ', "-");
+    let mut text = String::new();
+    comment(&mut text, source, &meta.name);
+    text.push_str("<idea-plugin>\n");
+    let _ = writeln!(text, "  <id>{}</id>", escape_xml(&id));
+    let _ = writeln!(text, "  <name>{}</name>", escape_xml(&meta.name));
+    let _ = writeln!(text, "  <version>{}</version>", escape_xml(&meta.version));
+    let _ = writeln!(
+        text,
+        "  <vendor url=\"{}\">{}</vendor>",
+        escape_xml(&meta.homepage),
+        escape_xml(&meta.author)
+    );
+    let _ = writeln!(
+        text,
+        "  <description>{} color schemes.</description>",
+        escape_xml(&meta.name)
+    );
+    let _ = writeln!(text, "  <idea-version since-build=\"{SINCE_BUILD}\" />");
+    text.push_str("  <depends>com.intellij.modules.platform</depends>\n");
+    text.push_str("  <extensions defaultExtensionNs=\"com.intellij\">\n");
+    for &variant in variants {
+        let _ = writeln!(
+            text,
+            "    <themeProvider id=\"{}\" path=\"/{}.theme.json\" />",
+            meta.uuid(variant.slug()),
+            stem(&source.meta, variant)
+        );
+    }
+    text.push_str("  </extensions>\n</idea-plugin>\n");
+    text
+}
+
+impl Target for JetBrains {
+    fn name(&self) -> &str {
+        "jetbrains"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let mut outputs = Vec::new();
+        for &variant in variants {
+            let theme = source.theme(variant);
+            outputs.push(Output::new(
+                super::extras_path(&source.meta, self.name(), variant, "icls"),
+                scheme(source, &theme),
+            ));
+            outputs.push(Output::json(
+                super::extras_path(&source.meta, self.name(), variant, "theme.json"),
+                &ui_theme(source, &theme),
+            ));
+        }
+        outputs.push(Output::new(
+            Path::new("extras")
+                .join(self.name())
+                .join("META-INF")
+                .join("plugin.xml"),
+            plugin(source, variants),
+        ));
+        Ok(outputs)
+    }
+}
@@ -0,0 +1,173 @@
+//! k9s: one skin per variant, covering the body, prompt, dialogs, frame,
+//! table, xray, YAML, log and chart views.
+//!
+//! k9s picks a skin by file name, set as `k9s.ui.skin` in its config or per
+//! cluster context.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct K9s;
+
+/// A line of the skin.
+enum Entry {
+    /// A mapping holding the entries below it.
+    Section(&'static str),
+    /// A single color.
+    Color(&'static str, Role),
+    /// A list of colors, e.g. a chart's ok and fault series.
+    Colors(&'static str, &'static [Role]),
+}
+
+use Entry::{Color, Colors, Section};
+
+/// The skin below the top-level `k9s` key, with each entry's nesting depth.
+const SKIN: &[(usize, Entry)] = &[
+    (0, Section("body")),
+    (1, Color("fgColor", Role::Text)),
+    (1, Color("bgColor", Role::Background)),
+    (1, Color("logoColor", Role::Accent)),
+    (0, Section("prompt")),
+    (1, Color("fgColor", Role::Text)),
+    (1, Color("bgColor", Role::Background)),
+    (1, Color("suggestColor", Role::TextPlaceholder)),
+    (0, Section("info")),
+    (1, Color("fgColor", Role::Accent)),
+    (1, Color("sectionColor", Role::Text)),
+    (0, Section("dialog")),
+    (1, Color("fgColor", Role::Text)),
+    (1, Color("bgColor", Role::SurfaceElevated)),
+    (1, Color("buttonFgColor", Role::Text)),
+    (1, Color("buttonBgColor", Role::Element)),
+    (1, Color("buttonFocusFgColor", Role::Background)),
+    (1, Color("buttonFocusBgColor", Role::Accent)),
+    (1, Color("labelFgColor", Role::Warning)),
+    (1, Color("fieldFgColor", Role::Text)),
+    (0, Section("frame")),
+    (1, Section("border")),
+    (2, Color("fgColor", Role::Border)),
+    (2, Color("focusColor", Role::BorderFocused)),
+    (1, Section("menu")),
+    (2, Color("fgColor", Role::TextMuted)),
+    (2, Color("keyColor", Role::Accent)),
+    (2, Color("numKeyColor", Role::Constant)),
+    (1, Section("crumbs")),
+    (2, Color("fgColor", Role::Text)),
+    (2, Color("bgColor", Role::Element)),
+    (2, Color("activeColor", Role::ElementSelected)),
+    (1, Section("status")),
+    (2, Color("newColor", Role::Info)),
+    (2, Color("modifyColor", Role::GitModified)),
+    (2, Color("addColor", Role::GitAdded)),
+    (2, Color("pendingColor", Role::Warning)),
+    (2, Color("errorColor", Role::Error)),
+    (2, Color("highlightColor", Role::Accent)),
+    (2, Color("killColor", Role::TextDisabled)),
+    (2, Color("completedColor", Role::TextMuted)),
+    (1, Section("title")),
+    (2, Color("fgColor", Role::Text)),
+    (2, Color("bgColor", Role::Background)),
+    (2, Color("highlightColor", Role::Accent)),
+    (2, Color("counterColor", Role::Constant)),
+    (2, Color("filterColor", Role::Warning)),
+    (0, Section("views")),
+    (1, Section("charts")),
+    (2, Color("bgColor", Role::Background)),
+    (
+        2,
+        Colors("defaultDialColors", &[Role::Success, Role::Error]),
+    ),
+    (
+        2,
+        Colors("defaultChartColors", &[Role::Success, Role::Error]),
+    ),
+    (1, Section("table")),
+    (2, Color("fgColor", Role::Text)),
+    (2, Color("bgColor", Role::Background)),
+    (2, Color("cursorFgColor", Role::Text)),
+    (2, Color("cursorBgColor", Role::ElementSelected)),
+    (2, Color("markColor", Role::Warning)),
+    (2, Section("header")),
+    (3, Color("fgColor", Role::TextMuted)),
+    (3, Color("bgColor", Role::Background)),
+    (3, Color("sorterColor", Role::Accent)),
+    (1, Section("xray")),
+    (2, Color("fgColor", Role::Text)),
+    (2, Color("bgColor", Role::Background)),
+    (2, Color("cursorColor", Role::ElementSelected)),
+    (2, Color("cursorTextColor", Role::Text)),
+    (2, Color("graphicColor", Role::Accent)),
+    (1, Section("yaml")),
+    (2, Color("keyColor", Role::Property)),
+    (2, Color("colonColor", Role::PunctuationDelimiter)),
+    (2, Color("valueColor", Role::String)),
+    (1, Section("logs")),
+    (2, Color("fgColor", Role::Text)),
+    (2, Color("bgColor", Role::Background)),
+    (2, Section("indicator")),
+    (3, Color("fgColor", Role::TextMuted)),
+    (3, Color("bgColor", Role::Statusline)),
+    (3, Color("toggleOnColor", Role::Success)),
+    (3, Color("toggleOffColor", Role::TextDisabled)),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push_str("\nk9s:\n");
+    let hex = |role| theme.opaque_color(role);
+    for (depth, entry) in SKIN {
+        let indent = "  ".repeat(depth + 1);
+        let _ = match entry {
+            Section(key) => writeln!(text, "{indent}{key}:"),
+            Color(key, role) => writeln!(text, "{indent}{key}: '{}'", hex(*role)),
+            Colors(key, roles) => {
+                let colors: Vec<String> = roles
+                    .iter()
+                    .map(|&role| format!("'{}'", hex(role)))
+                    .collect();
+                writeln!(text, "{indent}{key}: [{}]", colors.join(", "))
+            }
+        };
+    }
+    text
+}
+
+impl Target for K9s {
+    fn name(&self) -> &str {
+        "k9s"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "yaml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        // k9s follows the XDG layout on every platform, which puts its
+        // config in the local app data directory on Windows.
+        let app = if cfg!(windows) {
+            dirs.data().join("k9s")
+        } else {
+            dirs.app_data.join("k9s")
+        };
+        Some(InstallDir {
+            themes: app.join("skins"),
+            app,
+        })
+    }
+}
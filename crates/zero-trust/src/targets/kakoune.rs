@@ -0,0 +1,176 @@
+//! Kakoune: one colorscheme per variant, plus `zero-trust.kak` loading the
+//! dark one.
+//!
+//! Kakoune's faces are coarser than Helix's scopes, so each face borrows
+//! the role of the Helix key it corresponds to and the two editors stay in
+//! step.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use super::helix::{self, Paint};
+use super::textmate;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Kakoune;
+
+/// Code and markup faces and the Helix syntax scopes they borrow.
+const SYNTAX: &[(&str, &str)] = &[
+    ("value", "constant"),
+    ("type", "type"),
+    ("variable", "variable"),
+    ("module", "namespace"),
+    ("function", "function"),
+    ("string", "string"),
+    ("keyword", "keyword"),
+    ("operator", "operator"),
+    ("attribute", "attribute"),
+    ("comment", "comment"),
+    ("documentation", "comment.block.documentation"),
+    ("meta", "keyword.directive"),
+    ("builtin", "variable.builtin"),
+    ("title", "markup.heading"),
+    ("header", "markup.heading"),
+    ("mono", "markup.raw"),
+    ("block", "markup.raw"),
+    ("link", "markup.link.url"),
+    ("bullet", "markup.list"),
+    ("list", "markup.list"),
+];
+
+/// Core faces and the Helix interface keys they borrow. `Default` is set
+/// separately from the text and background colors.
+const UI: &[(&str, &str)] = &[
+    ("PrimarySelection", "ui.selection"),
+    ("SecondarySelection", "ui.selection"),
+    ("PrimaryCursor", "ui.cursor"),
+    ("SecondaryCursor", "ui.cursor"),
+    ("PrimaryCursorEol", "ui.cursor"),
+    ("SecondaryCursorEol", "ui.cursor"),
+    ("LineNumbers", "ui.linenr"),
+    ("LineNumberCursor", "ui.linenr.selected"),
+    ("LineNumbersWrapped", "ui.linenr"),
+    ("MenuForeground", "ui.menu.selected"),
+    ("MenuBackground", "ui.menu"),
+    ("MenuInfo", "ui.text.inactive"),
+    ("Information", "ui.popup"),
+    ("Error", "error"),
+    ("DiagnosticError", "diagnostic.error"),
+    ("DiagnosticWarning", "diagnostic.warning"),
+    ("StatusLine", "ui.statusline"),
+    ("StatusLineMode", "ui.statusline.normal"),
+    ("StatusLineInfo", "info"),
+    ("StatusLineValue", "warning"),
+    ("StatusCursor", "ui.cursor"),
+    ("Prompt", "ui.virtual.jump-label"),
+    ("MatchingChar", "ui.cursor.match"),
+    ("Whitespace", "ui.virtual.whitespace"),
+    ("WrapMarker", "ui.virtual.whitespace"),
+    ("BufferPadding", "ui.text.inactive"),
+];
+
+fn color(color: Color) -> String {
+    format!("rgb:{}", &color.to_string()[1..7])
+}
+
+fn lookup<T: Copy>(table: &[(&str, T)], key: &str) -> T {
+    table
+        .iter()
+        .find(|&&(k, _)| k == key)
+        .map(|&(_, value)| value)
+        .unwrap_or_else(|| panic!("no Helix key `{key}`"))
+}
+
+/// A face spec, `fg,bg,underline+attributes`, from a Helix paint.
+fn paint(theme: &Theme, paint: Paint) -> String {
+    let hex = |role| color(theme.opaque_color(role));
+    match paint {
+        Paint::Fg(fg) => hex(fg),
+        Paint::Bg(bg) => format!("default,{}", hex(bg)),
+        Paint::FgBg(fg, bg) => format!("{},{}", hex(fg), hex(bg)),
+        Paint::Undercurl(role) => format!("default,default,{}+c", hex(role)),
+    }
+}
+
+fn syntax(theme: &Theme, role: Role) -> String {
+    let attributes: String = textmate::font_style(theme.style(role))
+        .split_whitespace()
+        .map(|style| match style {
+            "italic" => 'i',
+            "bold" => 'b',
+            _ => 'u',
+        })
+        .collect();
+    let face = color(theme.opaque_color(role));
+    if attributes.is_empty() {
+        face
+    } else {
+        format!("{face}+{attributes}")
+    }
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    for &(face, key) in SYNTAX {
+        let role = lookup(helix::SYNTAX, key);
+        let _ = writeln!(text, "face global {face} {}", syntax(theme, role));
+    }
+    text.push('\n');
+    let _ = writeln!(
+        text,
+        "face global Default {}",
+        paint(theme, Paint::FgBg(Role::Text, Role::Background))
+    );
+    for &(face, key) in UI {
+        let spec = paint(theme, lookup(helix::UI, key));
+        let _ = writeln!(text, "face global {face} {spec}");
+    }
+    text
+}
+
+/// The colorscheme name of `variant`, also its file stem.
+fn scheme_name(meta: &Meta, variant: Variant) -> String {
+    meta.file_stem(variant)
+}
+
+impl Target for Kakoune {
+    fn name(&self) -> &str {
+        "kakoune"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name()).join("colors");
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    dir.join(format!("{}.kak", scheme_name(&source.meta, variant))),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect();
+        if variants.contains(&Variant::Dark) {
+            outputs.push(Output::new(
+                dir.join(format!("{}.kak", source.meta.stem())),
+                format!("colorscheme {}\n", scheme_name(&source.meta, Variant::Dark)),
+            ));
+        }
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("kak");
+        Some(InstallDir {
+            themes: app.join("colors"),
+            app,
+        })
+    }
+}
@@ -0,0 +1,145 @@
+//! Kate and the other KTextEditor applications: one KSyntaxHighlighting
+//! theme per variant, with `editor-colors` and `text-styles`.
+//!
+//! Per-language overrides name the items of each syntax definition rather
+//! than roles.
+
+use serde_json::{json, Map, Value};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Kate;
+
+/// Bumped whenever the mapping changes, so KDE applications reload themes
+/// they have cached.
+const REVISION: u32 = 1;
+
+/// The default text styles and the roles that paint them. Bold and
+/// italics follow the role's style.
+const TEXT_STYLES: &[(&str, Role)] = &[
+    ("Normal", Role::Text),
+    ("Keyword", Role::Keyword),
+    ("Function", Role::Function),
+    ("Variable", Role::Variable),
+    ("ControlFlow", Role::Keyword),
+    ("Operator", Role::Operator),
+    ("BuiltIn", Role::VariableSpecial),
+    ("Extension", Role::Type),
+    ("Preprocessor", Role::Preproc),
+    ("Attribute", Role::Attribute),
+    ("Char", Role::String),
+    ("SpecialChar", Role::StringEscape),
+    ("String", Role::String),
+    ("VerbatimString", Role::String),
+    ("SpecialString", Role::StringSpecial),
+    ("Import", Role::Namespace),
+    ("DataType", Role::Type),
+    ("DecVal", Role::Number),
+    ("BaseN", Role::Number),
+    ("Float", Role::Number),
+    ("Constant", Role::Constant),
+    ("Comment", Role::Comment),
+    ("Documentation", Role::CommentDoc),
+    ("Annotation", Role::Attribute),
+    ("CommentVar", Role::CommentDoc),
+    ("RegionMarker", Role::Label),
+    ("Information", Role::Info),
+    ("Warning", Role::Warning),
+    ("Alert", Role::Warning),
+    ("Error", Role::Error),
+    ("Others", Role::Text),
+];
+
+/// Editor color keys and the roles that paint them.
+const EDITOR_COLORS: &[(&str, Role)] = &[
+    ("BackgroundColor", Role::Background),
+    ("BracketMatching", Role::Highlight),
+    ("CodeFolding", Role::Cursorline),
+    ("CurrentLine", Role::Cursorline),
+    ("CurrentLineNumber", Role::LineNumberActive),
+    ("IconBorder", Role::Gutter),
+    ("IndentationLine", Role::Guide),
+    ("LineNumbers", Role::LineNumber),
+    ("MarkBookmark", Role::Info),
+    ("MarkBreakpointActive", Role::Error),
+    ("MarkBreakpointDisabled", Role::TextMuted),
+    ("MarkBreakpointReached", Role::Warning),
+    ("MarkError", Role::Error),
+    ("MarkExecution", Role::Success),
+    ("MarkWarning", Role::Warning),
+    ("ModifiedLines", Role::GitModified),
+    ("ReplaceHighlight", Role::Highlight),
+    ("SavedLines", Role::GitAdded),
+    ("SearchHighlight", Role::SearchMatch),
+    ("Separator", Role::Split),
+    ("SpellChecking", Role::Error),
+    ("TabMarker", Role::Invisible),
+    ("TemplateBackground", Role::Surface),
+    ("TemplateFocusedPlaceholder", Role::BorderFocused),
+    ("TemplatePlaceholder", Role::Border),
+    ("TemplateReadOnlyPlaceholder", Role::ElementDisabled),
+    ("TextSelection", Role::Selection),
+    ("WordWrapMarker", Role::Guide),
+];
+
+fn text_style(theme: &Theme, role: Role) -> Value {
+    let style = theme.style(role);
+    json!({
+        "text-color": theme.opaque_color(role).to_string(),
+        "bold": style.is_bold(),
+        "italic": style.italic,
+    })
+}
+
+fn render(source: &Source, theme: &Theme) -> Value {
+    let mut text_styles = Map::new();
+    for &(key, role) in TEXT_STYLES {
+        text_styles.insert(key.into(), text_style(theme, role));
+    }
+    let mut editor_colors = Map::new();
+    for &(key, role) in EDITOR_COLORS {
+        editor_colors.insert(key.into(), theme.opaque_color(role).to_string().into());
+    }
+    json!({
+        "metadata": {
+            "name": source.meta.theme_name(theme.variant),
+            "revision": REVISION,
+            "author": source.meta.author,
+            "license": source.meta.license,
+        },
+        "text-styles": text_styles,
+        "editor-colors": editor_colors,
+    })
+}
+
+impl Target for Kate {
+    fn name(&self) -> &str {
+        "kate"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::json(
+                    super::extras_path(&source.meta, self.name(), variant, "theme"),
+                    &render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    /// The user themes directory of KSyntaxHighlighting. Kate's own data directory
+    /// tells whether it is installed, as the themes directory only exists
+    /// once a user theme does.
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let data = dirs.data();
+        Some(InstallDir {
+            app: data.join("kate"),
+            themes: data.join("org.kde.syntax-highlighting").join("themes"),
+        })
+    }
+}
@@ -0,0 +1,94 @@
+//! kitty: one theme file per variant, with the metadata block `kitten
+//! themes` lists themes by.
+//!
+//! Selected text keeps its own colors, with only the selection background
+//! set.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::TERMINAL;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Kitty;
+
+/// Settings and the roles that paint them, in file order.
+const SETTINGS: &[(&str, Role)] = &[
+    ("foreground", Role::TerminalForeground),
+    ("background", Role::TerminalBackground),
+    ("selection_background", Role::Selection),
+    ("cursor", Role::Cursor),
+    ("cursor_text_color", Role::TerminalBackground),
+    ("url_color", Role::Link),
+    ("visual_bell_color", Role::Highlight),
+    ("active_border_color", Role::BorderFocused),
+    ("inactive_border_color", Role::Border),
+    ("bell_border_color", Role::Warning),
+    ("tab_bar_background", Role::Tabline),
+    ("tab_bar_margin_color", Role::Tabline),
+    ("active_tab_foreground", Role::Text),
+    ("active_tab_background", Role::TabActive),
+    ("inactive_tab_foreground", Role::TextMuted),
+    ("inactive_tab_background", Role::TabInactive),
+    ("mark1_foreground", Role::TerminalBackground),
+    ("mark1_background", Role::Accent),
+    ("mark2_foreground", Role::TerminalBackground),
+    ("mark2_background", Role::Warning),
+    ("mark3_foreground", Role::TerminalBackground),
+    ("mark3_background", Role::Info),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let meta = &source.meta;
+    let mut text = format!(
+        "## name: {}\n## author: {}\n## license: {}\n## upstream: {}\n## blurb: The {} variant of {}.\n\n",
+        source.meta.theme_name(theme.variant),
+        meta.author,
+        meta.license,
+        meta.homepage,
+        theme.appearance().name(),
+        meta.name,
+    );
+    for line in meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    let hex = |role| theme.opaque_color(role);
+    for &(key, role) in SETTINGS {
+        let _ = writeln!(text, "{key} {}", hex(role));
+    }
+    text.push_str("selection_foreground none\n\n");
+    for (index, &role) in TERMINAL.iter().enumerate() {
+        let _ = writeln!(text, "color{index} {}", hex(role));
+    }
+    text
+}
+
+impl Target for Kitty {
+    fn name(&self) -> &str {
+        "kitty"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "conf"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("kitty");
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
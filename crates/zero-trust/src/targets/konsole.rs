@@ -0,0 +1,108 @@
+//! Konsole: one `.colorscheme` per variant, with the normal, intense and
+//! faint shade of the background, the foreground and each ANSI color.
+//!
+//! The theme has no faint shades of its own here, so they are derived by
+//! lowering OKLCH lightness, or raising it on light themes, where faint
+//! text fades toward the light background instead. Konsole blends only
+//! the background, by the profile's opacity, so that takes the terminal
+//! background's alpha.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Appearance, Color, Theme, Variant};
+
+use super::TERMINAL;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Konsole;
+
+/// How far faint shades move in OKLCH lightness (`0.0..=1.0`).
+const FAINT: f64 = 0.15;
+
+/// `color` as Konsole's `r,g,b`.
+fn rgb(color: Color) -> String {
+    format!("{},{},{}", color.r, color.g, color.b)
+}
+
+/// The faint shade of `color`, faded toward the background of `theme`.
+fn faint(theme: &Theme, color: Color) -> Color {
+    match theme.appearance() {
+        Appearance::Dark => color.darken(FAINT),
+        Appearance::Light => color.lighten(FAINT),
+    }
+}
+
+/// Writes the normal, faint and intense sections of `name`.
+fn shades(text: &mut String, theme: &Theme, name: &str, normal: Color, intense: Color) {
+    for (suffix, color) in [
+        ("", normal),
+        ("Faint", faint(theme, normal)),
+        ("Intense", intense),
+    ] {
+        let _ = writeln!(text, "\n[{name}{suffix}]\nColor={}", rgb(color));
+    }
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    let color = |role| theme.opaque_color(role);
+    // Faint and intense text still sit on the one background.
+    let background = rgb(color(Role::TerminalBackground));
+    for suffix in ["", "Faint", "Intense"] {
+        let _ = writeln!(text, "\n[Background{suffix}]\nColor={background}");
+    }
+    for index in 0..8 {
+        shades(
+            &mut text,
+            theme,
+            &format!("Color{index}"),
+            color(TERMINAL[index]),
+            color(TERMINAL[index + 8]),
+        );
+    }
+    shades(
+        &mut text,
+        theme,
+        "Foreground",
+        color(Role::TerminalForeground),
+        color(Role::TerminalForegroundBright),
+    );
+    let _ = writeln!(
+        text,
+        "\n[General]\nBlur=false\nColorRandomization=false\nDescription={}\nOpacity={:.2}\nWallpaper=",
+        source.meta.theme_name(theme.variant),
+        f64::from(theme.color(Role::TerminalBackground).a) / 255.0
+    );
+    text
+}
+
+impl Target for Konsole {
+    fn name(&self) -> &str {
+        "konsole"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "colorscheme"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.data().join("konsole");
+        Some(InstallDir {
+            themes: app.clone(),
+            app,
+        })
+    }
+}
@@ -0,0 +1,245 @@
+//! Lapce: one color theme per variant, with the base palette, the syntax
+//! colors and the UI colors.
+//!
+//! Lapce blends translucent UI colors itself, so those keep their alpha.
+//! Lapce's syntax keys are colors only and shared by all languages, so font
+//! styles are left out.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Lapce;
+
+/// The base colors plugins and other themes refer to as `$name`.
+const BASE: &[(&str, Role)] = &[
+    ("white", Role::TerminalWhite),
+    ("black", Role::TerminalBlack),
+    ("grey", Role::TerminalBrightBlack),
+    ("blue", Role::TerminalBlue),
+    ("red", Role::TerminalRed),
+    ("yellow", Role::TerminalYellow),
+    ("orange", Role::TerminalBrightRed),
+    ("green", Role::TerminalGreen),
+    ("purple", Role::TerminalMagenta),
+    ("cyan", Role::TerminalCyan),
+    ("magenta", Role::TerminalBrightMagenta),
+];
+
+/// Tree-sitter captures and the roles that paint them.
+const SYNTAX: &[(&str, Role)] = &[
+    ("comment", Role::Comment),
+    ("constant", Role::Constant),
+    ("type", Role::Type),
+    ("typeAlias", Role::Type),
+    ("number", Role::Number),
+    ("enum", Role::Enum),
+    ("enumMember", Role::Variant),
+    ("enum-member", Role::Variant),
+    ("struct", Role::Type),
+    ("structure", Role::Type),
+    ("interface", Role::Type),
+    ("attribute", Role::Attribute),
+    ("constructor", Role::Constructor),
+    ("function", Role::Function),
+    ("method", Role::Function),
+    ("function.method", Role::Function),
+    ("property", Role::Property),
+    ("field", Role::Property),
+    ("string", Role::String),
+    ("string.escape", Role::StringEscape),
+    ("escape", Role::StringEscape),
+    ("string.regex", Role::StringRegex),
+    ("type.builtin", Role::Type),
+    ("builtinType", Role::Type),
+    ("embedded", Role::Embedded),
+    ("symbol", Role::StringSpecialSymbol),
+    ("keyword", Role::Keyword),
+    ("operator", Role::Operator),
+    ("variable", Role::Variable),
+    ("variable.builtin", Role::VariableSpecial),
+    ("variable.other.member", Role::Property),
+    ("namespace", Role::Namespace),
+    ("tag", Role::Tag),
+    ("punctuation.delimiter", Role::PunctuationDelimiter),
+    ("punctuation.bracket", Role::PunctuationBracket),
+    ("markup.heading", Role::Title),
+    ("markup.bold", Role::EmphasisStrong),
+    ("markup.italic", Role::Emphasis),
+    ("markup.list", Role::PunctuationListMarker),
+    ("markup.link.url", Role::LinkUri),
+    ("markup.link.text", Role::LinkText),
+    ("markup.raw", Role::TextLiteral),
+    ("bracket.color.1", Role::PunctuationBracket),
+    ("bracket.color.2", Role::Keyword),
+    ("bracket.color.3", Role::Function),
+];
+
+/// UI keys and the roles that paint them.
+const UI: &[(&str, Role)] = &[
+    ("lapce.error", Role::Error),
+    ("lapce.warn", Role::Warning),
+    ("lapce.dropdown_shadow", Role::Background),
+    ("lapce.border", Role::Border),
+    ("lapce.scroll_bar", Role::ScrollbarThumb),
+    ("lapce.button.primary.background", Role::Accent),
+    ("lapce.button.primary.foreground", Role::Background),
+    ("lapce.tab.active.background", Role::TabActive),
+    ("lapce.tab.active.foreground", Role::Text),
+    ("lapce.tab.active.underline", Role::Accent),
+    ("lapce.tab.inactive.background", Role::TabInactive),
+    ("lapce.tab.inactive.foreground", Role::TextMuted),
+    ("lapce.tab.inactive.underline", Role::Border),
+    ("lapce.tab.separator", Role::Split),
+    ("lapce.icon.active", Role::Icon),
+    ("lapce.icon.inactive", Role::IconMuted),
+    ("lapce.remote.icon", Role::Background),
+    ("lapce.remote.local", Role::Accent),
+    ("lapce.remote.connected", Role::Success),
+    ("lapce.remote.connecting", Role::Warning),
+    ("lapce.remote.disconnected", Role::Error),
+    ("lapce.plugin.name", Role::Text),
+    ("lapce.plugin.description", Role::TextMuted),
+    ("lapce.plugin.author", Role::Accent),
+    ("editor.background", Role::Background),
+    ("editor.foreground", Role::Text),
+    ("editor.dim", Role::TextMuted),
+    ("editor.focus", Role::Text),
+    ("editor.caret", Role::Cursor),
+    ("editor.selection", Role::Selection),
+    ("editor.current_line", Role::Cursorline),
+    ("editor.link", Role::Link),
+    ("editor.visible_whitespace", Role::Invisible),
+    ("editor.indent_guide", Role::Guide),
+    ("editor.drag_drop_background", Role::DropTarget),
+    ("editor.drag_drop_tab_background", Role::DropTarget),
+    ("editor.sticky_header_background", Role::Surface),
+    ("inlay_hint.foreground", Role::SyntaxHint),
+    ("inlay_hint.background", Role::HintBackground),
+    ("error_lens.error.foreground", Role::Error),
+    ("error_lens.error.background", Role::ErrorBackground),
+    ("error_lens.warning.foreground", Role::Warning),
+    ("error_lens.warning.background", Role::WarningBackground),
+    ("error_lens.other.foreground", Role::Info),
+    ("error_lens.other.background", Role::InfoBackground),
+    ("completion_lens.foreground", Role::SyntaxPredictive),
+    ("source_control.added", Role::GitAdded),
+    ("source_control.removed", Role::GitDeleted),
+    ("source_control.modified", Role::GitModified),
+    ("tooltip.background", Role::SurfaceElevated),
+    ("tooltip.foreground", Role::Text),
+    ("palette.background", Role::SurfaceElevated),
+    ("palette.foreground", Role::Text),
+    ("palette.current.background", Role::ElementSelected),
+    ("palette.current.foreground", Role::Text),
+    ("completion.background", Role::SurfaceElevated),
+    ("completion.current", Role::ElementSelected),
+    ("hover.background", Role::SurfaceElevated),
+    ("activity.background", Role::Panel),
+    ("activity.current", Role::ElementActive),
+    ("debug.breakpoint", Role::Error),
+    ("debug.breakpoint.hover", Role::ErrorMuted),
+    ("panel.background", Role::Panel),
+    ("panel.foreground", Role::Text),
+    ("panel.foreground.dim", Role::TextMuted),
+    ("panel.current.background", Role::ElementSelected),
+    ("panel.current.foreground", Role::Text),
+    ("panel.current.foreground.dim", Role::TextMuted),
+    ("panel.hovered.background", Role::ElementHover),
+    ("panel.hovered.active.background", Role::ElementActive),
+    ("panel.hovered.foreground", Role::Text),
+    ("panel.hovered.foreground.dim", Role::TextMuted),
+    ("status.background", Role::Statusline),
+    ("status.foreground", Role::Text),
+    ("status.modal.normal.background", Role::Accent),
+    ("status.modal.normal.foreground", Role::Background),
+    ("status.modal.insert.background", Role::Success),
+    ("status.modal.insert.foreground", Role::Background),
+    ("status.modal.visual.background", Role::Warning),
+    ("status.modal.visual.foreground", Role::Background),
+    ("status.modal.terminal.background", Role::Info),
+    ("status.modal.terminal.foreground", Role::Background),
+    ("markdown.blockquote", Role::TextMuted),
+    ("terminal.cursor", Role::Cursor),
+    ("terminal.foreground", Role::TerminalForeground),
+    ("terminal.background", Role::TerminalBackground),
+    ("terminal.black", Role::TerminalBlack),
+    ("terminal.red", Role::TerminalRed),
+    ("terminal.green", Role::TerminalGreen),
+    ("terminal.yellow", Role::TerminalYellow),
+    ("terminal.blue", Role::TerminalBlue),
+    ("terminal.magenta", Role::TerminalMagenta),
+    ("terminal.cyan", Role::TerminalCyan),
+    ("terminal.white", Role::TerminalWhite),
+    ("terminal.bright_black", Role::TerminalBrightBlack),
+    ("terminal.bright_red", Role::TerminalBrightRed),
+    ("terminal.bright_green", Role::TerminalBrightGreen),
+    ("terminal.bright_yellow", Role::TerminalBrightYellow),
+    ("terminal.bright_blue", Role::TerminalBrightBlue),
+    ("terminal.bright_magenta", Role::TerminalBrightMagenta),
+    ("terminal.bright_cyan", Role::TerminalBrightCyan),
+    ("terminal.bright_white", Role::TerminalBrightWhite),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    let _ = writeln!(
+        text,
+        "\n[color-theme]\nname = \"{}\"\n\n[color-theme.base]",
+        source.meta.theme_name(theme.variant)
+    );
+    for &(name, role) in BASE {
+        let _ = writeln!(text, "{name} = \"{}\"", theme.opaque_color(role));
+    }
+    text.push_str("\n[color-theme.syntax]\n");
+    for &(key, role) in SYNTAX {
+        let _ = writeln!(text, "\"{key}\" = \"{}\"", theme.opaque_color(role));
+    }
+    text.push_str("\n[color-theme.ui]\n");
+    for &(key, role) in UI {
+        let _ = writeln!(text, "\"{key}\" = \"{}\"", theme.color(role));
+    }
+    text
+}
+
+impl Target for Lapce {
+    fn name(&self) -> &str {
+        "lapce"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "toml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = if cfg!(target_os = "macos") {
+            dirs.app_data.join("dev.lapce.Lapce-Stable")
+        } else if cfg!(windows) {
+            dirs.app_data
+                .join("lapce")
+                .join("Lapce-Stable")
+                .join("config")
+        } else {
+            dirs.config.join("lapce-stable")
+        };
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+//! lazygit: one config snippet per variant with the `gui.theme` colors.
+//!
+//! lazygit merges the config files listed in `LG_CONFIG_FILE`, so the
+//! snippet can be used as it is next to the user's own `config.yml`.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Lazygit;
+
+/// `gui.theme` keys, the roles that paint them and whether they are bold.
+const THEME: &[(&str, Role, bool)] = &[
+    ("activeBorderColor", Role::BorderFocused, true),
+    ("inactiveBorderColor", Role::Border, false),
+    ("searchingActiveBorderColor", Role::Warning, true),
+    ("optionsTextColor", Role::Accent, false),
+    ("selectedLineBgColor", Role::ElementSelected, false),
+    ("inactiveViewSelectedLineBgColor", Role::ElementHover, false),
+    ("cherryPickedCommitFgColor", Role::Background, false),
+    ("cherryPickedCommitBgColor", Role::Info, false),
+    ("markedBaseCommitFgColor", Role::Background, false),
+    ("markedBaseCommitBgColor", Role::Warning, false),
+    ("unstagedChangesColor", Role::GitDeleted, false),
+    ("defaultFgColor", Role::Text, false),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push_str("\ngui:\n  theme:\n");
+    for &(key, role, bold) in THEME {
+        let _ = writeln!(text, "    {key}:\n      - '{}'", theme.opaque_color(role));
+        if bold {
+            text.push_str("      - bold\n");
+        }
+    }
+    text
+}
+
+impl Target for Lazygit {
+    fn name(&self) -> &str {
+        "lazygit"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "yml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,128 @@
+//! lite-xl and Pragtical: one colors module per variant setting the editor
+//! style and `style.syntax`, plus `zero_trust.lua` loading the dark one.
+//!
+//! Both editors blend translucent colors themselves, so interface colors
+//! keep their alpha. Syntax token types are colors only and shared by all
+//! languages, so font styles are left out.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct LiteXl;
+
+/// `style` fields and the roles that paint them.
+const STYLE: &[(&str, Role)] = &[
+    ("background", Role::Background),
+    ("background2", Role::Panel),
+    ("background3", Role::SurfaceElevated),
+    ("text", Role::Text),
+    ("caret", Role::Cursor),
+    ("accent", Role::Accent),
+    ("dim", Role::TextMuted),
+    ("divider", Role::Split),
+    ("selection", Role::Selection),
+    ("line_number", Role::LineNumber),
+    ("line_number2", Role::LineNumberActive),
+    ("line_highlight", Role::Cursorline),
+    ("scrollbar", Role::ScrollbarThumb),
+    ("scrollbar2", Role::ScrollbarThumbHover),
+    ("scrollbar_track", Role::ScrollbarTrack),
+    ("nagbar", Role::Error),
+    ("nagbar_text", Role::Background),
+    ("nagbar_dim", Role::Background),
+    ("drag_overlay", Role::DropTarget),
+    ("drag_overlay_tab", Role::Accent),
+    ("good", Role::Success),
+    ("warn", Role::Warning),
+    ("error", Role::Error),
+    ("modified", Role::GitModified),
+    ("guide", Role::Guide),
+];
+
+/// Syntax token types and the roles that paint them.
+const SYNTAX: &[(&str, Role)] = &[
+    ("normal", Role::Text),
+    ("symbol", Role::Variable),
+    ("comment", Role::Comment),
+    ("keyword", Role::Keyword),
+    ("keyword2", Role::Type),
+    ("number", Role::Number),
+    ("literal", Role::Constant),
+    ("string", Role::String),
+    ("operator", Role::Operator),
+    ("function", Role::Function),
+];
+
+/// The module name of `variant`, also its file stem.
+fn module_name(meta: &Meta, variant: Variant) -> String {
+    meta.file_stem(variant).replace('-', "_")
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "-- {line}");
+    }
+    text.push_str(
+        "\nlocal style = require \"core.style\"\nlocal common = require \"core.common\"\n\n",
+    );
+    for &(field, role) in STYLE {
+        let _ = writeln!(
+            text,
+            "style.{field} = {{ common.color \"{}\" }}",
+            theme.color(role)
+        );
+    }
+    text.push('\n');
+    for &(token, role) in SYNTAX {
+        let _ = writeln!(
+            text,
+            "style.syntax[\"{token}\"] = {{ common.color \"{}\" }}",
+            theme.opaque_color(role)
+        );
+    }
+    text
+}
+
+impl Target for LiteXl {
+    fn name(&self) -> &str {
+        "litexl"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name()).join("colors");
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    dir.join(format!("{}.lua", module_name(&source.meta, variant))),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect();
+        if variants.contains(&Variant::Dark) {
+            outputs.push(Output::new(
+                dir.join(format!("{}.lua", source.meta.stem().replace('-', "_"))),
+                format!(
+                    "require(\"core\").reload_module(\"colors.{}\")\n",
+                    module_name(&source.meta, Variant::Dark)
+                ),
+            ));
+        }
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("lite-xl");
+        Some(InstallDir {
+            themes: app.join("colors"),
+            app,
+        })
+    }
+}
@@ -0,0 +1,112 @@
+//! lsd: one `colors.yaml` per variant, coloring owners, permissions, dates,
+//! sizes, links and git status.
+//!
+//! lsd takes file type colors from `LS_COLORS`, which the dircolors target
+//! covers. It reads a custom theme from `colors.yaml` next to its config,
+//! so picking a variant means linking its file there.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Lsd;
+
+/// Colors at the top level.
+const TOP: &[(&str, Role)] = &[
+    ("user", Role::Warning),
+    ("group", Role::TextMuted),
+    ("tree-edge", Role::TextDisabled),
+];
+
+/// Sections of colors.
+const SECTIONS: &[(&str, &[(&str, Role)])] = &[
+    (
+        "permission",
+        &[
+            ("read", Role::Warning),
+            ("write", Role::Error),
+            ("exec", Role::Success),
+            ("exec-sticky", Role::Keyword),
+            ("no-access", Role::TextDisabled),
+            ("octal", Role::Keyword),
+            ("acl", Role::Info),
+            ("context", Role::Info),
+        ],
+    ),
+    (
+        "date",
+        &[
+            ("hour-old", Role::Accent),
+            ("day-old", Role::Text),
+            ("older", Role::TextMuted),
+        ],
+    ),
+    (
+        "size",
+        &[
+            ("none", Role::TextDisabled),
+            ("small", Role::TextMuted),
+            ("medium", Role::Info),
+            ("large", Role::Warning),
+        ],
+    ),
+    (
+        "inode",
+        &[("valid", Role::TextMuted), ("invalid", Role::TextDisabled)],
+    ),
+    ("links", &[("valid", Role::Info), ("invalid", Role::Error)]),
+    (
+        "git-status",
+        &[
+            ("default", Role::TextMuted),
+            ("unmodified", Role::TextMuted),
+            ("ignored", Role::TextDisabled),
+            ("new-in-index", Role::GitAdded),
+            ("new-in-workdir", Role::GitAdded),
+            ("typechange", Role::Keyword),
+            ("deleted", Role::GitDeleted),
+            ("renamed", Role::Info),
+            ("modified", Role::GitModified),
+            ("conflicted", Role::Error),
+        ],
+    ),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    for &(key, role) in TOP {
+        let _ = writeln!(text, "{key}: \"{}\"", theme.opaque_color(role));
+    }
+    for &(section, colors) in SECTIONS {
+        let _ = writeln!(text, "{section}:");
+        for &(key, role) in colors {
+            let _ = writeln!(text, "  {key}: \"{}\"", theme.opaque_color(role));
+        }
+    }
+    text
+}
+
+impl Target for Lsd {
+    fn name(&self) -> &str {
+        "lsd"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "yaml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,220 @@
+//! micro: two colorschemes per variant, one in truecolor and one for
+//! 256-color terminals, plus `zero-trust.micro` loading the dark one.
+//!
+//! micro approximates hex colors on its own where truecolor is not
+//! available, so the `-256` schemes carry the quantizer's picks instead.
+//! Syntax groups are shared by all languages.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::Depth;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Micro;
+
+/// How an interface group is painted.
+#[derive(Clone, Copy)]
+struct Paint {
+    fg: Option<Role>,
+    bg: Option<Role>,
+    bold: bool,
+}
+
+impl Paint {
+    const fn fg(role: Role) -> Paint {
+        Paint {
+            fg: Some(role),
+            bg: None,
+            bold: false,
+        }
+    }
+
+    const fn bg(role: Role) -> Paint {
+        Paint {
+            fg: None,
+            bg: Some(role),
+            bold: false,
+        }
+    }
+
+    const fn fg_bg(fg: Role, bg: Role) -> Paint {
+        Paint {
+            fg: Some(fg),
+            bg: Some(bg),
+            bold: false,
+        }
+    }
+
+    const fn bold(self) -> Paint {
+        Paint { bold: true, ..self }
+    }
+}
+
+/// Syntax groups and the roles that paint them. Bold and italics follow
+/// the role's style.
+const SYNTAX: &[(&str, Role)] = &[
+    ("comment", Role::Comment),
+    ("identifier", Role::Variable),
+    ("identifier.class", Role::Type),
+    ("identifier.macro", Role::Function),
+    ("identifier.var", Role::Variable),
+    ("constant", Role::Constant),
+    ("constant.bool", Role::Boolean),
+    ("constant.number", Role::Number),
+    ("constant.specialChar", Role::StringEscape),
+    ("constant.string", Role::String),
+    ("constant.string.url", Role::LinkUri),
+    ("statement", Role::Keyword),
+    ("symbol", Role::Punctuation),
+    ("symbol.brackets", Role::PunctuationBracket),
+    ("symbol.operator", Role::Operator),
+    ("symbol.tag", Role::Tag),
+    ("preproc", Role::Preproc),
+    ("type", Role::Type),
+    ("type.keyword", Role::Keyword),
+    ("special", Role::VariableSpecial),
+    ("underlined", Role::LinkText),
+];
+
+/// Interface groups and how they are painted. `default` comes first so the
+/// others fall back on its background.
+const UI: &[(&str, Paint)] = &[
+    ("default", Paint::fg_bg(Role::Text, Role::Background)),
+    ("selection", Paint::bg(Role::Selection)),
+    ("cursor-line", Paint::bg(Role::Cursorline)),
+    ("color-column", Paint::bg(Role::Surface)),
+    ("line-number", Paint::fg_bg(Role::LineNumber, Role::Gutter)),
+    (
+        "current-line-number",
+        Paint::fg_bg(Role::LineNumberActive, Role::Cursorline),
+    ),
+    ("gutter-error", Paint::fg(Role::Error)),
+    ("gutter-warning", Paint::fg(Role::Warning)),
+    ("diff-added", Paint::fg(Role::GitAdded)),
+    ("diff-modified", Paint::fg(Role::GitModified)),
+    ("diff-deleted", Paint::fg(Role::GitDeleted)),
+    ("statusline", Paint::fg_bg(Role::Text, Role::Statusline)),
+    (
+        "statusline.inactive",
+        Paint::fg_bg(Role::TextMuted, Role::Statusline),
+    ),
+    (
+        "statusline.suggestions",
+        Paint::fg_bg(Role::Text, Role::ElementSelected),
+    ),
+    ("tabbar", Paint::fg_bg(Role::TextMuted, Role::Tabline)),
+    ("tabbar.active", Paint::fg_bg(Role::Text, Role::TabActive)),
+    ("divider", Paint::fg_bg(Role::Split, Role::Background)),
+    ("scrollbar", Paint::fg(Role::ScrollbarThumb)),
+    ("indent-char", Paint::fg(Role::Invisible)),
+    ("ignore", Paint::fg(Role::TextMuted)),
+    (
+        "match-brace",
+        Paint::fg_bg(Role::Text, Role::Highlight).bold(),
+    ),
+    ("hlsearch", Paint::fg_bg(Role::Text, Role::SearchMatch)),
+    ("todo", Paint::fg(Role::Warning).bold()),
+    ("error", Paint::fg_bg(Role::Error, Role::ErrorBackground)),
+    ("message", Paint::fg(Role::Text)),
+    ("error-message", Paint::fg(Role::Error).bold()),
+    ("tab-error", Paint::bg(Role::ErrorBackground)),
+    ("trailingws", Paint::bg(Role::ErrorBackground)),
+];
+
+/// A `color-link` value: attributes, then `fg,bg` with either side left
+/// empty for the default.
+fn value(theme: &Theme, depth: Depth, attributes: &[&str], paint: Paint) -> String {
+    let color = |role: Option<Role>| {
+        role.map_or(String::new(), |role| depth.color(theme.opaque_color(role)))
+    };
+    let colors = match paint.bg {
+        Some(_) => format!("{},{}", color(paint.fg), color(paint.bg)),
+        None => color(paint.fg),
+    };
+    let mut words: Vec<&str> = attributes.to_vec();
+    if paint.bold {
+        words.push("bold");
+    }
+    words.push(&colors);
+    words.join(" ")
+}
+
+fn render(source: &Source, theme: &Theme, depth: Depth) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    for &(group, paint) in UI {
+        let spec = value(theme, depth, &[], paint);
+        let _ = writeln!(text, "color-link {group} \"{spec}\"");
+    }
+    text.push('\n');
+    for &(group, role) in SYNTAX {
+        let style = theme.style(role);
+        let mut attributes = Vec::new();
+        if style.is_bold() {
+            attributes.push("bold");
+        }
+        if style.italic {
+            attributes.push("italic");
+        }
+        if group == "underlined" {
+            attributes.push("underline");
+        }
+        let spec = value(theme, depth, &attributes, Paint::fg(role));
+        let _ = writeln!(text, "color-link {group} \"{spec}\"");
+    }
+    text
+}
+
+/// The colorscheme name of `variant` in `depth`, also its file stem.
+fn scheme_name(meta: &Meta, variant: Variant, depth: Depth) -> String {
+    format!("{}{}", meta.file_stem(variant), depth.suffix())
+}
+
+impl Target for Micro {
+    fn name(&self) -> &str {
+        "micro"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name()).join("colorschemes");
+        let mut outputs = Vec::new();
+        for &variant in variants {
+            let theme = source.theme(variant);
+            for depth in [Depth::Truecolor, Depth::Ansi256] {
+                outputs.push(Output::new(
+                    dir.join(format!(
+                        "{}.micro",
+                        scheme_name(&source.meta, variant, depth)
+                    )),
+                    render(source, &theme, depth),
+                ));
+            }
+        }
+        if variants.contains(&Variant::Dark) {
+            outputs.push(Output::new(
+                dir.join(format!("{}.micro", source.meta.stem())),
+                format!(
+                    "include \"{}\"\n",
+                    scheme_name(&source.meta, Variant::Dark, Depth::Truecolor)
+                ),
+            ));
+        }
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("micro");
+        Some(InstallDir {
+            themes: app.join("colorschemes"),
+            app,
+        })
+    }
+}
@@ -0,0 +1,387 @@
+//! The supported targets.
+//!
+//! Most formats have no alpha channel, terminals' among them, so targets
+//! write [`Theme::opaque_color`](zero_trust_palette::Theme::opaque_color)
+//! unless their module says which colors the application blends itself.
+//! The `[languages]` rules of the source only reach targets whose format
+//! can scope a color to one language; the others take the syntax roles
+//! alone. Targets without an install directory are the ones whose files
+//! are pulled into the user's own config or imported through the
+//! application, and `zero-trust install` skips them.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use zero_trust_palette::quantize::to_ansi256;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Variant};
+
+use crate::overrides::{self, Layer, Overridden};
+use crate::templates;
+use crate::{Error, Meta, Target};
+
+mod alacritty;
+mod bat;
+mod btop;
+mod delta;
+mod dircolors;
+mod emacs;
+mod eza;
+mod fish;
+mod foot;
+mod fzf;
+mod ghostty;
+mod gitui;
+mod glow;
+mod gnometerminal;
+mod gtksourceview;
+mod helix;
+mod htop;
+mod hyper;
+mod iterm2;
+mod jetbrains;
+mod k9s;
+mod kakoune;
+mod kate;
+mod kitty;
+mod konsole;
+mod lapce;
+mod lazygit;
+mod litexl;
+mod lsd;
+mod micro;
+mod nano;
+mod neomutt;
+mod neovim;
+mod newsboat;
+mod notepadplusplus;
+mod nova;
+mod ohmyposh;
+mod psreadline;
+mod rio;
+mod st;
+mod starship;
+mod sublime;
+mod tabby;
+mod taskwarrior;
+mod terminalapp;
+mod textmate;
+mod tig;
+mod tmtheme;
+mod tmux;
+mod vim;
+mod visualstudio;
+mod vscode;
+mod warp;
+mod wezterm;
+mod windowsterminal;
+mod xcode;
+mod yazi;
+mod zed;
+mod zellij;
+mod zsh;
+
+pub use alacritty::Alacritty;
+pub use bat::Bat;
+pub use btop::Btop;
+pub use delta::Delta;
+pub use dircolors::Dircolors;
+pub use emacs::Emacs;
+pub use eza::Eza;
+pub use fish::Fish;
+pub use foot::Foot;
+pub use fzf::Fzf;
+pub use ghostty::Ghostty;
+pub use gitui::Gitui;
+pub use glow::Glow;
+pub use gnometerminal::GnomeTerminal;
+pub use gtksourceview::GtkSourceView;
+pub use helix::Helix;
+pub use htop::Htop;
+pub use hyper::Hyper;
+pub use iterm2::ITerm2;
+pub use jetbrains::JetBrains;
+pub use k9s::K9s;
+pub use kakoune::Kakoune;
+pub use kate::Kate;
+pub use kitty::Kitty;
+pub use konsole::Konsole;
+pub use lapce::Lapce;
+pub use lazygit::Lazygit;
+pub use litexl::LiteXl;
+pub use lsd::Lsd;
+pub use micro::Micro;
+pub use nano::Nano;
+pub use neomutt::Neomutt;
+pub use neovim::Neovim;
+pub use newsboat::Newsboat;
+pub use notepadplusplus::NotepadPlusPlus;
+pub use nova::Nova;
+pub use ohmyposh::OhMyPosh;
+pub use psreadline::PsReadLine;
+pub use rio::Rio;
+pub use st::St;
+pub use starship::Starship;
+pub use sublime::Sublime;
+pub use tabby::Tabby;
+pub use taskwarrior::Taskwarrior;
+pub use terminalapp::TerminalApp;
+pub use tig::Tig;
+pub use tmtheme::TmTheme;
+pub use tmux::Tmux;
+pub use vim::Vim;
+pub use visualstudio::VisualStudio;
+pub use vscode::VsCode;
+pub use warp::Warp;
+pub use wezterm::WezTerm;
+pub use windowsterminal::WindowsTerminal;
+pub use xcode::Xcode;
+pub use yazi::Yazi;
+pub use zed::Zed;
+pub use zellij::Zellij;
+pub use zsh::Zsh;
+
+/// The targets built into the generator.
+pub fn builtin() -> Vec<Arc<dyn Target>> {
+    vec![
+        Arc::new(Zed),
+        Arc::new(VsCode),
+        Arc::new(Helix),
+        Arc::new(Neovim),
+        Arc::new(Vim),
+        Arc::new(Sublime),
+        Arc::new(JetBrains),
+        Arc::new(Emacs),
+        Arc::new(Kakoune),
+        Arc::new(Kate),
+        Arc::new(GtkSourceView),
+        Arc::new(Xcode),
+        Arc::new(VisualStudio),
+        Arc::new(NotepadPlusPlus),
+        Arc::new(Micro),
+        Arc::new(Nano),
+        Arc::new(TmTheme),
+        Arc::new(Nova),
+        Arc::new(Lapce),
+        Arc::new(LiteXl),
+        Arc::new(Alacritty),
+        Arc::new(Kitty),
+        Arc::new(WezTerm),
+        Arc::new(ITerm2),
+        Arc::new(WindowsTerminal),
+        Arc::new(Ghostty),
+        Arc::new(Foot),
+        Arc::new(Konsole),
+        Arc::new(GnomeTerminal),
+        Arc::new(Rio),
+        Arc::new(Warp),
+        Arc::new(Hyper),
+        Arc::new(Tabby),
+        Arc::new(St),
+        Arc::new(TerminalApp),
+        Arc::new(Tmux),
+        Arc::new(Bat),
+        Arc::new(Delta),
+        Arc::new(Lazygit),
+        Arc::new(Gitui),
+        Arc::new(Fzf),
+        Arc::new(Starship),
+        Arc::new(Zellij),
+        Arc::new(Btop),
+        Arc::new(Htop),
+        Arc::new(K9s),
+        Arc::new(Dircolors),
+        Arc::new(Eza),
+        Arc::new(Lsd),
+        Arc::new(Zsh),
+        Arc::new(Fish),
+        Arc::new(PsReadLine),
+        Arc::new(OhMyPosh),
+        Arc::new(Taskwarrior),
+        Arc::new(Neomutt),
+        Arc::new(Newsboat),
+        Arc::new(Tig),
+        Arc::new(Glow),
+        Arc::new(Yazi),
+    ]
+}
+
+/// The names of the eight basic ANSI colors, in index order.
+pub(crate) const ANSI_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// The terminal roles by ANSI index.
+pub(crate) const TERMINAL: [Role; 16] = [
+    Role::TerminalBlack,
+    Role::TerminalRed,
+    Role::TerminalGreen,
+    Role::TerminalYellow,
+    Role::TerminalBlue,
+    Role::TerminalMagenta,
+    Role::TerminalCyan,
+    Role::TerminalWhite,
+    Role::TerminalBrightBlack,
+    Role::TerminalBrightRed,
+    Role::TerminalBrightGreen,
+    Role::TerminalBrightYellow,
+    Role::TerminalBrightBlue,
+    Role::TerminalBrightMagenta,
+    Role::TerminalBrightCyan,
+    Role::TerminalBrightWhite,
+];
+
+/// The dim terminal roles by ANSI index, for terminals that let themes set
+/// faint colors.
+pub(crate) const TERMINAL_DIM: [Role; 8] = [
+    Role::TerminalDimBlack,
+    Role::TerminalDimRed,
+    Role::TerminalDimGreen,
+    Role::TerminalDimYellow,
+    Role::TerminalDimBlue,
+    Role::TerminalDimMagenta,
+    Role::TerminalDimCyan,
+    Role::TerminalDimWhite,
+];
+
+/// Which colors a file is written with, for targets that ship a 256-color
+/// file next to the truecolor one.
+#[derive(Clone, Copy)]
+pub(crate) enum Depth {
+    Truecolor,
+    Ansi256,
+}
+
+impl Depth {
+    /// `color` as hex, or as the index the quantizer picks.
+    pub(crate) fn color(self, color: Color) -> String {
+        match self {
+            Depth::Truecolor => color.to_string(),
+            Depth::Ansi256 => to_ansi256(color).to_string(),
+        }
+    }
+
+    /// The file stem suffix of files in this depth.
+    pub(crate) fn suffix(self) -> &'static str {
+        match self {
+            Depth::Truecolor => "",
+            Depth::Ansi256 => "-256",
+        }
+    }
+}
+
+/// Where a target writes the file of one variant, the same layout
+/// templates use: `extras/<target>/<stem>-<variant>.<extension>`.
+pub(crate) fn extras_path(meta: &Meta, target: &str, variant: Variant, extension: &str) -> PathBuf {
+    Path::new("extras")
+        .join(target)
+        .join(format!("{}.{extension}", meta.file_stem(variant)))
+}
+
+/// The targets available to a build: the built-in ones plus templates and
+/// plugins.
+#[derive(Clone)]
+pub struct Registry {
+    targets: Vec<Arc<dyn Target>>,
+}
+
+impl Registry {
+    /// Only the built-in targets.
+    pub fn builtin() -> Registry {
+        Registry { targets: builtin() }
+    }
+
+    /// The built-in targets plus every template in `dir`.
+    ///
+    /// A template named like a built-in target replaces it.
+    pub fn with_templates(dir: &Path) -> Result<Registry, Error> {
+        let mut registry = Registry::builtin();
+        for template in templates::load_dir(dir)? {
+            tracing::debug!(name = template.name(), path = %template.path().display(), "loaded template");
+            registry.insert(Arc::new(template));
+        }
+        Ok(registry)
+    }
+
+    /// Adds every WebAssembly plugin in `dir`.
+    ///
+    /// A plugin named like an existing target replaces it.
+    #[cfg(feature = "wasm")]
+    pub fn load_plugins(&mut self, dir: &Path) -> Result<(), Error> {
+        for plugin in crate::plugins::load_dir(dir)? {
+            tracing::debug!(name = plugin.name(), path = %plugin.path().display(), "loaded plugin");
+            self.insert(Arc::new(plugin));
+        }
+        Ok(())
+    }
+
+    /// Wraps the targets so they render with their override layers: the
+    /// target's `<name>.toml` in `dir`, then the `user` layer, if given,
+    /// for every target.
+    ///
+    /// An override file naming no loaded target is an error, so a typo in
+    /// the file name does not go unnoticed. A missing `user` file is not.
+    pub fn apply_overrides(&mut self, dir: &Path, user: Option<&Path>) -> Result<(), Error> {
+        let mut layers: Vec<Vec<(PathBuf, Layer)>> = vec![Vec::new(); self.targets.len()];
+        for (name, path) in overrides::list_dir(dir)? {
+            let Some(index) = self.targets.iter().position(|t| t.name() == name) else {
+                return Err(Error::UnknownTarget {
+                    name,
+                    available: self.targets.iter().map(|t| t.name().to_owned()).collect(),
+                });
+            };
+            let layer = Layer::load(&path)?;
+            layers[index].push((path, layer));
+        }
+        if let Some(path) = user.filter(|path| path.is_file()) {
+            let layer = Layer::load(path)?;
+            for target_layers in &mut layers {
+                target_layers.push((path.to_owned(), layer.clone()));
+            }
+        }
+        for (target, layers) in self.targets.iter_mut().zip(layers) {
+            for (path, _) in &layers {
+                tracing::debug!(name = target.name(), layer = %path.display(), "using override layer");
+            }
+            if !layers.is_empty() {
+                *target = Arc::new(Overridden::new(target.clone(), layers));
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds `target`, replacing any target of the same name in its place.
+    pub fn insert(&mut self, target: Arc<dyn Target>) {
+        match self.targets.iter_mut().find(|t| t.name() == target.name()) {
+            Some(existing) => *existing = target,
+            None => self.targets.push(target),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn Target>> {
+        self.targets.iter()
+    }
+
+    /// Looks up a target by its command-line name.
+    pub fn find(&self, name: &str) -> Option<&Arc<dyn Target>> {
+        self.targets.iter().find(|target| target.name() == name)
+    }
+
+    /// Resolves command-line target names; an empty list selects every target.
+    pub fn select(&self, names: &[String]) -> Result<Vec<Arc<dyn Target>>, Error> {
+        if names.is_empty() {
+            return Ok(self.targets.clone());
+        }
+        names
+            .iter()
+            .map(|name| {
+                self.find(name)
+                    .cloned()
+                    .ok_or_else(|| Error::UnknownTarget {
+                        name: name.clone(),
+                        available: self.targets.iter().map(|t| t.name().to_owned()).collect(),
+                    })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,219 @@
+//! GNU nano: two color includes per variant setting the interface colors,
+//! one with nano's `#rgb` colors and one for 8-color terminals.
+//!
+//! nano's syntax files name their colors directly, so code is colored by the
+//! terminal palette and only the interface is themed here. In the `-8`
+//! includes each color becomes the
+//! nearest of the theme's own first eight terminal colors; bars that would
+//! vanish are reversed, and an element that would look like plain text is
+//! set in bold instead, nano having no underline attribute.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::contrast::contrast_ratio;
+use zero_trust_palette::difference::delta_e_ok;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use super::{ANSI_NAMES, TERMINAL};
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Nano;
+
+/// How an interface element is painted; a missing side keeps the
+/// terminal's default.
+#[derive(Clone, Copy)]
+struct Paint {
+    fg: Option<Role>,
+    bg: Option<Role>,
+    bold: bool,
+}
+
+impl Paint {
+    const fn fg(role: Role) -> Paint {
+        Paint {
+            fg: Some(role),
+            bg: None,
+            bold: false,
+        }
+    }
+
+    const fn fg_bg(fg: Role, bg: Role) -> Paint {
+        Paint {
+            fg: Some(fg),
+            bg: Some(bg),
+            bold: false,
+        }
+    }
+
+    const fn bold(self) -> Paint {
+        Paint { bold: true, ..self }
+    }
+}
+
+/// Interface color settings and how they are painted.
+const INTERFACE: &[(&str, Paint)] = &[
+    (
+        "titlecolor",
+        Paint::fg_bg(Role::Text, Role::Titlebar).bold(),
+    ),
+    ("promptcolor", Paint::fg_bg(Role::Text, Role::Surface)),
+    ("statuscolor", Paint::fg_bg(Role::Text, Role::Statusline)),
+    (
+        "errorcolor",
+        Paint::fg_bg(Role::Error, Role::ErrorBackground).bold(),
+    ),
+    ("minicolor", Paint::fg_bg(Role::TextMuted, Role::Statusline)),
+    ("selectedcolor", Paint::fg_bg(Role::Text, Role::Selection)),
+    (
+        "spotlightcolor",
+        Paint::fg_bg(Role::Text, Role::SearchMatch),
+    ),
+    ("stripecolor", Paint::fg_bg(Role::Guide, Role::Cursorline)),
+    ("scrollercolor", Paint::fg(Role::ScrollbarThumb)),
+    ("numbercolor", Paint::fg(Role::LineNumber)),
+    ("keycolor", Paint::fg(Role::Accent).bold()),
+    ("functioncolor", Paint::fg(Role::TextMuted)),
+];
+
+/// Which colors an include is written with.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Depth {
+    /// nano's three-digit hex colors, which it maps onto the terminal's
+    /// 256-color palette.
+    Hex,
+    Basic,
+}
+
+impl Depth {
+    /// The file stem suffix of includes in this depth.
+    fn suffix(self) -> &'static str {
+        match self {
+            Depth::Hex => "",
+            Depth::Basic => "-8",
+        }
+    }
+}
+
+/// `color` as `#rgb`, each channel rounded to four bits.
+fn hex(color: Color) -> String {
+    let nibble = |channel: u8| (u16::from(channel) + 8) / 17;
+    format!(
+        "#{:x}{:x}{:x}",
+        nibble(color.r),
+        nibble(color.g),
+        nibble(color.b)
+    )
+}
+
+/// The index of the nearest of the theme's first eight terminal colors.
+fn basic(theme: &Theme, color: Color) -> usize {
+    (0..ANSI_NAMES.len())
+        .min_by(|&x, &y| {
+            let dx = delta_e_ok(color, theme.opaque_color(TERMINAL[x]));
+            let dy = delta_e_ok(color, theme.opaque_color(TERMINAL[y]));
+            dx.total_cmp(&dy)
+        })
+        .expect("there are eight basic colors")
+}
+
+/// A setting's value, `[bold,]fg,bg` with a missing side left out.
+fn value(bold: bool, fg: Option<String>, bg: Option<String>) -> String {
+    let mut text = String::new();
+    if bold {
+        text.push_str("bold,");
+    }
+    if let Some(fg) = fg {
+        text.push_str(&fg);
+    }
+    if let Some(bg) = bg {
+        let _ = write!(text, ",{bg}");
+    }
+    text
+}
+
+fn setting(theme: &Theme, depth: Depth, paint: Paint) -> String {
+    let color = |role| theme.opaque_color(role);
+    if depth == Depth::Hex {
+        return value(
+            paint.bold,
+            paint.fg.map(|role| hex(color(role))),
+            paint.bg.map(|role| hex(color(role))),
+        );
+    }
+    let fg_color = color(paint.fg.unwrap_or(Role::Text));
+    let bg_color = color(paint.bg.unwrap_or(Role::Background));
+    let plain = (
+        basic(theme, color(Role::Text)),
+        basic(theme, color(Role::Background)),
+    );
+    let (mut fg, mut bg) = (basic(theme, fg_color), basic(theme, bg_color));
+    // A bar that would vanish into the editor background is shown in
+    // reverse video, as nano's own defaults are.
+    if paint.bg.is_some() && bg == plain.1 {
+        (fg, bg) = (bg, fg);
+    }
+    if fg == bg {
+        fg = (0..ANSI_NAMES.len())
+            .max_by(|&x, &y| {
+                let backdrop = color(TERMINAL[bg]);
+                let cx = contrast_ratio(color(TERMINAL[x]), backdrop);
+                let cy = contrast_ratio(color(TERMINAL[y]), backdrop);
+                cx.total_cmp(&cy)
+            })
+            .expect("there are eight basic colors");
+    }
+    let bold = paint.bold || (fg, bg) == plain;
+    value(
+        bold,
+        paint.fg.map(|_| ANSI_NAMES[fg].to_owned()),
+        paint.bg.map(|_| ANSI_NAMES[bg].to_owned()),
+    )
+}
+
+fn render(source: &Source, theme: &Theme, depth: Depth) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    for &(key, paint) in INTERFACE {
+        let _ = writeln!(text, "set {key} {}", setting(theme, depth, paint));
+    }
+    text
+}
+
+impl Target for Nano {
+    fn name(&self) -> &str {
+        "nano"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name());
+        let mut outputs = Vec::new();
+        for &variant in variants {
+            let theme = source.theme(variant);
+            for depth in [Depth::Hex, Depth::Basic] {
+                outputs.push(Output::new(
+                    dir.join(format!(
+                        "{}{}.nanorc",
+                        source.meta.file_stem(variant),
+                        depth.suffix()
+                    )),
+                    render(source, &theme, depth),
+                ));
+            }
+        }
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("nano");
+        Some(InstallDir {
+            themes: app.clone(),
+            app,
+        })
+    }
+}
@@ -0,0 +1,142 @@
+//! neomutt: one colorscheme per variant, with the index, headers, body,
+//! quoting levels, sidebar and status line.
+//!
+//! Quoting levels are a ramp stepping the hue of the info color around the
+//! OKLCH circle at its lightness and chroma, each step lifted to WCAG AA
+//! against the background, so nested replies stay apart while reading at
+//! the same weight. Colors are the quantizer's 256-color picks;
+//! backgrounds that match the terminal's are left as `default`. The file
+//! is sourced from `neomuttrc`.
+
+use std::fmt::Write;
+
+use zero_trust_palette::contrast::{ensure_contrast, AA};
+use zero_trust_palette::quantize::to_ansi256;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::space::Oklch;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Neomutt;
+
+/// The number of quoting levels colored, `quoted` through `quoted4`.
+const QUOTE_LEVELS: usize = 5;
+
+/// Hue step between quoting levels, in degrees.
+const QUOTE_STEP: f64 = 50.0;
+
+/// `color` commands: the object, the attributes, the roles of the
+/// foreground and background, and the pattern for objects taking one.
+const COLORS: &[(&str, &str, Role, Option<Role>, &str)] = &[
+    ("normal", "", Role::Text, None, ""),
+    ("indicator", "", Role::Text, Some(Role::ElementSelected), ""),
+    ("tree", "", Role::TextMuted, None, ""),
+    ("markers", "", Role::TextDisabled, None, ""),
+    ("tilde", "", Role::TextDisabled, None, ""),
+    ("search", "", Role::Background, Some(Role::SearchMatch), ""),
+    ("status", "", Role::TextMuted, Some(Role::Statusline), ""),
+    ("prompt", "", Role::Accent, None, ""),
+    ("message", "", Role::Info, None, ""),
+    ("warning", "", Role::Warning, None, ""),
+    ("error", "bold", Role::Error, None, ""),
+    ("signature", "", Role::Comment, None, ""),
+    ("attachment", "", Role::Keyword, None, ""),
+    ("hdrdefault", "", Role::TextMuted, None, ""),
+    ("header", "bold", Role::Text, None, "^Subject:"),
+    ("header", "", Role::Accent, None, "^From:"),
+    ("header", "", Role::Info, None, "^(To|Cc|Bcc):"),
+    ("header", "", Role::Comment, None, "^Date:"),
+    ("body", "", Role::Link, None, "(https?|ftp)://[^ >\\\"]+"),
+    ("body", "", Role::Link, None, "[-a-z_0-9.+]+@[-a-z_0-9.]+"),
+    ("index", "bold", Role::Accent, None, "~N"),
+    ("index", "", Role::Accent, None, "~U"),
+    ("index", "bold", Role::Warning, None, "~F"),
+    ("index", "", Role::Keyword, None, "~T"),
+    ("index", "", Role::ErrorMuted, None, "~D"),
+    ("index_author", "", Role::Function, None, "~A"),
+    ("index_date", "", Role::TextMuted, None, "~A"),
+    ("index_size", "", Role::TextMuted, None, "~A"),
+    ("sidebar_divider", "", Role::Border, None, ""),
+    ("sidebar_ordinary", "", Role::TextMuted, None, ""),
+    (
+        "sidebar_highlight",
+        "",
+        Role::Text,
+        Some(Role::ElementHover),
+        "",
+    ),
+    (
+        "sidebar_indicator",
+        "bold",
+        Role::Accent,
+        Some(Role::ElementSelected),
+        "",
+    ),
+    ("sidebar_new", "bold", Role::Accent, None, ""),
+    ("sidebar_unread", "", Role::Accent, None, ""),
+    ("sidebar_flagged", "", Role::Warning, None, ""),
+];
+
+/// The colors of the quoting levels, outermost first.
+fn quote_ramp(theme: &Theme) -> [Color; QUOTE_LEVELS] {
+    let background = theme.opaque_color(Role::Background);
+    let base = theme.opaque_color(Role::Info).to_oklch();
+    core::array::from_fn(|level| {
+        let h = (base.h + QUOTE_STEP * level as f64) % 360.0;
+        let color = Color::from_oklch(Oklch { h, ..base });
+        ensure_contrast(color, background, AA)
+    })
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    let background = theme.opaque_color(Role::Background);
+    let color = |color: Color| format!("color{}", to_ansi256(color));
+    let bg = |role: Option<Role>| match role {
+        Some(role) if theme.opaque_color(role) != background => color(theme.opaque_color(role)),
+        _ => "default".to_owned(),
+    };
+    for &(object, attributes, fg, back, pattern) in COLORS {
+        let mut command = format!("color {object}");
+        if !attributes.is_empty() {
+            let _ = write!(command, " {attributes}");
+        }
+        let _ = write!(command, " {} {}", color(theme.opaque_color(fg)), bg(back));
+        if !pattern.is_empty() {
+            let _ = write!(command, " \"{pattern}\"");
+        }
+        let _ = writeln!(text, "{command}");
+    }
+    for (level, quote) in quote_ramp(theme).into_iter().enumerate() {
+        let object = if level == 0 {
+            "quoted".to_owned()
+        } else {
+            format!("quoted{level}")
+        };
+        let _ = writeln!(text, "color {object} {} default", color(quote));
+    }
+    text
+}
+
+impl Target for Neomutt {
+    fn name(&self) -> &str {
+        "neomutt"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "neomuttrc"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,314 @@
+//! Neovim: one Lua colorscheme per variant, plus `zero-trust.lua` choosing
+//! the dark or light one from `'background'`.
+//!
+//! Besides the classic Vim groups the schemes set tree-sitter captures,
+//! LSP semantic token groups, diagnostics and the groups of telescope,
+//! gitsigns, nvim-cmp and rainbow-delimiters. Language rules become
+//! `@capture.<language>` groups, which Neovim prefers over the plain
+//! capture inside that language.
+//!
+//! rainbow-delimiters names its groups after colors, which the evenly
+//! spaced bracket hues do not line up with, so the levels get groups of
+//! their own instead, e.g. `ZeroTrustRainbow1` for the outermost pair, for
+//! `vim.g.rainbow_delimiters.highlight` to list.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::brackets::{self, DEFAULT_LEVELS};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use super::vim::{self, scheme_name, Spec, CLASSIC, UI};
+use crate::install::{Dirs, InstallDir};
+use crate::languages;
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Neovim;
+
+/// Tree-sitter captures and LSP semantic token groups, styled like the
+/// role that paints them.
+const CAPTURES: &[(&str, Role)] = &[
+    ("@comment", Role::Comment),
+    ("@comment.documentation", Role::CommentDoc),
+    ("@string", Role::String),
+    ("@string.escape", Role::StringEscape),
+    ("@string.regexp", Role::StringRegex),
+    ("@string.special", Role::StringSpecial),
+    ("@string.special.symbol", Role::StringSpecialSymbol),
+    ("@string.special.url", Role::LinkUri),
+    ("@character", Role::String),
+    ("@character.special", Role::StringEscape),
+    ("@number", Role::Number),
+    ("@number.float", Role::Number),
+    ("@boolean", Role::Boolean),
+    ("@constant", Role::Constant),
+    ("@constant.builtin", Role::Constant),
+    ("@constant.macro", Role::Constant),
+    ("@constructor", Role::Constructor),
+    ("@type", Role::Type),
+    ("@type.builtin", Role::Type),
+    ("@type.definition", Role::Type),
+    ("@attribute", Role::Attribute),
+    ("@property", Role::Property),
+    ("@variable", Role::Variable),
+    ("@variable.builtin", Role::VariableSpecial),
+    ("@variable.parameter", Role::Variable),
+    ("@variable.member", Role::Property),
+    ("@function", Role::Function),
+    ("@function.builtin", Role::Function),
+    ("@function.call", Role::Function),
+    ("@function.macro", Role::Function),
+    ("@function.method", Role::Function),
+    ("@keyword", Role::Keyword),
+    ("@keyword.operator", Role::Operator),
+    ("@keyword.directive", Role::Preproc),
+    ("@operator", Role::Operator),
+    ("@punctuation", Role::Punctuation),
+    ("@punctuation.delimiter", Role::PunctuationDelimiter),
+    ("@punctuation.bracket", Role::PunctuationBracket),
+    ("@punctuation.special", Role::PunctuationSpecial),
+    ("@tag", Role::Tag),
+    ("@tag.attribute", Role::Attribute),
+    ("@tag.delimiter", Role::PunctuationDelimiter),
+    ("@label", Role::Label),
+    ("@module", Role::Namespace),
+    ("@markup.heading", Role::Title),
+    ("@markup.strong", Role::EmphasisStrong),
+    ("@markup.italic", Role::Emphasis),
+    ("@markup.link", Role::LinkText),
+    ("@markup.link.url", Role::LinkUri),
+    ("@markup.raw", Role::TextLiteral),
+    ("@markup.list", Role::PunctuationListMarker),
+    ("@markup.quote", Role::Comment),
+    ("@diff.plus", Role::GitAdded),
+    ("@diff.minus", Role::GitDeleted),
+    ("@diff.delta", Role::GitModified),
+    ("@lsp.type.namespace", Role::Namespace),
+    ("@lsp.type.type", Role::Type),
+    ("@lsp.type.class", Role::Type),
+    ("@lsp.type.struct", Role::Type),
+    ("@lsp.type.interface", Role::Type),
+    ("@lsp.type.typeParameter", Role::Type),
+    ("@lsp.type.enum", Role::Enum),
+    ("@lsp.type.enumMember", Role::Variant),
+    ("@lsp.type.function", Role::Function),
+    ("@lsp.type.method", Role::Function),
+    ("@lsp.type.macro", Role::Function),
+    ("@lsp.type.decorator", Role::Attribute),
+    ("@lsp.type.parameter", Role::Variable),
+    ("@lsp.type.variable", Role::Variable),
+    ("@lsp.type.property", Role::Property),
+    ("@lsp.type.lifetime", Role::Label),
+    ("@lsp.type.selfKeyword", Role::VariableSpecial),
+    ("@lsp.type.comment", Role::Comment),
+    ("@lsp.type.string", Role::String),
+    ("@lsp.type.keyword", Role::Keyword),
+    ("@lsp.type.number", Role::Number),
+    ("@lsp.type.operator", Role::Operator),
+];
+
+/// LSP modifier groups that only change the font style, as in VS Code.
+const MODIFIERS: &[(&str, &str)] = &[
+    ("@lsp.mod.mutable", "underline"),
+    ("@lsp.mod.async", "italic"),
+    ("@lsp.mod.deprecated", "strikethrough"),
+];
+
+/// Diagnostics, LSP and plugin groups.
+const EXTRAS: &[(&str, Spec)] = &[
+    ("DiagnosticError", Spec::fg(Role::Error)),
+    ("DiagnosticWarn", Spec::fg(Role::Warning)),
+    ("DiagnosticInfo", Spec::fg(Role::Info)),
+    ("DiagnosticHint", Spec::fg(Role::Hint)),
+    ("DiagnosticOk", Spec::fg(Role::Success)),
+    ("DiagnosticUnderlineError", Spec::undercurl(Role::Error)),
+    ("DiagnosticUnderlineWarn", Spec::undercurl(Role::Warning)),
+    ("DiagnosticUnderlineInfo", Spec::undercurl(Role::Info)),
+    ("DiagnosticUnderlineHint", Spec::undercurl(Role::Hint)),
+    (
+        "DiagnosticVirtualTextError",
+        Spec::fg_bg(Role::Error, Role::ErrorBackground),
+    ),
+    (
+        "DiagnosticVirtualTextWarn",
+        Spec::fg_bg(Role::Warning, Role::WarningBackground),
+    ),
+    (
+        "DiagnosticVirtualTextInfo",
+        Spec::fg_bg(Role::Info, Role::InfoBackground),
+    ),
+    (
+        "DiagnosticVirtualTextHint",
+        Spec::fg_bg(Role::Hint, Role::HintBackground),
+    ),
+    ("DiagnosticUnnecessary", Spec::fg(Role::TextMuted)),
+    ("LspReferenceText", Spec::bg(Role::Highlight)),
+    ("LspReferenceRead", Spec::bg(Role::Highlight)),
+    ("LspReferenceWrite", Spec::bg(Role::Highlight)),
+    (
+        "LspInlayHint",
+        Spec::fg_bg(Role::Hint, Role::HintBackground),
+    ),
+    (
+        "LspSignatureActiveParameter",
+        Spec::bg(Role::Highlight).bold(),
+    ),
+    ("GitSignsAdd", Spec::fg(Role::GitAdded)),
+    ("GitSignsChange", Spec::fg(Role::GitModified)),
+    ("GitSignsDelete", Spec::fg(Role::GitDeleted)),
+    ("TelescopeNormal", Spec::link("NormalFloat")),
+    ("TelescopeBorder", Spec::link("FloatBorder")),
+    ("TelescopeTitle", Spec::fg(Role::Text).bold()),
+    ("TelescopeSelection", Spec::bg(Role::ElementSelected)),
+    ("TelescopeMatching", Spec::fg(Role::Accent).bold()),
+    ("TelescopePromptPrefix", Spec::fg(Role::Accent)),
+    ("CmpItemAbbr", Spec::fg(Role::Text)),
+    ("CmpItemAbbrMatch", Spec::fg(Role::Accent).bold()),
+    ("CmpItemAbbrMatchFuzzy", Spec::fg(Role::Accent)),
+    (
+        "CmpItemAbbrDeprecated",
+        Spec::fg(Role::TextDisabled).strikethrough(),
+    ),
+    ("CmpItemMenu", Spec::fg(Role::TextMuted)),
+    ("CmpItemKind", Spec::fg(Role::Icon)),
+    ("CmpItemKindFunction", Spec::link("@function")),
+    ("CmpItemKindMethod", Spec::link("@function.method")),
+    ("CmpItemKindVariable", Spec::link("@variable")),
+    ("CmpItemKindField", Spec::link("@property")),
+    ("CmpItemKindProperty", Spec::link("@property")),
+    ("CmpItemKindKeyword", Spec::link("@keyword")),
+    ("CmpItemKindClass", Spec::link("@type")),
+    ("CmpItemKindStruct", Spec::link("@type")),
+    ("CmpItemKindInterface", Spec::link("@type")),
+    ("CmpItemKindEnum", Spec::link("@lsp.type.enum")),
+    ("CmpItemKindEnumMember", Spec::link("@lsp.type.enumMember")),
+    ("CmpItemKindModule", Spec::link("@module")),
+    ("CmpItemKindConstant", Spec::link("@constant")),
+    ("CmpItemKindSnippet", Spec::fg(Role::Predictive)),
+];
+
+/// The group of bracket `level`, counted from 1 for the outermost pair:
+/// the theme's stem in camel case, e.g. `ZeroTrustRainbow1`.
+fn rainbow_group(meta: &Meta, level: usize) -> String {
+    let mut group: String = meta
+        .stem()
+        .split('-')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect();
+    let _ = write!(group, "Rainbow{level}");
+    group
+}
+
+fn set(text: &mut String, group: &str, spec: &str) {
+    let _ = writeln!(text, "hl(0, \"{group}\", {spec})");
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "-- {line}");
+    }
+    let _ = write!(
+        text,
+        "
+vim.cmd(\"highlight clear\")
+if vim.fn.exists(\"syntax_on\") == 1 then
+  vim.cmd(\"syntax reset\")
+end
+vim.o.background = \"{}\"
+vim.g.colors_name = \"{}\"
+
+local hl = vim.api.nvim_set_hl
+
+",
+        theme.appearance().name(),
+        scheme_name(&source.meta, theme.variant),
+    );
+    for &(group, spec) in UI {
+        set(&mut text, group, &vim::lua(theme, spec));
+    }
+    for &(group, role) in CLASSIC.iter().chain(CAPTURES) {
+        set(&mut text, group, &vim::lua(theme, Spec::role(theme, role)));
+    }
+    for &(group, attribute) in MODIFIERS {
+        set(&mut text, group, &format!("{{ {attribute} = true }}"));
+    }
+    set(
+        &mut text,
+        "@lsp.mod.unsafe",
+        &vim::lua(theme, Spec::fg(Role::VariableSpecial).bold()),
+    );
+    for &(group, spec) in EXTRAS {
+        set(&mut text, group, &vim::lua(theme, spec));
+    }
+    let rainbow: [Color; DEFAULT_LEVELS] = brackets::rainbow(theme);
+    for (level, color) in rainbow.into_iter().enumerate() {
+        let group = rainbow_group(&source.meta, level + 1);
+        set(&mut text, &group, &format!("{{ fg = \"{color}\" }}"));
+    }
+    for rule in languages::rules(source, theme.variant) {
+        for &(capture, _) in CAPTURES.iter().filter(|(_, role)| *role == rule.role) {
+            let spec = vim::style_lua(rule.opaque, rule.style);
+            set(&mut text, &format!("{capture}.{}", rule.language), &spec);
+        }
+    }
+    text.push('\n');
+    for (index, role) in super::TERMINAL.iter().enumerate() {
+        let _ = writeln!(
+            text,
+            "vim.g.terminal_color_{index} = \"{}\"",
+            theme.opaque_color(*role)
+        );
+    }
+    text
+}
+
+impl Target for Neovim {
+    fn name(&self) -> &str {
+        "neovim"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name()).join("colors");
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    dir.join(format!("{}.lua", scheme_name(&source.meta, variant))),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect();
+        if variants.contains(&Variant::Dark) && variants.contains(&Variant::Light) {
+            outputs.push(Output::new(
+                dir.join(format!("{}.lua", source.meta.stem())),
+                format!(
+                    "-- Follows 'background': {} or {}.\nvim.cmd.colorscheme(vim.o.background == \"light\" and \"{1}\" or \"{0}\")\n",
+                    scheme_name(&source.meta, Variant::Dark),
+                    scheme_name(&source.meta, Variant::Light),
+                ),
+            ));
+        }
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = if cfg!(windows) {
+            dirs.home.join("AppData/Local/nvim")
+        } else {
+            dirs.config.join("nvim")
+        };
+        Some(InstallDir {
+            themes: app.join("colors"),
+            app,
+        })
+    }
+}
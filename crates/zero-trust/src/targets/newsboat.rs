@@ -0,0 +1,121 @@
+//! newsboat: one `colors` block per variant for the feed and article lists,
+//! the info bars, the key hints and the article view, with `highlight`
+//! rules for the article headers and links.
+//!
+//! newsboat takes palette indices, so colors are the quantizer's 256-color
+//! picks. Plain text keeps the terminal's own background. The block is
+//! pulled into newsboat's `config` with `include`.
+
+use std::fmt::Write;
+
+use zero_trust_palette::quantize::to_ansi256;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Newsboat;
+
+/// Elements, the roles of their foreground and background, and their
+/// attributes.
+const COLORS: &[(&str, Role, Option<Role>, &str)] = &[
+    ("background", Role::Text, None, ""),
+    ("listnormal", Role::TextMuted, None, ""),
+    ("listnormal_unread", Role::Text, None, "bold"),
+    ("listfocus", Role::Text, Some(Role::ElementSelected), ""),
+    (
+        "listfocus_unread",
+        Role::Text,
+        Some(Role::ElementSelected),
+        "bold",
+    ),
+    ("info", Role::Text, Some(Role::Statusline), "bold"),
+    ("article", Role::Text, None, ""),
+    ("end-of-text-marker", Role::TextDisabled, None, ""),
+    ("title", Role::Accent, Some(Role::Statusline), "bold"),
+    ("hint-key", Role::Accent, Some(Role::Statusline), "bold"),
+    (
+        "hint-keys-delimiter",
+        Role::TextDisabled,
+        Some(Role::Statusline),
+        "",
+    ),
+    (
+        "hint-separator",
+        Role::TextDisabled,
+        Some(Role::Statusline),
+        "",
+    ),
+    (
+        "hint-description",
+        Role::TextMuted,
+        Some(Role::Statusline),
+        "",
+    ),
+];
+
+/// Article highlights: the pattern, the role of the foreground and the
+/// attributes.
+const HIGHLIGHTS: &[(&str, Role, &str)] = &[
+    ("^Feed:.*", Role::Comment, ""),
+    ("^Title:.*", Role::Accent, "bold"),
+    ("^Author:.*", Role::Function, ""),
+    ("^Date:.*", Role::TextMuted, ""),
+    ("^Link:.*", Role::Link, ""),
+    ("^Podcast Download URL:.*", Role::Link, ""),
+    ("(https?|ftp)://[^ ]+", Role::Link, "underline"),
+    ("\\\\[[0-9]+\\\\]", Role::Constant, ""),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    let color = |role| format!("color{}", to_ansi256(theme.opaque_color(role)));
+    let attributes = |attributes: &str| {
+        if attributes.is_empty() {
+            String::new()
+        } else {
+            format!(" {attributes}")
+        }
+    };
+    for &(element, fg, bg, attrs) in COLORS {
+        let bg = bg.map_or_else(|| "default".to_owned(), color);
+        let _ = writeln!(
+            text,
+            "color {element} {} {bg}{}",
+            color(fg),
+            attributes(attrs)
+        );
+    }
+    text.push('\n');
+    for &(pattern, fg, attrs) in HIGHLIGHTS {
+        let _ = writeln!(
+            text,
+            "highlight article \"{pattern}\" {} default{}",
+            color(fg),
+            attributes(attrs)
+        );
+    }
+    text
+}
+
+impl Target for Newsboat {
+    fn name(&self) -> &str {
+        "newsboat"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "newsboat"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
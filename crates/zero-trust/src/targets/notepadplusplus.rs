@@ -0,0 +1,296 @@
+//! Notepad++: one stylers XML per variant, with the global styles and the
+//! lexer styles of C, C++, Python and XML.
+//!
+//! Notepad++ names a theme after its file, so the files are named after the
+//! themes rather than the variant slugs. Markdown is highlighted by a
+//! user-defined language that keeps its own colors, so it is left out, and
+//! lexer styles are numbered per lexer rather than named after roles.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::package::escape_xml;
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct NotepadPlusPlus;
+
+/// How a style is painted.
+#[derive(Clone, Copy)]
+struct Paint {
+    fg: Option<Role>,
+    bg: Option<Role>,
+    bold: bool,
+    italic: bool,
+}
+
+impl Paint {
+    const fn fg(role: Role) -> Paint {
+        Paint {
+            fg: Some(role),
+            bg: None,
+            bold: false,
+            italic: false,
+        }
+    }
+
+    const fn bg(role: Role) -> Paint {
+        Paint {
+            fg: None,
+            bg: Some(role),
+            bold: false,
+            italic: false,
+        }
+    }
+
+    const fn fg_bg(fg: Role, bg: Role) -> Paint {
+        Paint {
+            fg: Some(fg),
+            bg: Some(bg),
+            bold: false,
+            italic: false,
+        }
+    }
+
+    const fn bold(self) -> Paint {
+        Paint { bold: true, ..self }
+    }
+}
+
+/// Global styles with their Scintilla style ids, 0 for those Notepad++
+/// applies itself, and how they are painted.
+const GLOBAL: &[(&str, u32, Paint)] = &[
+    (
+        "Default Style",
+        32,
+        Paint::fg_bg(Role::Text, Role::Background),
+    ),
+    (
+        "Indent guideline style",
+        37,
+        Paint::fg_bg(Role::Guide, Role::Background),
+    ),
+    (
+        "Brace highlight style",
+        34,
+        Paint::fg_bg(Role::Text, Role::Highlight).bold(),
+    ),
+    (
+        "Bad brace colour",
+        35,
+        Paint::fg_bg(Role::Error, Role::ErrorBackground),
+    ),
+    (
+        "Current line background colour",
+        0,
+        Paint::bg(Role::Cursorline),
+    ),
+    ("Selected text colour", 0, Paint::bg(Role::Selection)),
+    ("Caret colour", 2069, Paint::fg(Role::Cursor)),
+    (
+        "Line number margin",
+        33,
+        Paint::fg_bg(Role::LineNumber, Role::Gutter),
+    ),
+    ("Fold", 0, Paint::fg_bg(Role::TextMuted, Role::Gutter)),
+    ("Fold active", 0, Paint::fg(Role::Accent)),
+    ("Fold margin", 0, Paint::fg_bg(Role::Gutter, Role::Gutter)),
+    ("White space symbol", 0, Paint::fg(Role::Invisible)),
+    ("Smart HighLighting", 29, Paint::bg(Role::Highlight)),
+    ("Find Mark Style", 31, Paint::bg(Role::SearchMatch)),
+    (
+        "Incremental highlight all",
+        28,
+        Paint::bg(Role::SearchMatch),
+    ),
+    ("Tags match highlighting", 27, Paint::bg(Role::Highlight)),
+    ("Tags attribute", 26, Paint::bg(Role::Highlight)),
+    ("Edge colour", 0, Paint::fg(Role::Guide)),
+    ("Active tab focused indicator", 0, Paint::fg(Role::Accent)),
+    (
+        "Active tab unfocused indicator",
+        0,
+        Paint::fg(Role::BorderVariant),
+    ),
+    ("Active tab text", 0, Paint::fg(Role::Text)),
+    (
+        "Inactive tabs",
+        0,
+        Paint::fg_bg(Role::TextMuted, Role::TabInactive),
+    ),
+    ("URL hovered", 0, Paint::fg(Role::Link)),
+    (
+        "Document map",
+        0,
+        Paint::fg_bg(Role::Text, Role::ScrollbarThumb),
+    ),
+    ("EOL custom color", 0, Paint::fg(Role::Invisible)),
+];
+
+/// A lexer's styles with their Scintilla style ids and the roles that
+/// paint them.
+type Styles = &'static [(&'static str, u32, Role)];
+
+/// Styles of the C and C++ lexers, which share their style ids.
+const C_FAMILY: Styles = &[
+    ("DEFAULT", 11, Role::Text),
+    ("PREPROCESSOR", 9, Role::Preproc),
+    ("INSTRUCTION WORD", 5, Role::Keyword),
+    ("TYPE WORD", 16, Role::Type),
+    ("NUMBER", 4, Role::Number),
+    ("STRING", 6, Role::String),
+    ("CHARACTER", 7, Role::String),
+    ("OPERATOR", 10, Role::Operator),
+    ("VERBATIM", 13, Role::String),
+    ("REGEX", 14, Role::StringRegex),
+    ("COMMENT", 1, Role::Comment),
+    ("COMMENT LINE", 2, Role::Comment),
+    ("COMMENT DOC", 3, Role::CommentDoc),
+    ("COMMENT LINE DOC", 15, Role::CommentDoc),
+    ("COMMENT DOC KEYWORD", 17, Role::CommentDoc),
+    ("COMMENT DOC KEYWORD ERROR", 18, Role::Error),
+    ("PREPROCESSOR COMMENT", 23, Role::Comment),
+    ("PREPROCESSOR COMMENT DOC", 24, Role::CommentDoc),
+];
+
+const PYTHON: Styles = &[
+    ("DEFAULT", 0, Role::Text),
+    ("COMMENTLINE", 1, Role::Comment),
+    ("NUMBER", 2, Role::Number),
+    ("STRING", 3, Role::String),
+    ("CHARACTER", 4, Role::String),
+    ("KEYWORDS", 5, Role::Keyword),
+    ("TRIPLE", 6, Role::String),
+    ("TRIPLEDOUBLE", 7, Role::String),
+    ("CLASSNAME", 8, Role::Type),
+    ("DEFNAME", 9, Role::Function),
+    ("OPERATOR", 10, Role::Operator),
+    ("IDENTIFIER", 11, Role::Variable),
+    ("COMMENTBLOCK", 12, Role::Comment),
+    ("STRINGEOL", 13, Role::Error),
+    ("BUILTINS", 14, Role::VariableSpecial),
+    ("DECORATOR", 15, Role::Attribute),
+    ("F STRING", 16, Role::String),
+    ("F CHARACTER", 17, Role::String),
+    ("F TRIPLE", 18, Role::String),
+    ("F TRIPLEDOUBLE", 19, Role::String),
+];
+
+const XML: Styles = &[
+    ("XMLSTART", 12, Role::Preproc),
+    ("XMLEND", 13, Role::Preproc),
+    ("DEFAULT", 0, Role::Text),
+    ("COMMENT", 9, Role::Comment),
+    ("NUMBER", 5, Role::Number),
+    ("DOUBLESTRING", 6, Role::String),
+    ("SINGLESTRING", 7, Role::String),
+    ("TAG", 1, Role::Tag),
+    ("TAGEND", 11, Role::Tag),
+    ("TAGUNKNOWN", 2, Role::Tag),
+    ("ATTRIBUTE", 3, Role::Attribute),
+    ("ATTRIBUTEUNKNOWN", 4, Role::Attribute),
+    ("SGMLDEFAULT", 21, Role::Preproc),
+    ("CDATA", 17, Role::TextLiteral),
+    ("ENTITY", 10, Role::StringEscape),
+];
+
+/// Lexers by name and description, each with its styles. Bold and italics
+/// follow the role's style.
+const LEXERS: &[(&str, &str, Styles)] = &[
+    ("c", "C", C_FAMILY),
+    ("cpp", "C++", C_FAMILY),
+    ("python", "Python", PYTHON),
+    ("xml", "XML", XML),
+];
+
+/// Notepad++'s `fontStyle` bits.
+const BOLD: u8 = 1;
+const ITALIC: u8 = 2;
+
+/// The attributes of a style element.
+fn attributes(theme: &Theme, name: &str, id: u32, paint: Paint) -> String {
+    let hex = |role| theme.opaque_color(role).to_string()[1..7].to_owned();
+    let mut text = format!("name=\"{}\" styleID=\"{id}\"", escape_xml(name));
+    if let Some(fg) = paint.fg {
+        let _ = write!(text, " fgColor=\"{}\"", hex(fg));
+    }
+    if let Some(bg) = paint.bg {
+        let _ = write!(text, " bgColor=\"{}\"", hex(bg));
+    }
+    let font_style = if paint.bold { BOLD } else { 0 } | if paint.italic { ITALIC } else { 0 };
+    let _ = write!(
+        text,
+        " fontName=\"\" fontStyle=\"{font_style}\" fontSize=\"\""
+    );
+    text
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\" ?>\n<!--\n");
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "  {}", escape_xml(&line));
+    }
+    text.push_str("-->\n<NotepadPlus>\n    <LexerStyles>\n");
+    for &(lexer, desc, styles) in LEXERS {
+        let _ = writeln!(
+            text,
+            "        <LexerType name=\"{lexer}\" desc=\"{}\" ext=\"\">",
+            escape_xml(desc)
+        );
+        for &(name, id, role) in styles {
+            let style = theme.style(role);
+            let paint = Paint {
+                bold: style.is_bold(),
+                italic: style.italic,
+                ..Paint::fg_bg(role, Role::Background)
+            };
+            let _ = writeln!(
+                text,
+                "            <WordsStyle {} />",
+                attributes(theme, name, id, paint)
+            );
+        }
+        text.push_str("        </LexerType>\n");
+    }
+    text.push_str("    </LexerStyles>\n    <GlobalStyles>\n");
+    for &(name, id, paint) in GLOBAL {
+        let _ = writeln!(
+            text,
+            "        <WidgetStyle {} />",
+            attributes(theme, name, id, paint)
+        );
+    }
+    text.push_str("    </GlobalStyles>\n</NotepadPlus>\n");
+    text
+}
+
+impl Target for NotepadPlusPlus {
+    fn name(&self) -> &str {
+        "notepadplusplus"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name());
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                let theme = source.theme(variant);
+                Output::new(
+                    dir.join(format!("{}.xml", source.meta.theme_name(theme.variant))),
+                    render(source, &theme),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.app_data.join("Notepad++");
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
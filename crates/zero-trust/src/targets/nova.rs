@@ -0,0 +1,184 @@
+//! Nova: an extension bundle with one theme stylesheet per variant and the
+//! `extension.json` listing them.
+//!
+//! Nova blends translucent editor colors itself, so those keep their alpha.
+//! Nova's syntax selectors are shared by all languages. Extensions are
+//! installed through Nova's extension library rather than by copying files.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::package::url_owner;
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Nova;
+
+/// Interface selectors and their color properties.
+const CHROME: &[(&str, &[(&str, Role)])] = &[
+    (
+        "editor",
+        &[
+            ("background-color", Role::Background),
+            ("color", Role::Text),
+            ("cursor-color", Role::Cursor),
+            ("selection-color", Role::Selection),
+            ("line-highlight-color", Role::LineHighlight),
+            ("invisibles-color", Role::Invisible),
+            ("guide-color", Role::Guide),
+            ("guide-active-color", Role::GuideActive),
+            ("find-highlight-color", Role::SearchMatch),
+            ("bracket-highlight-color", Role::Highlight),
+        ],
+    ),
+    (
+        "editor.gutter",
+        &[
+            ("background-color", Role::Gutter),
+            ("color", Role::LineNumber),
+            ("active-color", Role::LineNumberActive),
+        ],
+    ),
+    (
+        "sidebar",
+        &[
+            ("background-color", Role::Panel),
+            ("color", Role::Text),
+            ("selection-color", Role::ElementSelected),
+        ],
+    ),
+    (
+        "tabs",
+        &[
+            ("background-color", Role::Tabline),
+            ("color", Role::TextMuted),
+            ("active-background-color", Role::TabActive),
+            ("active-color", Role::Text),
+        ],
+    ),
+];
+
+/// Syntax selectors and the roles that paint them. Bold and italics follow
+/// the role's style.
+const SYNTAX: &[(&str, Role)] = &[
+    ("comment", Role::Comment),
+    ("comment.doc", Role::CommentDoc),
+    ("processing", Role::Preproc),
+    ("keyword", Role::Keyword),
+    ("keyword.operator", Role::Operator),
+    ("string", Role::String),
+    ("string.escape", Role::StringEscape),
+    ("string.regex", Role::StringRegex),
+    ("value", Role::Constant),
+    ("value.number", Role::Number),
+    ("value.boolean", Role::Boolean),
+    ("value.null", Role::Constant),
+    ("identifier", Role::Variable),
+    ("identifier.core", Role::VariableSpecial),
+    ("identifier.constant", Role::Constant),
+    ("identifier.function", Role::Function),
+    ("identifier.method", Role::Function),
+    ("identifier.type", Role::Type),
+    ("identifier.property", Role::Property),
+    ("identifier.argument", Role::Variable),
+    ("identifier.decorator", Role::Attribute),
+    ("tag", Role::Tag),
+    ("tag.attribute.name", Role::Attribute),
+    ("tag.attribute.value", Role::String),
+    ("bracket", Role::PunctuationBracket),
+    ("markup.heading", Role::Title),
+    ("markup.bold", Role::EmphasisStrong),
+    ("markup.italic", Role::Emphasis),
+    ("markup.link", Role::LinkUri),
+    ("markup.code", Role::TextLiteral),
+    ("markup.list.item", Role::PunctuationListMarker),
+    ("invalid", Role::Error),
+];
+
+/// The stylesheet file name of `variant` below `Themes/`.
+fn file_name(meta: &Meta, variant: Variant) -> String {
+    format!("{}.css", meta.file_stem(variant))
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::from("/*\n");
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, " * {line}");
+    }
+    let _ = writeln!(
+        text,
+        " */\n\n@meta {{\n\tname: \"{}\";\n\tauthor: \"{}\";\n\ttheme-type: {};\n}}",
+        source.meta.theme_name(theme.variant),
+        source.meta.author,
+        theme.appearance().name()
+    );
+    for &(selector, properties) in CHROME {
+        let _ = writeln!(text, "\n{selector} {{");
+        for &(property, role) in properties {
+            let _ = writeln!(text, "\t{property}: {};", theme.color(role));
+        }
+        text.push_str("}\n");
+    }
+    for &(selector, role) in SYNTAX {
+        let style = theme.style(role);
+        let _ = writeln!(
+            text,
+            "\n{selector} {{\n\tcolor: {};",
+            theme.opaque_color(role)
+        );
+        if style.is_bold() {
+            text.push_str("\tfont-weight: bold;\n");
+        }
+        if style.italic {
+            text.push_str("\tfont-style: italic;\n");
+        }
+        text.push_str("}\n");
+    }
+    text
+}
+
+impl Target for Nova {
+    fn name(&self) -> &str {
+        "nova"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let meta = &source.meta;
+        let bundle_name = meta.name.replace(' ', "");
+        let bundle: PathBuf = Path::new("extras")
+            .join(self.name())
+            .join(format!("{bundle_name}.novaextension"));
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    bundle.join("Themes").join(file_name(&source.meta, variant)),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect();
+        let owner = url_owner(&meta.homepage).unwrap_or(&meta.author);
+        let themes: Vec<String> = variants
+            .iter()
+            .map(|&variant| file_name(&source.meta, variant))
+            .collect();
+        outputs.push(Output::json(
+            bundle.join("extension.json"),
+            &json!({
+                "identifier": format!("{}.{bundle_name}", owner.to_lowercase().replace(' ', "")),
+                "name": meta.name,
+                "organization": meta.author,
+                "description": format!("{} for Nova.", meta.name),
+                "version": meta.version,
+                "license": meta.license,
+                "repository": meta.homepage,
+                "categories": ["themes"],
+                "themes": themes,
+            }),
+        ));
+        Ok(outputs)
+    }
+}
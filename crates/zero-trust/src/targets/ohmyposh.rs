@@ -0,0 +1,101 @@
+//! oh-my-posh: one prompt theme per variant, with a palette named like the
+//! starship target's and segments referring to it as `p:<name>`.
+//!
+//! The prompt shows the SSH session, the path, the git head and slow
+//! commands' run time on one line and a `❯` on the next, turning error
+//! colored after a failed command. The theme is passed to
+//! `oh-my-posh init` with `--config`.
+
+use serde_json::{json, Map, Value};
+use zero_trust_palette::{Theme, Variant};
+
+use super::starship::COLORS;
+use crate::{Error, Output, Source, Target};
+
+pub struct OhMyPosh;
+
+const SCHEMA: &str =
+    "https://raw.githubusercontent.com/JanDeDobbeleer/oh-my-posh/main/themes/schema.json";
+
+fn render(theme: &Theme) -> Value {
+    let palette: Map<String, Value> = COLORS
+        .iter()
+        .map(|&(name, role)| (name.to_owned(), json!(theme.opaque_color(role).to_string())))
+        .collect();
+    json!({
+        "$schema": SCHEMA,
+        "version": 2,
+        "final_space": true,
+        "palette": palette,
+        "blocks": [
+            {
+                "type": "prompt",
+                "alignment": "left",
+                "segments": [
+                    {
+                        "type": "session",
+                        "style": "plain",
+                        "foreground": "p:function",
+                        "template": "{{ if .SSHSession }}{{ .UserName }}@{{ .HostName }} {{ end }}"
+                    },
+                    {
+                        "type": "path",
+                        "style": "plain",
+                        "foreground": "p:accent",
+                        "template": "{{ .Path }} ",
+                        "properties": { "style": "full" }
+                    },
+                    {
+                        "type": "git",
+                        "style": "plain",
+                        "foreground": "p:keyword",
+                        "foreground_templates": [
+                            "{{ if or (.Working.Changed) (.Staging.Changed) }}p:modified{{ end }}"
+                        ],
+                        "template": "{{ .HEAD }} ",
+                        "properties": { "fetch_status": true }
+                    },
+                    {
+                        "type": "executiontime",
+                        "style": "plain",
+                        "foreground": "p:warning",
+                        "template": "{{ .FormattedMs }} ",
+                        "properties": { "threshold": 2000 }
+                    }
+                ]
+            },
+            {
+                "type": "prompt",
+                "alignment": "left",
+                "newline": true,
+                "segments": [
+                    {
+                        "type": "text",
+                        "style": "plain",
+                        "foreground": "p:accent",
+                        "foreground_templates": ["{{ if gt .Code 0 }}p:error{{ end }}"],
+                        "template": "❯"
+                    }
+                ]
+            }
+        ]
+    })
+}
+
+impl Target for OhMyPosh {
+    fn name(&self) -> &str {
+        "ohmyposh"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::json(
+                    super::extras_path(&source.meta, self.name(), variant, "omp.json"),
+                    &render(&source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,77 @@
+//! PSReadLine: one PowerShell profile snippet per variant calling
+//! `Set-PSReadLineOption -Colors` with the syntax roles.
+//!
+//! Colors are written as truecolor escape sequences rather than console
+//! color names, built from `[char]27` so the snippet also runs in Windows
+//! PowerShell 5.1, which has no `` `e `` escape. The snippet is dot-sourced
+//! from `$PROFILE`.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct PsReadLine;
+
+/// Color keys, the roles that paint them and whether the role is painted
+/// as the background.
+const COLORS: &[(&str, Role, bool)] = &[
+    ("Default", Role::Text, false),
+    ("Command", Role::Function, false),
+    ("Comment", Role::Comment, false),
+    ("ContinuationPrompt", Role::TextMuted, false),
+    ("Emphasis", Role::Accent, false),
+    ("Error", Role::Error, false),
+    ("InlinePrediction", Role::Predictive, false),
+    ("Keyword", Role::Keyword, false),
+    ("ListPrediction", Role::Accent, false),
+    ("ListPredictionSelected", Role::ElementSelected, true),
+    ("ListPredictionTooltip", Role::TextMuted, false),
+    ("Member", Role::Property, false),
+    ("Number", Role::Number, false),
+    ("Operator", Role::Operator, false),
+    ("Parameter", Role::Attribute, false),
+    ("Selection", Role::Selection, true),
+    ("String", Role::String, false),
+    ("Type", Role::Type, false),
+    ("Variable", Role::Variable, false),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push_str("\n$esc = [char]27\nSet-PSReadLineOption -Colors @{\n");
+    for &(key, role, background) in COLORS {
+        let color = theme.opaque_color(role);
+        let layer = if background { 48 } else { 38 };
+        let _ = writeln!(
+            text,
+            "    {key} = \"${{esc}}[{layer};2;{};{};{}m\"",
+            color.r, color.g, color.b
+        );
+    }
+    text.push_str("}\nRemove-Variable esc\n");
+    text
+}
+
+impl Target for PsReadLine {
+    fn name(&self) -> &str {
+        "psreadline"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "ps1"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,87 @@
+//! Rio: one theme per variant, picked by file stem with `theme` in
+//! `config.toml`.
+//!
+//! Rio names the ANSI colors with `light-` and `dim-` prefixes for the
+//! bright and faint ones.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::{ANSI_NAMES, TERMINAL, TERMINAL_DIM};
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Rio;
+
+/// Keys other than the ANSI colors and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("background", Role::TerminalBackground),
+    ("foreground", Role::TerminalForeground),
+    ("cursor", Role::Cursor),
+    ("vi-cursor", Role::Accent),
+    ("selection-background", Role::Selection),
+    ("selection-foreground", Role::TerminalForeground),
+    ("tabs", Role::TabInactive),
+    ("tabs-foreground", Role::TextMuted),
+    ("tabs-active", Role::TabActive),
+    ("tabs-active-foreground", Role::Text),
+    ("tabs-active-highlight", Role::Accent),
+    ("bar", Role::Tabline),
+    ("split", Role::Split),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push_str("\n[colors]\n");
+    let hex = |role| theme.opaque_color(role);
+    for &(key, role) in COLORS {
+        let _ = writeln!(text, "{key} = \"{}\"", hex(role));
+    }
+    let ansi: [(&str, &[Role]); 3] = [
+        ("", &TERMINAL[..8]),
+        ("light-", &TERMINAL[8..]),
+        ("dim-", &TERMINAL_DIM),
+    ];
+    for (prefix, roles) in ansi {
+        text.push('\n');
+        for (name, &role) in ANSI_NAMES.iter().zip(roles) {
+            let _ = writeln!(text, "{prefix}{name} = \"{}\"", hex(role));
+        }
+    }
+    text
+}
+
+impl Target for Rio {
+    fn name(&self) -> &str {
+        "rio"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "toml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = if cfg!(windows) {
+            dirs.data().join("rio")
+        } else {
+            dirs.config.join("rio")
+        };
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
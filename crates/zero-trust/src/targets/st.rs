@@ -0,0 +1,93 @@
+//! st: one `config.h` snippet per variant, replacing the `colorname` array
+//! and the default color indices of st's own `config.h`.
+//!
+//! Each color is commented with its nearest index in the 256-color cube
+//! and gray ramp, for builds and patches that only take indices. st is
+//! configured at compile time, so the snippet goes into its source tree.
+
+use std::fmt::Write;
+
+use zero_trust_palette::quantize::to_ansi256;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::{ANSI_NAMES, TERMINAL};
+use crate::{Error, Output, Source, Target};
+
+pub struct St;
+
+/// Colors appended after index 255, with their `config.h` variable and
+/// role. Their indices follow from the order.
+const DEFAULTS: &[(&str, &str, Role)] = &[
+    ("defaultcs", "cursor", Role::Cursor),
+    ("defaultrcs", "reverse cursor", Role::TerminalBackground),
+    ("defaultfg", "foreground", Role::TerminalForeground),
+    ("defaultbg", "background", Role::TerminalBackground),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::from("/*\n");
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, " * {line}");
+    }
+    text.push_str(
+        " */\n\n/* Terminal colors (16 first used in escape sequence) */\n\
+         static const char *colorname[] = {\n",
+    );
+    for (group, start) in [("normal", 0), ("bright", 8)] {
+        let _ = writeln!(text, "\t/* 8 {group} colors */");
+        for (offset, name) in ANSI_NAMES.iter().enumerate() {
+            let index = start + offset;
+            let color = theme.opaque_color(TERMINAL[index]);
+            let _ = writeln!(
+                text,
+                "\t\"{color}\", /* {index}: {group} {name}, 256-color {} */",
+                to_ansi256(color)
+            );
+        }
+        text.push('\n');
+    }
+    text.push_str(
+        "\t[255] = 0,\n\n\t/* more colors can be added after 255 to use with DefaultXX */\n",
+    );
+    for (offset, &(_, label, role)) in DEFAULTS.iter().enumerate() {
+        let color = theme.opaque_color(role);
+        let _ = writeln!(
+            text,
+            "\t\"{color}\", /* {}: {label}, 256-color {} */",
+            256 + offset,
+            to_ansi256(color)
+        );
+    }
+    text.push_str(
+        "};\n\n/*\n * Default colors (colorname index)\n \
+         * foreground, background, cursor, reverse cursor\n */\n",
+    );
+    for name in ["defaultfg", "defaultbg", "defaultcs", "defaultrcs"] {
+        let index = DEFAULTS
+            .iter()
+            .position(|&(variable, ..)| variable == name)
+            .expect("every default has a color");
+        let qualifier = if name == "defaultrcs" { "static " } else { "" };
+        let _ = writeln!(text, "{qualifier}unsigned int {name} = {};", 256 + index);
+    }
+    text
+}
+
+impl Target for St {
+    fn name(&self) -> &str {
+        "st"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "h"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,141 @@
+//! starship: one `palettes.zero_trust` table per variant, plus
+//! `format.toml` with module styles written in the palette's color names.
+//!
+//! Every variant names its palette `zero_trust`, so switching variants means
+//! swapping the palette table while the module styles stay as they are; the
+//! styles file is the same for every variant and is written once. starship
+//! reads a single config file, so both are meant to be pasted into the
+//! user's own `starship.toml`.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Starship;
+
+/// The name of the palette in every variant.
+const PALETTE: &str = "zero_trust";
+
+/// Palette color names and the roles that paint them, shared with the
+/// oh-my-posh palette.
+pub(super) const COLORS: &[(&str, Role)] = &[
+    ("background", Role::Background),
+    ("surface", Role::SurfaceElevated),
+    ("text", Role::Text),
+    ("muted", Role::TextMuted),
+    ("disabled", Role::TextDisabled),
+    ("border", Role::Border),
+    ("accent", Role::Accent),
+    ("link", Role::Link),
+    ("error", Role::Error),
+    ("warning", Role::Warning),
+    ("info", Role::Info),
+    ("success", Role::Success),
+    ("hint", Role::Hint),
+    ("added", Role::GitAdded),
+    ("modified", Role::GitModified),
+    ("deleted", Role::GitDeleted),
+    ("keyword", Role::Keyword),
+    ("function", Role::Function),
+    ("type", Role::Type),
+    ("string", Role::String),
+    ("constant", Role::Constant),
+    ("comment", Role::Comment),
+];
+
+/// Module options of `format.toml`, each a table with its keys and values.
+const MODULES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "character",
+        &[
+            ("success_symbol", "[❯](bold accent)"),
+            ("error_symbol", "[❯](bold error)"),
+            ("vimcmd_symbol", "[❮](bold success)"),
+        ],
+    ),
+    (
+        "directory",
+        &[("style", "bold accent"), ("read_only_style", "error")],
+    ),
+    ("git_branch", &[("style", "bold keyword")]),
+    ("git_commit", &[("style", "bold constant")]),
+    ("git_state", &[("style", "bold warning")]),
+    ("git_status", &[("style", "bold modified")]),
+    (
+        "git_metrics",
+        &[
+            ("added_style", "bold added"),
+            ("deleted_style", "bold deleted"),
+        ],
+    ),
+    ("cmd_duration", &[("style", "warning")]),
+    ("jobs", &[("style", "bold info")]),
+    ("status", &[("style", "bold error")]),
+    (
+        "username",
+        &[
+            ("style_user", "bold function"),
+            ("style_root", "bold error"),
+        ],
+    ),
+    ("hostname", &[("style", "bold type")]),
+    ("package", &[("style", "bold constant")]),
+    ("time", &[("style", "muted")]),
+];
+
+fn header(text: &mut String, title: &str, source: &Source) {
+    for line in source.meta.header(title) {
+        let _ = writeln!(text, "# {line}");
+    }
+}
+
+fn palette(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    header(&mut text, &source.meta.theme_name(theme.variant), source);
+    let _ = writeln!(text, "\npalette = \"{PALETTE}\"\n\n[palettes.{PALETTE}]");
+    for &(name, role) in COLORS {
+        let _ = writeln!(text, "{name} = \"{}\"", theme.opaque_color(role));
+    }
+    text
+}
+
+fn format(source: &Source) -> String {
+    let mut text = String::new();
+    header(&mut text, &source.meta.name, source);
+    for &(module, options) in MODULES {
+        let _ = writeln!(text, "\n[{module}]");
+        for &(key, value) in options {
+            let _ = writeln!(text, "{key} = \"{value}\"");
+        }
+    }
+    text
+}
+
+impl Target for Starship {
+    fn name(&self) -> &str {
+        "starship"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "toml"),
+                    palette(source, &source.theme(variant)),
+                )
+            })
+            .collect();
+        if !variants.is_empty() {
+            outputs.push(Output::new(
+                Path::new("extras").join(self.name()).join("format.toml"),
+                format(source),
+            ));
+        }
+        Ok(outputs)
+    }
+}
@@ -0,0 +1,188 @@
+//! Sublime Text: one `.sublime-color-scheme` file per variant, with
+//! editor globals and scope rules from the shared TextMate scopes.
+//!
+//! The scheme leaves `selection_foreground` unset so selected code keeps
+//! its syntax colors. Rules whose color would be hard to read on the
+//! selection get a `foreground_adjust` instead, which Sublime applies to
+//! selected text only.
+
+use serde_json::{json, Map, Value};
+use zero_trust_palette::contrast::{contrast_ratio, ensure_contrast};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use super::textmate::{self, SCOPES};
+use crate::install::{Dirs, InstallDir};
+use crate::languages;
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Sublime;
+
+/// The minimum contrast of a selected token against the selection.
+const SELECTED_CONTRAST: f64 = 3.0;
+
+/// Global color keys and the roles that paint them, in file order.
+/// Sublime blends translucent globals itself, so they keep their alpha.
+const GLOBALS: &[(&str, Role)] = &[
+    ("background", Role::Background),
+    ("foreground", Role::Text),
+    ("invisibles", Role::Invisible),
+    ("caret", Role::Cursor),
+    ("line_highlight", Role::LineHighlight),
+    ("misspelling", Role::Error),
+    ("fold_marker", Role::TextMuted),
+    ("accent", Role::Accent),
+    ("gutter", Role::Gutter),
+    ("gutter_foreground", Role::LineNumber),
+    ("gutter_foreground_highlight", Role::LineNumberActive),
+    ("line_diff_added", Role::GitAdded),
+    ("line_diff_modified", Role::GitModified),
+    ("line_diff_deleted", Role::GitDeleted),
+    ("selection", Role::Selection),
+    ("inactive_selection", Role::ElementSelected),
+    ("highlight", Role::Highlight),
+    ("find_highlight", Role::SearchMatch),
+    ("find_highlight_foreground", Role::Text),
+    ("scroll_highlight", Role::ScrollbarThumb),
+    ("scroll_selected_highlight", Role::ScrollbarThumbHover),
+    ("guide", Role::Guide),
+    ("active_guide", Role::GuideActive),
+    ("stack_guide", Role::Guide),
+    ("brackets_foreground", Role::PunctuationBracket),
+    ("bracket_contents_foreground", Role::PunctuationBracket),
+    ("tags_foreground", Role::Tag),
+];
+
+/// Font style keys that are options rather than colors.
+const OPTIONS: &[(&str, &str)] = &[
+    ("brackets_options", "underline"),
+    ("bracket_contents_options", "underline"),
+    ("tags_options", "stippled_underline"),
+];
+
+fn color(color: Color) -> Value {
+    Value::String(color.to_string())
+}
+
+/// The HSL lightness of `color`, in percent, the scale Sublime's `l()`
+/// adjuster works on.
+fn lightness(color: Color) -> f64 {
+    let (max, min) = [color.r, color.g, color.b]
+        .into_iter()
+        .fold((0, u8::MAX), |(max, min), c| (max.max(c), min.min(c)));
+    (max as f64 + min as f64) / 2.0 / 255.0 * 100.0
+}
+
+/// The `foreground_adjust` keeping `foreground` readable on the
+/// selection, if it needs one.
+fn selected_adjust(theme: &Theme, foreground: Color) -> Option<String> {
+    let selection = theme.opaque_color(Role::Selection);
+    if contrast_ratio(foreground, selection) >= SELECTED_CONTRAST {
+        return None;
+    }
+    let adjusted = ensure_contrast(foreground, selection, SELECTED_CONTRAST);
+    let delta = (lightness(adjusted) - lightness(foreground)).round();
+    if delta == 0.0 {
+        return None;
+    }
+    let sign = if delta > 0.0 { '+' } else { '-' };
+    Some(format!("l({sign} {}%)", delta.abs()))
+}
+
+fn rule(
+    theme: &Theme,
+    name: String,
+    scopes: &[String],
+    foreground: Color,
+    font_style: String,
+) -> Value {
+    let mut rule = Map::new();
+    rule.insert("name".into(), name.into());
+    rule.insert("scope".into(), scopes.join(", ").into());
+    rule.insert("foreground".into(), color(foreground));
+    if !font_style.is_empty() {
+        rule.insert("font_style".into(), font_style.into());
+    }
+    if let Some(adjust) = selected_adjust(theme, foreground) {
+        rule.insert("foreground_adjust".into(), adjust.into());
+    }
+    Value::Object(rule)
+}
+
+fn globals(theme: &Theme) -> Map<String, Value> {
+    let mut globals = Map::new();
+    for &(key, role) in GLOBALS {
+        globals.insert(key.into(), color(theme.color(role)));
+    }
+    for &(key, option) in OPTIONS {
+        globals.insert(key.into(), option.into());
+    }
+    globals
+}
+
+fn rules(source: &Source, theme: &Theme) -> Vec<Value> {
+    let mut rules: Vec<Value> = SCOPES
+        .iter()
+        .map(|&(role, scopes)| {
+            let scopes: Vec<String> = scopes.iter().map(|&scope| scope.into()).collect();
+            rule(
+                theme,
+                role.name().into(),
+                &scopes,
+                theme.opaque_color(role),
+                textmate::font_style(theme.style(role)),
+            )
+        })
+        .collect();
+    for language_rule in languages::rules(source, theme.variant) {
+        let scopes = textmate::language_scopes(language_rule.role, &language_rule.language);
+        if scopes.is_empty() {
+            continue;
+        }
+        rules.push(rule(
+            theme,
+            format!("{} ({})", language_rule.role.name(), language_rule.language),
+            &scopes,
+            language_rule.opaque,
+            textmate::font_style(language_rule.style),
+        ));
+    }
+    rules
+}
+
+impl Target for Sublime {
+    fn name(&self) -> &str {
+        "sublime"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                let theme = source.theme(variant);
+                let file = json!({
+                    "name": source.meta.theme_name(theme.variant),
+                    "author": source.meta.author,
+                    "globals": globals(&theme),
+                    "rules": rules(source, &theme),
+                });
+                Output::json(
+                    super::extras_path(&source.meta, self.name(), variant, "sublime-color-scheme"),
+                    &file,
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = if cfg!(any(target_os = "macos", windows)) {
+            dirs.app_data.join("Sublime Text")
+        } else {
+            dirs.app_data.join("sublime-text")
+        };
+        Some(InstallDir {
+            themes: app.join("Packages").join("User"),
+            app,
+        })
+    }
+}
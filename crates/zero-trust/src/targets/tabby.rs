@@ -0,0 +1,68 @@
+//! Tabby: one `config.yaml` snippet per variant, setting the color scheme
+//! of dark mode or, for light themes, of light mode.
+//!
+//! Tabby keeps its schemes inline in the config rather than in files of
+//! their own.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Appearance, Theme, Variant};
+
+use super::TERMINAL;
+use crate::{Error, Output, Source, Target};
+
+pub struct Tabby;
+
+/// Scheme keys other than `colors` and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("foreground", Role::TerminalForeground),
+    ("background", Role::TerminalBackground),
+    ("cursor", Role::Cursor),
+    ("cursorForeground", Role::TerminalBackground),
+    ("selection", Role::Selection),
+    ("selectionForeground", Role::TerminalForeground),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    let key = match theme.appearance() {
+        Appearance::Dark => "colorScheme",
+        Appearance::Light => "lightColorScheme",
+    };
+    let _ = writeln!(
+        text,
+        "\nterminal:\n  {key}:\n    name: {}",
+        source.meta.theme_name(theme.variant)
+    );
+    let hex = |role| theme.opaque_color(role);
+    for &(key, role) in COLORS {
+        let _ = writeln!(text, "    {key}: '{}'", hex(role));
+    }
+    text.push_str("    colors:\n");
+    for role in TERMINAL {
+        let _ = writeln!(text, "      - '{}'", hex(role));
+    }
+    text
+}
+
+impl Target for Tabby {
+    fn name(&self) -> &str {
+        "tabby"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "yaml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,142 @@
+//! Taskwarrior: one theme per variant, pulled into `.taskrc` with an
+//! `include` line.
+//!
+//! Priorities, due dates and task states are painted from the state ramps:
+//! overdue tasks in emphasized error, tasks due today in emphasized warning
+//! and upcoming ones in plain warning, with finished and deleted tasks in
+//! the muted steps and the active task on the success tint. Taskwarrior
+//! only knows the 256-color palette, so colors are the quantizer's picks.
+
+use std::fmt::Write;
+
+use zero_trust_palette::quantize::to_ansi256;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Taskwarrior;
+
+/// Color settings: the key, the attributes, and the roles of the
+/// foreground and background.
+const COLORS: &[(&str, &str, Option<Role>, Option<Role>)] = &[
+    // Tasks.
+    ("color.overdue", "bold", Some(Role::ErrorEmphasized), None),
+    (
+        "color.due.today",
+        "bold",
+        Some(Role::WarningEmphasized),
+        None,
+    ),
+    ("color.due", "", Some(Role::Warning), None),
+    ("color.scheduled", "", Some(Role::InfoEmphasized), None),
+    ("color.uda.priority.H", "bold", Some(Role::Error), None),
+    ("color.uda.priority.M", "", Some(Role::Warning), None),
+    ("color.uda.priority.L", "", Some(Role::InfoMuted), None),
+    (
+        "color.active",
+        "bold",
+        Some(Role::SuccessEmphasized),
+        Some(Role::SuccessBackground),
+    ),
+    ("color.blocking", "bold", Some(Role::Warning), None),
+    ("color.blocked", "", Some(Role::WarningMuted), None),
+    ("color.recurring", "", Some(Role::Info), None),
+    ("color.tagged", "", Some(Role::Info), None),
+    ("color.tag.next", "bold", Some(Role::Success), None),
+    ("color.completed", "", Some(Role::SuccessMuted), None),
+    ("color.deleted", "", Some(Role::ErrorMuted), None),
+    // Reports.
+    ("color.header", "", Some(Role::Accent), None),
+    ("color.footnote", "", Some(Role::Info), None),
+    ("color.label", "", Some(Role::TextMuted), None),
+    ("color.label.sort", "bold", Some(Role::Text), None),
+    ("color.alternate", "", None, Some(Role::Cursorline)),
+    ("color.warning", "bold", Some(Role::Warning), None),
+    ("color.error", "", Some(Role::Error), None),
+    ("color.debug", "", Some(Role::TextMuted), None),
+    ("color.summary.bar", "", None, Some(Role::Success)),
+    (
+        "color.summary.background",
+        "",
+        None,
+        Some(Role::ElementActive),
+    ),
+    ("color.sync.added", "", Some(Role::Success), None),
+    ("color.sync.changed", "", Some(Role::Warning), None),
+    ("color.sync.rejected", "", Some(Role::Error), None),
+    ("color.undo.before", "", Some(Role::GitDeleted), None),
+    ("color.undo.after", "", Some(Role::GitAdded), None),
+    // Calendar.
+    (
+        "color.calendar.today",
+        "bold",
+        Some(Role::Background),
+        Some(Role::Accent),
+    ),
+    (
+        "color.calendar.due.today",
+        "bold",
+        Some(Role::Background),
+        Some(Role::WarningEmphasized),
+    ),
+    (
+        "color.calendar.due",
+        "",
+        Some(Role::Background),
+        Some(Role::Warning),
+    ),
+    (
+        "color.calendar.overdue",
+        "",
+        Some(Role::Background),
+        Some(Role::Error),
+    ),
+    ("color.calendar.weekend", "", None, Some(Role::Surface)),
+    ("color.calendar.holiday", "", Some(Role::Info), None),
+    ("color.calendar.weeknumber", "", Some(Role::TextMuted), None),
+    // Charts.
+    ("color.burndown.done", "", None, Some(Role::Success)),
+    ("color.burndown.started", "", None, Some(Role::Warning)),
+    ("color.burndown.pending", "", None, Some(Role::Error)),
+    ("color.history.add", "", None, Some(Role::Error)),
+    ("color.history.done", "", None, Some(Role::Success)),
+    ("color.history.delete", "", None, Some(Role::Warning)),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    let index = |role| to_ansi256(theme.opaque_color(role));
+    for &(key, attributes, fg, bg) in COLORS {
+        let mut value = Vec::new();
+        if !attributes.is_empty() {
+            value.push(attributes.to_owned());
+        }
+        value.extend(fg.map(|role| format!("color{}", index(role))));
+        value.extend(bg.map(|role| format!("on color{}", index(role))));
+        let _ = writeln!(text, "{key}={}", value.join(" "));
+    }
+    text
+}
+
+impl Target for Taskwarrior {
+    fn name(&self) -> &str {
+        "taskwarrior"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "theme"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
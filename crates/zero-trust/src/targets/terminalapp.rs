@@ -0,0 +1,152 @@
+//! Terminal.app: one `.terminal` settings profile per variant.
+//!
+//! Terminal.app stores each color as an `NSColor` archived with
+//! `NSKeyedArchiver`: a property list of its own, embedded as data. The
+//! archives here are XML property lists, which the unarchiver reads as
+//! readily as binary ones. Profiles are imported from Terminal's settings
+//! or by opening the file.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use super::TERMINAL;
+use crate::package::escape_xml;
+use crate::{Error, Output, Source, Target};
+
+pub struct TerminalApp;
+
+/// The profile format version Terminal.app writes.
+const PROFILE_VERSION: &str = "2.07";
+
+/// Profile colors other than the ANSI ones and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("BackgroundColor", Role::TerminalBackground),
+    ("TextColor", Role::TerminalForeground),
+    ("TextBoldColor", Role::TerminalForegroundBright),
+    ("CursorColor", Role::Cursor),
+    ("SelectionColor", Role::Selection),
+];
+
+/// The ANSI color keys, in `TERMINAL` order.
+const ANSI: [&str; 16] = [
+    "ANSIBlackColor",
+    "ANSIRedColor",
+    "ANSIGreenColor",
+    "ANSIYellowColor",
+    "ANSIBlueColor",
+    "ANSIMagentaColor",
+    "ANSICyanColor",
+    "ANSIWhiteColor",
+    "ANSIBrightBlackColor",
+    "ANSIBrightRedColor",
+    "ANSIBrightGreenColor",
+    "ANSIBrightYellowColor",
+    "ANSIBrightBlueColor",
+    "ANSIBrightMagentaColor",
+    "ANSIBrightCyanColor",
+    "ANSIBrightWhiteColor",
+];
+
+const PLIST_PROLOGUE: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+     <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+     \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n";
+
+/// Standard base64 with padding, the encoding of property list data.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| {
+            group | u32::from(byte) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                text.push(char::from(
+                    ALPHABET[(group >> (18 - 6 * i) & 0x3F) as usize],
+                ));
+            } else {
+                text.push('=');
+            }
+        }
+    }
+    text
+}
+
+/// `color` as an archived calibrated-RGB `NSColor`. The components are
+/// written as ASCII fractions, NUL-terminated, like Cocoa's own archives.
+fn archive(color: Color) -> String {
+    let fractions = color.float_rgba().to_string();
+    let rgb: Vec<&str> = fractions.split(' ').take(3).collect();
+    let mut components = rgb.join(" ").into_bytes();
+    components.push(0);
+    format!(
+        "{PLIST_PROLOGUE}<plist version=\"1.0\"><dict>\
+         <key>$archiver</key><string>NSKeyedArchiver</string>\
+         <key>$objects</key><array><string>$null</string><dict>\
+         <key>$class</key><dict><key>CF$UID</key><integer>2</integer></dict>\
+         <key>NSColorSpace</key><integer>1</integer>\
+         <key>NSRGB</key><data>{}</data></dict><dict>\
+         <key>$classes</key><array><string>NSColor</string><string>NSObject</string></array>\
+         <key>$classname</key><string>NSColor</string></dict></array>\
+         <key>$top</key><dict><key>root</key><dict><key>CF$UID</key><integer>1</integer></dict></dict>\
+         <key>$version</key><integer>100000</integer></dict></plist>\n",
+        base64(&components)
+    )
+}
+
+fn data(text: &mut String, key: &str, color: Color) {
+    let _ = writeln!(
+        text,
+        "\t<key>{key}</key>\n\t<data>{}</data>",
+        base64(archive(color).as_bytes())
+    );
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = format!("{PLIST_PROLOGUE}<!--\n");
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "  {}", escape_xml(&line));
+    }
+    let _ = writeln!(
+        text,
+        "-->\n<plist version=\"1.0\">\n<dict>\n\
+         \t<key>name</key>\n\t<string>{}</string>\n\
+         \t<key>type</key>\n\t<string>Window Settings</string>\n\
+         \t<key>ProfileCurrentVersion</key>\n\t<real>{PROFILE_VERSION}</real>",
+        escape_xml(&source.meta.theme_name(theme.variant))
+    );
+    for &(key, role) in COLORS {
+        data(&mut text, key, theme.opaque_color(role));
+    }
+    for (key, role) in ANSI.into_iter().zip(TERMINAL) {
+        data(&mut text, key, theme.opaque_color(role));
+    }
+    text.push_str("</dict>\n</plist>\n");
+    text
+}
+
+impl Target for TerminalApp {
+    fn name(&self) -> &str {
+        "terminalapp"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name());
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                let theme = source.theme(variant);
+                Output::new(
+                    dir.join(format!(
+                        "{}.terminal",
+                        source.meta.theme_name(theme.variant)
+                    )),
+                    render(source, &theme),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,185 @@
+//! TextMate scopes for the syntax roles, shared by the targets whose
+//! highlighting is driven by TextMate grammars.
+
+use zero_trust_palette::roles::{Role, Style};
+
+/// The scopes each syntax role paints.
+///
+/// Selectors match by prefix and the most specific one wins, so the order
+/// here only matters for readability. `syntax.primary`, `syntax.hint` and
+/// `syntax.predictive` have no scope: they are the default foreground and
+/// editor features rather than tokens.
+pub(crate) const SCOPES: &[(Role, &[&str])] = &[
+    (
+        Role::Comment,
+        &["comment", "punctuation.definition.comment"],
+    ),
+    (
+        Role::CommentDoc,
+        &[
+            "comment.block.documentation",
+            "comment.line.documentation",
+            "comment.line.double-slash.documentation",
+        ],
+    ),
+    (Role::String, &["string", "punctuation.definition.string"]),
+    (Role::StringEscape, &["constant.character.escape"]),
+    (Role::StringRegex, &["string.regexp"]),
+    (
+        Role::StringSpecial,
+        &["string.other", "constant.other.placeholder"],
+    ),
+    (Role::StringSpecialSymbol, &["constant.other.symbol"]),
+    (Role::Number, &["constant.numeric"]),
+    (Role::Boolean, &["constant.language.boolean"]),
+    (
+        Role::Constant,
+        &["constant", "constant.language", "variable.other.constant"],
+    ),
+    (
+        Role::Keyword,
+        &["keyword", "storage.type", "storage.modifier"],
+    ),
+    (Role::Operator, &["keyword.operator"]),
+    (
+        Role::Function,
+        &[
+            "entity.name.function",
+            "support.function",
+            "meta.function-call.generic",
+        ],
+    ),
+    (
+        Role::Constructor,
+        &["entity.name.function.constructor", "support.class.builtin"],
+    ),
+    (
+        Role::Type,
+        &[
+            "entity.name.type",
+            "entity.name.class",
+            "entity.other.inherited-class",
+            "support.type",
+            "support.class",
+        ],
+    ),
+    (Role::Enum, &["entity.name.type.enum"]),
+    (Role::Variant, &["variable.other.enummember"]),
+    (
+        Role::Namespace,
+        &[
+            "entity.name.namespace",
+            "entity.name.type.namespace",
+            "entity.name.module",
+        ],
+    ),
+    (
+        Role::Variable,
+        &["variable", "variable.other", "variable.parameter"],
+    ),
+    (Role::VariableSpecial, &["variable.language"]),
+    (
+        Role::Property,
+        &[
+            "variable.other.property",
+            "variable.other.object.property",
+            "support.variable.property",
+            "meta.object-literal.key",
+        ],
+    ),
+    (
+        Role::Attribute,
+        &["entity.other.attribute-name", "meta.attribute"],
+    ),
+    (Role::Tag, &["entity.name.tag"]),
+    (
+        Role::Label,
+        &[
+            "entity.name.label",
+            "entity.name.type.lifetime",
+            "storage.modifier.lifetime",
+        ],
+    ),
+    (Role::Punctuation, &["punctuation"]),
+    (
+        Role::PunctuationBracket,
+        &[
+            "punctuation.section",
+            "punctuation.definition.block",
+            "meta.brace",
+        ],
+    ),
+    (
+        Role::PunctuationDelimiter,
+        &["punctuation.separator", "punctuation.terminator"],
+    ),
+    (
+        Role::PunctuationListMarker,
+        &["punctuation.definition.list"],
+    ),
+    (
+        Role::PunctuationSpecial,
+        &[
+            "punctuation.definition.template-expression",
+            "punctuation.section.embedded",
+        ],
+    ),
+    (
+        Role::Preproc,
+        &["meta.preprocessor", "keyword.control.directive"],
+    ),
+    (
+        Role::Embedded,
+        &["meta.embedded", "meta.template.expression"],
+    ),
+    (
+        Role::Selector,
+        &[
+            "meta.selector",
+            "entity.other.attribute-name.class.css",
+            "entity.other.attribute-name.id.css",
+        ],
+    ),
+    (
+        Role::SelectorPseudo,
+        &[
+            "entity.other.attribute-name.pseudo-class",
+            "entity.other.attribute-name.pseudo-element",
+        ],
+    ),
+    (Role::Title, &["markup.heading", "entity.name.section"]),
+    (Role::Emphasis, &["markup.italic"]),
+    (Role::EmphasisStrong, &["markup.bold"]),
+    (Role::LinkText, &["string.other.link", "markup.link"]),
+    (Role::LinkUri, &["markup.underline.link"]),
+    (Role::TextLiteral, &["markup.inline.raw", "markup.raw"]),
+];
+
+/// The scopes of `role` inside `language`.
+///
+/// Grammars end their scopes with the language name, e.g.
+/// `entity.name.function.rust`, so appending it narrows a selector to one
+/// language.
+pub(crate) fn language_scopes(role: Role, language: &str) -> Vec<String> {
+    SCOPES
+        .iter()
+        .filter(|(r, _)| *r == role)
+        .flat_map(|(_, scopes)| scopes.iter())
+        .map(|scope| format!("{scope}.{language}"))
+        .collect()
+}
+
+/// The TextMate `fontStyle` of `style`: `italic`, `bold`, both or empty.
+///
+/// An empty string is meaningful: it resets a style inherited from a less
+/// specific selector, e.g. bold `keyword` under `keyword.operator`.
+pub(crate) fn font_style(style: Style) -> String {
+    let mut words = Vec::new();
+    if style.italic {
+        words.push("italic");
+    }
+    if style.is_bold() {
+        words.push("bold");
+    }
+    words.join(" ")
+}
@@ -0,0 +1,129 @@
+//! tig: one `tigrc` color section per variant for the main, diff, status,
+//! tree and help views.
+//!
+//! Diff lines take the git roles, with changed words on the success and
+//! error tints as in the delta target, and the log's ids, dates, authors
+//! and refs take syntax roles. Colors are the quantizer's 256-color picks;
+//! plain lines keep the terminal's own background. The section is pulled
+//! into `~/.tigrc` with `source`.
+
+use std::fmt::Write;
+
+use zero_trust_palette::quantize::to_ansi256;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Tig;
+
+/// Areas, the roles of their foreground and background, and their
+/// attributes.
+const COLORS: &[(&str, Role, Option<Role>, &str)] = &[
+    // Chrome.
+    ("default", Role::Text, None, ""),
+    ("cursor", Role::Text, Some(Role::ElementSelected), "bold"),
+    ("title-focus", Role::Text, Some(Role::Statusline), "bold"),
+    ("title-blur", Role::TextMuted, Some(Role::Statusline), ""),
+    ("status", Role::Info, None, ""),
+    (
+        "search-result",
+        Role::Background,
+        Some(Role::SearchMatch),
+        "",
+    ),
+    ("delimiter", Role::TextDisabled, None, ""),
+    ("line-number", Role::LineNumber, None, ""),
+    ("header", Role::Accent, None, "bold"),
+    ("section", Role::Accent, None, ""),
+    ("overflow", Role::Error, None, ""),
+    // Main view.
+    ("id", Role::Constant, None, ""),
+    ("date", Role::TextMuted, None, ""),
+    ("author", Role::Function, None, ""),
+    ("mode", Role::Keyword, None, ""),
+    ("graph-commit", Role::Accent, None, ""),
+    ("main-commit", Role::Text, None, ""),
+    ("main-head", Role::Success, None, "bold"),
+    ("main-local-tag", Role::Warning, None, ""),
+    ("main-tag", Role::Warning, None, "bold"),
+    ("main-remote", Role::Keyword, None, ""),
+    ("main-tracked", Role::Keyword, None, "bold"),
+    ("main-replace", Role::Info, None, ""),
+    ("main-ref", Role::Type, None, ""),
+    // Diff view.
+    ("diff-header", Role::Text, None, "bold"),
+    ("diff-index", Role::TextMuted, None, ""),
+    ("diff-chunk", Role::Info, None, ""),
+    ("diff-add", Role::GitAdded, None, ""),
+    ("diff-del", Role::GitDeleted, None, ""),
+    (
+        "diff-add-highlight",
+        Role::GitAdded,
+        Some(Role::SuccessBackground),
+        "",
+    ),
+    (
+        "diff-del-highlight",
+        Role::GitDeleted,
+        Some(Role::ErrorBackground),
+        "",
+    ),
+    ("diff-oldmode", Role::Warning, None, ""),
+    ("diff-newmode", Role::Warning, None, ""),
+    ("diff-stat", Role::Accent, None, ""),
+    ("diff-add2", Role::GitAdded, None, ""),
+    ("diff-del2", Role::GitDeleted, None, ""),
+    ("diff-new-file-mode", Role::GitAdded, None, "bold"),
+    ("diff-deleted-file-mode", Role::GitDeleted, None, "bold"),
+    ("diff-rename-from", Role::Info, None, ""),
+    ("diff-rename-to", Role::Info, None, ""),
+    ("diff-similarity", Role::TextMuted, None, ""),
+    // Status and tree views.
+    ("stat-none", Role::Text, None, ""),
+    ("stat-staged", Role::GitAdded, None, ""),
+    ("stat-unstaged", Role::GitModified, None, ""),
+    ("stat-untracked", Role::GitDeleted, None, ""),
+    ("directory", Role::Accent, None, ""),
+    ("file", Role::Text, None, ""),
+    ("file-size", Role::TextMuted, None, ""),
+    // Help view.
+    ("help-group", Role::Accent, None, "bold"),
+    ("help-action", Role::Keyword, None, ""),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push('\n');
+    let color = |role| format!("color{}", to_ansi256(theme.opaque_color(role)));
+    for &(area, fg, bg, attributes) in COLORS {
+        let bg = bg.map_or_else(|| "default".to_owned(), color);
+        let mut command = format!("color {area} {} {bg}", color(fg));
+        if !attributes.is_empty() {
+            let _ = write!(command, " {attributes}");
+        }
+        let _ = writeln!(text, "{command}");
+    }
+    text
+}
+
+impl Target for Tig {
+    fn name(&self) -> &str {
+        "tig"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "tigrc"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,146 @@
+//! TextMate `.tmTheme`: one property list per variant with the editor
+//! settings and scope rules from the shared TextMate scopes.
+//!
+//! The format is also read by syntect and so by `bat`, `delta` and the
+//! other tools built on it, and syntect gives alpha values of 0 and 1
+//! special meanings, so no color keeps its alpha.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::textmate::{self, SCOPES};
+use crate::languages;
+use crate::package::escape_xml;
+use crate::{Error, Output, Source, Target};
+
+pub struct TmTheme;
+
+/// Editor settings and the roles that paint them, in file order.
+const SETTINGS: &[(&str, Role)] = &[
+    ("background", Role::Background),
+    ("foreground", Role::Text),
+    ("caret", Role::Cursor),
+    ("invisibles", Role::Invisible),
+    ("lineHighlight", Role::LineHighlight),
+    ("selection", Role::Selection),
+    ("inactiveSelection", Role::ElementSelected),
+    ("findHighlight", Role::SearchMatch),
+    ("highlight", Role::Highlight),
+    ("gutter", Role::Gutter),
+    ("gutterForeground", Role::LineNumber),
+    ("guide", Role::Guide),
+    ("activeGuide", Role::GuideActive),
+    ("stackGuide", Role::Guide),
+    ("bracketsForeground", Role::Accent),
+    ("bracketContentsForeground", Role::Accent),
+    ("misspelling", Role::Error),
+    ("accent", Role::Accent),
+];
+
+fn entry(text: &mut String, indent: usize, key: &str, value: &str) {
+    let _ = writeln!(
+        text,
+        "{:indent$}<key>{key}</key>\n{:indent$}<string>{}</string>",
+        "",
+        "",
+        escape_xml(value)
+    );
+}
+
+/// A scope rule; an empty `font_style` is written too, so the rule resets
+/// the style it would otherwise inherit.
+fn rule(text: &mut String, name: &str, scopes: &[String], color: &str, font_style: &str) {
+    text.push_str("  <dict>\n");
+    entry(text, 3, "name", name);
+    entry(text, 3, "scope", &scopes.join(", "));
+    text.push_str("   <key>settings</key>\n   <dict>\n");
+    entry(text, 4, "foreground", color);
+    entry(text, 4, "fontStyle", font_style);
+    text.push_str("   </dict>\n  </dict>\n");
+}
+
+/// The property list of `theme`; the bat target ships it too.
+pub(super) fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+         \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n<!--\n",
+    );
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "  {}", escape_xml(&line));
+    }
+    text.push_str("-->\n<plist version=\"1.0\">\n<dict>\n");
+    entry(&mut text, 1, "name", &source.meta.theme_name(theme.variant));
+    entry(&mut text, 1, "author", &source.meta.author);
+    entry(
+        &mut text,
+        1,
+        "uuid",
+        &source.meta.uuid(theme.variant.slug()),
+    );
+    entry(
+        &mut text,
+        1,
+        "semanticClass",
+        &format!(
+            "theme.{}.{}",
+            theme.appearance().name(),
+            source
+                .meta
+                .theme_name(theme.variant)
+                .to_lowercase()
+                .replace(' ', "_")
+        ),
+    );
+    entry(&mut text, 1, "colorSpaceName", "sRGB");
+    text.push_str(" <key>settings</key>\n <array>\n  <dict>\n   <key>settings</key>\n   <dict>\n");
+    for &(key, role) in SETTINGS {
+        entry(&mut text, 4, key, &theme.opaque_color(role).to_string());
+    }
+    text.push_str("   </dict>\n  </dict>\n");
+    for &(role, scopes) in SCOPES {
+        let scopes: Vec<String> = scopes.iter().map(|&scope| scope.into()).collect();
+        rule(
+            &mut text,
+            role.name(),
+            &scopes,
+            &theme.opaque_color(role).to_string(),
+            &textmate::font_style(theme.style(role)),
+        );
+    }
+    for language_rule in languages::rules(source, theme.variant) {
+        let scopes = textmate::language_scopes(language_rule.role, &language_rule.language);
+        if scopes.is_empty() {
+            continue;
+        }
+        rule(
+            &mut text,
+            &format!("{} ({})", language_rule.role.name(), language_rule.language),
+            &scopes,
+            &language_rule.opaque.to_string(),
+            &textmate::font_style(language_rule.style),
+        );
+    }
+    text.push_str(" </array>\n</dict>\n</plist>\n");
+    text
+}
+
+impl Target for TmTheme {
+    fn name(&self) -> &str {
+        "tmtheme"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "tmTheme"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
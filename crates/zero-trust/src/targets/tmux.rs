@@ -0,0 +1,171 @@
+//! tmux: one sourceable theme per variant, plus `zero-trust.tmux` with the
+//! dark colors, setting the status line, pane border, message and mode
+//! styles.
+//!
+//! Each file sets the styles twice, in truecolor when the client reports
+//! the `RGB` terminal feature and in the quantizer's 256-color picks
+//! otherwise, including on tmux releases too old to report features at
+//! all.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::quantize::to_ansi256;
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Tmux;
+
+/// How a style option is painted.
+#[derive(Clone, Copy)]
+struct Paint {
+    fg: Role,
+    bg: Option<Role>,
+    bold: bool,
+}
+
+impl Paint {
+    const fn fg(role: Role) -> Paint {
+        Paint {
+            fg: role,
+            bg: None,
+            bold: false,
+        }
+    }
+
+    const fn fg_bg(fg: Role, bg: Role) -> Paint {
+        Paint {
+            fg,
+            bg: Some(bg),
+            bold: false,
+        }
+    }
+
+    const fn bold(self) -> Paint {
+        Paint { bold: true, ..self }
+    }
+}
+
+/// Style options and how they are painted.
+const STYLES: &[(&str, Paint)] = &[
+    (
+        "status-style",
+        Paint::fg_bg(Role::TextMuted, Role::Statusline),
+    ),
+    (
+        "status-left-style",
+        Paint::fg_bg(Role::TerminalBackground, Role::Accent).bold(),
+    ),
+    (
+        "status-right-style",
+        Paint::fg_bg(Role::Text, Role::Statusline),
+    ),
+    (
+        "window-status-style",
+        Paint::fg_bg(Role::TextMuted, Role::Statusline),
+    ),
+    (
+        "window-status-current-style",
+        Paint::fg_bg(Role::Accent, Role::TabActive).bold(),
+    ),
+    (
+        "window-status-activity-style",
+        Paint::fg_bg(Role::Warning, Role::Statusline),
+    ),
+    (
+        "window-status-bell-style",
+        Paint::fg_bg(Role::Error, Role::Statusline).bold(),
+    ),
+    ("pane-border-style", Paint::fg(Role::Border)),
+    ("pane-active-border-style", Paint::fg(Role::BorderFocused)),
+    (
+        "message-style",
+        Paint::fg_bg(Role::Text, Role::SurfaceElevated),
+    ),
+    (
+        "message-command-style",
+        Paint::fg_bg(Role::Accent, Role::SurfaceElevated),
+    ),
+    ("mode-style", Paint::fg_bg(Role::Text, Role::Selection)),
+];
+
+/// Color options and the roles that paint them.
+const COLOURS: &[(&str, Role)] = &[
+    ("display-panes-colour", Role::Border),
+    ("display-panes-active-colour", Role::Accent),
+    ("clock-mode-colour", Role::Accent),
+];
+
+/// `color` in 256-color terminals.
+fn colour256(color: Color) -> String {
+    format!("colour{}", to_ansi256(color))
+}
+
+/// Writes every option of `theme`, with colors written by `colour`.
+fn options(text: &mut String, theme: &Theme, colour: fn(Color) -> String) {
+    let colour = |role| colour(theme.opaque_color(role));
+    for &(option, paint) in STYLES {
+        let mut style = format!("fg={}", colour(paint.fg));
+        if let Some(bg) = paint.bg {
+            let _ = write!(style, ",bg={}", colour(bg));
+        }
+        if paint.bold {
+            style.push_str(",bold");
+        }
+        let _ = writeln!(text, "    set -g {option} \"{style}\"");
+    }
+    for &(option, role) in COLOURS {
+        let _ = writeln!(text, "    set -g {option} \"{}\"", colour(role));
+    }
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push_str("\n%if \"#{m:*RGB*,#{client_termfeatures}}\"\n");
+    options(&mut text, theme, |color| color.to_string());
+    text.push_str("%else\n");
+    options(&mut text, theme, colour256);
+    text.push_str("%endif\n");
+    text
+}
+
+impl Target for Tmux {
+    fn name(&self) -> &str {
+        "tmux"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "tmux"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect();
+        if variants.contains(&Variant::Dark) {
+            outputs.push(Output::new(
+                Path::new("extras")
+                    .join(self.name())
+                    .join(format!("{}.tmux", source.meta.stem())),
+                render(source, &source.theme(Variant::Dark)),
+            ));
+        }
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("tmux");
+        Some(InstallDir {
+            themes: app.clone(),
+            app,
+        })
+    }
+}
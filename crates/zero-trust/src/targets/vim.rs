@@ -0,0 +1,411 @@
+//! Vim: one classic Vim script colorscheme per variant, plus
+//! `zero-trust.vim` choosing the dark or light one from `'background'`,
+//! and the highlight groups shared with the Neovim colorschemes.
+//!
+//! Every group carries `cterm` colors from the 256-color quantizer next to
+//! the `gui` ones, so Vim without `'termguicolors'`, e.g. on a server over
+//! SSH, shows the nearest approximation.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::quantize::to_ansi256;
+use zero_trust_palette::roles::{Role, Style};
+use zero_trust_palette::{Color, Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Vim;
+
+/// Font attributes of a highlight group.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct Attrs {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub undercurl: bool,
+    pub strikethrough: bool,
+}
+
+impl Attrs {
+    /// The attributes in the order Vim lists them, by their Vim names.
+    pub fn names(self) -> Vec<&'static str> {
+        [
+            (self.bold, "bold"),
+            (self.italic, "italic"),
+            (self.underline, "underline"),
+            (self.undercurl, "undercurl"),
+            (self.strikethrough, "strikethrough"),
+        ]
+        .into_iter()
+        .filter_map(|(set, name)| set.then_some(name))
+        .collect()
+    }
+
+    /// Italics and bold as `style` asks for them.
+    pub fn of(style: Style) -> Attrs {
+        Attrs {
+            bold: style.is_bold(),
+            italic: style.italic,
+            ..Attrs::default()
+        }
+    }
+}
+
+/// How a highlight group is painted: colors by role, or a link to another
+/// group.
+#[derive(Clone, Copy)]
+pub(super) struct Spec {
+    pub fg: Option<Role>,
+    pub bg: Option<Role>,
+    /// The underline color.
+    pub sp: Option<Role>,
+    pub attrs: Attrs,
+    pub link: Option<&'static str>,
+}
+
+impl Spec {
+    const NONE: Spec = Spec {
+        fg: None,
+        bg: None,
+        sp: None,
+        attrs: Attrs {
+            bold: false,
+            italic: false,
+            underline: false,
+            undercurl: false,
+            strikethrough: false,
+        },
+        link: None,
+    };
+
+    pub const fn fg(role: Role) -> Spec {
+        Spec {
+            fg: Some(role),
+            ..Spec::NONE
+        }
+    }
+
+    pub const fn bg(role: Role) -> Spec {
+        Spec {
+            bg: Some(role),
+            ..Spec::NONE
+        }
+    }
+
+    pub const fn fg_bg(fg: Role, bg: Role) -> Spec {
+        Spec {
+            fg: Some(fg),
+            bg: Some(bg),
+            ..Spec::NONE
+        }
+    }
+
+    /// A curly underline in the color of `role`, for diagnostics and spelling.
+    pub const fn undercurl(role: Role) -> Spec {
+        let mut spec = Spec {
+            sp: Some(role),
+            ..Spec::NONE
+        };
+        spec.attrs.undercurl = true;
+        spec
+    }
+
+    pub const fn link(group: &'static str) -> Spec {
+        Spec {
+            link: Some(group),
+            ..Spec::NONE
+        }
+    }
+
+    pub const fn bold(mut self) -> Spec {
+        self.attrs.bold = true;
+        self
+    }
+
+    pub const fn underline(mut self) -> Spec {
+        self.attrs.underline = true;
+        self
+    }
+
+    pub const fn strikethrough(mut self) -> Spec {
+        self.attrs.strikethrough = true;
+        self
+    }
+
+    /// The foreground of `role` with its italics and bold.
+    pub fn role(theme: &Theme, role: Role) -> Spec {
+        Spec {
+            fg: Some(role),
+            attrs: Attrs::of(theme.style(role)),
+            ..Spec::NONE
+        }
+    }
+}
+
+/// Editor interface groups.
+pub(super) const UI: &[(&str, Spec)] = &[
+    ("Normal", Spec::fg_bg(Role::Text, Role::Background)),
+    (
+        "NormalFloat",
+        Spec::fg_bg(Role::Text, Role::SurfaceElevated),
+    ),
+    (
+        "FloatBorder",
+        Spec::fg_bg(Role::Border, Role::SurfaceElevated),
+    ),
+    ("Cursor", Spec::fg_bg(Role::Background, Role::Cursor)),
+    ("CursorLine", Spec::bg(Role::Cursorline)),
+    ("CursorColumn", Spec::bg(Role::Cursorline)),
+    ("ColorColumn", Spec::bg(Role::Cursorline)),
+    ("LineNr", Spec::fg_bg(Role::LineNumber, Role::Gutter)),
+    (
+        "CursorLineNr",
+        Spec::fg_bg(Role::LineNumberActive, Role::Gutter).bold(),
+    ),
+    ("SignColumn", Spec::bg(Role::Gutter)),
+    ("FoldColumn", Spec::fg_bg(Role::LineNumber, Role::Gutter)),
+    ("Folded", Spec::fg_bg(Role::TextMuted, Role::Subheader)),
+    ("VertSplit", Spec::fg(Role::Split)),
+    ("WinSeparator", Spec::fg(Role::Split)),
+    ("StatusLine", Spec::fg_bg(Role::TextMuted, Role::Statusline)),
+    (
+        "StatusLineNC",
+        Spec::fg_bg(Role::TextPlaceholder, Role::Statusline),
+    ),
+    ("TabLine", Spec::fg_bg(Role::TextMuted, Role::TabInactive)),
+    ("TabLineSel", Spec::fg_bg(Role::Text, Role::TabActive)),
+    ("TabLineFill", Spec::bg(Role::Tabline)),
+    ("Visual", Spec::bg(Role::Selection)),
+    ("Search", Spec::bg(Role::SearchMatch)),
+    ("IncSearch", Spec::fg_bg(Role::Background, Role::Accent)),
+    ("CurSearch", Spec::fg_bg(Role::Background, Role::Accent)),
+    ("MatchParen", Spec::bg(Role::Highlight).bold()),
+    ("Pmenu", Spec::fg_bg(Role::Text, Role::SurfaceElevated)),
+    ("PmenuSel", Spec::bg(Role::ElementSelected)),
+    ("PmenuSbar", Spec::bg(Role::ScrollbarTrack)),
+    ("PmenuThumb", Spec::bg(Role::ScrollbarThumb)),
+    ("WildMenu", Spec::bg(Role::ElementSelected)),
+    ("QuickFixLine", Spec::bg(Role::ElementSelected)),
+    ("NonText", Spec::fg(Role::Invisible)),
+    ("Whitespace", Spec::fg(Role::Invisible)),
+    ("SpecialKey", Spec::fg(Role::Invisible)),
+    ("EndOfBuffer", Spec::fg(Role::Background)),
+    ("Directory", Spec::fg(Role::Link)),
+    ("Conceal", Spec::fg(Role::TextMuted)),
+    ("ErrorMsg", Spec::fg(Role::Error)),
+    ("WarningMsg", Spec::fg(Role::Warning)),
+    ("MoreMsg", Spec::fg(Role::Success)),
+    ("Question", Spec::fg(Role::Info)),
+    ("ModeMsg", Spec::fg(Role::Text).bold()),
+    ("DiffAdd", Spec::bg(Role::SuccessBackground)),
+    ("DiffChange", Spec::bg(Role::WarningBackground)),
+    (
+        "DiffDelete",
+        Spec::fg_bg(Role::GitDeleted, Role::ErrorBackground),
+    ),
+    ("DiffText", Spec::bg(Role::InfoBackground)),
+    ("SpellBad", Spec::undercurl(Role::Error)),
+    ("SpellCap", Spec::undercurl(Role::Warning)),
+    ("SpellLocal", Spec::undercurl(Role::Info)),
+    ("SpellRare", Spec::undercurl(Role::Hint)),
+    ("Underlined", Spec::fg(Role::Link).underline()),
+    ("Todo", Spec::fg(Role::Warning).bold()),
+    ("Error", Spec::fg(Role::Error)),
+];
+
+/// The classic syntax groups, styled like the role that paints them.
+pub(super) const CLASSIC: &[(&str, Role)] = &[
+    ("Comment", Role::Comment),
+    ("SpecialComment", Role::CommentDoc),
+    ("Constant", Role::Constant),
+    ("String", Role::String),
+    ("Character", Role::String),
+    ("SpecialChar", Role::StringEscape),
+    ("Number", Role::Number),
+    ("Float", Role::Number),
+    ("Boolean", Role::Boolean),
+    ("Identifier", Role::Variable),
+    ("Function", Role::Function),
+    ("Statement", Role::Keyword),
+    ("Conditional", Role::Keyword),
+    ("Repeat", Role::Keyword),
+    ("Exception", Role::Keyword),
+    ("Keyword", Role::Keyword),
+    ("StorageClass", Role::Keyword),
+    ("Label", Role::Label),
+    ("Operator", Role::Operator),
+    ("PreProc", Role::Preproc),
+    ("Include", Role::Preproc),
+    ("Define", Role::Preproc),
+    ("Macro", Role::Function),
+    ("PreCondit", Role::Preproc),
+    ("Type", Role::Type),
+    ("Structure", Role::Type),
+    ("Typedef", Role::Type),
+    ("Special", Role::PunctuationSpecial),
+    ("Tag", Role::Tag),
+    ("Delimiter", Role::PunctuationDelimiter),
+    ("Title", Role::Title),
+];
+
+/// A Lua table for `nvim_set_hl` from resolved colors.
+pub(super) fn lua_table(
+    fg: Option<Color>,
+    bg: Option<Color>,
+    sp: Option<Color>,
+    attrs: Attrs,
+) -> String {
+    let mut fields = Vec::new();
+    for (key, color) in [("fg", fg), ("bg", bg), ("sp", sp)] {
+        if let Some(color) = color {
+            fields.push(format!("{key} = \"{color}\""));
+        }
+    }
+    for name in attrs.names() {
+        fields.push(format!("{name} = true"));
+    }
+    if fields.is_empty() {
+        "{}".into()
+    } else {
+        format!("{{ {} }}", fields.join(", "))
+    }
+}
+
+/// The Lua table for `nvim_set_hl` painting `spec` in `theme`.
+pub(super) fn lua(theme: &Theme, spec: Spec) -> String {
+    if let Some(link) = spec.link {
+        return format!("{{ link = \"{link}\" }}");
+    }
+    let color = |role: Option<Role>| role.map(|role| theme.opaque_color(role));
+    lua_table(color(spec.fg), color(spec.bg), color(spec.sp), spec.attrs)
+}
+
+/// The Lua table for a foreground `color` with the italics and bold of
+/// `style`.
+pub(super) fn style_lua(color: Color, style: Style) -> String {
+    lua_table(Some(color), None, None, Attrs::of(style))
+}
+
+/// The colorscheme name of `variant`, also its file stem.
+pub(super) fn scheme_name(meta: &Meta, variant: Variant) -> String {
+    meta.file_stem(variant)
+}
+
+/// A `:highlight` command painting `spec` in `theme`.
+fn highlight(theme: &Theme, group: &str, spec: Spec) -> String {
+    if let Some(link) = spec.link {
+        return format!("hi! link {group} {link}");
+    }
+    let mut line = format!("hi {group}");
+    for (gui, cterm, role) in [("guifg", "ctermfg", spec.fg), ("guibg", "ctermbg", spec.bg)] {
+        if let Some(role) = role {
+            let color = theme.opaque_color(role);
+            let _ = write!(line, " {gui}={color} {cterm}={}", to_ansi256(color));
+        }
+    }
+    if let Some(role) = spec.sp {
+        let _ = write!(line, " guisp={}", theme.opaque_color(role));
+    }
+    // Terminals rarely draw curly underlines; a straight one reads the same.
+    let cterm = Attrs {
+        underline: spec.attrs.underline || spec.attrs.undercurl,
+        undercurl: false,
+        ..spec.attrs
+    };
+    let _ = write!(
+        line,
+        " gui={} cterm={}",
+        attr_list(spec.attrs),
+        attr_list(cterm)
+    );
+    line
+}
+
+fn attr_list(attrs: Attrs) -> String {
+    let names = attrs.names();
+    if names.is_empty() {
+        "NONE".to_owned()
+    } else {
+        names.join(",")
+    }
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "\" {line}");
+    }
+    let _ = writeln!(
+        text,
+        "
+set background={}
+hi clear
+if exists(\"syntax_on\")
+  syntax reset
+endif
+let g:colors_name = \"{}\"
+",
+        theme.appearance().name(),
+        scheme_name(&source.meta, theme.variant),
+    );
+    for &(group, spec) in UI {
+        let _ = writeln!(text, "{}", highlight(theme, group, spec));
+    }
+    for &(group, role) in CLASSIC {
+        let _ = writeln!(text, "{}", highlight(theme, group, Spec::role(theme, role)));
+    }
+    let colors: Vec<String> = super::TERMINAL
+        .iter()
+        .map(|&role| format!("\"{}\"", theme.opaque_color(role)))
+        .collect();
+    let _ = writeln!(
+        text,
+        "\nlet g:terminal_ansi_colors = [{}]",
+        colors.join(", ")
+    );
+    text
+}
+
+impl Target for Vim {
+    fn name(&self) -> &str {
+        "vim"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name()).join("colors");
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    dir.join(format!("{}.vim", scheme_name(&source.meta, variant))),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect();
+        if variants.contains(&Variant::Dark) && variants.contains(&Variant::Light) {
+            outputs.push(Output::new(
+                dir.join(format!("{}.vim", source.meta.stem())),
+                format!(
+                    "\" Follows 'background': {} or {}.\nexecute \"colorscheme\" (&background ==# \"light\" ? \"{1}\" : \"{0}\")\n",
+                    scheme_name(&source.meta, Variant::Dark),
+                    scheme_name(&source.meta, Variant::Light),
+                ),
+            ));
+        }
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = if cfg!(windows) {
+            dirs.home.join("vimfiles")
+        } else {
+            dirs.home.join(".vim")
+        };
+        Some(InstallDir {
+            themes: app.join("colors"),
+            app,
+        })
+    }
+}
@@ -0,0 +1,272 @@
+//! Visual Studio on Windows: one `.vssettings` file per variant with the
+//! Fonts and Colors of the text editor and the output window, imported
+//! through *Tools → Import and Export Settings*.
+//!
+//! Visual Studio keeps every color as an OLE `COLORREF`, `0x00BBGGRR`,
+//! with `0x02000000` meaning the default, and has no italics, so only bold
+//! is carried over. Item names are shared by all languages.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Appearance, Color, Theme, Variant};
+
+use crate::package::escape_xml;
+use crate::{Error, Output, Source, Target};
+
+pub struct VisualStudio;
+
+/// The release whose settings format the files declare, Visual Studio 2022.
+const APPLICATION_VERSION: &str = "17.0";
+
+/// The color value that leaves an item's foreground or background alone.
+const AUTOMATIC: &str = "0x02000000";
+
+/// The built-in themes the editor colors are layered on.
+const DARK_THEME: &str = "{1DED0138-47CE-435E-84EF-9EC1F439B749}";
+const LIGHT_THEME: &str = "{DE3DBBCD-F642-433C-8353-8F1DF4370ABA}";
+
+/// How a Fonts and Colors item is painted. `bold` is `None` for the syntax
+/// items, whose weight follows the foreground role's style.
+#[derive(Clone, Copy)]
+struct Item {
+    fg: Option<Role>,
+    bg: Option<Role>,
+    bold: Option<bool>,
+}
+
+impl Item {
+    /// A syntax item in `role`, bold where the role is.
+    const fn syntax(role: Role) -> Item {
+        Item {
+            fg: Some(role),
+            bg: None,
+            bold: None,
+        }
+    }
+
+    const fn fg(role: Role) -> Item {
+        Item {
+            fg: Some(role),
+            bg: None,
+            bold: Some(false),
+        }
+    }
+
+    const fn bg(role: Role) -> Item {
+        Item {
+            fg: None,
+            bg: Some(role),
+            bold: Some(false),
+        }
+    }
+
+    const fn fg_bg(fg: Role, bg: Role) -> Item {
+        Item {
+            fg: Some(fg),
+            bg: Some(bg),
+            bold: Some(false),
+        }
+    }
+}
+
+/// The text editor's items. Squiggles and margin marks take their color
+/// from the foreground.
+const TEXT_EDITOR: &[(&str, Item)] = &[
+    ("Plain Text", Item::fg_bg(Role::Text, Role::Background)),
+    ("Selected Text", Item::bg(Role::Selection)),
+    ("Inactive Selected Text", Item::bg(Role::ElementSelected)),
+    ("Line Number", Item::fg_bg(Role::LineNumber, Role::Gutter)),
+    ("Indicator Margin", Item::bg(Role::Gutter)),
+    ("Visible White Space", Item::fg(Role::Invisible)),
+    ("Brace Matching (Rectangle)", Item::bg(Role::Highlight)),
+    (
+        "MarkerFormatDefinition/HighlightedReference",
+        Item::bg(Role::Highlight),
+    ),
+    ("CurrentLineActiveFormat", Item::bg(Role::Cursorline)),
+    ("Syntax Error", Item::fg(Role::Error)),
+    ("Compiler Error", Item::fg(Role::Error)),
+    ("Warning", Item::fg(Role::Warning)),
+    ("Suggestion ellipsis (...)", Item::fg(Role::Hint)),
+    ("Track Changes after save", Item::fg(Role::GitAdded)),
+    ("Track Changes before save", Item::fg(Role::GitModified)),
+    ("Track reverted changes", Item::fg(Role::GitDeleted)),
+    ("Excluded Code", Item::fg(Role::TextMuted)),
+    ("Comment", Item::syntax(Role::Comment)),
+    ("XML Doc Comment", Item::syntax(Role::CommentDoc)),
+    ("XML Doc Tag", Item::syntax(Role::CommentDoc)),
+    ("Keyword", Item::syntax(Role::Keyword)),
+    ("keyword - control", Item::syntax(Role::Keyword)),
+    ("Preprocessor Keyword", Item::syntax(Role::Preproc)),
+    ("preprocessor text", Item::syntax(Role::Preproc)),
+    ("Operator", Item::syntax(Role::Operator)),
+    ("operator - overloaded", Item::syntax(Role::Operator)),
+    ("punctuation", Item::syntax(Role::Punctuation)),
+    ("String", Item::syntax(Role::String)),
+    ("string - verbatim", Item::syntax(Role::String)),
+    (
+        "string - escape character",
+        Item::syntax(Role::StringEscape),
+    ),
+    ("Number", Item::syntax(Role::Number)),
+    ("Identifier", Item::syntax(Role::Variable)),
+    ("class name", Item::syntax(Role::Type)),
+    ("struct name", Item::syntax(Role::Type)),
+    ("interface name", Item::syntax(Role::Type)),
+    ("delegate name", Item::syntax(Role::Type)),
+    ("type parameter name", Item::syntax(Role::Type)),
+    ("enum name", Item::syntax(Role::Enum)),
+    ("enum member name", Item::syntax(Role::Variant)),
+    ("module name", Item::syntax(Role::Namespace)),
+    ("namespace name", Item::syntax(Role::Namespace)),
+    ("method name", Item::syntax(Role::Function)),
+    ("extension method name", Item::syntax(Role::Function)),
+    ("field name", Item::syntax(Role::Property)),
+    ("property name", Item::syntax(Role::Property)),
+    ("event name", Item::syntax(Role::Property)),
+    ("constant name", Item::syntax(Role::Constant)),
+    ("local name", Item::syntax(Role::Variable)),
+    ("parameter name", Item::syntax(Role::Variable)),
+    ("label name", Item::syntax(Role::Label)),
+    ("cppMacroSemanticTokenFormat", Item::syntax(Role::Function)),
+    ("cppEnumSemanticTokenFormat", Item::syntax(Role::Enum)),
+    (
+        "cppEnumeratorSemanticTokenFormat",
+        Item::syntax(Role::Variant),
+    ),
+    (
+        "cppFunctionSemanticTokenFormat",
+        Item::syntax(Role::Function),
+    ),
+    (
+        "cppMemberFunctionSemanticTokenFormat",
+        Item::syntax(Role::Function),
+    ),
+    (
+        "cppStaticMemberFunctionSemanticTokenFormat",
+        Item::syntax(Role::Function),
+    ),
+    (
+        "cppMemberFieldSemanticTokenFormat",
+        Item::syntax(Role::Property),
+    ),
+    (
+        "cppLocalVariableSemanticTokenFormat",
+        Item::syntax(Role::Variable),
+    ),
+    (
+        "cppGlobalVariableSemanticTokenFormat",
+        Item::syntax(Role::Variable),
+    ),
+    (
+        "cppParameterSemanticTokenFormat",
+        Item::syntax(Role::Variable),
+    ),
+    (
+        "cppNamespaceSemanticTokenFormat",
+        Item::syntax(Role::Namespace),
+    ),
+];
+
+/// The output window's items.
+const OUTPUT_WINDOW: &[(&str, Item)] = &[
+    (
+        "Plain Text",
+        Item::fg_bg(Role::TerminalForeground, Role::TerminalBackground),
+    ),
+    ("Selected Text", Item::bg(Role::Selection)),
+    ("Inactive Selected Text", Item::bg(Role::ElementSelected)),
+];
+
+/// Fonts and Colors categories by GUID, each with its items.
+const CATEGORIES: &[(&str, &[(&str, Item)])] = &[
+    ("{A27B4E24-A735-4d1d-B8E7-9716E1E3D8E0}", TEXT_EDITOR),
+    ("{9973EFDF-317D-431C-8BC1-5E88CBFD4F7F}", OUTPUT_WINDOW),
+];
+
+/// `color` as an OLE `COLORREF`, which stores the channels blue first.
+fn ole(color: Color) -> String {
+    format!("0x00{:02X}{:02X}{:02X}", color.b, color.g, color.r)
+}
+
+fn item(text: &mut String, theme: &Theme, name: &str, item: Item) {
+    let value = |role: Option<Role>| {
+        role.map_or(AUTOMATIC.to_owned(), |role| ole(theme.opaque_color(role)))
+    };
+    let bold = item
+        .bold
+        .unwrap_or_else(|| item.fg.is_some_and(|role| theme.style(role).is_bold()));
+    let _ = writeln!(
+        text,
+        "              <Item Name=\"{}\" Foreground=\"{}\" Background=\"{}\" BoldFont=\"{}\" />",
+        escape_xml(name),
+        value(item.fg),
+        value(item.bg),
+        if bold { "Yes" } else { "No" },
+    );
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<!--\n");
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "  {}", escape_xml(&line));
+    }
+    let base = match theme.appearance() {
+        Appearance::Dark => DARK_THEME,
+        Appearance::Light => LIGHT_THEME,
+    };
+    let _ = write!(
+        text,
+        "-->
+<UserSettings>
+  <ApplicationIdentity version=\"{APPLICATION_VERSION}\" />
+  <ToolsOptions>
+    <ToolsOptionsCategory name=\"Environment\" RegisteredName=\"Environment\" />
+  </ToolsOptions>
+  <Category name=\"Environment_Group\" RegisteredName=\"Environment_Group\">
+    <Category name=\"Environment_FontsAndColors\" Category=\"{{1EDA5DD4-927A-43a7-810E-7FD247D0DA1D}}\" Package=\"{{DA9FB551-C724-11d0-AE1F-00A0C90FFFC3}}\" RegisteredName=\"Environment_FontsAndColors\" PackageName=\"Visual Studio Environment Package\">
+      <PropertyValue name=\"Version\">2</PropertyValue>
+      <FontsAndColors Version=\"2.0\">
+        <Theme Id=\"{base}\" />
+        <Categories>
+"
+    );
+    for &(guid, items) in CATEGORIES {
+        let _ = writeln!(
+            text,
+            "          <Category GUID=\"{guid}\" FontIsDefault=\"Yes\">\n            <Items>"
+        );
+        for &(name, spec) in items {
+            item(&mut text, theme, name, spec);
+        }
+        text.push_str("            </Items>\n          </Category>\n");
+    }
+    text.push_str(
+        "        </Categories>
+      </FontsAndColors>
+    </Category>
+  </Category>
+</UserSettings>
+",
+    );
+    text
+}
+
+impl Target for VisualStudio {
+    fn name(&self) -> &str {
+        "visualstudio"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "vssettings"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
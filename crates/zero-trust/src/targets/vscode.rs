@@ -0,0 +1,448 @@
+//! VS Code: one color theme file per variant, with workbench colors,
+//! TextMate token colors and semantic token colors.
+//!
+//! Semantic tokens reuse the syntax role of the TextMate scopes they stand
+//! for, so a language server taking over highlighting does not change the
+//! colors. A `package.json` contributing the rendered variants makes the
+//! directory an unpacked extension, which `zero-trust install` copies into
+//! `~/.vscode/extensions` under the folder name VS Code gives installed
+//! extensions; `zero-trust package vscode` builds a `.vsix` instead.
+
+use std::path::Path;
+
+use serde_json::{json, Map, Value};
+use zero_trust_palette::brackets::{self, DEFAULT_LEVELS};
+use zero_trust_palette::roles::{Role, Style};
+use zero_trust_palette::{Appearance, Color, Theme, Variant};
+
+use super::textmate::{self, SCOPES};
+use crate::install::{Dirs, InstallDir};
+use crate::languages;
+use crate::package::{self, url_owner, VSCODE_ENGINE};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct VsCode;
+
+const SCHEMA: &str = "vscode://schemas/color-theme";
+
+/// Workbench color keys and the roles that paint them, in file order.
+const COLORS: &[(&str, Role)] = &[
+    ("foreground", Role::Text),
+    ("descriptionForeground", Role::TextMuted),
+    ("disabledForeground", Role::TextDisabled),
+    ("errorForeground", Role::Error),
+    ("focusBorder", Role::BorderFocused),
+    ("icon.foreground", Role::Icon),
+    ("selection.background", Role::Selection),
+    ("textLink.foreground", Role::Link),
+    ("textLink.activeForeground", Role::Link),
+    ("activityBar.background", Role::Surface),
+    ("activityBar.foreground", Role::Icon),
+    ("activityBar.inactiveForeground", Role::IconMuted),
+    ("activityBar.border", Role::Border),
+    ("activityBar.activeBorder", Role::Accent),
+    ("activityBarBadge.background", Role::Accent),
+    ("activityBarBadge.foreground", Role::Background),
+    ("badge.background", Role::ElementSelected),
+    ("badge.foreground", Role::Text),
+    ("sideBar.background", Role::Panel),
+    ("sideBar.foreground", Role::Text),
+    ("sideBar.border", Role::Border),
+    ("sideBarTitle.foreground", Role::TextMuted),
+    ("sideBarSectionHeader.background", Role::Panel),
+    ("sideBarSectionHeader.foreground", Role::Text),
+    ("sideBarSectionHeader.border", Role::Border),
+    ("titleBar.activeBackground", Role::Titlebar),
+    ("titleBar.activeForeground", Role::Text),
+    ("titleBar.inactiveBackground", Role::TitlebarInactive),
+    ("titleBar.inactiveForeground", Role::TextMuted),
+    ("titleBar.border", Role::Border),
+    ("statusBar.background", Role::Statusline),
+    ("statusBar.foreground", Role::TextMuted),
+    ("statusBar.border", Role::Border),
+    ("statusBar.noFolderBackground", Role::Statusline),
+    ("statusBar.debuggingBackground", Role::WarningBackground),
+    ("statusBar.debuggingForeground", Role::Warning),
+    ("statusBarItem.hoverBackground", Role::ElementHover),
+    ("statusBarItem.remoteBackground", Role::ElementSelected),
+    ("statusBarItem.remoteForeground", Role::Text),
+    ("editorGroup.border", Role::Split),
+    ("editorGroupHeader.tabsBackground", Role::Tabline),
+    ("editorGroupHeader.tabsBorder", Role::Border),
+    ("tab.activeBackground", Role::TabActive),
+    ("tab.activeForeground", Role::Text),
+    ("tab.activeBorderTop", Role::Accent),
+    ("tab.inactiveBackground", Role::TabInactive),
+    ("tab.inactiveForeground", Role::TextMuted),
+    ("tab.hoverBackground", Role::ElementHover),
+    ("tab.border", Role::Border),
+    ("breadcrumb.background", Role::Background),
+    ("breadcrumb.foreground", Role::TextMuted),
+    ("breadcrumb.focusForeground", Role::Text),
+    ("breadcrumb.activeSelectionForeground", Role::Accent),
+    ("panel.background", Role::Panel),
+    ("panel.border", Role::Border),
+    ("panelTitle.activeForeground", Role::Text),
+    ("panelTitle.activeBorder", Role::Accent),
+    ("panelTitle.inactiveForeground", Role::TextMuted),
+    ("list.activeSelectionBackground", Role::ElementSelected),
+    ("list.activeSelectionForeground", Role::Text),
+    ("list.inactiveSelectionBackground", Role::ElementActive),
+    ("list.hoverBackground", Role::ElementHover),
+    ("list.focusOutline", Role::BorderFocused),
+    ("list.highlightForeground", Role::Accent),
+    ("list.errorForeground", Role::Error),
+    ("list.warningForeground", Role::Warning),
+    ("list.dropBackground", Role::DropTarget),
+    ("tree.indentGuidesStroke", Role::Guide),
+    ("input.background", Role::Element),
+    ("input.foreground", Role::Text),
+    ("input.border", Role::Border),
+    ("input.placeholderForeground", Role::TextPlaceholder),
+    ("inputValidation.errorBackground", Role::ErrorBackground),
+    ("inputValidation.errorBorder", Role::ErrorBorder),
+    ("inputValidation.warningBackground", Role::WarningBackground),
+    ("inputValidation.warningBorder", Role::WarningBorder),
+    ("inputValidation.infoBackground", Role::InfoBackground),
+    ("inputValidation.infoBorder", Role::InfoBorder),
+    ("dropdown.background", Role::Element),
+    ("dropdown.foreground", Role::Text),
+    ("dropdown.border", Role::Border),
+    ("button.background", Role::Accent),
+    ("button.foreground", Role::Background),
+    ("button.secondaryBackground", Role::Element),
+    ("button.secondaryForeground", Role::Text),
+    ("button.secondaryHoverBackground", Role::ElementHover),
+    ("scrollbarSlider.background", Role::ScrollbarThumb),
+    ("scrollbarSlider.hoverBackground", Role::ScrollbarThumbHover),
+    (
+        "scrollbarSlider.activeBackground",
+        Role::ScrollbarThumbHover,
+    ),
+    ("notifications.background", Role::SurfaceElevated),
+    ("notifications.foreground", Role::Text),
+    ("notifications.border", Role::Border),
+    ("editorWidget.background", Role::SurfaceElevated),
+    ("editorWidget.foreground", Role::Text),
+    ("editorWidget.border", Role::Border),
+    ("editorSuggestWidget.background", Role::SurfaceElevated),
+    ("editorSuggestWidget.border", Role::Border),
+    ("editorSuggestWidget.foreground", Role::Text),
+    (
+        "editorSuggestWidget.selectedBackground",
+        Role::ElementSelected,
+    ),
+    ("editorSuggestWidget.highlightForeground", Role::Accent),
+    ("editorHoverWidget.background", Role::SurfaceElevated),
+    ("editorHoverWidget.border", Role::Border),
+    ("peekView.border", Role::BorderFocused),
+    ("peekViewEditor.background", Role::Surface),
+    ("peekViewResult.background", Role::Panel),
+    ("peekViewTitle.background", Role::Toolbar),
+    ("editor.background", Role::Background),
+    ("editor.foreground", Role::Text),
+    ("editorCursor.foreground", Role::Cursor),
+    ("editor.selectionBackground", Role::Selection),
+    ("editor.inactiveSelectionBackground", Role::Highlight),
+    ("editor.lineHighlightBackground", Role::Cursorline),
+    ("editor.rangeHighlightBackground", Role::LineHighlight),
+    ("editor.findMatchBackground", Role::SearchMatch),
+    ("editor.findMatchHighlightBackground", Role::Highlight),
+    ("editor.wordHighlightBackground", Role::Highlight),
+    ("editor.wordHighlightStrongBackground", Role::Highlight),
+    ("editorLink.activeForeground", Role::Link),
+    ("editorLineNumber.foreground", Role::LineNumber),
+    ("editorLineNumber.activeForeground", Role::LineNumberActive),
+    ("editorGutter.background", Role::Gutter),
+    ("editorGutter.addedBackground", Role::GitAdded),
+    ("editorGutter.modifiedBackground", Role::GitModified),
+    ("editorGutter.deletedBackground", Role::GitDeleted),
+    ("editorIndentGuide.background1", Role::Guide),
+    ("editorIndentGuide.activeBackground1", Role::GuideActive),
+    ("editorRuler.foreground", Role::Guide),
+    ("editorWhitespace.foreground", Role::Invisible),
+    ("editorBracketMatch.background", Role::Highlight),
+    ("editorBracketMatch.border", Role::BorderFocused),
+    (
+        "editorBracketHighlight.unexpectedBracket.foreground",
+        Role::Error,
+    ),
+    ("editorCodeLens.foreground", Role::TextMuted),
+    ("editorInlayHint.foreground", Role::Hint),
+    ("editorInlayHint.background", Role::HintBackground),
+    ("editorGhostText.foreground", Role::SyntaxPredictive),
+    ("editorError.foreground", Role::Error),
+    ("editorWarning.foreground", Role::Warning),
+    ("editorInfo.foreground", Role::Info),
+    ("editorHint.foreground", Role::Hint),
+    ("editorOverviewRuler.border", Role::Border),
+    ("editorOverviewRuler.addedForeground", Role::GitAdded),
+    ("editorOverviewRuler.modifiedForeground", Role::GitModified),
+    ("editorOverviewRuler.deletedForeground", Role::GitDeleted),
+    ("editorOverviewRuler.errorForeground", Role::Error),
+    ("editorOverviewRuler.warningForeground", Role::Warning),
+    ("diffEditor.insertedTextBackground", Role::SuccessBackground),
+    ("diffEditor.removedTextBackground", Role::ErrorBackground),
+    ("merge.currentHeaderBackground", Role::GitConflictOurs),
+    ("merge.incomingHeaderBackground", Role::GitConflictTheirs),
+    ("gitDecoration.addedResourceForeground", Role::GitAdded),
+    (
+        "gitDecoration.modifiedResourceForeground",
+        Role::GitModified,
+    ),
+    ("gitDecoration.deletedResourceForeground", Role::GitDeleted),
+    ("gitDecoration.untrackedResourceForeground", Role::GitAdded),
+    ("gitDecoration.ignoredResourceForeground", Role::Ignored),
+    ("gitDecoration.conflictingResourceForeground", Role::Error),
+    ("terminal.background", Role::TerminalBackground),
+    ("terminal.foreground", Role::TerminalForeground),
+    ("terminal.selectionBackground", Role::Selection),
+    ("terminalCursor.foreground", Role::Cursor),
+    ("terminal.ansiBlack", Role::TerminalBlack),
+    ("terminal.ansiRed", Role::TerminalRed),
+    ("terminal.ansiGreen", Role::TerminalGreen),
+    ("terminal.ansiYellow", Role::TerminalYellow),
+    ("terminal.ansiBlue", Role::TerminalBlue),
+    ("terminal.ansiMagenta", Role::TerminalMagenta),
+    ("terminal.ansiCyan", Role::TerminalCyan),
+    ("terminal.ansiWhite", Role::TerminalWhite),
+    ("terminal.ansiBrightBlack", Role::TerminalBrightBlack),
+    ("terminal.ansiBrightRed", Role::TerminalBrightRed),
+    ("terminal.ansiBrightGreen", Role::TerminalBrightGreen),
+    ("terminal.ansiBrightYellow", Role::TerminalBrightYellow),
+    ("terminal.ansiBrightBlue", Role::TerminalBrightBlue),
+    ("terminal.ansiBrightMagenta", Role::TerminalBrightMagenta),
+    ("terminal.ansiBrightCyan", Role::TerminalBrightCyan),
+    ("terminal.ansiBrightWhite", Role::TerminalBrightWhite),
+];
+
+/// Semantic token types, standard and rust-analyzer's, and the syntax
+/// roles of the TextMate scopes they replace.
+const SEMANTIC: &[(&str, Role)] = &[
+    ("namespace", Role::Namespace),
+    ("type", Role::Type),
+    ("class", Role::Type),
+    ("struct", Role::Type),
+    ("interface", Role::Type),
+    ("typeAlias", Role::Type),
+    ("union", Role::Type),
+    ("builtinType", Role::Type),
+    ("typeParameter", Role::Type),
+    ("enum", Role::Enum),
+    ("enumMember", Role::Variant),
+    ("function", Role::Function),
+    ("method", Role::Function),
+    ("macro", Role::Function),
+    ("decorator", Role::Attribute),
+    ("attribute", Role::Attribute),
+    ("builtinAttribute", Role::Attribute),
+    ("derive", Role::Attribute),
+    ("parameter", Role::Variable),
+    ("variable", Role::Variable),
+    ("variable.readonly", Role::Constant),
+    ("variable.constant", Role::Constant),
+    ("selfKeyword", Role::VariableSpecial),
+    ("property", Role::Property),
+    ("label", Role::Label),
+    ("lifetime", Role::Label),
+    ("comment", Role::Comment),
+    ("comment.documentation", Role::CommentDoc),
+    ("string", Role::String),
+    ("escapeSequence", Role::StringEscape),
+    ("formatSpecifier", Role::StringSpecial),
+    ("regexp", Role::StringRegex),
+    ("keyword", Role::Keyword),
+    ("number", Role::Number),
+    ("boolean", Role::Boolean),
+    ("operator", Role::Operator),
+];
+
+/// Token modifiers that change the font style but keep the token's color:
+/// mutable bindings are underlined as rust-analyzer suggests, async code
+/// is italic and deprecated items are struck through.
+const MODIFIERS: &[(&str, &str)] = &[
+    ("*.mutable", "underline"),
+    ("*.async", "italic"),
+    ("*.deprecated", "strikethrough"),
+];
+
+fn color(color: Color) -> Value {
+    Value::String(color.to_string())
+}
+
+/// The extension's publisher: the homepage's owner, as for Nova.
+fn publisher(meta: &Meta) -> String {
+    url_owner(&meta.homepage)
+        .unwrap_or(&meta.author)
+        .to_lowercase()
+        .replace(' ', "")
+}
+
+/// The manifest of the unpacked extension.
+fn manifest(meta: &Meta, variants: &[Variant]) -> Value {
+    let themes: Vec<Value> = variants
+        .iter()
+        .map(|&variant| {
+            json!({
+                "label": meta.theme_name(variant),
+                "uiTheme": package::ui_theme(variant),
+                "path": format!("./{}.json", meta.file_stem(variant)),
+            })
+        })
+        .collect();
+    json!({
+        "name": meta.id(),
+        "displayName": meta.name,
+        "version": meta.version,
+        "publisher": publisher(meta),
+        "license": meta.license,
+        "engines": { "vscode": VSCODE_ENGINE },
+        "categories": ["Themes"],
+        "contributes": { "themes": themes },
+    })
+}
+
+/// The VS Code theme kind, which decides the defaults for keys left out.
+fn kind(variant: Variant) -> &'static str {
+    match (variant, variant.appearance()) {
+        (Variant::HighContrast, Appearance::Dark) => "hcDark",
+        (Variant::HighContrast, Appearance::Light) => "hcLight",
+        (_, Appearance::Dark) => "dark",
+        (_, Appearance::Light) => "light",
+    }
+}
+
+fn colors(theme: &Theme) -> Map<String, Value> {
+    let mut colors = Map::new();
+    for &(key, role) in COLORS {
+        colors.insert(key.into(), color(theme.color(role)));
+    }
+    let rainbow: [Color; DEFAULT_LEVELS] = brackets::rainbow(theme);
+    for (level, &bracket) in rainbow.iter().enumerate() {
+        let n = level + 1;
+        colors.insert(
+            format!("editorBracketHighlight.foreground{n}"),
+            color(bracket),
+        );
+        colors.insert(
+            format!("editorBracketPairGuide.activeBackground{n}"),
+            color(bracket),
+        );
+    }
+    colors
+}
+
+fn token_settings(theme: &Theme, role: Role) -> Value {
+    json!({
+        "foreground": color(theme.opaque_color(role)),
+        "fontStyle": textmate::font_style(theme.style(role)),
+    })
+}
+
+fn token_colors(source: &Source, theme: &Theme) -> Vec<Value> {
+    let mut rules: Vec<Value> = SCOPES
+        .iter()
+        .map(|&(role, scopes)| {
+            json!({
+                "name": role.name(),
+                "scope": scopes,
+                "settings": token_settings(theme, role),
+            })
+        })
+        .collect();
+    for rule in languages::rules(source, theme.variant) {
+        let scopes = textmate::language_scopes(rule.role, &rule.language);
+        if scopes.is_empty() {
+            continue;
+        }
+        rules.push(json!({
+            "name": format!("{} ({})", rule.role.name(), rule.language),
+            "scope": scopes,
+            "settings": {
+                "foreground": color(rule.opaque),
+                "fontStyle": textmate::font_style(rule.style),
+            },
+        }));
+    }
+    rules
+}
+
+fn semantic_style(foreground: Color, style: Style) -> Value {
+    json!({
+        "foreground": color(foreground),
+        "italic": style.italic,
+        "bold": style.is_bold(),
+    })
+}
+
+fn semantic_token_colors(source: &Source, theme: &Theme) -> Map<String, Value> {
+    let mut tokens = Map::new();
+    for &(token, role) in SEMANTIC {
+        tokens.insert(
+            token.into(),
+            semantic_style(theme.opaque_color(role), theme.style(role)),
+        );
+    }
+    for &(modifier, font_style) in MODIFIERS {
+        tokens.insert(modifier.into(), json!({ font_style: true }));
+    }
+    // Unsafe code stands out in the special-variable color, in bold.
+    tokens.insert(
+        "*.unsafe".into(),
+        json!({
+            "foreground": color(theme.opaque_color(Role::VariableSpecial)),
+            "bold": true,
+        }),
+    );
+    for rule in languages::rules(source, theme.variant) {
+        for &(token, _) in SEMANTIC.iter().filter(|(_, role)| *role == rule.role) {
+            tokens.insert(
+                format!("{token}:{}", rule.language),
+                semantic_style(rule.opaque, rule.style),
+            );
+        }
+    }
+    tokens
+}
+
+impl Target for VsCode {
+    fn name(&self) -> &str {
+        "vscode"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let mut outputs: Vec<Output> = variants
+            .iter()
+            .map(|&variant| {
+                let theme = source.theme(variant);
+                let file = json!({
+                    "$schema": SCHEMA,
+                    "name": source.meta.theme_name(theme.variant),
+                    "type": kind(variant),
+                    "semanticHighlighting": true,
+                    "colors": colors(&theme),
+                    "tokenColors": token_colors(source, &theme),
+                    "semanticTokenColors": semantic_token_colors(source, &theme),
+                });
+                Output::json(
+                    super::extras_path(&source.meta, self.name(), variant, "json"),
+                    &file,
+                )
+            })
+            .collect();
+        outputs.push(Output::json(
+            Path::new("extras").join(self.name()).join("package.json"),
+            &manifest(&source.meta, variants),
+        ));
+        Ok(outputs)
+    }
+
+    fn install_dir(&self, dirs: &Dirs, meta: &Meta) -> Option<InstallDir> {
+        let app = dirs.home.join(".vscode");
+        let folder = format!("{}.{}-{}", publisher(meta), meta.id(), meta.version);
+        Some(InstallDir {
+            themes: app.join("extensions").join(folder),
+            app,
+        })
+    }
+}
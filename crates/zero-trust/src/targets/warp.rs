@@ -0,0 +1,83 @@
+//! Warp: one YAML theme per variant.
+//!
+//! Warp can also paint the background as a vertical gradient;
+//! that form is written commented out under the plain background, running
+//! from the terminal background down to the panel color.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Appearance, Theme, Variant};
+
+use super::{ANSI_NAMES, TERMINAL};
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Warp;
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    let hex = |role| theme.opaque_color(role);
+    // Whether Warp derives its interface shades by darkening or lightening
+    // the background.
+    let details = match theme.appearance() {
+        Appearance::Dark => "darker",
+        Appearance::Light => "lighter",
+    };
+    let _ = writeln!(
+        text,
+        "\nname: {}\naccent: '{}'\ncursor: '{}'\nforeground: '{}'\nbackground: '{}'\n\
+         # background:\n#   top: '{}'\n#   bottom: '{}'\ndetails: {details}\nterminal_colors:",
+        source.meta.theme_name(theme.variant),
+        hex(Role::Accent),
+        hex(Role::Cursor),
+        hex(Role::TerminalForeground),
+        hex(Role::TerminalBackground),
+        hex(Role::TerminalBackground),
+        hex(Role::Panel),
+    );
+    for (group, roles) in [("normal", &TERMINAL[..8]), ("bright", &TERMINAL[8..])] {
+        let _ = writeln!(text, "  {group}:");
+        for (name, &role) in ANSI_NAMES.iter().zip(roles) {
+            let _ = writeln!(text, "    {name}: '{}'", hex(role));
+        }
+    }
+    text
+}
+
+impl Target for Warp {
+    fn name(&self) -> &str {
+        "warp"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "yaml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = if cfg!(target_os = "macos") {
+            dirs.home.join(".warp")
+        } else if cfg!(windows) {
+            dirs.data().join("warp").join("Warp")
+        } else {
+            dirs.data().join("warp-terminal")
+        };
+        let themes = if cfg!(windows) {
+            app.join("data").join("themes")
+        } else {
+            app.join("themes")
+        };
+        Some(InstallDir { app, themes })
+    }
+}
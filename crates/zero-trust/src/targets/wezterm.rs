@@ -0,0 +1,109 @@
+//! WezTerm: one TOML color scheme per variant, picked by name with
+//! `color_scheme` in `wezterm.lua`.
+//!
+//! The scheme's `[metadata]` name is the theme name, which is what
+//! `color_scheme` refers to.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::TERMINAL;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct WezTerm;
+
+/// Keys of `[colors]` and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("foreground", Role::TerminalForeground),
+    ("background", Role::TerminalBackground),
+    ("cursor_bg", Role::Cursor),
+    ("cursor_fg", Role::TerminalBackground),
+    ("cursor_border", Role::Cursor),
+    ("compose_cursor", Role::Warning),
+    ("selection_fg", Role::TerminalForeground),
+    ("selection_bg", Role::Selection),
+    ("scrollbar_thumb", Role::ScrollbarThumb),
+    ("split", Role::Split),
+    ("visual_bell", Role::Highlight),
+];
+
+/// Tab bar tables below `[colors.tab_bar]` with their background and
+/// foreground roles.
+const TABS: &[(&str, Role, Role)] = &[
+    ("active_tab", Role::TabActive, Role::Text),
+    ("inactive_tab", Role::TabInactive, Role::TextMuted),
+    ("inactive_tab_hover", Role::ElementHover, Role::Text),
+    ("new_tab", Role::Tabline, Role::TextMuted),
+    ("new_tab_hover", Role::ElementHover, Role::Text),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let meta = &source.meta;
+    let mut text = String::new();
+    for line in meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    let hex = |role| theme.opaque_color(role);
+    text.push_str("\n[colors]\n");
+    for &(key, role) in COLORS {
+        let _ = writeln!(text, "{key} = \"{}\"", hex(role));
+    }
+    for (key, roles) in [("ansi", &TERMINAL[..8]), ("brights", &TERMINAL[8..])] {
+        let colors: Vec<String> = roles
+            .iter()
+            .map(|&role| format!("\"{}\"", hex(role)))
+            .collect();
+        let _ = writeln!(text, "{key} = [{}]", colors.join(", "));
+    }
+    let _ = writeln!(
+        text,
+        "\n[colors.tab_bar]\nbackground = \"{}\"\ninactive_tab_edge = \"{}\"",
+        hex(Role::Tabline),
+        hex(Role::Border)
+    );
+    for &(tab, bg, fg) in TABS {
+        let _ = writeln!(
+            text,
+            "\n[colors.tab_bar.{tab}]\nbg_color = \"{}\"\nfg_color = \"{}\"",
+            hex(bg),
+            hex(fg)
+        );
+    }
+    let _ = writeln!(
+        text,
+        "\n[metadata]\nname = \"{}\"\nauthor = \"{}\"\norigin_url = \"{}\"",
+        source.meta.theme_name(theme.variant),
+        meta.author,
+        meta.homepage
+    );
+    text
+}
+
+impl Target for WezTerm {
+    fn name(&self) -> &str {
+        "wezterm"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "toml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("wezterm");
+        Some(InstallDir {
+            themes: app.join("colors"),
+            app,
+        })
+    }
+}
@@ -0,0 +1,83 @@
+//! Windows Terminal: a settings fragment with one color scheme per variant.
+//!
+//! The `schemes` entries can also be pasted into `settings.json` as they
+//! are. Windows Terminal draws the selection translucent on its own.
+
+use std::path::Path;
+
+use serde_json::{json, Map, Value};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::Variant;
+
+use super::TERMINAL;
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct WindowsTerminal;
+
+/// Scheme keys other than the ANSI colors and the roles that paint them.
+const COLORS: &[(&str, Role)] = &[
+    ("background", Role::TerminalBackground),
+    ("foreground", Role::TerminalForeground),
+    ("cursorColor", Role::Cursor),
+    ("selectionBackground", Role::Selection),
+];
+
+/// The scheme keys of the sixteen ANSI colors, in `TERMINAL` order.
+const ANSI: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "purple",
+    "cyan",
+    "white",
+    "brightBlack",
+    "brightRed",
+    "brightGreen",
+    "brightYellow",
+    "brightBlue",
+    "brightPurple",
+    "brightCyan",
+    "brightWhite",
+];
+
+impl Target for WindowsTerminal {
+    fn name(&self) -> &str {
+        "windowsterminal"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let schemes: Vec<Value> = variants
+            .iter()
+            .map(|&variant| {
+                let theme = source.theme(variant);
+                let mut scheme = Map::new();
+                scheme.insert("name".into(), source.meta.theme_name(theme.variant).into());
+                let keys = COLORS.iter().copied().chain(ANSI.into_iter().zip(TERMINAL));
+                for (key, role) in keys {
+                    scheme.insert(key.into(), theme.opaque_color(role).to_string().into());
+                }
+                Value::Object(scheme)
+            })
+            .collect();
+        Ok(vec![Output::json(
+            Path::new("extras")
+                .join(self.name())
+                .join(format!("{}.json", source.meta.stem())),
+            &json!({ "schemes": schemes }),
+        )])
+    }
+
+    fn install_dir(&self, dirs: &Dirs, meta: &Meta) -> Option<InstallDir> {
+        if !cfg!(windows) {
+            return None;
+        }
+        let app = dirs.data().join("Microsoft").join("Windows Terminal");
+        Some(InstallDir {
+            themes: app.join("Fragments").join(meta.name.replace(' ', "")),
+            app,
+        })
+    }
+}
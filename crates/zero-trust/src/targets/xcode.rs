@@ -0,0 +1,193 @@
+//! Xcode: one `.xccolortheme` property list per variant, with the source
+//! editor's syntax categories and the console colors.
+//!
+//! Xcode names a theme after its file, so the files are named after the
+//! themes rather than the variant slugs. Editor backgrounds keep their
+//! alpha, which Xcode blends itself. Xcode has no per-language categories.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::package::escape_xml;
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Xcode;
+
+/// The point size of every font; Xcode scales them all together.
+const FONT_SIZE: &str = "12.0";
+
+/// Source editor syntax categories and the roles that paint them. Bold and
+/// italics follow the role's style.
+const SYNTAX: &[(&str, Role)] = &[
+    ("xcode.syntax.plain", Role::Text),
+    ("xcode.syntax.keyword", Role::Keyword),
+    ("xcode.syntax.string", Role::String),
+    ("xcode.syntax.character", Role::StringEscape),
+    ("xcode.syntax.number", Role::Number),
+    ("xcode.syntax.comment", Role::Comment),
+    ("xcode.syntax.comment.doc", Role::CommentDoc),
+    ("xcode.syntax.comment.doc.keyword", Role::CommentDoc),
+    ("xcode.syntax.mark", Role::Title),
+    ("xcode.syntax.url", Role::Link),
+    ("xcode.syntax.preprocessor", Role::Preproc),
+    ("xcode.syntax.attribute", Role::Attribute),
+    ("xcode.syntax.declaration.type", Role::Type),
+    ("xcode.syntax.declaration.other", Role::Function),
+    ("xcode.syntax.identifier.class", Role::Type),
+    ("xcode.syntax.identifier.class.system", Role::Type),
+    ("xcode.syntax.identifier.type", Role::Type),
+    ("xcode.syntax.identifier.type.system", Role::Type),
+    ("xcode.syntax.identifier.constant", Role::Constant),
+    ("xcode.syntax.identifier.constant.system", Role::Constant),
+    ("xcode.syntax.identifier.function", Role::Function),
+    ("xcode.syntax.identifier.function.system", Role::Function),
+    ("xcode.syntax.identifier.macro", Role::Function),
+    ("xcode.syntax.identifier.macro.system", Role::Function),
+    ("xcode.syntax.identifier.variable", Role::Variable),
+    (
+        "xcode.syntax.identifier.variable.system",
+        Role::VariableSpecial,
+    ),
+    ("xcode.syntax.markup.code", Role::TextLiteral),
+    ("xcode.syntax.regex", Role::StringRegex),
+    ("xcode.syntax.regex.capturename", Role::Label),
+    ("xcode.syntax.regex.charname", Role::StringEscape),
+    ("xcode.syntax.regex.number", Role::Number),
+    ("xcode.syntax.regex.other", Role::StringRegex),
+];
+
+/// Source editor colors and the roles that paint them.
+const EDITOR: &[(&str, Role)] = &[
+    ("DVTSourceTextBackground", Role::Background),
+    (
+        "DVTSourceTextCurrentLineHighlightColor",
+        Role::LineHighlight,
+    ),
+    ("DVTSourceTextInsertionPointColor", Role::Cursor),
+    ("DVTSourceTextInvisiblesColor", Role::Invisible),
+    ("DVTSourceTextSelectionColor", Role::Selection),
+    ("DVTSourceTextBlockDimBackgroundColor", Role::Surface),
+    ("DVTMarkupTextBackgroundColor", Role::Surface),
+    ("DVTMarkupTextNormalColor", Role::Text),
+    ("DVTMarkupTextLinkColor", Role::Link),
+    ("DVTMarkupTextCodeColor", Role::TextLiteral),
+];
+
+/// Console colors and the roles that paint them. The `Exectuable` typo is
+/// Xcode's own.
+const CONSOLE: &[(&str, Role)] = &[
+    ("DVTConsoleTextBackgroundColor", Role::TerminalBackground),
+    ("DVTConsoleTextInsertionPointColor", Role::Cursor),
+    ("DVTConsoleTextSelectionColor", Role::Selection),
+    ("DVTConsoleDebuggerInputTextColor", Role::TerminalForeground),
+    ("DVTConsoleDebuggerOutputTextColor", Role::TextMuted),
+    ("DVTConsoleDebuggerPromptTextColor", Role::Accent),
+    (
+        "DVTConsoleExectuableInputTextColor",
+        Role::TerminalForeground,
+    ),
+    (
+        "DVTConsoleExectuableOutputTextColor",
+        Role::TerminalForeground,
+    ),
+];
+
+/// Console fonts, set to bold where Xcode's defaults are bold.
+const CONSOLE_FONTS: &[(&str, bool)] = &[
+    ("DVTConsoleDebuggerInputTextFont", true),
+    ("DVTConsoleDebuggerOutputTextFont", false),
+    ("DVTConsoleDebuggerPromptTextFont", true),
+    ("DVTConsoleExectuableInputTextFont", false),
+    ("DVTConsoleExectuableOutputTextFont", false),
+];
+
+fn font(bold: bool, italic: bool) -> String {
+    let face = match (bold, italic) {
+        (false, false) => "SFMono-Regular",
+        (true, false) => "SFMono-Bold",
+        (false, true) => "SFMono-RegularItalic",
+        (true, true) => "SFMono-BoldItalic",
+    };
+    format!("{face} - {FONT_SIZE}")
+}
+
+fn entry(text: &mut String, indent: usize, key: &str, value: impl std::fmt::Display) {
+    let _ = writeln!(
+        text,
+        "{:indent$}<key>{key}</key>\n{:indent$}<string>{value}</string>",
+        "", ""
+    );
+}
+
+fn color(text: &mut String, indent: usize, key: &str, color: Color) {
+    entry(text, indent, key, color.float_rgba());
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+         \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n<!--\n",
+    );
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "  {}", escape_xml(&line));
+    }
+    text.push_str("-->\n<plist version=\"1.0\">\n<dict>\n");
+    for &(key, role) in CONSOLE {
+        color(&mut text, 1, key, theme.color(role));
+    }
+    for &(key, bold) in CONSOLE_FONTS {
+        entry(&mut text, 1, key, font(bold, false));
+    }
+    entry(&mut text, 1, "DVTFontAndColorVersion", 1);
+    for &(key, role) in EDITOR {
+        color(&mut text, 1, key, theme.color(role));
+    }
+    text.push_str(" <key>DVTSourceTextSyntaxColors</key>\n <dict>\n");
+    for &(key, role) in SYNTAX {
+        color(&mut text, 2, key, theme.opaque_color(role));
+    }
+    text.push_str(" </dict>\n <key>DVTSourceTextSyntaxFonts</key>\n <dict>\n");
+    for &(key, role) in SYNTAX {
+        let style = theme.style(role);
+        let bold = style.is_bold();
+        entry(&mut text, 2, key, font(bold, style.italic));
+    }
+    text.push_str(" </dict>\n</dict>\n</plist>\n");
+    text
+}
+
+impl Target for Xcode {
+    fn name(&self) -> &str {
+        "xcode"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let dir = Path::new("extras").join(self.name());
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                let theme = source.theme(variant);
+                Output::new(
+                    dir.join(format!(
+                        "{}.xccolortheme",
+                        source.meta.theme_name(theme.variant)
+                    )),
+                    render(source, &theme),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.home.join("Library/Developer/Xcode");
+        Some(InstallDir {
+            themes: app.join("UserData/FontAndColorThemes"),
+            app,
+        })
+    }
+}
@@ -0,0 +1,196 @@
+//! yazi: one `theme.toml` per variant with the manager, mode, status bar and
+//! select styles and the file type rules.
+//!
+//! File types follow the dircolors target: special files by kind, then its
+//! extension groups in the same roles, in truecolor here since yazi takes
+//! hex colors. yazi reads a single `theme.toml` from its config directory,
+//! so picking a variant means linking its file there.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::dircolors::EXTENSIONS;
+use crate::{Error, Output, Source, Target};
+
+pub struct Yazi;
+
+/// How a style is painted.
+#[derive(Clone, Copy)]
+struct Paint {
+    fg: Role,
+    bg: Option<Role>,
+    bold: bool,
+}
+
+impl Paint {
+    const fn fg(role: Role) -> Paint {
+        Paint {
+            fg: role,
+            bg: None,
+            bold: false,
+        }
+    }
+
+    const fn on(fg: Role, bg: Role) -> Paint {
+        Paint {
+            fg,
+            bg: Some(bg),
+            bold: false,
+        }
+    }
+
+    const fn bold(self) -> Paint {
+        Paint { bold: true, ..self }
+    }
+
+    /// The paint as an inline table.
+    fn style(self, theme: &Theme) -> String {
+        let mut style = format!("fg = \"{}\"", theme.opaque_color(self.fg));
+        if let Some(bg) = self.bg {
+            let _ = write!(style, ", bg = \"{}\"", theme.opaque_color(bg));
+        }
+        if self.bold {
+            style.push_str(", bold = true");
+        }
+        style
+    }
+}
+
+/// Sections of styles.
+const SECTIONS: &[(&str, &[(&str, Paint)])] = &[
+    (
+        "manager",
+        &[
+            ("cwd", Paint::fg(Role::Accent)),
+            ("hovered", Paint::on(Role::Text, Role::ElementSelected)),
+            ("preview_hovered", Paint::on(Role::Text, Role::ElementHover)),
+            ("find_keyword", Paint::fg(Role::Warning).bold()),
+            ("find_position", Paint::on(Role::Keyword, Role::Element)),
+            ("marker_copied", Paint::on(Role::Success, Role::Success)),
+            ("marker_cut", Paint::on(Role::Error, Role::Error)),
+            ("marker_marked", Paint::on(Role::Info, Role::Info)),
+            ("marker_selected", Paint::on(Role::Warning, Role::Warning)),
+            (
+                "tab_active",
+                Paint::on(Role::Background, Role::Accent).bold(),
+            ),
+            ("tab_inactive", Paint::on(Role::TextMuted, Role::Element)),
+            ("count_copied", Paint::on(Role::Background, Role::Success)),
+            ("count_cut", Paint::on(Role::Background, Role::Error)),
+            ("count_selected", Paint::on(Role::Background, Role::Warning)),
+            ("border_style", Paint::fg(Role::Border)),
+        ],
+    ),
+    (
+        "mode",
+        &[
+            (
+                "normal_main",
+                Paint::on(Role::Background, Role::Accent).bold(),
+            ),
+            ("normal_alt", Paint::on(Role::Accent, Role::Element)),
+            (
+                "select_main",
+                Paint::on(Role::Background, Role::Warning).bold(),
+            ),
+            ("select_alt", Paint::on(Role::Warning, Role::Element)),
+            (
+                "unset_main",
+                Paint::on(Role::Background, Role::Error).bold(),
+            ),
+            ("unset_alt", Paint::on(Role::Error, Role::Element)),
+        ],
+    ),
+    (
+        "status",
+        &[
+            ("overall", Paint::on(Role::Text, Role::Statusline)),
+            ("perm_type", Paint::fg(Role::Accent)),
+            ("perm_read", Paint::fg(Role::Warning)),
+            ("perm_write", Paint::fg(Role::Error)),
+            ("perm_exec", Paint::fg(Role::Success)),
+            ("perm_sep", Paint::fg(Role::TextDisabled)),
+            ("progress_label", Paint::fg(Role::Text).bold()),
+            ("progress_normal", Paint::on(Role::Accent, Role::Element)),
+            ("progress_error", Paint::on(Role::Error, Role::Element)),
+        ],
+    ),
+    (
+        "select",
+        &[
+            ("border", Paint::fg(Role::BorderFocused)),
+            ("active", Paint::fg(Role::Accent).bold()),
+            ("inactive", Paint::fg(Role::TextMuted)),
+        ],
+    ),
+];
+
+/// File type rules by kind, ahead of the extension groups.
+const KINDS: &[(&str, Paint)] = &[
+    ("orphan", Paint::fg(Role::Error).bold()),
+    ("link", Paint::fg(Role::Info)),
+    ("block", Paint::fg(Role::Warning).bold()),
+    ("char", Paint::fg(Role::Warning).bold()),
+    ("fifo", Paint::fg(Role::Warning)),
+    ("sock", Paint::fg(Role::Keyword)),
+    ("exec", Paint::fg(Role::Success).bold()),
+];
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    for &(section, styles) in SECTIONS {
+        let _ = writeln!(text, "\n[{section}]");
+        for &(key, paint) in styles {
+            let _ = writeln!(text, "{key} = {{ {} }}", paint.style(theme));
+        }
+    }
+    text.push_str("\n[filetype]\nrules = [\n");
+    let _ = writeln!(
+        text,
+        "    {{ name = \"*/\", {} }},",
+        Paint::fg(Role::Accent).bold().style(theme)
+    );
+    for &(kind, paint) in KINDS {
+        let _ = writeln!(
+            text,
+            "    {{ name = \"*\", is = \"{kind}\", {} }},",
+            paint.style(theme)
+        );
+    }
+    for &(heading, role, extensions) in EXTENSIONS {
+        let _ = writeln!(text, "    # {heading}");
+        let style = Paint::fg(role).style(theme);
+        for extension in extensions {
+            let _ = writeln!(text, "    {{ name = \"*.{extension}\", {style} }},");
+        }
+    }
+    let _ = writeln!(
+        text,
+        "    {{ name = \"*\", {} }},\n]",
+        Paint::fg(Role::Text).style(theme)
+    );
+    text
+}
+
+impl Target for Yazi {
+    fn name(&self) -> &str {
+        "yazi"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "toml"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
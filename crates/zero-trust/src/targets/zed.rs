@@ -0,0 +1,260 @@
+//! Zed: one theme family file holding every variant.
+
+use serde_json::{json, Map, Value};
+use zero_trust_palette::brackets::{self, DEFAULT_LEVELS};
+use zero_trust_palette::roles::{Role, PLAYERS};
+use zero_trust_palette::{Color, Theme, Variant};
+
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Zed;
+
+const SCHEMA: &str = "https://zed.dev/schema/themes/v0.2.0.json";
+
+/// Zed style keys and the roles that paint them, in file order.
+const STYLE: &[(&str, Role)] = &[
+    ("border", Role::Border),
+    ("border.variant", Role::BorderVariant),
+    ("border.focused", Role::BorderFocused),
+    ("border.selected", Role::BorderSelected),
+    ("border.transparent", Role::Border),
+    ("border.disabled", Role::BorderDisabled),
+    ("elevated_surface.background", Role::SurfaceElevated),
+    ("surface.background", Role::Background),
+    ("background", Role::Background),
+    ("element.background", Role::Element),
+    ("element.hover", Role::ElementHover),
+    ("element.active", Role::ElementActive),
+    ("element.selected", Role::ElementSelected),
+    ("element.disabled", Role::ElementDisabled),
+    ("drop_target.background", Role::DropTarget),
+    ("ghost_element.background", Role::Ghost),
+    ("ghost_element.hover", Role::GhostHover),
+    ("ghost_element.active", Role::GhostActive),
+    ("ghost_element.selected", Role::ElementSelected),
+    ("ghost_element.disabled", Role::ElementDisabled),
+    ("text", Role::Text),
+    ("text.muted", Role::TextMuted),
+    ("text.placeholder", Role::TextPlaceholder),
+    ("text.disabled", Role::TextDisabled),
+    ("text.accent", Role::Accent),
+    ("icon", Role::Icon),
+    ("icon.muted", Role::IconMuted),
+    ("icon.disabled", Role::TextDisabled),
+    ("icon.placeholder", Role::IconPlaceholder),
+    ("icon.accent", Role::Accent),
+    ("status_bar.background", Role::Statusline),
+    ("title_bar.background", Role::Titlebar),
+    ("title_bar.inactive_background", Role::TitlebarInactive),
+    ("toolbar.background", Role::Toolbar),
+    ("tab_bar.background", Role::Tabline),
+    ("tab.inactive_background", Role::TabInactive),
+    ("tab.active_background", Role::TabActive),
+    ("search.match_background", Role::SearchMatch),
+    ("panel.background", Role::Panel),
+    ("panel.focused_border", Role::Accent),
+    ("panel.indent_guide", Role::Guide),
+    ("panel.indent_guide_active", Role::GuideActive),
+    ("panel.indent_guide_hover", Role::GuideHover),
+    ("pane.focused_border", Role::Accent),
+    ("pane_group.border", Role::Split),
+    ("scrollbar.thumb.background", Role::ScrollbarThumb),
+    (
+        "scrollbar.thumb.hover_background",
+        Role::ScrollbarThumbHover,
+    ),
+    ("scrollbar.thumb.border", Role::Border),
+    ("scrollbar.track.background", Role::ScrollbarTrack),
+    ("scrollbar.track.border", Role::ScrollbarTrackBorder),
+    ("minimap.thumb.background", Role::ScrollbarThumb),
+    ("minimap.thumb.hover_background", Role::ScrollbarThumbHover),
+    ("minimap.thumb.active_background", Role::ScrollbarThumbHover),
+    ("minimap.thumb.border", Role::Border),
+    ("editor.foreground", Role::Text),
+    ("editor.background", Role::Background),
+    ("editor.gutter.background", Role::Gutter),
+    ("editor.subheader.background", Role::Subheader),
+    ("editor.active_line.background", Role::Cursorline),
+    ("editor.highlighted_line.background", Role::LineHighlight),
+    ("editor.line_number", Role::LineNumber),
+    ("editor.active_line_number", Role::LineNumberActive),
+    ("editor.hover_line_number", Role::LineNumberHover),
+    ("editor.invisible", Role::Invisible),
+    ("editor.wrap_guide", Role::Guide),
+    ("editor.active_wrap_guide", Role::GuideActive),
+    ("editor.indent_guide", Role::Guide),
+    ("editor.indent_guide_active", Role::GuideActive),
+    ("editor.document_highlight.read_background", Role::Highlight),
+    (
+        "editor.document_highlight.write_background",
+        Role::Highlight,
+    ),
+    (
+        "editor.document_highlight.bracket_background",
+        Role::Highlight,
+    ),
+    ("terminal.background", Role::TerminalBackground),
+    ("terminal.foreground", Role::TerminalForeground),
+    ("terminal.bright_foreground", Role::TerminalForegroundBright),
+    ("terminal.dim_foreground", Role::TerminalForegroundDim),
+    ("terminal.ansi.black", Role::TerminalBlack),
+    ("terminal.ansi.bright_black", Role::TerminalBrightBlack),
+    ("terminal.ansi.dim_black", Role::TerminalDimBlack),
+    ("terminal.ansi.red", Role::TerminalRed),
+    ("terminal.ansi.bright_red", Role::TerminalBrightRed),
+    ("terminal.ansi.dim_red", Role::TerminalDimRed),
+    ("terminal.ansi.green", Role::TerminalGreen),
+    ("terminal.ansi.bright_green", Role::TerminalBrightGreen),
+    ("terminal.ansi.dim_green", Role::TerminalDimGreen),
+    ("terminal.ansi.yellow", Role::TerminalYellow),
+    ("terminal.ansi.bright_yellow", Role::TerminalBrightYellow),
+    ("terminal.ansi.dim_yellow", Role::TerminalDimYellow),
+    ("terminal.ansi.blue", Role::TerminalBlue),
+    ("terminal.ansi.bright_blue", Role::TerminalBrightBlue),
+    ("terminal.ansi.dim_blue", Role::TerminalDimBlue),
+    ("terminal.ansi.magenta", Role::TerminalMagenta),
+    ("terminal.ansi.bright_magenta", Role::TerminalBrightMagenta),
+    ("terminal.ansi.dim_magenta", Role::TerminalDimMagenta),
+    ("terminal.ansi.cyan", Role::TerminalCyan),
+    ("terminal.ansi.bright_cyan", Role::TerminalBrightCyan),
+    ("terminal.ansi.dim_cyan", Role::TerminalDimCyan),
+    ("terminal.ansi.background", Role::TerminalBackground),
+    ("terminal.ansi.white", Role::TerminalWhite),
+    ("terminal.ansi.bright_white", Role::TerminalBrightWhite),
+    ("terminal.ansi.dim_white", Role::TerminalDimWhite),
+    ("link_text.hover", Role::Link),
+    ("version_control.added", Role::GitAdded),
+    ("version_control.modified", Role::GitModified),
+    ("version_control.deleted", Role::GitDeleted),
+    ("version_control.renamed", Role::GitModified),
+    ("version_control.conflict", Role::Error),
+    ("version_control.ignored", Role::Ignored),
+    ("version_control.word_added", Role::SuccessBackground),
+    ("version_control.word_deleted", Role::ErrorBackground),
+    (
+        "version_control.conflict_marker.ours",
+        Role::GitConflictOurs,
+    ),
+    (
+        "version_control.conflict_marker.theirs",
+        Role::GitConflictTheirs,
+    ),
+];
+
+/// Zed's file and diagnostic states, each with `.background` and `.border`
+/// keys, and the status role family they reuse.
+const STATES: &[(&str, [Role; 3])] = &[
+    ("conflict", ERROR),
+    ("created", SUCCESS),
+    ("deleted", ERROR),
+    ("error", ERROR),
+    ("hidden", IGNORED),
+    ("hint", [Role::Hint, Role::HintBackground, Role::HintBorder]),
+    ("ignored", IGNORED),
+    ("info", [Role::Info, Role::InfoBackground, Role::InfoBorder]),
+    ("modified", WARNING),
+    (
+        "predictive",
+        [
+            Role::Predictive,
+            Role::PredictiveBackground,
+            Role::PredictiveBorder,
+        ],
+    ),
+    ("renamed", WARNING),
+    ("success", SUCCESS),
+    ("unreachable", IGNORED),
+    ("warning", WARNING),
+];
+
+const ERROR: [Role; 3] = [Role::Error, Role::ErrorBackground, Role::ErrorBorder];
+const SUCCESS: [Role; 3] = [Role::Success, Role::SuccessBackground, Role::SuccessBorder];
+const WARNING: [Role; 3] = [Role::Warning, Role::WarningBackground, Role::WarningBorder];
+const IGNORED: [Role; 3] = [Role::Ignored, Role::IgnoredBackground, Role::IgnoredBorder];
+
+fn color(color: Color) -> Value {
+    Value::String(color.to_string())
+}
+
+fn style(theme: &Theme) -> Map<String, Value> {
+    let mut style = Map::new();
+    let accents: [Color; DEFAULT_LEVELS] = brackets::rainbow(theme);
+    style.insert("accents".into(), accents.into_iter().map(color).collect());
+    for &(key, role) in STYLE {
+        style.insert(key.into(), color(theme.color(role)));
+    }
+    for &(key, [base, background, border]) in STATES {
+        style.insert(key.into(), color(theme.color(base)));
+        style.insert(format!("{key}.background"), color(theme.color(background)));
+        style.insert(format!("{key}.border"), color(theme.color(border)));
+    }
+
+    let players = PLAYERS.iter().map(|player| {
+        json!({
+            "cursor": color(theme.palette[player.cursor]),
+            "background": color(theme.palette[player.background]),
+            "selection": color(theme.palette[player.selection]),
+        })
+    });
+    style.insert("players".into(), players.collect());
+
+    let mut syntax = Map::new();
+    for &role in Role::ALL.iter().filter(|role| role.is_syntax()) {
+        let key = role.name().trim_start_matches("syntax.");
+        let s = theme.style(role);
+        syntax.insert(
+            key.into(),
+            json!({
+                "color": color(theme.color(role)),
+                "font_style": s.italic.then_some("italic"),
+                "font_weight": s.weight,
+            }),
+        );
+    }
+    style.insert("syntax".into(), Value::Object(syntax));
+    style
+}
+
+impl Target for Zed {
+    fn name(&self) -> &str {
+        "zed"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        let themes: Vec<Value> = variants
+            .iter()
+            .map(|&variant| {
+                let theme = source.theme(variant);
+                json!({
+                    "name": source.meta.theme_name(theme.variant),
+                    "appearance": theme.appearance().name(),
+                    "style": style(&theme),
+                })
+            })
+            .collect();
+        let family = json!({
+            "$schema": SCHEMA,
+            "name": source.meta.name,
+            "author": source.meta.author,
+            "themes": themes,
+        });
+        Ok(vec![Output::json(
+            format!("themes/{}.json", source.meta.stem()),
+            &family,
+        )])
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        // Zed uses `~/.config/zed` on macOS as well.
+        let app = if cfg!(windows) {
+            dirs.app_data.join("Zed")
+        } else {
+            dirs.config.join("zed")
+        };
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
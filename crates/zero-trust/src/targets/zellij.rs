@@ -0,0 +1,64 @@
+//! zellij: one KDL theme per variant, named after its file.
+//!
+//! The named color slots are the terminal palette, so the panes' frames,
+//! tab bar and status bar match the programs running inside; `orange` has
+//! no ANSI slot and takes bright red, as in the Lapce base palette. `fg`
+//! and `bg` paint the chrome's text and the ribbons behind it.
+
+use std::fmt::Write;
+
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Theme, Variant};
+
+use super::{ANSI_NAMES, TERMINAL};
+use crate::install::{Dirs, InstallDir};
+use crate::{Error, Meta, Output, Source, Target};
+
+pub struct Zellij;
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "// {line}");
+    }
+    let slots = [("fg", Role::Text), ("bg", Role::ElementActive)]
+        .into_iter()
+        .chain(ANSI_NAMES.into_iter().zip(TERMINAL))
+        .chain([("orange", Role::TerminalBrightRed)]);
+    let _ = writeln!(
+        text,
+        "\nthemes {{\n    {} {{",
+        source.meta.file_stem(theme.variant)
+    );
+    for (slot, role) in slots {
+        let _ = writeln!(text, "        {slot} \"{}\"", theme.opaque_color(role));
+    }
+    text.push_str("    }\n}\n");
+    text
+}
+
+impl Target for Zellij {
+    fn name(&self) -> &str {
+        "zellij"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "kdl"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+
+    fn install_dir(&self, dirs: &Dirs, _: &Meta) -> Option<InstallDir> {
+        let app = dirs.config.join("zellij");
+        Some(InstallDir {
+            themes: app.join("themes"),
+            app,
+        })
+    }
+}
@@ -0,0 +1,131 @@
+//! zsh-syntax-highlighting: one script per variant filling in
+//! `ZSH_HIGHLIGHT_STYLES` from the syntax roles.
+//!
+//! Commands, keywords, strings and options take the colors they have in the
+//! editor, and the `brackets` highlighter's levels take the rainbow bracket
+//! colors. Styles are hex colors, which zsh 5.7 and later draws when the
+//! terminal has truecolor. The script is sourced from `.zshrc` after the
+//! plugin.
+
+use std::fmt::Write;
+
+use zero_trust_palette::brackets::{self, DEFAULT_LEVELS};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Color, Theme, Variant};
+
+use crate::{Error, Output, Source, Target};
+
+pub struct Zsh;
+
+/// Highlighter styles, the roles of their foreground and their attributes.
+const STYLES: &[(&str, Role, &str)] = &[
+    ("unknown-token", Role::Error, "bold"),
+    ("reserved-word", Role::Keyword, ""),
+    ("alias", Role::Function, ""),
+    ("suffix-alias", Role::Function, "underline"),
+    ("global-alias", Role::Function, ""),
+    ("builtin", Role::Function, ""),
+    ("function", Role::Function, ""),
+    ("command", Role::Function, ""),
+    ("precommand", Role::Keyword, "italic"),
+    ("hashed-command", Role::Function, ""),
+    ("arg0", Role::Function, ""),
+    ("commandseparator", Role::PunctuationDelimiter, ""),
+    ("autodirectory", Role::Accent, "underline"),
+    ("path", Role::Text, "underline"),
+    (
+        "path_pathseparator",
+        Role::PunctuationDelimiter,
+        "underline",
+    ),
+    ("path_prefix", Role::Text, "underline"),
+    ("globbing", Role::StringRegex, ""),
+    ("history-expansion", Role::Keyword, ""),
+    (
+        "command-substitution-delimiter",
+        Role::PunctuationSpecial,
+        "",
+    ),
+    (
+        "process-substitution-delimiter",
+        Role::PunctuationSpecial,
+        "",
+    ),
+    (
+        "back-quoted-argument-delimiter",
+        Role::PunctuationSpecial,
+        "",
+    ),
+    ("single-hyphen-option", Role::Property, ""),
+    ("double-hyphen-option", Role::Property, ""),
+    ("single-quoted-argument", Role::String, ""),
+    ("double-quoted-argument", Role::String, ""),
+    ("dollar-quoted-argument", Role::String, ""),
+    ("rc-quote", Role::StringEscape, ""),
+    ("dollar-double-quoted-argument", Role::Variable, ""),
+    ("back-double-quoted-argument", Role::StringEscape, ""),
+    ("back-dollar-quoted-argument", Role::StringEscape, ""),
+    ("assign", Role::Variable, ""),
+    ("redirection", Role::Operator, ""),
+    ("named-fd", Role::Number, ""),
+    ("numeric-fd", Role::Number, ""),
+    ("comment", Role::Comment, ""),
+    ("bracket-error", Role::Error, "bold"),
+];
+
+/// `style` for a foreground of `color` with `attributes`.
+fn style(color: Color, attributes: &str) -> String {
+    if attributes.is_empty() {
+        format!("fg={color}")
+    } else {
+        format!("fg={color},{attributes}")
+    }
+}
+
+fn render(source: &Source, theme: &Theme) -> String {
+    let mut text = String::new();
+    for line in source.meta.header(&source.meta.theme_name(theme.variant)) {
+        let _ = writeln!(text, "# {line}");
+    }
+    text.push_str("\ntypeset -gA ZSH_HIGHLIGHT_STYLES\n");
+    for &(key, role, attributes) in STYLES {
+        let _ = writeln!(
+            text,
+            "ZSH_HIGHLIGHT_STYLES[{key}]='{}'",
+            style(theme.opaque_color(role), attributes)
+        );
+    }
+    let rainbow: [Color; DEFAULT_LEVELS] = brackets::rainbow(theme);
+    for (level, color) in rainbow.into_iter().enumerate() {
+        let _ = writeln!(
+            text,
+            "ZSH_HIGHLIGHT_STYLES[bracket-level-{}]='{}'",
+            level + 1,
+            style(color, "bold")
+        );
+    }
+    let _ = writeln!(
+        text,
+        "ZSH_HIGHLIGHT_STYLES[cursor-matchingbracket]='{}'",
+        style(theme.opaque_color(Role::Accent), "standout")
+    );
+    text
+}
+
+impl Target for Zsh {
+    fn name(&self) -> &str {
+        "zsh"
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        Ok(variants
+            .iter()
+            .map(|&variant| {
+                Output::new(
+                    super::extras_path(&source.meta, self.name(), variant, "zsh"),
+                    render(source, &source.theme(variant)),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,139 @@
+//! User-defined targets written as Tera templates.
+//!
+//! Every `*.tera` file in a template directory becomes a target named after
+//! the file: `mytool.conf.tera` is the target `mytool` and renders one
+//! `extras/mytool/<stem>-<variant>.conf` per variant, the stem being the
+//! theme's [`Meta::stem`](crate::Meta::stem). The template sees
+//! the [`Model`] of the variant being rendered:
+//!
+//! ```text
+//! # {{ name }}
+//! background = "{{ roles["ui.background"].hex }}"
+//! keyword    = "{{ roles["syntax.keyword"].opaque.bare }}"
+//! {% for color in ansi %}color{{ loop.index0 }} = {{ color.hex }}
+//! {% endfor %}
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use tera::{Context, Tera};
+use zero_trust_palette::Variant;
+
+use crate::model::Model;
+use crate::{Error, Output, Source, Target};
+
+/// The template directory used when none is given.
+pub const DEFAULT_DIR: &str = "templates";
+
+/// A target rendered from one template file.
+pub struct TemplateTarget {
+    name: String,
+    extension: Option<String>,
+    path: PathBuf,
+    text: String,
+    tera: Tera,
+}
+
+fn template_error(path: &Path, err: tera::Error) -> Error {
+    Error::Template {
+        path: path.to_owned(),
+        source: err,
+    }
+}
+
+impl TemplateTarget {
+    /// Parses the template at `path`.
+    pub fn load(path: &Path) -> Result<TemplateTarget, Error> {
+        let text = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".tera"))
+            .unwrap_or_default();
+        let (name, extension) = match file_name.split_once('.') {
+            Some((name, extension)) => (name, Some(extension.to_owned())),
+            None => (file_name, None),
+        };
+        let mut tera = Tera::default();
+        tera.add_raw_template(name, &text)
+            .map_err(|e| template_error(path, e))?;
+        Ok(TemplateTarget {
+            name: name.to_owned(),
+            extension,
+            path: path.to_owned(),
+            text,
+            tera,
+        })
+    }
+
+    /// The template file this target was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Target for TemplateTarget {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn fingerprint(&self) -> &[u8] {
+        self.text.as_bytes()
+    }
+
+    fn render(&self, source: &Source, variants: &[Variant]) -> Result<Vec<Output>, Error> {
+        variants
+            .iter()
+            .map(|&variant| {
+                let start = Instant::now();
+                let model = Model::new(source, &source.theme(variant));
+                let context =
+                    Context::from_serialize(&model).map_err(|e| template_error(&self.path, e))?;
+                let contents = self
+                    .tera
+                    .render(&self.name, &context)
+                    .map_err(|e| template_error(&self.path, e))?;
+                tracing::debug!(
+                    template = %self.path.display(),
+                    variant = variant.slug(),
+                    elapsed = ?start.elapsed(),
+                    "rendered template"
+                );
+                let stem = source.meta.file_stem(variant);
+                let file = match &self.extension {
+                    Some(extension) => format!("{stem}.{extension}"),
+                    None => stem,
+                };
+                Ok(Output::new(
+                    Path::new("extras").join(&self.name).join(file),
+                    contents,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Loads every `*.tera` file in `dir`, sorted by file name.
+///
+/// A missing directory yields no targets.
+pub fn load_dir(dir: &Path) -> Result<Vec<TemplateTarget>, Error> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(Error::io(dir, e)),
+    };
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| Error::io(dir, e))?.path();
+        if path.extension().is_some_and(|ext| ext == "tera") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    paths
+        .iter()
+        .map(|path| TemplateTarget::load(path))
+        .collect()
+}
@@ -0,0 +1,47 @@
+use std::fs;
+use std::path::Path;
+
+use zero_trust::appearance::{self, Config};
+use zero_trust_palette::Appearance;
+
+#[test]
+fn platform_outputs_are_read() {
+    assert_eq!(
+        appearance::parse_portal("(<<uint32 1>>,)\n"),
+        Some(Appearance::Dark)
+    );
+    assert_eq!(
+        appearance::parse_portal("(<<uint32 0>>,)\n"),
+        Some(Appearance::Light)
+    );
+    assert_eq!(appearance::parse_defaults("Dark\n"), Appearance::Dark);
+    assert_eq!(appearance::parse_defaults(""), Appearance::Light);
+    assert_eq!(
+        appearance::parse_registry(
+            "\nHKEY_CURRENT_USER\\...\\Personalize\n    AppsUseLightTheme    REG_DWORD    0x0\n"
+        ),
+        Some(Appearance::Dark)
+    );
+}
+
+#[test]
+fn apps_switch_between_their_variant_files() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("appearance");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("dark.toml"), "dark").unwrap();
+    fs::write(dir.join("light.toml"), "light").unwrap();
+    fs::write(
+        dir.join("auto.toml"),
+        "[[apps]]\nname = \"test\"\npath = \"out/theme.toml\"\ndark = \"dark.toml\"\nlight = \"light.toml\"\n",
+    )
+    .unwrap();
+    let config = Config::load(&dir.join("auto.toml"), &dir).unwrap();
+    let app = &config.apps[0];
+    assert_eq!(app.path, dir.join("out/theme.toml"));
+
+    assert!(app.apply(Appearance::Dark).unwrap());
+    assert!(!app.apply(Appearance::Dark).unwrap());
+    assert!(app.apply(Appearance::Light).unwrap());
+    assert_eq!(fs::read_to_string(&app.path).unwrap(), "light");
+}
@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::PathBuf;
+
+use zero_trust::cache::{self, Cache};
+use zero_trust::model::Model;
+use zero_trust::targets::Zed;
+use zero_trust::{write_outputs, Format, Source, Target, DEFAULT_VARIANTS};
+use zero_trust_palette::{Color, Swatch};
+
+fn models(source: &Source) -> Vec<Model> {
+    DEFAULT_VARIANTS
+        .iter()
+        .map(|&variant| Model::new(source, &source.theme(variant)))
+        .collect()
+}
+
+fn out_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn fingerprints_follow_the_theme() {
+    let source = Source::default();
+    let mut edited = Source::default();
+    edited
+        .palette
+        .set(Swatch::AccentBlue, Color::hex("#5A9EDA"));
+    assert_eq!(
+        cache::fingerprint(&Zed, &models(&source), Format::Pretty),
+        cache::fingerprint(&Zed, &models(&Source::default()), Format::Pretty)
+    );
+    assert_ne!(
+        cache::fingerprint(&Zed, &models(&source), Format::Pretty),
+        cache::fingerprint(&Zed, &models(&edited), Format::Pretty)
+    );
+}
+
+#[test]
+fn targets_go_stale_when_their_files_change() {
+    let dir = out_dir("cache");
+    let source = Source::default();
+    let fingerprint = cache::fingerprint(&Zed, &models(&source), Format::Pretty);
+    let outputs = Zed.render(&source, DEFAULT_VARIANTS).unwrap();
+    write_outputs(&dir, &outputs).unwrap();
+
+    let mut cache = Cache::default();
+    assert!(!cache.is_fresh("zed", &fingerprint, &dir));
+    cache.record("zed", fingerprint.clone(), &outputs);
+    cache.save(&dir).unwrap();
+    let cache = Cache::load(&dir);
+    assert!(cache.is_fresh("zed", &fingerprint, &dir));
+
+    fs::write(dir.join(&outputs[0].path), "{}").unwrap();
+    assert!(!cache.is_fresh("zed", &fingerprint, &dir));
+}
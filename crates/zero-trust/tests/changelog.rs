@@ -0,0 +1,40 @@
+use zero_trust::changelog::{self, Change};
+use zero_trust::Source;
+use zero_trust_palette::roles::{Role, Style};
+use zero_trust_palette::{Color, Swatch};
+
+#[test]
+fn changes_read_like_release_notes() {
+    let old = Source::default();
+    let mut new = old.clone();
+    new.palette
+        .set(Swatch::FgMuted, Color::from_hex("#5A5A6E").unwrap());
+    let mut darker = new.clone();
+    darker
+        .palette
+        .set(Swatch::FgMuted, Color::from_hex("#50505F").unwrap());
+    let comment = Role::from_name("syntax.comment").unwrap();
+    darker.roles.set(comment, Style::new(Swatch::FgMuted));
+
+    let changes = changelog::changes(&new, &darker);
+    assert_eq!(changes.len(), 2);
+    assert!(matches!(
+        changes[0],
+        Change::Swatch {
+            swatch: Swatch::FgMuted,
+            ..
+        }
+    ));
+    assert_eq!(
+        changes[0].to_string(),
+        "`fg.muted` darkened and desaturated from #5A5A6E to #50505F"
+    );
+    assert_eq!(
+        changes[1].to_string(),
+        "`syntax.comment` now uses `fg.muted` instead of `accent.green`, no longer italic"
+    );
+    assert_eq!(
+        changelog::to_markdown(&changelog::changes(&old, &old)),
+        "No palette or role changes.\n"
+    );
+}
@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use zero_trust::Source;
+
+fn help(text: &str) -> Option<String> {
+    let err = Source::parse(text, Path::new("theme.toml")).unwrap_err();
+    let diagnostic = err
+        .diagnostic()
+        .expect("parse errors point into the source");
+    diagnostic.help_text().map(str::to_owned)
+}
+
+#[test]
+fn misspelled_names_get_a_suggestion() {
+    assert_eq!(
+        help("[roles]\n\"syntax.keywrd\" = { swatch = \"accent.blue\" }\n").as_deref(),
+        Some("did you mean `syntax.keyword`?")
+    );
+    assert_eq!(
+        help("[roles]\n\"syntax.keyword\" = { swatch = \"acent.blue\" }\n").as_deref(),
+        Some("did you mean `accent.blue`?")
+    );
+    assert_eq!(
+        help("[tuning]\nbrightnes = 1.1\n").as_deref(),
+        Some("did you mean `brightness`?")
+    );
+}
+
+#[test]
+fn malformed_colors_explain_the_format() {
+    let help = help("[palette]\n\"bg.base\" = \"#1E1E1\"\n").unwrap();
+    assert!(help.contains("`#RRGGBB`"), "{help}");
+}
+
+#[test]
+fn unrelated_names_get_no_suggestion() {
+    assert_eq!(
+        help("[roles]\n\"lorem\" = { swatch = \"bg.base\" }\n"),
+        None
+    );
+}
+
+#[test]
+fn tuning_factors_must_be_finite_and_positive() {
+    for value in ["0", "-0.5", "nan", "inf"] {
+        let text = format!("[tuning]\nbrightness = {value}\n");
+        let err = Source::parse(&text, Path::new("theme.toml")).unwrap_err();
+        let diagnostic = err
+            .diagnostic()
+            .expect("parse errors point into the source");
+        assert!(
+            diagnostic
+                .to_string()
+                .contains("a finite factor greater than 0"),
+            "{diagnostic}"
+        );
+        let label = miette::Diagnostic::labels(&diagnostic)
+            .and_then(|mut labels| labels.next())
+            .expect("the error points at the value");
+        assert_eq!(
+            &text[label.offset()..label.offset() + label.len()],
+            value,
+            "{value}"
+        );
+    }
+    let text = "[tuning]\nbrightness = 1.2\nsaturation = 0.5\n";
+    assert!(Source::parse(text, Path::new("theme.toml")).is_ok());
+}
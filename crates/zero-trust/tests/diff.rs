@@ -0,0 +1,60 @@
+use std::path::Path;
+
+use zero_trust::diff::{diff, Change};
+
+fn json(old: &str, new: &str) -> Vec<Change> {
+    diff(Path::new("theme.json"), Some(old), new)
+}
+
+#[test]
+fn keys_added_and_removed_are_reported_by_path() {
+    let changes = json(
+        r##"{"style": {"editor.background": "#000000", "gone": 1}}"##,
+        r##"{"style": {"editor.background": "#000000", "new": "#FFFFFF"}}"##,
+    );
+    assert_eq!(
+        changes,
+        vec![
+            Change::Added {
+                at: "style.new".into(),
+                new: r##""#FFFFFF""##.into(),
+            },
+            Change::Removed {
+                at: "style.gone".into(),
+                old: "1".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn named_array_elements_match_by_name_across_reordering() {
+    let changes = json(
+        r##"{"themes": [{"name": "Dark", "bg": "#000000"}, {"name": "Light", "bg": "#FFFFFF"}]}"##,
+        r##"{"themes": [{"name": "Light", "bg": "#FAFAFA"}, {"name": "Dark", "bg": "#000000"}]}"##,
+    );
+    assert_eq!(
+        changes,
+        vec![Change::Changed {
+            at: "themes[Light].bg".into(),
+            old: r##""#FFFFFF""##.into(),
+            new: r##""#FAFAFA""##.into(),
+        }]
+    );
+}
+
+#[test]
+fn repeated_names_fall_back_to_matching_by_index() {
+    let changes = json(
+        r#"[{"name": "a", "v": 1}, {"name": "a", "v": 2}]"#,
+        r#"[{"name": "a", "v": 1}, {"name": "a", "v": 3}]"#,
+    );
+    assert_eq!(
+        changes,
+        vec![Change::Changed {
+            at: "[1].v".into(),
+            old: "2".into(),
+            new: "3".into(),
+        }]
+    );
+}
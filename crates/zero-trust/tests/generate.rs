@@ -0,0 +1,94 @@
+use std::fs;
+use std::path::Path;
+
+use zero_trust::targets::{Registry, Zed};
+use zero_trust::{render_all, schema, Format, Source, Target, DEFAULT_VARIANTS};
+use zero_trust_palette::Variant;
+
+fn repo_root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../.."))
+}
+
+#[test]
+fn committed_theme_files_are_up_to_date() {
+    let source = Source::load(&repo_root().join(Source::FILE_NAME)).unwrap();
+    for target in Registry::builtin().iter() {
+        for output in target.render(&source, DEFAULT_VARIANTS).unwrap() {
+            let committed = fs::read_to_string(repo_root().join(&output.path)).unwrap();
+            assert!(
+                committed == output.contents,
+                "{} is stale; run `cargo run -p zero-trust -- build` to regenerate it",
+                output.path.display()
+            );
+        }
+    }
+}
+
+#[test]
+fn theme_toml_matches_the_shipped_palette() {
+    // The default source is theme.toml itself; an empty one has nothing but
+    // the constants in zero-trust-palette. Only the lint allowlist, which
+    // has no constant, may differ, and it must silence every warning.
+    let constants = Source::parse("", Path::new(Source::FILE_NAME)).unwrap();
+    let Source { lint, .. } = Source::default();
+    assert_eq!(Source { lint, ..constants }, Source::default());
+    assert!(Source::default().near_duplicates().is_empty());
+}
+
+#[test]
+fn committed_schema_is_up_to_date() {
+    let committed = fs::read_to_string(repo_root().join(schema::FILE_NAME)).unwrap();
+    assert!(
+        committed == schema::to_string(),
+        "{} is stale; run `cargo run -p zero-trust -- schema -o ../{0}` to regenerate it",
+        schema::FILE_NAME
+    );
+}
+
+#[test]
+fn rendering_is_deterministic() {
+    let source = Source::load(&repo_root().join(Source::FILE_NAME)).unwrap();
+    let targets: Vec<_> = Registry::builtin().iter().cloned().collect();
+    let first = render_all(&targets, &source, Variant::ALL).unwrap();
+    let second = render_all(&targets, &Source::default(), Variant::ALL).unwrap();
+    assert_eq!(first, second);
+    for output in &first {
+        assert!(
+            !output.contents.contains('\r'),
+            "{} has CRLF line endings",
+            output.path.display()
+        );
+    }
+}
+
+#[test]
+fn minified_json_holds_the_same_data() {
+    let source = Source::default();
+    for output in Zed.render(&source, DEFAULT_VARIANTS).unwrap() {
+        let minified = output.clone().format(Format::Minified);
+        assert_eq!(minified.contents.lines().count(), 1);
+        let parse = |text: &str| serde_json::from_str::<serde_json::Value>(text).unwrap();
+        assert_eq!(parse(&minified.contents), parse(&output.contents));
+    }
+}
+
+#[test]
+fn structured_outputs_parse() {
+    let targets: Vec<_> = Registry::builtin().iter().cloned().collect();
+    for output in render_all(&targets, &Source::default(), Variant::ALL).unwrap() {
+        let path = output.path.display();
+        match output.path.extension().and_then(|ext| ext.to_str()) {
+            // Only Kate's themes are JSON; the other tools' are line based.
+            Some("theme") if !output.path.starts_with("extras/kate") => {}
+            Some("json" | "sublime-color-scheme" | "theme") => {
+                serde_json::from_str::<serde_json::Value>(&output.contents)
+                    .unwrap_or_else(|e| panic!("{path}: {e}"));
+            }
+            Some("toml") => {
+                toml::from_str::<toml::Table>(&output.contents)
+                    .unwrap_or_else(|e| panic!("{path}: {e}"));
+            }
+            _ => {}
+        }
+    }
+}
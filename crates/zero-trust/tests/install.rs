@@ -0,0 +1,52 @@
+use std::fs;
+use std::path::PathBuf;
+
+use zero_trust::install::{self, Action, Dirs, Mode};
+use zero_trust::targets::VsCode;
+use zero_trust::{Source, Target, DEFAULT_VARIANTS};
+
+fn home(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn dirs(home: PathBuf) -> Dirs {
+    Dirs {
+        config: home.join(".config"),
+        app_data: home.join(".config"),
+        home,
+    }
+}
+
+#[test]
+fn vscode_installs_as_an_unpacked_extension() {
+    let home = home("install-vscode");
+    let dirs = dirs(home.clone());
+    let source = Source::default();
+    assert_eq!(install::detect(&VsCode, &dirs, &source.meta), None);
+
+    fs::create_dir_all(home.join(".vscode")).unwrap();
+    let dir = install::detect(&VsCode, &dirs, &source.meta).unwrap();
+    let folder = format!("yannickboog.zero-trust-theme-{}", source.meta.version);
+    assert_eq!(dir.themes, home.join(".vscode/extensions").join(folder));
+
+    let outputs = VsCode.render(&source, DEFAULT_VARIANTS).unwrap();
+    let actions = install::install_actions(&dir, &outputs, Mode::Copy, &home);
+    let copied: Vec<_> = actions
+        .iter()
+        .map(|action| match action {
+            Action::Copy { to, .. } => to.strip_prefix(&dir.themes).unwrap().to_owned(),
+            other => panic!("unexpected {other}"),
+        })
+        .collect();
+    assert_eq!(
+        copied,
+        [
+            PathBuf::from("zero-trust-dark.json"),
+            PathBuf::from("zero-trust-light.json"),
+            PathBuf::from("package.json"),
+        ]
+    );
+}
@@ -0,0 +1,85 @@
+use std::path::Path;
+
+use zero_trust::model::Model;
+use zero_trust::targets::{Neovim, VsCode};
+use zero_trust::{languages, Source, Target};
+use zero_trust_palette::roles::Role;
+use zero_trust_palette::{Swatch, Variant};
+
+const RUST: &str = r#"
+[languages.rust]
+"syntax.label" = { swatch = "accent.coral", italic = true }
+"#;
+
+#[test]
+fn language_rules_resolve_per_variant() {
+    let source = Source::parse(RUST, Path::new("theme.toml")).unwrap();
+    let label = Role::from_name("syntax.label").unwrap();
+    for variant in [Variant::Dark, Variant::Light] {
+        let rules = languages::rules(&source, variant);
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].language, "rust");
+        assert_eq!(rules[0].role, label);
+        assert!(rules[0].style.italic);
+        assert_eq!(
+            rules[0].color,
+            source.theme(variant).palette[Swatch::AccentCoral]
+        );
+    }
+
+    let model = Model::new(&source, &source.theme(Variant::Dark));
+    assert_eq!(model.languages["rust"].len(), 1);
+    assert!(model.languages["rust"]["syntax.label"].italic);
+}
+
+#[test]
+fn only_syntax_roles_can_be_specialized() {
+    let err = Source::parse(
+        "[languages.rust]\n\"syntax.lable\" = { swatch = \"accent.coral\" }\n",
+        Path::new("theme.toml"),
+    )
+    .unwrap_err();
+    let diagnostic = err.diagnostic().unwrap();
+    assert_eq!(diagnostic.help_text(), Some("did you mean `syntax.label`?"));
+    assert!(Source::parse(
+        "[languages.rust]\n\"ui.background\" = { swatch = \"bg.base\" }\n",
+        Path::new("theme.toml"),
+    )
+    .is_err());
+}
+
+#[test]
+fn vscode_scopes_language_rules() {
+    let source = Source::parse(RUST, Path::new("theme.toml")).unwrap();
+    let output = &VsCode.render(&source, &[Variant::Dark]).unwrap()[0];
+    let theme: serde_json::Value = serde_json::from_str(&output.contents).unwrap();
+    let coral = source.theme(Variant::Dark).palette[Swatch::AccentCoral].to_string();
+
+    let rule = theme["tokenColors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|rule| rule["name"] == "syntax.label (rust)")
+        .unwrap();
+    assert!(rule["scope"]
+        .as_array()
+        .unwrap()
+        .contains(&"entity.name.type.lifetime.rust".into()));
+    assert_eq!(rule["settings"]["foreground"], coral.as_str());
+    assert_eq!(rule["settings"]["fontStyle"], "italic");
+
+    let semantic = &theme["semanticTokenColors"]["lifetime:rust"];
+    assert_eq!(semantic["foreground"], coral.as_str());
+    assert_eq!(semantic["italic"], true);
+}
+
+#[test]
+fn neovim_sets_language_captures() {
+    let source = Source::parse(RUST, Path::new("theme.toml")).unwrap();
+    let output = &Neovim.render(&source, &[Variant::Dark]).unwrap()[0];
+    let coral = source.theme(Variant::Dark).palette[Swatch::AccentCoral];
+    for group in ["@label.rust", "@lsp.type.lifetime.rust"] {
+        let line = format!("hl(0, \"{group}\", {{ fg = \"{coral}\", italic = true }})");
+        assert!(output.contents.contains(&line), "missing {line}");
+    }
+}
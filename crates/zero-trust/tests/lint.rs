@@ -0,0 +1,50 @@
+use std::path::Path;
+
+use zero_trust::Source;
+use zero_trust_palette::difference::Metric;
+use zero_trust_palette::Swatch;
+
+fn pairs(text: &str) -> Vec<(Swatch, Swatch)> {
+    Source::parse(text, Path::new("theme.toml"))
+        .unwrap()
+        .near_duplicates()
+        .into_iter()
+        .map(|pair| (pair.first, pair.second))
+        .collect()
+}
+
+#[test]
+fn lint_threshold_is_configurable() {
+    let shipped = pairs("");
+    assert!(shipped.contains(&(Swatch::AccentCoral, Swatch::AccentSalmon)));
+    assert!(pairs("[lint]\nthreshold = 0.0").is_empty());
+
+    let ciede = Source::parse("[lint]\nmetric = \"ciede2000\"", Path::new("theme.toml")).unwrap();
+    assert_eq!(ciede.lint.metric, Metric::Ciede2000);
+    assert_eq!(ciede.lint.duplicates().threshold, 3.0);
+    assert_eq!(
+        pairs("[lint]\nmetric = \"ciede2000\"\nthreshold = 5.0"),
+        [
+            (Swatch::AccentCornflower, Swatch::AccentPeriwinkle),
+            (Swatch::AccentCoral, Swatch::AccentSalmon),
+        ]
+    );
+}
+
+#[test]
+fn allowed_pairs_are_not_reported_in_either_order() {
+    let allowed = pairs("[lint]\nallow = [[\"accent.salmon\", \"accent.coral\"]]");
+    assert!(!allowed.contains(&(Swatch::AccentCoral, Swatch::AccentSalmon)));
+    assert_eq!(allowed.len(), pairs("").len() - 1);
+
+    let err = Source::parse(
+        "[lint]\nallow = [[\"accent.coral\", \"acent.salmon\"]]",
+        Path::new("theme.toml"),
+    )
+    .unwrap_err();
+    let diagnostic = err.diagnostic().unwrap();
+    assert_eq!(
+        diagnostic.help_text(),
+        Some("did you mean `accent.salmon`?")
+    );
+}
@@ -0,0 +1,26 @@
+use zero_trust::Meta;
+
+#[test]
+fn uuids_are_stable_and_well_formed() {
+    let meta = Meta::default();
+    let uuid = meta.uuid("dark");
+    assert_eq!(uuid, meta.uuid("dark"));
+    assert_ne!(uuid, meta.uuid("light"));
+    let groups: Vec<&str> = uuid.split('-').collect();
+    assert_eq!(
+        groups.iter().map(|group| group.len()).collect::<Vec<_>>(),
+        [8, 4, 4, 4, 12]
+    );
+    assert!(groups[2].starts_with('8'));
+    assert!(matches!(groups[3].as_bytes()[0], b'8' | b'9' | b'a' | b'b'));
+}
+
+#[test]
+fn headers_carry_the_crate_version() {
+    let header = Meta::default().header("Zero Trust Dark");
+    assert_eq!(
+        header[0],
+        format!("Zero Trust Dark {}", env!("CARGO_PKG_VERSION"))
+    );
+    assert!(header.iter().any(|line| line == "License: MIT"));
+}
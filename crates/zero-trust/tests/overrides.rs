@@ -0,0 +1,72 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use zero_trust::targets::Registry;
+use zero_trust::{Error, Source, DEFAULT_VARIANTS};
+
+fn overrides_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    for (file, text) in files {
+        fs::write(dir.join(file), text).unwrap();
+    }
+    dir
+}
+
+fn render_zed(registry: &Registry) -> String {
+    let outputs = registry
+        .find("zed")
+        .unwrap()
+        .render(&Source::default(), DEFAULT_VARIANTS)
+        .unwrap();
+    outputs[0].contents.clone()
+}
+
+#[test]
+fn overrides_apply_only_to_their_target() {
+    let dir = overrides_dir(
+        "overrides",
+        &[(
+            "zed.toml",
+            "[palette]\n\"bg.base\" = \"#101010\"\n\n[roles]\n\"syntax.keyword\" = { swatch = \"accent.coral\" }\n",
+        )],
+    );
+    let base = render_zed(&Registry::builtin());
+    assert!(!base.contains("#101010"));
+
+    let mut registry = Registry::builtin();
+    registry.apply_overrides(&dir, None).unwrap();
+    let overridden = render_zed(&registry);
+    assert!(overridden.contains("#101010"));
+    assert_ne!(
+        registry.find("zed").unwrap().fingerprint(),
+        Registry::builtin().find("zed").unwrap().fingerprint()
+    );
+}
+
+#[test]
+fn overrides_for_unknown_targets_are_rejected() {
+    let dir = overrides_dir("overrides-typo", &[("zedd.toml", "")]);
+    let err = Registry::builtin().apply_overrides(&dir, None).unwrap_err();
+    assert!(matches!(err, Error::UnknownTarget { ref name, .. } if name == "zedd"));
+}
+
+#[test]
+fn user_overrides_win_over_target_overrides() {
+    let targets = overrides_dir(
+        "overrides-targets",
+        &[("zed.toml", "[palette]\n\"bg.base\" = \"#101010\"\n")],
+    );
+    let user = overrides_dir(
+        "overrides-user",
+        &[("user.toml", "[palette]\n\"bg.base\" = \"#0A0A0A\"\n")],
+    );
+    let mut registry = Registry::builtin();
+    registry
+        .apply_overrides(&targets, Some(&user.join("user.toml")))
+        .unwrap();
+    let rendered = render_zed(&registry);
+    assert!(rendered.contains("#0A0A0A"));
+    assert!(!rendered.contains("#101010"));
+}
@@ -0,0 +1,61 @@
+use std::io::Cursor;
+
+use zero_trust::package::{Extension, Vsix, VsixTheme};
+
+fn vsix() -> Vsix {
+    Vsix {
+        extension: Extension {
+            id: "zero-trust-theme".into(),
+            name: "Zero Trust <Theme>".into(),
+            description: String::new(),
+            version: "0.1.0".into(),
+            repository: Some("https://github.com/yannickboog/zero-trust-theme".into()),
+        },
+        publisher: "yannickboog".into(),
+        themes: vec![VsixTheme {
+            label: "Zero Trust Dark".into(),
+            ui_theme: "vs-dark",
+            file_name: "zero-trust-dark.json".into(),
+            contents: "{}\n".into(),
+        }],
+        license: Some("MIT".into()),
+        readme: None,
+        icon: None,
+    }
+}
+
+#[test]
+fn publisher_defaults_to_the_repository_owner() {
+    assert_eq!(vsix().extension.repository_owner(), Some("yannickboog"));
+}
+
+#[test]
+fn archive_holds_the_manifests_and_themes() {
+    let vsix = vsix();
+    let bytes = vsix.to_bytes();
+    assert_eq!(bytes, vsix.to_bytes(), "archives are reproducible");
+    let archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut names: Vec<String> = archive
+        .file_names()
+        .map(|name| name.unwrap().into_owned())
+        .collect();
+    names.sort_unstable();
+    assert_eq!(
+        names,
+        [
+            "[Content_Types].xml",
+            "extension.vsixmanifest",
+            "extension/LICENSE.txt",
+            "extension/package.json",
+            "extension/themes/zero-trust-dark.json",
+        ]
+    );
+    assert!(vsix
+        .vsixmanifest()
+        .contains("<DisplayName>Zero Trust &lt;Theme&gt;</DisplayName>"));
+    let package: serde_json::Value = serde_json::from_str(&vsix.package_json()).unwrap();
+    assert_eq!(
+        package["contributes"]["themes"][0]["path"],
+        "./themes/zero-trust-dark.json"
+    );
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "wasm")]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use zero_trust::plugins::PluginTarget;
+use zero_trust::{Error, Source, Target, DEFAULT_VARIANTS};
+
+/// Compiles a plugin that ignores its request and answers with `response`.
+fn plugin(name: &str, response: &str) -> PathBuf {
+    let wat = format!(
+        r#"(module
+            (memory (export "memory") 1)
+            (data (i32.const 0) "{}")
+            (func (export "zt_alloc") (param $len i32) (result i32)
+                (drop (memory.grow (i32.add (i32.shr_u (local.get $len) (i32.const 16)) (i32.const 1))))
+                i32.const 65536)
+            (func (export "zt_render") (param i32 i32) (result i64) i64.const {}))"#,
+        response.replace('\\', "\\\\").replace('"', "\\\""),
+        response.len()
+    );
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("{name}.wasm"));
+    fs::write(&path, wat::parse_str(wat).unwrap()).unwrap();
+    path
+}
+
+#[test]
+fn plugin_files_land_in_the_target_directory() {
+    let path = plugin(
+        "echo",
+        r#"{"files":[{"path":"themes/dark.conf","contents":"bg = 1E1E1E\n"}]}"#,
+    );
+    let target = PluginTarget::load(&path).unwrap();
+    assert_eq!(target.name(), "echo");
+    let outputs = target.render(&Source::default(), DEFAULT_VARIANTS).unwrap();
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].path, Path::new("extras/echo/themes/dark.conf"));
+    assert_eq!(outputs[0].contents, "bg = 1E1E1E\n");
+}
+
+#[test]
+fn plugin_errors_are_reported() {
+    let path = plugin("failing", r#"{"error":"unsupported"}"#);
+    let err = PluginTarget::load(&path)
+        .unwrap()
+        .render(&Source::default(), DEFAULT_VARIANTS)
+        .unwrap_err();
+    assert!(matches!(err, Error::Plugin { ref message, .. } if message == "unsupported"));
+}
+
+#[test]
+fn plugins_cannot_write_outside_their_directory() {
+    let path = plugin(
+        "escaping",
+        r#"{"files":[{"path":"../../theme.toml","contents":""}]}"#,
+    );
+    let err = PluginTarget::load(&path)
+        .unwrap()
+        .render(&Source::default(), DEFAULT_VARIANTS)
+        .unwrap_err();
+    assert!(err.to_string().contains("refusing to write outside"));
+}
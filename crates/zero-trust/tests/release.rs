@@ -0,0 +1,78 @@
+use std::fs;
+use std::path::Path;
+
+use minisign::KeyPair;
+use zero_trust::release::{self, Manifest};
+
+#[test]
+fn manifest_reads_back_and_detects_tampering() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("release");
+    fs::create_dir_all(dir.join("themes")).unwrap();
+    fs::write(dir.join("themes/zero-trust.json"), "{}\n").unwrap();
+    let mut manifest = Manifest::default();
+    manifest.insert(Path::new("themes/zero-trust.json"), b"{}\n");
+
+    let text = manifest.to_string();
+    assert_eq!(
+        text,
+        format!(
+            "{}  themes/zero-trust.json\n",
+            zero_trust::cache::sha256(b"{}\n")
+        )
+    );
+    assert_eq!(Manifest::parse(&text), Some(manifest.clone()));
+    assert!(manifest.verify(&dir).is_empty());
+
+    fs::write(dir.join("themes/zero-trust.json"), "{ }\n").unwrap();
+    assert_eq!(
+        manifest.verify(&dir),
+        vec![dir.join("themes/zero-trust.json")]
+    );
+}
+
+#[test]
+fn manifest_paths_cannot_leave_the_directory() {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("release-escape");
+    let dir = root.join("out");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(root.join("outside.txt"), "secret\n").unwrap();
+    let hash = zero_trust::cache::sha256(b"secret\n");
+    let outside = root.join("outside.txt");
+    let text = format!("{hash}  ../outside.txt\n{hash}  {}\n", outside.display());
+    let manifest = Manifest::parse(&text).unwrap();
+    assert_eq!(manifest.verify(&dir).len(), 2);
+}
+
+#[test]
+fn included_artifacts_cannot_leave_the_directory() {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("release-include");
+    let dir = root.join("out");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("zero-trust.vsix"), "vsix\n").unwrap();
+    fs::write(root.join("outside.txt"), "secret\n").unwrap();
+
+    let mut manifest = Manifest::default();
+    manifest
+        .include(&dir, Path::new("zero-trust.vsix"))
+        .unwrap();
+    for path in [Path::new("../outside.txt"), &root.join("outside.txt")] {
+        let err = manifest.include(&dir, path).unwrap_err();
+        assert!(err.to_string().contains("not a path inside"), "{err}");
+    }
+    assert_eq!(manifest.len(), 1);
+}
+
+#[test]
+fn signatures_verify_only_the_signed_manifest() {
+    let KeyPair { pk, sk } = KeyPair::generate_unencrypted_keypair().unwrap();
+    let secret_key = sk.to_box(None).unwrap().into_string();
+    let public_key = pk.to_box().unwrap().into_string();
+    let manifest = "0000000000000000000000000000000000000000000000000000000000000000  a.json\n";
+
+    let signature = release::sign(manifest, &secret_key, None).unwrap();
+    release::verify_signature(manifest, &signature, &public_key).unwrap();
+    release::verify_signature(manifest, &signature, &pk.to_base64()).unwrap();
+    assert!(
+        release::verify_signature(&manifest.replace('a', "b"), &signature, &public_key).is_err()
+    );
+}
@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use zero_trust::scaffold::{self, Project};
+use zero_trust::targets::Registry;
+use zero_trust::{render_all, Source};
+use zero_trust_palette::Variant;
+
+fn scaffolded_source(project: &Project) -> Source {
+    let files = scaffold::files(project);
+    let theme = files
+        .iter()
+        .find(|output| output.path == Path::new(Source::FILE_NAME))
+        .unwrap();
+    Source::parse(&theme.contents, Path::new(Source::FILE_NAME)).unwrap()
+}
+
+#[test]
+fn scaffolded_source_renames_the_theme_and_keeps_the_palette() {
+    let project = Project::from_dir_name("my-fork", "Jane Doe".into());
+    assert_eq!(project.name, "My Fork");
+    assert_eq!(project.id, "my-fork");
+
+    let source = scaffolded_source(&project);
+    assert_eq!(source.meta.name, "My Fork");
+    assert_eq!(source.meta.author, "Jane Doe");
+
+    let original =
+        Source::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../../theme.toml")).unwrap();
+    assert_eq!(source.palette, original.palette);
+    assert_eq!(source.roles, original.roles);
+}
+
+#[test]
+fn scaffolded_project_builds_under_its_own_name() {
+    let project = Project::from_dir_name("my-fork", "Jane Doe".into());
+    let source = scaffolded_source(&project);
+    let targets: Vec<_> = Registry::builtin().iter().cloned().collect();
+    for output in render_all(&targets, &source, Variant::ALL).unwrap() {
+        let path = output.path.to_string_lossy().to_lowercase();
+        assert!(
+            !path.contains("zero-trust") && !path.contains("zero_trust"),
+            "{path}"
+        );
+        for line in output.contents.lines() {
+            assert!(
+                !(line.contains("name") && line.contains("Zero Trust")),
+                "{path}: {line}"
+            );
+        }
+        for variant in Variant::ALL {
+            assert!(!output
+                .contents
+                .contains(&format!("Zero Trust {}", variant.label())));
+        }
+    }
+}
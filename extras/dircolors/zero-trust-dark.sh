@@ -0,0 +1,7 @@
+# Zero Trust Dark 0.1.0
+# Author: Yannick Boog
+# Homepage: https://github.com/yannickboog/zero-trust-theme
+# License: MIT
+# Generated by zero-trust from theme.toml; do not edit.
+
+export LS_COLORS='di=01;38;5;75:ln=38;5;75:or=01;38;5;209:mi=38;5;209:pi=38;5;221:so=38;5;74:do=38;5;74:bd=01;38;5;221:cd=01;38;5;221:ex=01;38;5;107:su=38;5;234;48;5;209:sg=38;5;234;48;5;221:tw=38;5;234;48;5;107:ow=38;5;75;48;5;236:st=38;5;252;48;5;236:*.tar=38;5;221:*.tgz=38;5;221:*.gz=38;5;221:*.bz2=38;5;221:*.xz=38;5;221:*.zst=38;5;221:*.lz4=38;5;221:*.zip=38;5;221:*.7z=38;5;221:*.rar=38;5;221:*.jar=38;5;221:*.deb=38;5;221:*.rpm=38;5;221:*.apk=38;5;221:*.dmg=38;5;221:*.iso=38;5;221:*.png=38;5;74:*.jpg=38;5;74:*.jpeg=38;5;74:*.gif=38;5;74:*.webp=38;5;74:*.avif=38;5;74:*.bmp=38;5;74:*.ico=38;5;74:*.svg=38;5;74:*.tif=38;5;74:*.tiff=38;5;74:*.heic=38;5;74:*.mp3=38;5;75:*.flac=38;5;75:*.ogg=38;5;75:*.opus=38;5;75:*.wav=38;5;75:*.m4a=38;5;75:*.mp4=38;5;75:*.mkv=38;5;75:*.webm=38;5;75:*.mov=38;5;75:*.avi=38;5;75:*.pdf=38;5;173:*.epub=38;5;173:*.md=38;5;173:*.rst=38;5;173:*.txt=38;5;173:*.doc=38;5;173:*.docx=38;5;173:*.odt=38;5;173:*.xls=38;5;173:*.xlsx=38;5;173:*.ods=38;5;173:*.json=38;5;153:*.toml=38;5;153:*.yaml=38;5;153:*.yml=38;5;153:*.xml=38;5;153:*.ini=38;5;153:*.conf=38;5;153:*.csv=38;5;153:*.sql=38;5;153:*.lock=38;5;153:*.rs=38;5;187:*.c=38;5;187:*.h=38;5;187:*.cpp=38;5;187:*.hpp=38;5;187:*.go=38;5;187:*.py=38;5;187:*.rb=38;5;187:*.js=38;5;187:*.ts=38;5;187:*.java=38;5;187:*.kt=38;5;187:*.swift=38;5;187:*.sh=38;5;187:*.lua=38;5;187:*.bak=38;5;71:*.tmp=38;5;71:*.swp=38;5;71:*.orig=38;5;71:*.log=38;5;71:*.part=38;5;71'
@@ -0,0 +1,7 @@
+# Zero Trust Light 0.1.0
+# Author: Yannick Boog
+# Homepage: https://github.com/yannickboog/zero-trust-theme
+# License: MIT
+# Generated by zero-trust from theme.toml; do not edit.
+
+export LS_COLORS='di=01;38;5;24:ln=38;5;24:or=01;38;5;131:mi=38;5;131:pi=38;5;58:so=38;5;31:do=38;5;31:bd=01;38;5;58:cd=01;38;5;58:ex=01;38;5;64:su=38;5;231;48;5;131:sg=38;5;231;48;5;58:tw=38;5;231;48;5;64:ow=38;5;24;48;5;254:st=38;5;236;48;5;254:*.tar=38;5;58:*.tgz=38;5;58:*.gz=38;5;58:*.bz2=38;5;58:*.xz=38;5;58:*.zst=38;5;58:*.lz4=38;5;58:*.zip=38;5;58:*.7z=38;5;58:*.rar=38;5;58:*.jar=38;5;58:*.deb=38;5;58:*.rpm=38;5;58:*.apk=38;5;58:*.dmg=38;5;58:*.iso=38;5;58:*.png=38;5;31:*.jpg=38;5;31:*.jpeg=38;5;31:*.gif=38;5;31:*.webp=38;5;31:*.avif=38;5;31:*.bmp=38;5;31:*.ico=38;5;31:*.svg=38;5;31:*.tif=38;5;31:*.tiff=38;5;31:*.heic=38;5;31:*.mp3=38;5;24:*.flac=38;5;24:*.ogg=38;5;24:*.opus=38;5;24:*.wav=38;5;24:*.m4a=38;5;24:*.mp4=38;5;24:*.mkv=38;5;24:*.webm=38;5;24:*.mov=38;5;24:*.avi=38;5;24:*.pdf=38;5;131:*.epub=38;5;131:*.md=38;5;131:*.rst=38;5;131:*.txt=38;5;131:*.doc=38;5;131:*.docx=38;5;131:*.odt=38;5;131:*.xls=38;5;131:*.xlsx=38;5;131:*.ods=38;5;131:*.json=38;5;24:*.toml=38;5;24:*.yaml=38;5;24:*.yml=38;5;24:*.xml=38;5;24:*.ini=38;5;24:*.conf=38;5;24:*.csv=38;5;24:*.sql=38;5;24:*.lock=38;5;24:*.rs=38;5;238:*.c=38;5;238:*.h=38;5;238:*.cpp=38;5;238:*.hpp=38;5;238:*.go=38;5;238:*.py=38;5;238:*.rb=38;5;238:*.js=38;5;238:*.ts=38;5;238:*.java=38;5;238:*.kt=38;5;238:*.swift=38;5;238:*.sh=38;5;238:*.lua=38;5;238:*.bak=38;5;65:*.tmp=38;5;65:*.swp=38;5;65:*.orig=38;5;65:*.log=38;5;65:*.part=38;5;65'
@@ -0,0 +1,185 @@
+;;; zero-trust-light-theme.el --- Zero Trust Light -*- lexical-binding: t -*-
+;; Zero Trust Light 0.1.0
+;; Author: Yannick Boog
+;; Homepage: https://github.com/yannickboog/zero-trust-theme
+;; License: MIT
+;; Generated by zero-trust from theme.toml; do not edit.
+
+;;; Code:
+
+(deftheme zero-trust-light "Zero Trust Light.")
+
+(custom-theme-set-faces
+ 'zero-trust-light
+ '(default ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#FAFAFA")) (((min-colors 256)) (:foreground "color-236" :background "color-231")) (t (:foreground "brightblack" :background "brightwhite"))))
+ '(cursor ((((min-colors 16777216)) (:background "#006C99")) (((min-colors 256)) (:background "color-24")) (t (:background "brightblack"))))
+ '(region ((((min-colors 16777216)) (:background "#9FD1F9")) (((min-colors 256)) (:background "color-153")) (t (:background "white"))))
+ '(secondary-selection ((((min-colors 16777216)) (:background "#9CC9F9")) (((min-colors 256)) (:background "color-117")) (t (:background "cyan"))))
+ '(hl-line ((((min-colors 16777216)) (:background "#E7E7E7")) (((min-colors 256)) (:background "color-254")) (t (:background "white"))))
+ '(highlight ((((min-colors 16777216)) (:background "#9CA8B7")) (((min-colors 256)) (:background "color-248")) (t (:background "cyan"))))
+ '(isearch ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#9CA8B7" :weight bold)) (((min-colors 256)) (:foreground "color-236" :background "color-248" :weight bold)) (t (:foreground "brightblack" :background "cyan" :weight bold))))
+ '(lazy-highlight ((((min-colors 16777216)) (:background "#9CA8B7")) (((min-colors 256)) (:background "color-248")) (t (:background "cyan"))))
+ '(isearch-fail ((((min-colors 16777216)) (:foreground "#AE4835" :background "#E4BCBB")) (((min-colors 256)) (:foreground "color-131" :background "color-181")) (t (:foreground "red" :background "white"))))
+ '(match ((((min-colors 16777216)) (:background "#9CA8B7")) (((min-colors 256)) (:background "color-248")) (t (:background "cyan"))))
+ '(fringe ((((min-colors 16777216)) (:background "#EFEFEF")) (((min-colors 256)) (:background "color-255")) (t (:background "white"))))
+ '(line-number ((((min-colors 16777216)) (:foreground "#919191" :background "#EFEFEF")) (((min-colors 256)) (:foreground "color-246" :background "color-255")) (t (:foreground "brightblack" :background "white"))))
+ '(line-number-current-line ((((min-colors 16777216)) (:foreground "#070707" :background "#EFEFEF" :weight bold)) (((min-colors 256)) (:foreground "color-232" :background "color-255" :weight bold)) (t (:foreground "black" :background "white" :weight bold))))
+ '(vertical-border ((((min-colors 16777216)) (:foreground "#C8C8C8")) (((min-colors 256)) (:foreground "color-251")) (t (:foreground "white"))))
+ '(window-divider ((((min-colors 16777216)) (:foreground "#C8C8C8")) (((min-colors 256)) (:foreground "color-251")) (t (:foreground "white"))))
+ '(fill-column-indicator ((((min-colors 16777216)) (:foreground "#C8C8C8")) (((min-colors 256)) (:foreground "color-251")) (t (:foreground "white"))))
+ '(minibuffer-prompt ((((min-colors 16777216)) (:foreground "#006C99" :weight bold)) (((min-colors 256)) (:foreground "color-24" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(link ((((min-colors 16777216)) (:foreground "#2568AA" :underline t)) (((min-colors 256)) (:foreground "color-25" :underline t)) (t (:foreground "brightblue" :underline t))))
+ '(link-visited ((((min-colors 16777216)) (:foreground "#2568AA" :underline t)) (((min-colors 256)) (:foreground "color-25" :underline t)) (t (:foreground "brightblue" :underline t))))
+ '(shadow ((((min-colors 16777216)) (:foreground "#6D6D6D")) (((min-colors 256)) (:foreground "color-242")) (t (:foreground "brightblack"))))
+ '(tooltip ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-236" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(show-paren-match ((((min-colors 16777216)) (:background "#9CA8B7" :weight bold)) (((min-colors 256)) (:background "color-248" :weight bold)) (t (:background "cyan" :weight bold))))
+ '(show-paren-mismatch ((((min-colors 16777216)) (:foreground "#AE4835" :background "#E4BCBB")) (((min-colors 256)) (:foreground "color-131" :background "color-181")) (t (:foreground "red" :background "white"))))
+ '(trailing-whitespace ((((min-colors 16777216)) (:background "#E4BCBB")) (((min-colors 256)) (:background "color-181")) (t (:background "white"))))
+ '(whitespace-space ((((min-colors 16777216)) (:foreground "#C8C8C8")) (((min-colors 256)) (:foreground "color-251")) (t (:foreground "white"))))
+ '(whitespace-tab ((((min-colors 16777216)) (:foreground "#C8C8C8")) (((min-colors 256)) (:foreground "color-251")) (t (:foreground "white"))))
+ '(whitespace-newline ((((min-colors 16777216)) (:foreground "#C8C8C8")) (((min-colors 256)) (:foreground "color-251")) (t (:foreground "white"))))
+ '(error ((((min-colors 16777216)) (:foreground "#AE4835" :weight bold)) (((min-colors 256)) (:foreground "color-131" :weight bold)) (t (:foreground "red" :weight bold))))
+ '(warning ((((min-colors 16777216)) (:foreground "#5C4C00" :weight bold)) (((min-colors 256)) (:foreground "color-58" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(success ((((min-colors 16777216)) (:foreground "#6B8232" :weight bold)) (((min-colors 256)) (:foreground "color-64" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(font-lock-warning-face ((((min-colors 16777216)) (:foreground "#5C4C00" :weight bold)) (((min-colors 256)) (:foreground "color-58" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(mode-line ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-236" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(mode-line-inactive ((((min-colors 16777216)) (:foreground "#6D6D6D" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-242" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(mode-line-buffer-id ((((min-colors 16777216)) (:weight bold)) (((min-colors 256)) (:weight bold)) (t (:weight bold))))
+ '(mode-line-emphasis ((((min-colors 16777216)) (:foreground "#006C99" :weight bold)) (((min-colors 256)) (:foreground "color-24" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(mode-line-highlight ((((min-colors 16777216)) (:background "#D0D0D0")) (((min-colors 256)) (:background "color-252")) (t (:background "white"))))
+ '(header-line ((((min-colors 16777216)) (:foreground "#6D6D6D" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-242" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(tab-bar ((((min-colors 16777216)) (:foreground "#6D6D6D" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-242" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(tab-bar-tab ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#FAFAFA")) (((min-colors 256)) (:foreground "color-236" :background "color-231")) (t (:foreground "brightblack" :background "brightwhite"))))
+ '(tab-bar-tab-inactive ((((min-colors 16777216)) (:foreground "#6D6D6D" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-242" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(completions-common-part ((((min-colors 16777216)) (:foreground "#006C99")) (((min-colors 256)) (:foreground "color-24")) (t (:foreground "brightblack"))))
+ '(completions-first-difference ((((min-colors 16777216)) (:weight bold)) (((min-colors 256)) (:weight bold)) (t (:weight bold))))
+ '(flymake-error ((((min-colors 16777216)) (:underline (:style wave :color "#AE4835"))) (((min-colors 256)) (:underline (:style wave :color "color-131"))) (t (:underline (:style wave :color "red")))))
+ '(flymake-warning ((((min-colors 16777216)) (:underline (:style wave :color "#5C4C00"))) (((min-colors 256)) (:underline (:style wave :color "color-58"))) (t (:underline (:style wave :color "brightblack")))))
+ '(flymake-note ((((min-colors 16777216)) (:underline (:style wave :color "#006C99"))) (((min-colors 256)) (:underline (:style wave :color "color-24"))) (t (:underline (:style wave :color "brightblack")))))
+ '(flycheck-error ((((min-colors 16777216)) (:underline (:style wave :color "#AE4835"))) (((min-colors 256)) (:underline (:style wave :color "color-131"))) (t (:underline (:style wave :color "red")))))
+ '(flycheck-warning ((((min-colors 16777216)) (:underline (:style wave :color "#5C4C00"))) (((min-colors 256)) (:underline (:style wave :color "color-58"))) (t (:underline (:style wave :color "brightblack")))))
+ '(flycheck-info ((((min-colors 16777216)) (:underline (:style wave :color "#006C99"))) (((min-colors 256)) (:underline (:style wave :color "color-24"))) (t (:underline (:style wave :color "brightblack")))))
+ '(flyspell-incorrect ((((min-colors 16777216)) (:underline (:style wave :color "#AE4835"))) (((min-colors 256)) (:underline (:style wave :color "color-131"))) (t (:underline (:style wave :color "red")))))
+ '(flyspell-duplicate ((((min-colors 16777216)) (:underline (:style wave :color "#5C4C00"))) (((min-colors 256)) (:underline (:style wave :color "color-58"))) (t (:underline (:style wave :color "brightblack")))))
+ '(org-todo ((((min-colors 16777216)) (:foreground "#5C4C00" :weight bold)) (((min-colors 256)) (:foreground "color-58" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-done ((((min-colors 16777216)) (:foreground "#6B8232" :weight bold)) (((min-colors 256)) (:foreground "color-64" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-headline-done ((((min-colors 16777216)) (:foreground "#6D6D6D")) (((min-colors 256)) (:foreground "color-242")) (t (:foreground "brightblack"))))
+ '(org-date ((((min-colors 16777216)) (:foreground "#2568AA" :underline t)) (((min-colors 256)) (:foreground "color-25" :underline t)) (t (:foreground "brightblue" :underline t))))
+ '(org-link ((((min-colors 16777216)) (:foreground "#2568AA" :underline t)) (((min-colors 256)) (:foreground "color-25" :underline t)) (t (:foreground "brightblue" :underline t))))
+ '(org-block ((((min-colors 16777216)) (:background "#E3E3E3")) (((min-colors 256)) (:background "color-254")) (t (:background "white"))))
+ '(org-block-begin-line ((((min-colors 16777216)) (:foreground "#527F3C" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-65" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(org-block-end-line ((((min-colors 16777216)) (:foreground "#527F3C" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-65" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(org-table ((((min-colors 16777216)) (:foreground "#6D6D6D")) (((min-colors 256)) (:foreground "color-242")) (t (:foreground "brightblack"))))
+ '(diff-added ((((min-colors 16777216)) (:foreground "#536B3D" :background "#A4C8A3")) (((min-colors 256)) (:foreground "color-58" :background "color-151")) (t (:foreground "brightblack" :background "cyan"))))
+ '(diff-removed ((((min-colors 16777216)) (:foreground "#CE6F77" :background "#E4BCBB")) (((min-colors 256)) (:foreground "color-167" :background "color-181")) (t (:foreground "brightblack" :background "white"))))
+ '(diff-changed ((((min-colors 16777216)) (:foreground "#664A00" :background "#9FD1F9")) (((min-colors 256)) (:foreground "color-58" :background "color-153")) (t (:foreground "brightblack" :background "white"))))
+ '(diff-header ((((min-colors 16777216)) (:foreground "#6D6D6D" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-242" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(diff-file-header ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#E3E3E3" :weight bold)) (((min-colors 256)) (:foreground "color-236" :background "color-254" :weight bold)) (t (:foreground "brightblack" :background "white" :weight bold))))
+ '(magit-section-heading ((((min-colors 16777216)) (:foreground "#006C99" :weight bold)) (((min-colors 256)) (:foreground "color-24" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(magit-section-highlight ((((min-colors 16777216)) (:background "#E7E7E7")) (((min-colors 256)) (:background "color-254")) (t (:background "white"))))
+ '(magit-branch-local ((((min-colors 16777216)) (:foreground "#2568AA")) (((min-colors 256)) (:foreground "color-25")) (t (:foreground "brightblue"))))
+ '(magit-branch-remote ((((min-colors 16777216)) (:foreground "#536B3D")) (((min-colors 256)) (:foreground "color-58")) (t (:foreground "brightblack"))))
+ '(magit-branch-current ((((min-colors 16777216)) (:foreground "#2568AA" :weight bold)) (((min-colors 256)) (:foreground "color-25" :weight bold)) (t (:foreground "brightblue" :weight bold))))
+ '(magit-hash ((((min-colors 16777216)) (:foreground "#6D6D6D")) (((min-colors 256)) (:foreground "color-242")) (t (:foreground "brightblack"))))
+ '(magit-tag ((((min-colors 16777216)) (:foreground "#006C99")) (((min-colors 256)) (:foreground "color-24")) (t (:foreground "brightblack"))))
+ '(magit-dimmed ((((min-colors 16777216)) (:foreground "#6D6D6D")) (((min-colors 256)) (:foreground "color-242")) (t (:foreground "brightblack"))))
+ '(magit-diff-added ((((min-colors 16777216)) (:foreground "#536B3D" :background "#A4C8A3")) (((min-colors 256)) (:foreground "color-58" :background "color-151")) (t (:foreground "brightblack" :background "cyan"))))
+ '(magit-diff-added-highlight ((((min-colors 16777216)) (:foreground "#536B3D" :background "#A4C8A3")) (((min-colors 256)) (:foreground "color-58" :background "color-151")) (t (:foreground "brightblack" :background "cyan"))))
+ '(magit-diff-removed ((((min-colors 16777216)) (:foreground "#CE6F77" :background "#E4BCBB")) (((min-colors 256)) (:foreground "color-167" :background "color-181")) (t (:foreground "brightblack" :background "white"))))
+ '(magit-diff-removed-highlight ((((min-colors 16777216)) (:foreground "#CE6F77" :background "#E4BCBB")) (((min-colors 256)) (:foreground "color-167" :background "color-181")) (t (:foreground "brightblack" :background "white"))))
+ '(magit-diff-context ((((min-colors 16777216)) (:foreground "#6D6D6D")) (((min-colors 256)) (:foreground "color-242")) (t (:foreground "brightblack"))))
+ '(magit-diff-context-highlight ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#E7E7E7")) (((min-colors 256)) (:foreground "color-236" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(magit-diff-hunk-heading ((((min-colors 16777216)) (:foreground "#6D6D6D" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-242" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(magit-diff-hunk-heading-highlight ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#9CC9F9")) (((min-colors 256)) (:foreground "color-236" :background "color-117")) (t (:foreground "brightblack" :background "cyan"))))
+ '(magit-diff-file-heading ((((min-colors 16777216)) (:weight bold)) (((min-colors 256)) (:weight bold)) (t (:weight bold))))
+ '(magit-diffstat-added ((((min-colors 16777216)) (:foreground "#536B3D")) (((min-colors 256)) (:foreground "color-58")) (t (:foreground "brightblack"))))
+ '(magit-diffstat-removed ((((min-colors 16777216)) (:foreground "#CE6F77")) (((min-colors 256)) (:foreground "color-167")) (t (:foreground "brightblack"))))
+ '(magit-log-author ((((min-colors 16777216)) (:foreground "#006C99")) (((min-colors 256)) (:foreground "color-24")) (t (:foreground "brightblack"))))
+ '(magit-log-date ((((min-colors 16777216)) (:foreground "#6D6D6D")) (((min-colors 256)) (:foreground "color-242")) (t (:foreground "brightblack"))))
+ '(magit-process-ok ((((min-colors 16777216)) (:foreground "#6B8232" :weight bold)) (((min-colors 256)) (:foreground "color-64" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(magit-process-ng ((((min-colors 16777216)) (:foreground "#AE4835" :weight bold)) (((min-colors 256)) (:foreground "color-131" :weight bold)) (t (:foreground "red" :weight bold))))
+ '(company-tooltip ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-236" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(company-tooltip-selection ((((min-colors 16777216)) (:background "#9CC9F9")) (((min-colors 256)) (:background "color-117")) (t (:background "cyan"))))
+ '(company-tooltip-common ((((min-colors 16777216)) (:foreground "#006C99" :weight bold)) (((min-colors 256)) (:foreground "color-24" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(company-tooltip-annotation ((((min-colors 16777216)) (:foreground "#6D6D6D")) (((min-colors 256)) (:foreground "color-242")) (t (:foreground "brightblack"))))
+ '(company-scrollbar-bg ((((min-colors 16777216)) (:background "#E7E7E7")) (((min-colors 256)) (:background "color-254")) (t (:background "white"))))
+ '(company-scrollbar-fg ((((min-colors 16777216)) (:background "#A6A6A6")) (((min-colors 256)) (:background "color-248")) (t (:background "brightblack"))))
+ '(company-tooltip-scrollbar-track ((((min-colors 16777216)) (:background "#E7E7E7")) (((min-colors 256)) (:background "color-254")) (t (:background "white"))))
+ '(company-tooltip-scrollbar-thumb ((((min-colors 16777216)) (:background "#A6A6A6")) (((min-colors 256)) (:background "color-248")) (t (:background "brightblack"))))
+ '(company-preview ((((min-colors 16777216)) (:foreground "#8257B8")) (((min-colors 256)) (:foreground "color-97")) (t (:foreground "brightblue"))))
+ '(company-preview-common ((((min-colors 16777216)) (:foreground "#8257B8")) (((min-colors 256)) (:foreground "color-97")) (t (:foreground "brightblue"))))
+ '(corfu-default ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-236" :background "color-254")) (t (:foreground "brightblack" :background "white"))))
+ '(corfu-current ((((min-colors 16777216)) (:foreground "#2E2E2E" :background "#9CC9F9")) (((min-colors 256)) (:foreground "color-236" :background "color-117")) (t (:foreground "brightblack" :background "cyan"))))
+ '(corfu-border ((((min-colors 16777216)) (:background "#D0D0D0")) (((min-colors 256)) (:background "color-252")) (t (:background "white"))))
+ '(corfu-bar ((((min-colors 16777216)) (:background "#A6A6A6")) (((min-colors 256)) (:background "color-248")) (t (:background "brightblack"))))
+ '(corfu-annotations ((((min-colors 16777216)) (:foreground "#6D6D6D")) (((min-colors 256)) (:foreground "color-242")) (t (:foreground "brightblack"))))
+ '(corfu-deprecated ((((min-colors 16777216)) (:foreground "#6D6D6D" :strike-through t)) (((min-colors 256)) (:foreground "color-242" :strike-through t)) (t (:foreground "brightblack" :strike-through t))))
+ '(font-lock-comment-face ((((min-colors 16777216)) (:foreground "#527F3C" :slant italic)) (((min-colors 256)) (:foreground "color-65" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(font-lock-comment-delimiter-face ((((min-colors 16777216)) (:foreground "#527F3C" :slant italic)) (((min-colors 256)) (:foreground "color-65" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(font-lock-doc-face ((((min-colors 16777216)) (:foreground "#527F3C" :slant italic)) (((min-colors 256)) (:foreground "color-65" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(font-lock-doc-markup-face ((((min-colors 16777216)) (:foreground "#527F3C" :slant italic)) (((min-colors 256)) (:foreground "color-65" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(font-lock-string-face ((((min-colors 16777216)) (:foreground "#9D634C")) (((min-colors 256)) (:foreground "color-131")) (t (:foreground "brightblack"))))
+ '(font-lock-escape-face ((((min-colors 16777216)) (:foreground "#73591A")) (((min-colors 256)) (:foreground "color-94")) (t (:foreground "brightblack"))))
+ '(font-lock-regexp-face ((((min-colors 16777216)) (:foreground "#B95455")) (((min-colors 256)) (:foreground "color-131")) (t (:foreground "red"))))
+ '(font-lock-regexp-grouping-backslash ((((min-colors 16777216)) (:foreground "#73591A")) (((min-colors 256)) (:foreground "color-94")) (t (:foreground "brightblack"))))
+ '(font-lock-regexp-grouping-construct ((((min-colors 16777216)) (:foreground "#73591A")) (((min-colors 256)) (:foreground "color-94")) (t (:foreground "brightblack"))))
+ '(font-lock-number-face ((((min-colors 16777216)) (:foreground "#3D5231")) (((min-colors 256)) (:foreground "color-239")) (t (:foreground "brightblack"))))
+ '(font-lock-constant-face ((((min-colors 16777216)) (:foreground "#006C99")) (((min-colors 256)) (:foreground "color-24")) (t (:foreground "brightblack"))))
+ '(font-lock-builtin-face ((((min-colors 16777216)) (:foreground "#B93C38")) (((min-colors 256)) (:foreground "color-160")) (t (:foreground "red"))))
+ '(font-lock-keyword-face ((((min-colors 16777216)) (:foreground "#3178B0" :weight bold)) (((min-colors 256)) (:foreground "color-31" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(font-lock-operator-face ((((min-colors 16777216)) (:foreground "#19659E")) (((min-colors 256)) (:foreground "color-25")) (t (:foreground "brightblack"))))
+ '(font-lock-negation-char-face ((((min-colors 16777216)) (:foreground "#19659E")) (((min-colors 256)) (:foreground "color-25")) (t (:foreground "brightblack"))))
+ '(font-lock-function-name-face ((((min-colors 16777216)) (:foreground "#434213")) (((min-colors 256)) (:foreground "color-238")) (t (:foreground "brightblack"))))
+ '(font-lock-function-call-face ((((min-colors 16777216)) (:foreground "#434213")) (((min-colors 256)) (:foreground "color-238")) (t (:foreground "brightblack"))))
+ '(font-lock-type-face ((((min-colors 16777216)) (:foreground "#007866")) (((min-colors 256)) (:foreground "color-29")) (t (:foreground "brightblack"))))
+ '(font-lock-variable-name-face ((((min-colors 16777216)) (:foreground "#0E5471")) (((min-colors 256)) (:foreground "color-24")) (t (:foreground "brightblack"))))
+ '(font-lock-variable-use-face ((((min-colors 16777216)) (:foreground "#0E5471")) (((min-colors 256)) (:foreground "color-24")) (t (:foreground "brightblack"))))
+ '(font-lock-property-name-face ((((min-colors 16777216)) (:foreground "#0E5471")) (((min-colors 256)) (:foreground "color-24")) (t (:foreground "brightblack"))))
+ '(font-lock-property-use-face ((((min-colors 16777216)) (:foreground "#0E5471")) (((min-colors 256)) (:foreground "color-24")) (t (:foreground "brightblack"))))
+ '(font-lock-preprocessor-face ((((min-colors 16777216)) (:foreground "#2E2E2E")) (((min-colors 256)) (:foreground "color-236")) (t (:foreground "brightblack"))))
+ '(font-lock-punctuation-face ((((min-colors 16777216)) (:foreground "#2E2E2E")) (((min-colors 256)) (:foreground "color-236")) (t (:foreground "brightblack"))))
+ '(font-lock-bracket-face ((((min-colors 16777216)) (:foreground "#2E2E2E")) (((min-colors 256)) (:foreground "color-236")) (t (:foreground "brightblack"))))
+ '(font-lock-delimiter-face ((((min-colors 16777216)) (:foreground "#2E2E2E")) (((min-colors 256)) (:foreground "color-236")) (t (:foreground "brightblack"))))
+ '(font-lock-misc-punctuation-face ((((min-colors 16777216)) (:foreground "#DA3130")) (((min-colors 256)) (:foreground "color-160")) (t (:foreground "red"))))
+ '(org-document-title ((((min-colors 16777216)) (:foreground "#995D95" :weight bold)) (((min-colors 256)) (:foreground "color-96" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-1 ((((min-colors 16777216)) (:foreground "#995D95" :weight bold)) (((min-colors 256)) (:foreground "color-96" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-2 ((((min-colors 16777216)) (:foreground "#995D95" :weight bold)) (((min-colors 256)) (:foreground "color-96" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-3 ((((min-colors 16777216)) (:foreground "#995D95" :weight bold)) (((min-colors 256)) (:foreground "color-96" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-4 ((((min-colors 16777216)) (:foreground "#995D95" :weight bold)) (((min-colors 256)) (:foreground "color-96" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-5 ((((min-colors 16777216)) (:foreground "#995D95" :weight bold)) (((min-colors 256)) (:foreground "color-96" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-6 ((((min-colors 16777216)) (:foreground "#995D95" :weight bold)) (((min-colors 256)) (:foreground "color-96" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-7 ((((min-colors 16777216)) (:foreground "#995D95" :weight bold)) (((min-colors 256)) (:foreground "color-96" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-8 ((((min-colors 16777216)) (:foreground "#995D95" :weight bold)) (((min-colors 256)) (:foreground "color-96" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-code ((((min-colors 16777216)) (:foreground "#9D634C")) (((min-colors 256)) (:foreground "color-131")) (t (:foreground "brightblack"))))
+ '(org-verbatim ((((min-colors 16777216)) (:foreground "#9D634C")) (((min-colors 256)) (:foreground "color-131")) (t (:foreground "brightblack"))))
+ '(org-list-dt ((((min-colors 16777216)) (:foreground "#C02F68")) (((min-colors 256)) (:foreground "color-161")) (t (:foreground "red"))))
+ '(org-meta-line ((((min-colors 16777216)) (:foreground "#527F3C" :slant italic)) (((min-colors 256)) (:foreground "color-65" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(org-drawer ((((min-colors 16777216)) (:foreground "#527F3C" :slant italic)) (((min-colors 256)) (:foreground "color-65" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(org-tag ((((min-colors 16777216)) (:foreground "#006C99")) (((min-colors 256)) (:foreground "color-24")) (t (:foreground "brightblack"))))
+ '(bold ((((min-colors 16777216)) (:foreground "#2568AA" :weight bold)) (((min-colors 256)) (:foreground "color-25" :weight bold)) (t (:foreground "brightblue" :weight bold))))
+ '(italic ((((min-colors 16777216)) (:foreground "#006C99")) (((min-colors 256)) (:foreground "color-24")) (t (:foreground "brightblack"))))
+ '(ansi-color-black ((((min-colors 16777216)) (:foreground "#070707" :background "#070707")) (((min-colors 256)) (:foreground "color-232" :background "color-232")) (t (:foreground "black" :background "black"))))
+ '(ansi-color-red ((((min-colors 16777216)) (:foreground "#AE4835" :background "#AE4835")) (((min-colors 256)) (:foreground "color-131" :background "color-131")) (t (:foreground "red" :background "red"))))
+ '(ansi-color-green ((((min-colors 16777216)) (:foreground "#6B8232" :background "#6B8232")) (((min-colors 256)) (:foreground "color-64" :background "color-64")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(ansi-color-yellow ((((min-colors 16777216)) (:foreground "#5C4C00" :background "#5C4C00")) (((min-colors 256)) (:foreground "color-58" :background "color-58")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(ansi-color-blue ((((min-colors 16777216)) (:foreground "#006C99" :background "#006C99")) (((min-colors 256)) (:foreground "color-24" :background "color-24")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(ansi-color-magenta ((((min-colors 16777216)) (:foreground "#8257B8" :background "#8257B8")) (((min-colors 256)) (:foreground "color-97" :background "color-97")) (t (:foreground "brightblue" :background "brightblue"))))
+ '(ansi-color-cyan ((((min-colors 16777216)) (:foreground "#006458" :background "#006458")) (((min-colors 256)) (:foreground "color-23" :background "color-23")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(ansi-color-white ((((min-colors 16777216)) (:foreground "#E3E3E3" :background "#E3E3E3")) (((min-colors 256)) (:foreground "color-254" :background "color-254")) (t (:foreground "white" :background "white"))))
+ '(ansi-color-bright-black ((((min-colors 16777216)) (:foreground "#070707" :background "#070707")) (((min-colors 256)) (:foreground "color-232" :background "color-232")) (t (:foreground "black" :background "black"))))
+ '(ansi-color-bright-red ((((min-colors 16777216)) (:foreground "#C8373E" :background "#C8373E")) (((min-colors 256)) (:foreground "color-160" :background "color-160")) (t (:foreground "red" :background "red"))))
+ '(ansi-color-bright-green ((((min-colors 16777216)) (:foreground "#426526" :background "#426526")) (((min-colors 256)) (:foreground "color-58" :background "color-58")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(ansi-color-bright-yellow ((((min-colors 16777216)) (:foreground "#4A3F00" :background "#4A3F00")) (((min-colors 256)) (:foreground "color-238" :background "color-238")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(ansi-color-bright-blue ((((min-colors 16777216)) (:foreground "#096193" :background "#096193")) (((min-colors 256)) (:foreground "color-24" :background "color-24")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(ansi-color-bright-magenta ((((min-colors 16777216)) (:foreground "#6C4B9A" :background "#6C4B9A")) (((min-colors 256)) (:foreground "color-61" :background "color-61")) (t (:foreground "brightblue" :background "brightblue"))))
+ '(ansi-color-bright-cyan ((((min-colors 16777216)) (:foreground "#004838" :background "#004838")) (((min-colors 256)) (:foreground "color-237" :background "color-237")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(ansi-color-bright-white ((((min-colors 16777216)) (:foreground "#919191" :background "#919191")) (((min-colors 256)) (:foreground "color-246" :background "color-246")) (t (:foreground "brightblack" :background "brightblack")))))
+
+;;;###autoload
+(when load-file-name
+  (add-to-list 'custom-theme-load-path
+               (file-name-as-directory (file-name-directory load-file-name))))
+
+(provide-theme 'zero-trust-light)
+
+;;; zero-trust-light-theme.el ends here
@@ -0,0 +1,185 @@
+;;; zero-trust-theme.el --- Zero Trust Dark -*- lexical-binding: t -*-
+;; Zero Trust Dark 0.1.0
+;; Author: Yannick Boog
+;; Homepage: https://github.com/yannickboog/zero-trust-theme
+;; License: MIT
+;; Generated by zero-trust from theme.toml; do not edit.
+
+;;; Code:
+
+(deftheme zero-trust "Zero Trust Dark.")
+
+(custom-theme-set-faces
+ 'zero-trust
+ '(default ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#1E1E1E")) (((min-colors 256)) (:foreground "color-252" :background "color-234")) (t (:foreground "white" :background "black"))))
+ '(cursor ((((min-colors 16777216)) (:background "#4FC1FF")) (((min-colors 256)) (:background "color-75")) (t (:background "cyan"))))
+ '(region ((((min-colors 16777216)) (:background "#1A4A6B")) (((min-colors 256)) (:background "color-24")) (t (:background "brightblack"))))
+ '(secondary-selection ((((min-colors 16777216)) (:background "#264F78")) (((min-colors 256)) (:background "color-24")) (t (:background "brightblack"))))
+ '(hl-line ((((min-colors 16777216)) (:background "#2A2A2A")) (((min-colors 256)) (:background "color-235")) (t (:background "black"))))
+ '(highlight ((((min-colors 16777216)) (:background "#515C6A")) (((min-colors 256)) (:background "color-240")) (t (:background "brightblack"))))
+ '(isearch ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#515C6A" :weight bold)) (((min-colors 256)) (:foreground "color-252" :background "color-240" :weight bold)) (t (:foreground "white" :background "brightblack" :weight bold))))
+ '(lazy-highlight ((((min-colors 16777216)) (:background "#515C6A")) (((min-colors 256)) (:background "color-240")) (t (:background "brightblack"))))
+ '(isearch-fail ((((min-colors 16777216)) (:foreground "#F48771" :background "#5A3A3A")) (((min-colors 256)) (:foreground "color-209" :background "color-238")) (t (:foreground "brightred" :background "brightblack"))))
+ '(match ((((min-colors 16777216)) (:background "#515C6A")) (((min-colors 256)) (:background "color-240")) (t (:background "brightblack"))))
+ '(fringe ((((min-colors 16777216)) (:background "#252525")) (((min-colors 256)) (:background "color-235")) (t (:background "black"))))
+ '(line-number ((((min-colors 16777216)) (:foreground "#6B6B6B" :background "#252525")) (((min-colors 256)) (:foreground "color-242" :background "color-235")) (t (:foreground "brightblack" :background "black"))))
+ '(line-number-current-line ((((min-colors 16777216)) (:foreground "#FFFFFF" :background "#252525" :weight bold)) (((min-colors 256)) (:foreground "color-231" :background "color-235" :weight bold)) (t (:foreground "brightwhite" :background "black" :weight bold))))
+ '(vertical-border ((((min-colors 16777216)) (:foreground "#404040")) (((min-colors 256)) (:foreground "color-238")) (t (:foreground "brightblack"))))
+ '(window-divider ((((min-colors 16777216)) (:foreground "#404040")) (((min-colors 256)) (:foreground "color-238")) (t (:foreground "brightblack"))))
+ '(fill-column-indicator ((((min-colors 16777216)) (:foreground "#404040")) (((min-colors 256)) (:foreground "color-238")) (t (:foreground "brightblack"))))
+ '(minibuffer-prompt ((((min-colors 16777216)) (:foreground "#4FC1FF" :weight bold)) (((min-colors 256)) (:foreground "color-75" :weight bold)) (t (:foreground "cyan" :weight bold))))
+ '(link ((((min-colors 16777216)) (:foreground "#74B7FF" :underline t)) (((min-colors 256)) (:foreground "color-111" :underline t)) (t (:foreground "cyan" :underline t))))
+ '(link-visited ((((min-colors 16777216)) (:foreground "#74B7FF" :underline t)) (((min-colors 256)) (:foreground "color-111" :underline t)) (t (:foreground "cyan" :underline t))))
+ '(shadow ((((min-colors 16777216)) (:foreground "#8B8B8B")) (((min-colors 256)) (:foreground "color-245")) (t (:foreground "brightblack"))))
+ '(tooltip ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-252" :background "color-236")) (t (:foreground "white" :background "black"))))
+ '(show-paren-match ((((min-colors 16777216)) (:background "#515C6A" :weight bold)) (((min-colors 256)) (:background "color-240" :weight bold)) (t (:background "brightblack" :weight bold))))
+ '(show-paren-mismatch ((((min-colors 16777216)) (:foreground "#F48771" :background "#5A3A3A")) (((min-colors 256)) (:foreground "color-209" :background "color-238")) (t (:foreground "brightred" :background "brightblack"))))
+ '(trailing-whitespace ((((min-colors 16777216)) (:background "#5A3A3A")) (((min-colors 256)) (:background "color-238")) (t (:background "brightblack"))))
+ '(whitespace-space ((((min-colors 16777216)) (:foreground "#404040")) (((min-colors 256)) (:foreground "color-238")) (t (:foreground "brightblack"))))
+ '(whitespace-tab ((((min-colors 16777216)) (:foreground "#404040")) (((min-colors 256)) (:foreground "color-238")) (t (:foreground "brightblack"))))
+ '(whitespace-newline ((((min-colors 16777216)) (:foreground "#404040")) (((min-colors 256)) (:foreground "color-238")) (t (:foreground "brightblack"))))
+ '(error ((((min-colors 16777216)) (:foreground "#F48771" :weight bold)) (((min-colors 256)) (:foreground "color-209" :weight bold)) (t (:foreground "brightred" :weight bold))))
+ '(warning ((((min-colors 16777216)) (:foreground "#F4D03F" :weight bold)) (((min-colors 256)) (:foreground "color-221" :weight bold)) (t (:foreground "yellow" :weight bold))))
+ '(success ((((min-colors 16777216)) (:foreground "#90A959" :weight bold)) (((min-colors 256)) (:foreground "color-107" :weight bold)) (t (:foreground "yellow" :weight bold))))
+ '(font-lock-warning-face ((((min-colors 16777216)) (:foreground "#F4D03F" :weight bold)) (((min-colors 256)) (:foreground "color-221" :weight bold)) (t (:foreground "yellow" :weight bold))))
+ '(mode-line ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-252" :background "color-236")) (t (:foreground "white" :background "black"))))
+ '(mode-line-inactive ((((min-colors 16777216)) (:foreground "#8B8B8B" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-245" :background "color-236")) (t (:foreground "brightblack" :background "black"))))
+ '(mode-line-buffer-id ((((min-colors 16777216)) (:weight bold)) (((min-colors 256)) (:weight bold)) (t (:weight bold))))
+ '(mode-line-emphasis ((((min-colors 16777216)) (:foreground "#4FC1FF" :weight bold)) (((min-colors 256)) (:foreground "color-75" :weight bold)) (t (:foreground "cyan" :weight bold))))
+ '(mode-line-highlight ((((min-colors 16777216)) (:background "#3A3A3A")) (((min-colors 256)) (:background "color-237")) (t (:background "brightblack"))))
+ '(header-line ((((min-colors 16777216)) (:foreground "#8B8B8B" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-245" :background "color-236")) (t (:foreground "brightblack" :background "black"))))
+ '(tab-bar ((((min-colors 16777216)) (:foreground "#8B8B8B" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-245" :background "color-236")) (t (:foreground "brightblack" :background "black"))))
+ '(tab-bar-tab ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#1E1E1E")) (((min-colors 256)) (:foreground "color-252" :background "color-234")) (t (:foreground "white" :background "black"))))
+ '(tab-bar-tab-inactive ((((min-colors 16777216)) (:foreground "#8B8B8B" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-245" :background "color-236")) (t (:foreground "brightblack" :background "black"))))
+ '(completions-common-part ((((min-colors 16777216)) (:foreground "#4FC1FF")) (((min-colors 256)) (:foreground "color-75")) (t (:foreground "cyan"))))
+ '(completions-first-difference ((((min-colors 16777216)) (:weight bold)) (((min-colors 256)) (:weight bold)) (t (:weight bold))))
+ '(flymake-error ((((min-colors 16777216)) (:underline (:style wave :color "#F48771"))) (((min-colors 256)) (:underline (:style wave :color "color-209"))) (t (:underline (:style wave :color "brightred")))))
+ '(flymake-warning ((((min-colors 16777216)) (:underline (:style wave :color "#F4D03F"))) (((min-colors 256)) (:underline (:style wave :color "color-221"))) (t (:underline (:style wave :color "yellow")))))
+ '(flymake-note ((((min-colors 16777216)) (:underline (:style wave :color "#4FC1FF"))) (((min-colors 256)) (:underline (:style wave :color "color-75"))) (t (:underline (:style wave :color "cyan")))))
+ '(flycheck-error ((((min-colors 16777216)) (:underline (:style wave :color "#F48771"))) (((min-colors 256)) (:underline (:style wave :color "color-209"))) (t (:underline (:style wave :color "brightred")))))
+ '(flycheck-warning ((((min-colors 16777216)) (:underline (:style wave :color "#F4D03F"))) (((min-colors 256)) (:underline (:style wave :color "color-221"))) (t (:underline (:style wave :color "yellow")))))
+ '(flycheck-info ((((min-colors 16777216)) (:underline (:style wave :color "#4FC1FF"))) (((min-colors 256)) (:underline (:style wave :color "color-75"))) (t (:underline (:style wave :color "cyan")))))
+ '(flyspell-incorrect ((((min-colors 16777216)) (:underline (:style wave :color "#F48771"))) (((min-colors 256)) (:underline (:style wave :color "color-209"))) (t (:underline (:style wave :color "brightred")))))
+ '(flyspell-duplicate ((((min-colors 16777216)) (:underline (:style wave :color "#F4D03F"))) (((min-colors 256)) (:underline (:style wave :color "color-221"))) (t (:underline (:style wave :color "yellow")))))
+ '(org-todo ((((min-colors 16777216)) (:foreground "#F4D03F" :weight bold)) (((min-colors 256)) (:foreground "color-221" :weight bold)) (t (:foreground "yellow" :weight bold))))
+ '(org-done ((((min-colors 16777216)) (:foreground "#90A959" :weight bold)) (((min-colors 256)) (:foreground "color-107" :weight bold)) (t (:foreground "yellow" :weight bold))))
+ '(org-headline-done ((((min-colors 16777216)) (:foreground "#8B8B8B")) (((min-colors 256)) (:foreground "color-245")) (t (:foreground "brightblack"))))
+ '(org-date ((((min-colors 16777216)) (:foreground "#74B7FF" :underline t)) (((min-colors 256)) (:foreground "color-111" :underline t)) (t (:foreground "cyan" :underline t))))
+ '(org-link ((((min-colors 16777216)) (:foreground "#74B7FF" :underline t)) (((min-colors 256)) (:foreground "color-111" :underline t)) (t (:foreground "cyan" :underline t))))
+ '(org-block ((((min-colors 16777216)) (:background "#2D2D2D")) (((min-colors 256)) (:background "color-236")) (t (:background "black"))))
+ '(org-block-begin-line ((((min-colors 16777216)) (:foreground "#6A9955" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-71" :background "color-236")) (t (:foreground "brightblack" :background "black"))))
+ '(org-block-end-line ((((min-colors 16777216)) (:foreground "#6A9955" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-71" :background "color-236")) (t (:foreground "brightblack" :background "black"))))
+ '(org-table ((((min-colors 16777216)) (:foreground "#8B8B8B")) (((min-colors 256)) (:foreground "color-245")) (t (:foreground "brightblack"))))
+ '(diff-added ((((min-colors 16777216)) (:foreground "#A3BE8C" :background "#3A5A3A")) (((min-colors 256)) (:foreground "color-144" :background "color-23")) (t (:foreground "cyan" :background "brightblack"))))
+ '(diff-removed ((((min-colors 16777216)) (:foreground "#BF616A" :background "#5A3A3A")) (((min-colors 256)) (:foreground "color-131" :background "color-238")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(diff-changed ((((min-colors 16777216)) (:foreground "#EBCB8B" :background "#1A4A6B")) (((min-colors 256)) (:foreground "color-186" :background "color-24")) (t (:foreground "yellow" :background "brightblack"))))
+ '(diff-header ((((min-colors 16777216)) (:foreground "#8B8B8B" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-245" :background "color-236")) (t (:foreground "brightblack" :background "black"))))
+ '(diff-file-header ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#2D2D2D" :weight bold)) (((min-colors 256)) (:foreground "color-252" :background "color-236" :weight bold)) (t (:foreground "white" :background "black" :weight bold))))
+ '(magit-section-heading ((((min-colors 16777216)) (:foreground "#4FC1FF" :weight bold)) (((min-colors 256)) (:foreground "color-75" :weight bold)) (t (:foreground "cyan" :weight bold))))
+ '(magit-section-highlight ((((min-colors 16777216)) (:background "#2A2A2A")) (((min-colors 256)) (:background "color-235")) (t (:background "black"))))
+ '(magit-branch-local ((((min-colors 16777216)) (:foreground "#74B7FF")) (((min-colors 256)) (:foreground "color-111")) (t (:foreground "cyan"))))
+ '(magit-branch-remote ((((min-colors 16777216)) (:foreground "#A3BE8C")) (((min-colors 256)) (:foreground "color-144")) (t (:foreground "cyan"))))
+ '(magit-branch-current ((((min-colors 16777216)) (:foreground "#74B7FF" :weight bold)) (((min-colors 256)) (:foreground "color-111" :weight bold)) (t (:foreground "cyan" :weight bold))))
+ '(magit-hash ((((min-colors 16777216)) (:foreground "#8B8B8B")) (((min-colors 256)) (:foreground "color-245")) (t (:foreground "brightblack"))))
+ '(magit-tag ((((min-colors 16777216)) (:foreground "#4FC1FF")) (((min-colors 256)) (:foreground "color-75")) (t (:foreground "cyan"))))
+ '(magit-dimmed ((((min-colors 16777216)) (:foreground "#8B8B8B")) (((min-colors 256)) (:foreground "color-245")) (t (:foreground "brightblack"))))
+ '(magit-diff-added ((((min-colors 16777216)) (:foreground "#A3BE8C" :background "#3A5A3A")) (((min-colors 256)) (:foreground "color-144" :background "color-23")) (t (:foreground "cyan" :background "brightblack"))))
+ '(magit-diff-added-highlight ((((min-colors 16777216)) (:foreground "#A3BE8C" :background "#3A5A3A")) (((min-colors 256)) (:foreground "color-144" :background "color-23")) (t (:foreground "cyan" :background "brightblack"))))
+ '(magit-diff-removed ((((min-colors 16777216)) (:foreground "#BF616A" :background "#5A3A3A")) (((min-colors 256)) (:foreground "color-131" :background "color-238")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(magit-diff-removed-highlight ((((min-colors 16777216)) (:foreground "#BF616A" :background "#5A3A3A")) (((min-colors 256)) (:foreground "color-131" :background "color-238")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(magit-diff-context ((((min-colors 16777216)) (:foreground "#8B8B8B")) (((min-colors 256)) (:foreground "color-245")) (t (:foreground "brightblack"))))
+ '(magit-diff-context-highlight ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#2A2A2A")) (((min-colors 256)) (:foreground "color-252" :background "color-235")) (t (:foreground "white" :background "black"))))
+ '(magit-diff-hunk-heading ((((min-colors 16777216)) (:foreground "#8B8B8B" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-245" :background "color-236")) (t (:foreground "brightblack" :background "black"))))
+ '(magit-diff-hunk-heading-highlight ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#264F78")) (((min-colors 256)) (:foreground "color-252" :background "color-24")) (t (:foreground "white" :background "brightblack"))))
+ '(magit-diff-file-heading ((((min-colors 16777216)) (:weight bold)) (((min-colors 256)) (:weight bold)) (t (:weight bold))))
+ '(magit-diffstat-added ((((min-colors 16777216)) (:foreground "#A3BE8C")) (((min-colors 256)) (:foreground "color-144")) (t (:foreground "cyan"))))
+ '(magit-diffstat-removed ((((min-colors 16777216)) (:foreground "#BF616A")) (((min-colors 256)) (:foreground "color-131")) (t (:foreground "brightblack"))))
+ '(magit-log-author ((((min-colors 16777216)) (:foreground "#4FC1FF")) (((min-colors 256)) (:foreground "color-75")) (t (:foreground "cyan"))))
+ '(magit-log-date ((((min-colors 16777216)) (:foreground "#8B8B8B")) (((min-colors 256)) (:foreground "color-245")) (t (:foreground "brightblack"))))
+ '(magit-process-ok ((((min-colors 16777216)) (:foreground "#90A959" :weight bold)) (((min-colors 256)) (:foreground "color-107" :weight bold)) (t (:foreground "yellow" :weight bold))))
+ '(magit-process-ng ((((min-colors 16777216)) (:foreground "#F48771" :weight bold)) (((min-colors 256)) (:foreground "color-209" :weight bold)) (t (:foreground "brightred" :weight bold))))
+ '(company-tooltip ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-252" :background "color-236")) (t (:foreground "white" :background "black"))))
+ '(company-tooltip-selection ((((min-colors 16777216)) (:background "#264F78")) (((min-colors 256)) (:background "color-24")) (t (:background "brightblack"))))
+ '(company-tooltip-common ((((min-colors 16777216)) (:foreground "#4FC1FF" :weight bold)) (((min-colors 256)) (:foreground "color-75" :weight bold)) (t (:foreground "cyan" :weight bold))))
+ '(company-tooltip-annotation ((((min-colors 16777216)) (:foreground "#8B8B8B")) (((min-colors 256)) (:foreground "color-245")) (t (:foreground "brightblack"))))
+ '(company-scrollbar-bg ((((min-colors 16777216)) (:background "#2A2A2A")) (((min-colors 256)) (:background "color-235")) (t (:background "black"))))
+ '(company-scrollbar-fg ((((min-colors 16777216)) (:background "#5A5A5A")) (((min-colors 256)) (:background "color-240")) (t (:background "brightblack"))))
+ '(company-tooltip-scrollbar-track ((((min-colors 16777216)) (:background "#2A2A2A")) (((min-colors 256)) (:background "color-235")) (t (:background "black"))))
+ '(company-tooltip-scrollbar-thumb ((((min-colors 16777216)) (:background "#5A5A5A")) (((min-colors 256)) (:background "color-240")) (t (:background "brightblack"))))
+ '(company-preview ((((min-colors 16777216)) (:foreground "#B78CF2")) (((min-colors 256)) (:foreground "color-141")) (t (:foreground "brightblue"))))
+ '(company-preview-common ((((min-colors 16777216)) (:foreground "#B78CF2")) (((min-colors 256)) (:foreground "color-141")) (t (:foreground "brightblue"))))
+ '(corfu-default ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-252" :background "color-236")) (t (:foreground "white" :background "black"))))
+ '(corfu-current ((((min-colors 16777216)) (:foreground "#CCCCCC" :background "#264F78")) (((min-colors 256)) (:foreground "color-252" :background "color-24")) (t (:foreground "white" :background "brightblack"))))
+ '(corfu-border ((((min-colors 16777216)) (:background "#3A3A3A")) (((min-colors 256)) (:background "color-237")) (t (:background "brightblack"))))
+ '(corfu-bar ((((min-colors 16777216)) (:background "#5A5A5A")) (((min-colors 256)) (:background "color-240")) (t (:background "brightblack"))))
+ '(corfu-annotations ((((min-colors 16777216)) (:foreground "#8B8B8B")) (((min-colors 256)) (:foreground "color-245")) (t (:foreground "brightblack"))))
+ '(corfu-deprecated ((((min-colors 16777216)) (:foreground "#8B8B8B" :strike-through t)) (((min-colors 256)) (:foreground "color-245" :strike-through t)) (t (:foreground "brightblack" :strike-through t))))
+ '(font-lock-comment-face ((((min-colors 16777216)) (:foreground "#6A9955" :slant italic)) (((min-colors 256)) (:foreground "color-71" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(font-lock-comment-delimiter-face ((((min-colors 16777216)) (:foreground "#6A9955" :slant italic)) (((min-colors 256)) (:foreground "color-71" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(font-lock-doc-face ((((min-colors 16777216)) (:foreground "#6A9955" :slant italic)) (((min-colors 256)) (:foreground "color-71" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(font-lock-doc-markup-face ((((min-colors 16777216)) (:foreground "#6A9955" :slant italic)) (((min-colors 256)) (:foreground "color-71" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(font-lock-string-face ((((min-colors 16777216)) (:foreground "#CE9178")) (((min-colors 256)) (:foreground "color-173")) (t (:foreground "brightblack"))))
+ '(font-lock-escape-face ((((min-colors 16777216)) (:foreground "#D7BA7D")) (((min-colors 256)) (:foreground "color-180")) (t (:foreground "yellow"))))
+ '(font-lock-regexp-face ((((min-colors 16777216)) (:foreground "#D16969")) (((min-colors 256)) (:foreground "color-167")) (t (:foreground "brightred"))))
+ '(font-lock-regexp-grouping-backslash ((((min-colors 16777216)) (:foreground "#D7BA7D")) (((min-colors 256)) (:foreground "color-180")) (t (:foreground "yellow"))))
+ '(font-lock-regexp-grouping-construct ((((min-colors 16777216)) (:foreground "#D7BA7D")) (((min-colors 256)) (:foreground "color-180")) (t (:foreground "yellow"))))
+ '(font-lock-number-face ((((min-colors 16777216)) (:foreground "#B5CEA8")) (((min-colors 256)) (:foreground "color-151")) (t (:foreground "white"))))
+ '(font-lock-constant-face ((((min-colors 16777216)) (:foreground "#4FC1FF")) (((min-colors 256)) (:foreground "color-75")) (t (:foreground "cyan"))))
+ '(font-lock-builtin-face ((((min-colors 16777216)) (:foreground "#FF7B72")) (((min-colors 256)) (:foreground "color-210")) (t (:foreground "brightred"))))
+ '(font-lock-keyword-face ((((min-colors 16777216)) (:foreground "#569CD6" :weight bold)) (((min-colors 256)) (:foreground "color-74" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(font-lock-operator-face ((((min-colors 16777216)) (:foreground "#79C0FF")) (((min-colors 256)) (:foreground "color-111")) (t (:foreground "cyan"))))
+ '(font-lock-negation-char-face ((((min-colors 16777216)) (:foreground "#79C0FF")) (((min-colors 256)) (:foreground "color-111")) (t (:foreground "cyan"))))
+ '(font-lock-function-name-face ((((min-colors 16777216)) (:foreground "#DCDCAA")) (((min-colors 256)) (:foreground "color-187")) (t (:foreground "white"))))
+ '(font-lock-function-call-face ((((min-colors 16777216)) (:foreground "#DCDCAA")) (((min-colors 256)) (:foreground "color-187")) (t (:foreground "white"))))
+ '(font-lock-type-face ((((min-colors 16777216)) (:foreground "#4EC9B0")) (((min-colors 256)) (:foreground "color-43")) (t (:foreground "cyan"))))
+ '(font-lock-variable-name-face ((((min-colors 16777216)) (:foreground "#9CDCFE")) (((min-colors 256)) (:foreground "color-153")) (t (:foreground "white"))))
+ '(font-lock-variable-use-face ((((min-colors 16777216)) (:foreground "#9CDCFE")) (((min-colors 256)) (:foreground "color-153")) (t (:foreground "white"))))
+ '(font-lock-property-name-face ((((min-colors 16777216)) (:foreground "#9CDCFE")) (((min-colors 256)) (:foreground "color-153")) (t (:foreground "white"))))
+ '(font-lock-property-use-face ((((min-colors 16777216)) (:foreground "#9CDCFE")) (((min-colors 256)) (:foreground "color-153")) (t (:foreground "white"))))
+ '(font-lock-preprocessor-face ((((min-colors 16777216)) (:foreground "#CCCCCC")) (((min-colors 256)) (:foreground "color-252")) (t (:foreground "white"))))
+ '(font-lock-punctuation-face ((((min-colors 16777216)) (:foreground "#CCCCCC")) (((min-colors 256)) (:foreground "color-252")) (t (:foreground "white"))))
+ '(font-lock-bracket-face ((((min-colors 16777216)) (:foreground "#CCCCCC")) (((min-colors 256)) (:foreground "color-252")) (t (:foreground "white"))))
+ '(font-lock-delimiter-face ((((min-colors 16777216)) (:foreground "#CCCCCC")) (((min-colors 256)) (:foreground "color-252")) (t (:foreground "white"))))
+ '(font-lock-misc-punctuation-face ((((min-colors 16777216)) (:foreground "#F85149")) (((min-colors 256)) (:foreground "color-203")) (t (:foreground "brightred"))))
+ '(org-document-title ((((min-colors 16777216)) (:foreground "#C586C0" :weight bold)) (((min-colors 256)) (:foreground "color-175" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-1 ((((min-colors 16777216)) (:foreground "#C586C0" :weight bold)) (((min-colors 256)) (:foreground "color-175" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-2 ((((min-colors 16777216)) (:foreground "#C586C0" :weight bold)) (((min-colors 256)) (:foreground "color-175" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-3 ((((min-colors 16777216)) (:foreground "#C586C0" :weight bold)) (((min-colors 256)) (:foreground "color-175" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-4 ((((min-colors 16777216)) (:foreground "#C586C0" :weight bold)) (((min-colors 256)) (:foreground "color-175" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-5 ((((min-colors 16777216)) (:foreground "#C586C0" :weight bold)) (((min-colors 256)) (:foreground "color-175" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-6 ((((min-colors 16777216)) (:foreground "#C586C0" :weight bold)) (((min-colors 256)) (:foreground "color-175" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-7 ((((min-colors 16777216)) (:foreground "#C586C0" :weight bold)) (((min-colors 256)) (:foreground "color-175" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-level-8 ((((min-colors 16777216)) (:foreground "#C586C0" :weight bold)) (((min-colors 256)) (:foreground "color-175" :weight bold)) (t (:foreground "brightblack" :weight bold))))
+ '(org-code ((((min-colors 16777216)) (:foreground "#CE9178")) (((min-colors 256)) (:foreground "color-173")) (t (:foreground "brightblack"))))
+ '(org-verbatim ((((min-colors 16777216)) (:foreground "#CE9178")) (((min-colors 256)) (:foreground "color-173")) (t (:foreground "brightblack"))))
+ '(org-list-dt ((((min-colors 16777216)) (:foreground "#FF6B9D")) (((min-colors 256)) (:foreground "color-204")) (t (:foreground "brightred"))))
+ '(org-meta-line ((((min-colors 16777216)) (:foreground "#6A9955" :slant italic)) (((min-colors 256)) (:foreground "color-71" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(org-drawer ((((min-colors 16777216)) (:foreground "#6A9955" :slant italic)) (((min-colors 256)) (:foreground "color-71" :slant italic)) (t (:foreground "brightblack" :slant italic))))
+ '(org-tag ((((min-colors 16777216)) (:foreground "#4FC1FF")) (((min-colors 256)) (:foreground "color-75")) (t (:foreground "cyan"))))
+ '(bold ((((min-colors 16777216)) (:foreground "#74B7FF" :weight bold)) (((min-colors 256)) (:foreground "color-111" :weight bold)) (t (:foreground "cyan" :weight bold))))
+ '(italic ((((min-colors 16777216)) (:foreground "#4FC1FF")) (((min-colors 256)) (:foreground "color-75")) (t (:foreground "cyan"))))
+ '(ansi-color-black ((((min-colors 16777216)) (:foreground "#2D2D2D" :background "#2D2D2D")) (((min-colors 256)) (:foreground "color-236" :background "color-236")) (t (:foreground "black" :background "black"))))
+ '(ansi-color-red ((((min-colors 16777216)) (:foreground "#F48771" :background "#F48771")) (((min-colors 256)) (:foreground "color-209" :background "color-209")) (t (:foreground "brightred" :background "brightred"))))
+ '(ansi-color-green ((((min-colors 16777216)) (:foreground "#90A959" :background "#90A959")) (((min-colors 256)) (:foreground "color-107" :background "color-107")) (t (:foreground "yellow" :background "yellow"))))
+ '(ansi-color-yellow ((((min-colors 16777216)) (:foreground "#F4D03F" :background "#F4D03F")) (((min-colors 256)) (:foreground "color-221" :background "color-221")) (t (:foreground "yellow" :background "yellow"))))
+ '(ansi-color-blue ((((min-colors 16777216)) (:foreground "#4FC1FF" :background "#4FC1FF")) (((min-colors 256)) (:foreground "color-75" :background "color-75")) (t (:foreground "cyan" :background "cyan"))))
+ '(ansi-color-magenta ((((min-colors 16777216)) (:foreground "#B78CF2" :background "#B78CF2")) (((min-colors 256)) (:foreground "color-141" :background "color-141")) (t (:foreground "brightblue" :background "brightblue"))))
+ '(ansi-color-cyan ((((min-colors 16777216)) (:foreground "#7FDBCA" :background "#7FDBCA")) (((min-colors 256)) (:foreground "color-116" :background "color-116")) (t (:foreground "cyan" :background "cyan"))))
+ '(ansi-color-white ((((min-colors 16777216)) (:foreground "#FFFFFF" :background "#FFFFFF")) (((min-colors 256)) (:foreground "color-231" :background "color-231")) (t (:foreground "brightwhite" :background "brightwhite"))))
+ '(ansi-color-bright-black ((((min-colors 16777216)) (:foreground "#6B6B6B" :background "#6B6B6B")) (((min-colors 256)) (:foreground "color-242" :background "color-242")) (t (:foreground "brightblack" :background "brightblack"))))
+ '(ansi-color-bright-red ((((min-colors 16777216)) (:foreground "#FF6B6B" :background "#FF6B6B")) (((min-colors 256)) (:foreground "color-203" :background "color-203")) (t (:foreground "brightred" :background "brightred"))))
+ '(ansi-color-bright-green ((((min-colors 16777216)) (:foreground "#A8D08D" :background "#A8D08D")) (((min-colors 256)) (:foreground "color-150" :background "color-150")) (t (:foreground "yellow" :background "yellow"))))
+ '(ansi-color-bright-yellow ((((min-colors 16777216)) (:foreground "#FFE66D" :background "#FFE66D")) (((min-colors 256)) (:foreground "color-221" :background "color-221")) (t (:foreground "brightyellow" :background "brightyellow"))))
+ '(ansi-color-bright-blue ((((min-colors 16777216)) (:foreground "#7DC8FF" :background "#7DC8FF")) (((min-colors 256)) (:foreground "color-117" :background "color-117")) (t (:foreground "cyan" :background "cyan"))))
+ '(ansi-color-bright-magenta ((((min-colors 16777216)) (:foreground "#C9A8FF" :background "#C9A8FF")) (((min-colors 256)) (:foreground "color-183" :background "color-183")) (t (:foreground "white" :background "white"))))
+ '(ansi-color-bright-cyan ((((min-colors 16777216)) (:foreground "#9FFFE0" :background "#9FFFE0")) (((min-colors 256)) (:foreground "color-158" :background "color-158")) (t (:foreground "brightcyan" :background "brightcyan"))))
+ '(ansi-color-bright-white ((((min-colors 16777216)) (:foreground "#FFFFFF" :background "#FFFFFF")) (((min-colors 256)) (:foreground "color-231" :background "color-231")) (t (:foreground "brightwhite" :background "brightwhite")))))
+
+;;;###autoload
+(when load-file-name
+  (add-to-list 'custom-theme-load-path
+               (file-name-as-directory (file-name-directory load-file-name))))
+
+(provide-theme 'zero-trust)
+
+;;; zero-trust-theme.el ends here
@@ -0,0 +1,12 @@
+# Zero Trust Dark 0.1.0
+# Author: Yannick Boog
+# Homepage: https://github.com/yannickboog/zero-trust-theme
+# License: MIT
+# Generated by zero-trust from theme.toml; do not edit.
+
+case "${COLORTERM:-}" in
+    truecolor | 24bit) zero_trust_fzf='--color=fg:#CCCCCC,bg:#1E1E1E,hl:#4FC1FF,fg+:#CCCCCC,bg+:#264F78,hl+:#4FC1FF,selected-bg:#4A4A4A,info:#8B8B8B,prompt:#4FC1FF,pointer:#4FC1FF,marker:#90A959,spinner:#4FC1FF,header:#6A9955,query:#CCCCCC,disabled:#4A4A4A,border:#3A3A3A,label:#8B8B8B,gutter:#1E1E1E,scrollbar:#5A5A5A,separator:#3A3A3A,preview-fg:#CCCCCC,preview-bg:#1E1E1E' ;;
+    *) zero_trust_fzf='--color=fg:252,bg:234,hl:75,fg+:252,bg+:24,hl+:75,selected-bg:239,info:245,prompt:75,pointer:75,marker:107,spinner:75,header:71,query:252,disabled:239,border:237,label:245,gutter:234,scrollbar:240,separator:237,preview-fg:252,preview-bg:234' ;;
+esac
+export FZF_DEFAULT_OPTS="${FZF_DEFAULT_OPTS:+$FZF_DEFAULT_OPTS }$zero_trust_fzf"
+unset zero_trust_fzf
@@ -0,0 +1,12 @@
+# Zero Trust Light 0.1.0
+# Author: Yannick Boog
+# Homepage: https://github.com/yannickboog/zero-trust-theme
+# License: MIT
+# Generated by zero-trust from theme.toml; do not edit.
+
+case "${COLORTERM:-}" in
+    truecolor | 24bit) zero_trust_fzf='--color=fg:#2E2E2E,bg:#FAFAFA,hl:#006C99,fg+:#2E2E2E,bg+:#9CC9F9,hl+:#006C99,selected-bg:#BABABA,info:#6D6D6D,prompt:#006C99,pointer:#006C99,marker:#6B8232,spinner:#006C99,header:#527F3C,query:#2E2E2E,disabled:#BABABA,border:#D0D0D0,label:#6D6D6D,gutter:#FAFAFA,scrollbar:#A6A6A6,separator:#D0D0D0,preview-fg:#2E2E2E,preview-bg:#FAFAFA' ;;
+    *) zero_trust_fzf='--color=fg:236,bg:231,hl:24,fg+:236,bg+:117,hl+:24,selected-bg:250,info:242,prompt:24,pointer:24,marker:64,spinner:24,header:65,query:236,disabled:250,border:252,label:242,gutter:231,scrollbar:248,separator:252,preview-fg:236,preview-bg:231' ;;
+esac
+export FZF_DEFAULT_OPTS="${FZF_DEFAULT_OPTS:+$FZF_DEFAULT_OPTS }$zero_trust_fzf"
+unset zero_trust_fzf
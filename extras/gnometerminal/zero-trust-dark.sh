@@ -0,0 +1,31 @@
+#!/bin/sh
+# Zero Trust Dark 0.1.0
+# Author: Yannick Boog
+# Homepage: https://github.com/yannickboog/zero-trust-theme
+# License: MIT
+# Generated by zero-trust from theme.toml; do not edit.
+
+set -eu
+
+uuid=8fc6d9a3-af53-8f14-8347-5937b82c61c9
+profile="org.gnome.Terminal.Legacy.Profile:/org/gnome/terminal/legacy/profiles:/:$uuid/"
+list=$(gsettings get org.gnome.Terminal.ProfilesList list)
+case "$list" in
+    *"$uuid"*) ;;
+    "@as []") gsettings set org.gnome.Terminal.ProfilesList list "['$uuid']" ;;
+    *) gsettings set org.gnome.Terminal.ProfilesList list "${list%]}, '$uuid']" ;;
+esac
+
+gsettings set "$profile" visible-name "'Zero Trust Dark'"
+gsettings set "$profile" use-theme-colors false
+gsettings set "$profile" bold-color-same-as-fg false
+gsettings set "$profile" cursor-colors-set true
+gsettings set "$profile" highlight-colors-set true
+gsettings set "$profile" background-color "'#1E1E1E'"
+gsettings set "$profile" foreground-color "'#CCCCCC'"
+gsettings set "$profile" bold-color "'#FFFFFF'"
+gsettings set "$profile" cursor-background-color "'#4FC1FF'"
+gsettings set "$profile" cursor-foreground-color "'#1E1E1E'"
+gsettings set "$profile" highlight-background-color "'#1A4A6B'"
+gsettings set "$profile" highlight-foreground-color "'#CCCCCC'"
+gsettings set "$profile" palette "['#2D2D2D', '#F48771', '#90A959', '#F4D03F', '#4FC1FF', '#B78CF2', '#7FDBCA', '#FFFFFF', '#6B6B6B', '#FF6B6B', '#A8D08D', '#FFE66D', '#7DC8FF', '#C9A8FF', '#9FFFE0', '#FFFFFF']"
@@ -0,0 +1,31 @@
+#!/bin/sh
+# Zero Trust Light 0.1.0
+# Author: Yannick Boog
+# Homepage: https://github.com/yannickboog/zero-trust-theme
+# License: MIT
+# Generated by zero-trust from theme.toml; do not edit.
+
+set -eu
+
+uuid=45a56332-7cd1-84b1-9d10-107defda1f95
+profile="org.gnome.Terminal.Legacy.Profile:/org/gnome/terminal/legacy/profiles:/:$uuid/"
+list=$(gsettings get org.gnome.Terminal.ProfilesList list)
+case "$list" in
+    *"$uuid"*) ;;
+    "@as []") gsettings set org.gnome.Terminal.ProfilesList list "['$uuid']" ;;
+    *) gsettings set org.gnome.Terminal.ProfilesList list "${list%]}, '$uuid']" ;;
+esac
+
+gsettings set "$profile" visible-name "'Zero Trust Light'"
+gsettings set "$profile" use-theme-colors false
+gsettings set "$profile" bold-color-same-as-fg false
+gsettings set "$profile" cursor-colors-set true
+gsettings set "$profile" highlight-colors-set true
+gsettings set "$profile" background-color "'#FAFAFA'"
+gsettings set "$profile" foreground-color "'#2E2E2E'"
+gsettings set "$profile" bold-color "'#070707'"
+gsettings set "$profile" cursor-background-color "'#006C99'"
+gsettings set "$profile" cursor-foreground-color "'#FAFAFA'"
+gsettings set "$profile" highlight-background-color "'#9FD1F9'"
+gsettings set "$profile" highlight-foreground-color "'#2E2E2E'"
+gsettings set "$profile" palette "['#070707', '#AE4835', '#6B8232', '#5C4C00', '#006C99', '#8257B8', '#006458', '#E3E3E3', '#070707', '#C8373E', '#426526', '#4A3F00', '#096193', '#6C4B9A', '#004838', '#919191']"
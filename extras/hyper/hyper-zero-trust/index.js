@@ -0,0 +1,70 @@
+/*
+ * Zero Trust Theme 0.1.0
+ * Author: Yannick Boog
+ * Homepage: https://github.com/yannickboog/zero-trust-theme
+ * License: MIT
+ * Generated by zero-trust from theme.toml; do not edit.
+ */
+
+const themes = {
+  "dark": {
+    "foregroundColor": "#CCCCCC",
+    "backgroundColor": "#1E1E1E",
+    "borderColor": "#3A3A3A",
+    "cursorColor": "#4FC1FF",
+    "cursorAccentColor": "#1E1E1E",
+    "selectionColor": "#1A4A6B",
+    "colors": {
+      "black": "#2D2D2D",
+      "red": "#F48771",
+      "green": "#90A959",
+      "yellow": "#F4D03F",
+      "blue": "#4FC1FF",
+      "magenta": "#B78CF2",
+      "cyan": "#7FDBCA",
+      "white": "#FFFFFF",
+      "lightBlack": "#6B6B6B",
+      "lightRed": "#FF6B6B",
+      "lightGreen": "#A8D08D",
+      "lightYellow": "#FFE66D",
+      "lightBlue": "#7DC8FF",
+      "lightMagenta": "#C9A8FF",
+      "lightCyan": "#9FFFE0",
+      "lightWhite": "#FFFFFF"
+    }
+  },
+  "light": {
+    "foregroundColor": "#2E2E2E",
+    "backgroundColor": "#FAFAFA",
+    "borderColor": "#D0D0D0",
+    "cursorColor": "#006C99",
+    "cursorAccentColor": "#FAFAFA",
+    "selectionColor": "#9FD1F9",
+    "colors": {
+      "black": "#070707",
+      "red": "#AE4835",
+      "green": "#6B8232",
+      "yellow": "#5C4C00",
+      "blue": "#006C99",
+      "magenta": "#8257B8",
+      "cyan": "#006458",
+      "white": "#E3E3E3",
+      "lightBlack": "#070707",
+      "lightRed": "#C8373E",
+      "lightGreen": "#426526",
+      "lightYellow": "#4A3F00",
+      "lightBlue": "#096193",
+      "lightMagenta": "#6C4B9A",
+      "lightCyan": "#004838",
+      "lightWhite": "#919191"
+    }
+  }
+};
+
+exports.decorateConfig = (config) => {
+  const variant = (config.zeroTrust && config.zeroTrust.variant) || "dark";
+  const theme = themes[variant] || themes[Object.keys(themes)[0]];
+  return Object.assign({}, config, theme, {
+    colors: Object.assign({}, config.colors, theme.colors),
+  });
+};
@@ -0,0 +1 @@
+require("core").reload_module("colors.zero_trust_dark")
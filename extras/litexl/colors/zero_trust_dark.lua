@@ -0,0 +1,45 @@
+-- Zero Trust Dark 0.1.0
+-- Author: Yannick Boog
+-- Homepage: https://github.com/yannickboog/zero-trust-theme
+-- License: MIT
+-- Generated by zero-trust from theme.toml; do not edit.
+
+local style = require "core.style"
+local common = require "core.common"
+
+style.background = { common.color "#1E1E1E" }
+style.background2 = { common.color "#2D2D2D" }
+style.background3 = { common.color "#2D2D2D" }
+style.text = { common.color "#CCCCCC" }
+style.caret = { common.color "#4FC1FF" }
+style.accent = { common.color "#4FC1FF" }
+style.dim = { common.color "#8B8B8B" }
+style.divider = { common.color "#404040" }
+style.selection = { common.color "#1A4A6B" }
+style.line_number = { common.color "#6B6B6B" }
+style.line_number2 = { common.color "#FFFFFF" }
+style.line_highlight = { common.color "#2A2A2A" }
+style.scrollbar = { common.color "#5A5A5A" }
+style.scrollbar2 = { common.color "#6A6A6A" }
+style.scrollbar_track = { common.color "#2A2A2A" }
+style.nagbar = { common.color "#F48771" }
+style.nagbar_text = { common.color "#1E1E1E" }
+style.nagbar_dim = { common.color "#1E1E1E" }
+style.drag_overlay = { common.color "#1A4A6B" }
+style.drag_overlay_tab = { common.color "#4FC1FF" }
+style.good = { common.color "#90A959" }
+style.warn = { common.color "#F4D03F" }
+style.error = { common.color "#F48771" }
+style.modified = { common.color "#EBCB8B" }
+style.guide = { common.color "#404040" }
+
+style.syntax["normal"] = { common.color "#CCCCCC" }
+style.syntax["symbol"] = { common.color "#9CDCFE" }
+style.syntax["comment"] = { common.color "#6A9955" }
+style.syntax["keyword"] = { common.color "#569CD6" }
+style.syntax["keyword2"] = { common.color "#4EC9B0" }
+style.syntax["number"] = { common.color "#B5CEA8" }
+style.syntax["literal"] = { common.color "#4FC1FF" }
+style.syntax["string"] = { common.color "#CE9178" }
+style.syntax["operator"] = { common.color "#79C0FF" }
+style.syntax["function"] = { common.color "#DCDCAA" }
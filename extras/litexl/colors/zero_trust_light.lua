@@ -0,0 +1,45 @@
+-- Zero Trust Light 0.1.0
+-- Author: Yannick Boog
+-- Homepage: https://github.com/yannickboog/zero-trust-theme
+-- License: MIT
+-- Generated by zero-trust from theme.toml; do not edit.
+
+local style = require "core.style"
+local common = require "core.common"
+
+style.background = { common.color "#FAFAFA" }
+style.background2 = { common.color "#E3E3E3" }
+style.background3 = { common.color "#E3E3E3" }
+style.text = { common.color "#2E2E2E" }
+style.caret = { common.color "#006C99" }
+style.accent = { common.color "#006C99" }
+style.dim = { common.color "#6D6D6D" }
+style.divider = { common.color "#C8C8C8" }
+style.selection = { common.color "#9FD1F9" }
+style.line_number = { common.color "#919191" }
+style.line_number2 = { common.color "#070707" }
+style.line_highlight = { common.color "#E7E7E7" }
+style.scrollbar = { common.color "#A6A6A6" }
+style.scrollbar2 = { common.color "#929292" }
+style.scrollbar_track = { common.color "#E7E7E7" }
+style.nagbar = { common.color "#AE4835" }
+style.nagbar_text = { common.color "#FAFAFA" }
+style.nagbar_dim = { common.color "#FAFAFA" }
+style.drag_overlay = { common.color "#9FD1F9" }
+style.drag_overlay_tab = { common.color "#006C99" }
+style.good = { common.color "#6B8232" }
+style.warn = { common.color "#5C4C00" }
+style.error = { common.color "#AE4835" }
+style.modified = { common.color "#664A00" }
+style.guide = { common.color "#C8C8C8" }
+
+style.syntax["normal"] = { common.color "#2E2E2E" }
+style.syntax["symbol"] = { common.color "#0E5471" }
+style.syntax["comment"] = { common.color "#527F3C" }
+style.syntax["keyword"] = { common.color "#3178B0" }
+style.syntax["keyword2"] = { common.color "#007866" }
+style.syntax["number"] = { common.color "#3D5231" }
+style.syntax["literal"] = { common.color "#006C99" }
+style.syntax["string"] = { common.color "#9D634C" }
+style.syntax["operator"] = { common.color "#19659E" }
+style.syntax["function"] = { common.color "#434213" }
@@ -0,0 +1,250 @@
+-- Zero Trust Dark 0.1.0
+-- Author: Yannick Boog
+-- Homepage: https://github.com/yannickboog/zero-trust-theme
+-- License: MIT
+-- Generated by zero-trust from theme.toml; do not edit.
+
+vim.cmd("highlight clear")
+if vim.fn.exists("syntax_on") == 1 then
+  vim.cmd("syntax reset")
+end
+vim.o.background = "dark"
+vim.g.colors_name = "zero-trust-dark"
+
+local hl = vim.api.nvim_set_hl
+
+hl(0, "Normal", { fg = "#CCCCCC", bg = "#1E1E1E" })
+hl(0, "NormalFloat", { fg = "#CCCCCC", bg = "#2D2D2D" })
+hl(0, "FloatBorder", { fg = "#3A3A3A", bg = "#2D2D2D" })
+hl(0, "Cursor", { fg = "#1E1E1E", bg = "#4FC1FF" })
+hl(0, "CursorLine", { bg = "#2A2A2A" })
+hl(0, "CursorColumn", { bg = "#2A2A2A" })
+hl(0, "ColorColumn", { bg = "#2A2A2A" })
+hl(0, "LineNr", { fg = "#6B6B6B", bg = "#252525" })
+hl(0, "CursorLineNr", { fg = "#FFFFFF", bg = "#252525", bold = true })
+hl(0, "SignColumn", { bg = "#252525" })
+hl(0, "FoldColumn", { fg = "#6B6B6B", bg = "#252525" })
+hl(0, "Folded", { fg = "#8B8B8B", bg = "#2A2A2A" })
+hl(0, "VertSplit", { fg = "#404040" })
+hl(0, "WinSeparator", { fg = "#404040" })
+hl(0, "StatusLine", { fg = "#8B8B8B", bg = "#2D2D2D" })
+hl(0, "StatusLineNC", { fg = "#6B6B6B", bg = "#2D2D2D" })
+hl(0, "TabLine", { fg = "#8B8B8B", bg = "#2D2D2D" })
+hl(0, "TabLineSel", { fg = "#CCCCCC", bg = "#1E1E1E" })
+hl(0, "TabLineFill", { bg = "#2D2D2D" })
+hl(0, "Visual", { bg = "#1A4A6B" })
+hl(0, "Search", { bg = "#515C6A" })
+hl(0, "IncSearch", { fg = "#1E1E1E", bg = "#4FC1FF" })
+hl(0, "CurSearch", { fg = "#1E1E1E", bg = "#4FC1FF" })
+hl(0, "MatchParen", { bg = "#515C6A", bold = true })
+hl(0, "Pmenu", { fg = "#CCCCCC", bg = "#2D2D2D" })
+hl(0, "PmenuSel", { bg = "#264F78" })
+hl(0, "PmenuSbar", { bg = "#2A2A2A" })
+hl(0, "PmenuThumb", { bg = "#5A5A5A" })
+hl(0, "WildMenu", { bg = "#264F78" })
+hl(0, "QuickFixLine", { bg = "#264F78" })
+hl(0, "NonText", { fg = "#404040" })
+hl(0, "Whitespace", { fg = "#404040" })
+hl(0, "SpecialKey", { fg = "#404040" })
+hl(0, "EndOfBuffer", { fg = "#1E1E1E" })
+hl(0, "Directory", { fg = "#74B7FF" })
+hl(0, "Conceal", { fg = "#8B8B8B" })
+hl(0, "ErrorMsg", { fg = "#F48771" })
+hl(0, "WarningMsg", { fg = "#F4D03F" })
+hl(0, "MoreMsg", { fg = "#90A959" })
+hl(0, "Question", { fg = "#4FC1FF" })
+hl(0, "ModeMsg", { fg = "#CCCCCC", bold = true })
+hl(0, "DiffAdd", { bg = "#3A5A3A" })
+hl(0, "DiffChange", { bg = "#5A5A2A" })
+hl(0, "DiffDelete", { fg = "#BF616A", bg = "#5A3A3A" })
+hl(0, "DiffText", { bg = "#1A4A6B" })
+hl(0, "SpellBad", { sp = "#F48771", undercurl = true })
+hl(0, "SpellCap", { sp = "#F4D03F", undercurl = true })
+hl(0, "SpellLocal", { sp = "#4FC1FF", undercurl = true })
+hl(0, "SpellRare", { sp = "#8B8B8B", undercurl = true })
+hl(0, "Underlined", { fg = "#74B7FF", underline = true })
+hl(0, "Todo", { fg = "#F4D03F", bold = true })
+hl(0, "Error", { fg = "#F48771" })
+hl(0, "Comment", { fg = "#6A9955", italic = true })
+hl(0, "SpecialComment", { fg = "#6A9955", italic = true })
+hl(0, "Constant", { fg = "#4FC1FF" })
+hl(0, "String", { fg = "#CE9178" })
+hl(0, "Character", { fg = "#CE9178" })
+hl(0, "SpecialChar", { fg = "#D7BA7D" })
+hl(0, "Number", { fg = "#B5CEA8" })
+hl(0, "Float", { fg = "#B5CEA8" })
+hl(0, "Boolean", { fg = "#569CD6" })
+hl(0, "Identifier", { fg = "#9CDCFE" })
+hl(0, "Function", { fg = "#DCDCAA" })
+hl(0, "Statement", { fg = "#569CD6", bold = true })
+hl(0, "Conditional", { fg = "#569CD6", bold = true })
+hl(0, "Repeat", { fg = "#569CD6", bold = true })
+hl(0, "Exception", { fg = "#569CD6", bold = true })
+hl(0, "Keyword", { fg = "#569CD6", bold = true })
+hl(0, "StorageClass", { fg = "#569CD6", bold = true })
+hl(0, "Label", { fg = "#4FC1FF" })
+hl(0, "Operator", { fg = "#79C0FF" })
+hl(0, "PreProc", { fg = "#CCCCCC" })
+hl(0, "Include", { fg = "#CCCCCC" })
+hl(0, "Define", { fg = "#CCCCCC" })
+hl(0, "Macro", { fg = "#DCDCAA" })
+hl(0, "PreCondit", { fg = "#CCCCCC" })
+hl(0, "Type", { fg = "#4EC9B0" })
+hl(0, "Structure", { fg = "#4EC9B0" })
+hl(0, "Typedef", { fg = "#4EC9B0" })
+hl(0, "Special", { fg = "#F85149" })
+hl(0, "Tag", { fg = "#569CD6" })
+hl(0, "Delimiter", { fg = "#CCCCCC" })
+hl(0, "Title", { fg = "#C586C0", bold = true })
+hl(0, "@comment", { fg = "#6A9955", italic = true })
+hl(0, "@comment.documentation", { fg = "#6A9955", italic = true })
+hl(0, "@string", { fg = "#CE9178" })
+hl(0, "@string.escape", { fg = "#D7BA7D" })
+hl(0, "@string.regexp", { fg = "#D16969" })
+hl(0, "@string.special", { fg = "#C586C0" })
+hl(0, "@string.special.symbol", { fg = "#4FC1FF" })
+hl(0, "@string.special.url", { fg = "#CE9178" })
+hl(0, "@character", { fg = "#CE9178" })
+hl(0, "@character.special", { fg = "#D7BA7D" })
+hl(0, "@number", { fg = "#B5CEA8" })
+hl(0, "@number.float", { fg = "#B5CEA8" })
+hl(0, "@boolean", { fg = "#569CD6" })
+hl(0, "@constant", { fg = "#4FC1FF" })
+hl(0, "@constant.builtin", { fg = "#4FC1FF" })
+hl(0, "@constant.macro", { fg = "#4FC1FF" })
+hl(0, "@constructor", { fg = "#DCDCAA" })
+hl(0, "@type", { fg = "#4EC9B0" })
+hl(0, "@type.builtin", { fg = "#4EC9B0" })
+hl(0, "@type.definition", { fg = "#4EC9B0" })
+hl(0, "@attribute", { fg = "#C586C0" })
+hl(0, "@property", { fg = "#9CDCFE" })
+hl(0, "@variable", { fg = "#9CDCFE" })
+hl(0, "@variable.builtin", { fg = "#FF7B72" })
+hl(0, "@variable.parameter", { fg = "#9CDCFE" })
+hl(0, "@variable.member", { fg = "#9CDCFE" })
+hl(0, "@function", { fg = "#DCDCAA" })
+hl(0, "@function.builtin", { fg = "#DCDCAA" })
+hl(0, "@function.call", { fg = "#DCDCAA" })
+hl(0, "@function.macro", { fg = "#DCDCAA" })
+hl(0, "@function.method", { fg = "#DCDCAA" })
+hl(0, "@keyword", { fg = "#569CD6", bold = true })
+hl(0, "@keyword.operator", { fg = "#79C0FF" })
+hl(0, "@keyword.directive", { fg = "#CCCCCC" })
+hl(0, "@operator", { fg = "#79C0FF" })
+hl(0, "@punctuation", { fg = "#CCCCCC" })
+hl(0, "@punctuation.delimiter", { fg = "#CCCCCC" })
+hl(0, "@punctuation.bracket", { fg = "#CCCCCC" })
+hl(0, "@punctuation.special", { fg = "#F85149" })
+hl(0, "@tag", { fg = "#569CD6" })
+hl(0, "@tag.attribute", { fg = "#C586C0" })
+hl(0, "@tag.delimiter", { fg = "#CCCCCC" })
+hl(0, "@label", { fg = "#4FC1FF" })
+hl(0, "@module", { fg = "#CCCCCC" })
+hl(0, "@markup.heading", { fg = "#C586C0", bold = true })
+hl(0, "@markup.strong", { fg = "#74B7FF", bold = true })
+hl(0, "@markup.italic", { fg = "#4FC1FF" })
+hl(0, "@markup.link", { fg = "#CE9178" })
+hl(0, "@markup.link.url", { fg = "#CE9178" })
+hl(0, "@markup.raw", { fg = "#CE9178" })
+hl(0, "@markup.list", { fg = "#FF6B9D" })
+hl(0, "@markup.quote", { fg = "#6A9955", italic = true })
+hl(0, "@diff.plus", { fg = "#A3BE8C" })
+hl(0, "@diff.minus", { fg = "#BF616A" })
+hl(0, "@diff.delta", { fg = "#EBCB8B" })
+hl(0, "@lsp.type.namespace", { fg = "#CCCCCC" })
+hl(0, "@lsp.type.type", { fg = "#4EC9B0" })
+hl(0, "@lsp.type.class", { fg = "#4EC9B0" })
+hl(0, "@lsp.type.struct", { fg = "#4EC9B0" })
+hl(0, "@lsp.type.interface", { fg = "#4EC9B0" })
+hl(0, "@lsp.type.typeParameter", { fg = "#4EC9B0" })
+hl(0, "@lsp.type.enum", { fg = "#FF6B9D" })
+hl(0, "@lsp.type.enumMember", { fg = "#4FC1FF" })
+hl(0, "@lsp.type.function", { fg = "#DCDCAA" })
+hl(0, "@lsp.type.method", { fg = "#DCDCAA" })
+hl(0, "@lsp.type.macro", { fg = "#DCDCAA" })
+hl(0, "@lsp.type.decorator", { fg = "#C586C0" })
+hl(0, "@lsp.type.parameter", { fg = "#9CDCFE" })
+hl(0, "@lsp.type.variable", { fg = "#9CDCFE" })
+hl(0, "@lsp.type.property", { fg = "#9CDCFE" })
+hl(0, "@lsp.type.lifetime", { fg = "#4FC1FF" })
+hl(0, "@lsp.type.selfKeyword", { fg = "#FF7B72" })
+hl(0, "@lsp.type.comment", { fg = "#6A9955", italic = true })
+hl(0, "@lsp.type.string", { fg = "#CE9178" })
+hl(0, "@lsp.type.keyword", { fg = "#569CD6", bold = true })
+hl(0, "@lsp.type.number", { fg = "#B5CEA8" })
+hl(0, "@lsp.type.operator", { fg = "#79C0FF" })
+hl(0, "@lsp.mod.mutable", { underline = true })
+hl(0, "@lsp.mod.async", { italic = true })
+hl(0, "@lsp.mod.deprecated", { strikethrough = true })
+hl(0, "@lsp.mod.unsafe", { fg = "#FF7B72", bold = true })
+hl(0, "DiagnosticError", { fg = "#F48771" })
+hl(0, "DiagnosticWarn", { fg = "#F4D03F" })
+hl(0, "DiagnosticInfo", { fg = "#4FC1FF" })
+hl(0, "DiagnosticHint", { fg = "#8B8B8B" })
+hl(0, "DiagnosticOk", { fg = "#90A959" })
+hl(0, "DiagnosticUnderlineError", { sp = "#F48771", undercurl = true })
+hl(0, "DiagnosticUnderlineWarn", { sp = "#F4D03F", undercurl = true })
+hl(0, "DiagnosticUnderlineInfo", { sp = "#4FC1FF", undercurl = true })
+hl(0, "DiagnosticUnderlineHint", { sp = "#8B8B8B", undercurl = true })
+hl(0, "DiagnosticVirtualTextError", { fg = "#F48771", bg = "#5A3A3A" })
+hl(0, "DiagnosticVirtualTextWarn", { fg = "#F4D03F", bg = "#5A5A2A" })
+hl(0, "DiagnosticVirtualTextInfo", { fg = "#4FC1FF", bg = "#1A4A6B" })
+hl(0, "DiagnosticVirtualTextHint", { fg = "#8B8B8B", bg = "#2A2A2A" })
+hl(0, "DiagnosticUnnecessary", { fg = "#8B8B8B" })
+hl(0, "LspReferenceText", { bg = "#515C6A" })
+hl(0, "LspReferenceRead", { bg = "#515C6A" })
+hl(0, "LspReferenceWrite", { bg = "#515C6A" })
+hl(0, "LspInlayHint", { fg = "#8B8B8B", bg = "#2A2A2A" })
+hl(0, "LspSignatureActiveParameter", { bg = "#515C6A", bold = true })
+hl(0, "GitSignsAdd", { fg = "#A3BE8C" })
+hl(0, "GitSignsChange", { fg = "#EBCB8B" })
+hl(0, "GitSignsDelete", { fg = "#BF616A" })
+hl(0, "TelescopeNormal", { link = "NormalFloat" })
+hl(0, "TelescopeBorder", { link = "FloatBorder" })
+hl(0, "TelescopeTitle", { fg = "#CCCCCC", bold = true })
+hl(0, "TelescopeSelection", { bg = "#264F78" })
+hl(0, "TelescopeMatching", { fg = "#4FC1FF", bold = true })
+hl(0, "TelescopePromptPrefix", { fg = "#4FC1FF" })
+hl(0, "CmpItemAbbr", { fg = "#CCCCCC" })
+hl(0, "CmpItemAbbrMatch", { fg = "#4FC1FF", bold = true })
+hl(0, "CmpItemAbbrMatchFuzzy", { fg = "#4FC1FF" })
+hl(0, "CmpItemAbbrDeprecated", { fg = "#4A4A4A", strikethrough = true })
+hl(0, "CmpItemMenu", { fg = "#8B8B8B" })
+hl(0, "CmpItemKind", { fg = "#8B8B8B" })
+hl(0, "CmpItemKindFunction", { link = "@function" })
+hl(0, "CmpItemKindMethod", { link = "@function.method" })
+hl(0, "CmpItemKindVariable", { link = "@variable" })
+hl(0, "CmpItemKindField", { link = "@property" })
+hl(0, "CmpItemKindProperty", { link = "@property" })
+hl(0, "CmpItemKindKeyword", { link = "@keyword" })
+hl(0, "CmpItemKindClass", { link = "@type" })
+hl(0, "CmpItemKindStruct", { link = "@type" })
+hl(0, "CmpItemKindInterface", { link = "@type" })
+hl(0, "CmpItemKindEnum", { link = "@lsp.type.enum" })
+hl(0, "CmpItemKindEnumMember", { link = "@lsp.type.enumMember" })
+hl(0, "CmpItemKindModule", { link = "@module" })
+hl(0, "CmpItemKindConstant", { link = "@constant" })
+hl(0, "CmpItemKindSnippet", { fg = "#B78CF2" })
+hl(0, "ZeroTrustRainbow1", { fg = "#CCA449" })
+hl(0, "ZeroTrustRainbow2", { fg = "#78BE7B" })
+hl(0, "ZeroTrustRainbow3", { fg = "#29BFCD" })
+hl(0, "ZeroTrustRainbow4", { fg = "#86A8F5" })
+hl(0, "ZeroTrustRainbow5", { fg = "#D08FD3" })
+hl(0, "ZeroTrustRainbow6", { fg = "#EB8B84" })
+
+vim.g.terminal_color_0 = "#2D2D2D"
+vim.g.terminal_color_1 = "#F48771"
+vim.g.terminal_color_2 = "#90A959"
+vim.g.terminal_color_3 = "#F4D03F"
+vim.g.terminal_color_4 = "#4FC1FF"
+vim.g.terminal_color_5 = "#B78CF2"
+vim.g.terminal_color_6 = "#7FDBCA"
+vim.g.terminal_color_7 = "#FFFFFF"
+vim.g.terminal_color_8 = "#6B6B6B"
+vim.g.terminal_color_9 = "#FF6B6B"
+vim.g.terminal_color_10 = "#A8D08D"
+vim.g.terminal_color_11 = "#FFE66D"
+vim.g.terminal_color_12 = "#7DC8FF"
+vim.g.terminal_color_13 = "#C9A8FF"
+vim.g.terminal_color_14 = "#9FFFE0"
+vim.g.terminal_color_15 = "#FFFFFF"
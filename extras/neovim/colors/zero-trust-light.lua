@@ -0,0 +1,250 @@
+-- Zero Trust Light 0.1.0
+-- Author: Yannick Boog
+-- Homepage: https://github.com/yannickboog/zero-trust-theme
+-- License: MIT
+-- Generated by zero-trust from theme.toml; do not edit.
+
+vim.cmd("highlight clear")
+if vim.fn.exists("syntax_on") == 1 then
+  vim.cmd("syntax reset")
+end
+vim.o.background = "light"
+vim.g.colors_name = "zero-trust-light"
+
+local hl = vim.api.nvim_set_hl
+
+hl(0, "Normal", { fg = "#2E2E2E", bg = "#FAFAFA" })
+hl(0, "NormalFloat", { fg = "#2E2E2E", bg = "#E3E3E3" })
+hl(0, "FloatBorder", { fg = "#D0D0D0", bg = "#E3E3E3" })
+hl(0, "Cursor", { fg = "#FAFAFA", bg = "#006C99" })
+hl(0, "CursorLine", { bg = "#E7E7E7" })
+hl(0, "CursorColumn", { bg = "#E7E7E7" })
+hl(0, "ColorColumn", { bg = "#E7E7E7" })
+hl(0, "LineNr", { fg = "#919191", bg = "#EFEFEF" })
+hl(0, "CursorLineNr", { fg = "#070707", bg = "#EFEFEF", bold = true })
+hl(0, "SignColumn", { bg = "#EFEFEF" })
+hl(0, "FoldColumn", { fg = "#919191", bg = "#EFEFEF" })
+hl(0, "Folded", { fg = "#6D6D6D", bg = "#E7E7E7" })
+hl(0, "VertSplit", { fg = "#C8C8C8" })
+hl(0, "WinSeparator", { fg = "#C8C8C8" })
+hl(0, "StatusLine", { fg = "#6D6D6D", bg = "#E3E3E3" })
+hl(0, "StatusLineNC", { fg = "#919191", bg = "#E3E3E3" })
+hl(0, "TabLine", { fg = "#6D6D6D", bg = "#E3E3E3" })
+hl(0, "TabLineSel", { fg = "#2E2E2E", bg = "#FAFAFA" })
+hl(0, "TabLineFill", { bg = "#E3E3E3" })
+hl(0, "Visual", { bg = "#9FD1F9" })
+hl(0, "Search", { bg = "#9CA8B7" })
+hl(0, "IncSearch", { fg = "#FAFAFA", bg = "#006C99" })
+hl(0, "CurSearch", { fg = "#FAFAFA", bg = "#006C99" })
+hl(0, "MatchParen", { bg = "#9CA8B7", bold = true })
+hl(0, "Pmenu", { fg = "#2E2E2E", bg = "#E3E3E3" })
+hl(0, "PmenuSel", { bg = "#9CC9F9" })
+hl(0, "PmenuSbar", { bg = "#E7E7E7" })
+hl(0, "PmenuThumb", { bg = "#A6A6A6" })
+hl(0, "WildMenu", { bg = "#9CC9F9" })
+hl(0, "QuickFixLine", { bg = "#9CC9F9" })
+hl(0, "NonText", { fg = "#C8C8C8" })
+hl(0, "Whitespace", { fg = "#C8C8C8" })
+hl(0, "SpecialKey", { fg = "#C8C8C8" })
+hl(0, "EndOfBuffer", { fg = "#FAFAFA" })
+hl(0, "Directory", { fg = "#2568AA" })
+hl(0, "Conceal", { fg = "#6D6D6D" })
+hl(0, "ErrorMsg", { fg = "#AE4835" })
+hl(0, "WarningMsg", { fg = "#5C4C00" })
+hl(0, "MoreMsg", { fg = "#6B8232" })
+hl(0, "Question", { fg = "#006C99" })
+hl(0, "ModeMsg", { fg = "#2E2E2E", bold = true })
+hl(0, "DiffAdd", { bg = "#A4C8A3" })
+hl(0, "DiffChange", { bg = "#B9BB87" })
+hl(0, "DiffDelete", { fg = "#CE6F77", bg = "#E4BCBB" })
+hl(0, "DiffText", { bg = "#9FD1F9" })
+hl(0, "SpellBad", { sp = "#AE4835", undercurl = true })
+hl(0, "SpellCap", { sp = "#5C4C00", undercurl = true })
+hl(0, "SpellLocal", { sp = "#006C99", undercurl = true })
+hl(0, "SpellRare", { sp = "#6D6D6D", undercurl = true })
+hl(0, "Underlined", { fg = "#2568AA", underline = true })
+hl(0, "Todo", { fg = "#5C4C00", bold = true })
+hl(0, "Error", { fg = "#AE4835" })
+hl(0, "Comment", { fg = "#527F3C", italic = true })
+hl(0, "SpecialComment", { fg = "#527F3C", italic = true })
+hl(0, "Constant", { fg = "#006C99" })
+hl(0, "String", { fg = "#9D634C" })
+hl(0, "Character", { fg = "#9D634C" })
+hl(0, "SpecialChar", { fg = "#73591A" })
+hl(0, "Number", { fg = "#3D5231" })
+hl(0, "Float", { fg = "#3D5231" })
+hl(0, "Boolean", { fg = "#3178B0" })
+hl(0, "Identifier", { fg = "#0E5471" })
+hl(0, "Function", { fg = "#434213" })
+hl(0, "Statement", { fg = "#3178B0", bold = true })
+hl(0, "Conditional", { fg = "#3178B0", bold = true })
+hl(0, "Repeat", { fg = "#3178B0", bold = true })
+hl(0, "Exception", { fg = "#3178B0", bold = true })
+hl(0, "Keyword", { fg = "#3178B0", bold = true })
+hl(0, "StorageClass", { fg = "#3178B0", bold = true })
+hl(0, "Label", { fg = "#006C99" })
+hl(0, "Operator", { fg = "#19659E" })
+hl(0, "PreProc", { fg = "#2E2E2E" })
+hl(0, "Include", { fg = "#2E2E2E" })
+hl(0, "Define", { fg = "#2E2E2E" })
+hl(0, "Macro", { fg = "#434213" })
+hl(0, "PreCondit", { fg = "#2E2E2E" })
+hl(0, "Type", { fg = "#007866" })
+hl(0, "Structure", { fg = "#007866" })
+hl(0, "Typedef", { fg = "#007866" })
+hl(0, "Special", { fg = "#DA3130" })
+hl(0, "Tag", { fg = "#3178B0" })
+hl(0, "Delimiter", { fg = "#2E2E2E" })
+hl(0, "Title", { fg = "#995D95", bold = true })
+hl(0, "@comment", { fg = "#527F3C", italic = true })
+hl(0, "@comment.documentation", { fg = "#527F3C", italic = true })
+hl(0, "@string", { fg = "#9D634C" })
+hl(0, "@string.escape", { fg = "#73591A" })
+hl(0, "@string.regexp", { fg = "#B95455" })
+hl(0, "@string.special", { fg = "#995D95" })
+hl(0, "@string.special.symbol", { fg = "#006C99" })
+hl(0, "@string.special.url", { fg = "#9D634C" })
+hl(0, "@character", { fg = "#9D634C" })
+hl(0, "@character.special", { fg = "#73591A" })
+hl(0, "@number", { fg = "#3D5231" })
+hl(0, "@number.float", { fg = "#3D5231" })
+hl(0, "@boolean", { fg = "#3178B0" })
+hl(0, "@constant", { fg = "#006C99" })
+hl(0, "@constant.builtin", { fg = "#006C99" })
+hl(0, "@constant.macro", { fg = "#006C99" })
+hl(0, "@constructor", { fg = "#434213" })
+hl(0, "@type", { fg = "#007866" })
+hl(0, "@type.builtin", { fg = "#007866" })
+hl(0, "@type.definition", { fg = "#007866" })
+hl(0, "@attribute", { fg = "#995D95" })
+hl(0, "@property", { fg = "#0E5471" })
+hl(0, "@variable", { fg = "#0E5471" })
+hl(0, "@variable.builtin", { fg = "#B93C38" })
+hl(0, "@variable.parameter", { fg = "#0E5471" })
+hl(0, "@variable.member", { fg = "#0E5471" })
+hl(0, "@function", { fg = "#434213" })
+hl(0, "@function.builtin", { fg = "#434213" })
+hl(0, "@function.call", { fg = "#434213" })
+hl(0, "@function.macro", { fg = "#434213" })
+hl(0, "@function.method", { fg = "#434213" })
+hl(0, "@keyword", { fg = "#3178B0", bold = true })
+hl(0, "@keyword.operator", { fg = "#19659E" })
+hl(0, "@keyword.directive", { fg = "#2E2E2E" })
+hl(0, "@operator", { fg = "#19659E" })
+hl(0, "@punctuation", { fg = "#2E2E2E" })
+hl(0, "@punctuation.delimiter", { fg = "#2E2E2E" })
+hl(0, "@punctuation.bracket", { fg = "#2E2E2E" })
+hl(0, "@punctuation.special", { fg = "#DA3130" })
+hl(0, "@tag", { fg = "#3178B0" })
+hl(0, "@tag.attribute", { fg = "#995D95" })
+hl(0, "@tag.delimiter", { fg = "#2E2E2E" })
+hl(0, "@label", { fg = "#006C99" })
+hl(0, "@module", { fg = "#2E2E2E" })
+hl(0, "@markup.heading", { fg = "#995D95", bold = true })
+hl(0, "@markup.strong", { fg = "#2568AA", bold = true })
+hl(0, "@markup.italic", { fg = "#006C99" })
+hl(0, "@markup.link", { fg = "#9D634C" })
+hl(0, "@markup.link.url", { fg = "#9D634C" })
+hl(0, "@markup.raw", { fg = "#9D634C" })
+hl(0, "@markup.list", { fg = "#C02F68" })
+hl(0, "@markup.quote", { fg = "#527F3C", italic = true })
+hl(0, "@diff.plus", { fg = "#536B3D" })
+hl(0, "@diff.minus", { fg = "#CE6F77" })
+hl(0, "@diff.delta", { fg = "#664A00" })
+hl(0, "@lsp.type.namespace", { fg = "#2E2E2E" })
+hl(0, "@lsp.type.type", { fg = "#007866" })
+hl(0, "@lsp.type.class", { fg = "#007866" })
+hl(0, "@lsp.type.struct", { fg = "#007866" })
+hl(0, "@lsp.type.interface", { fg = "#007866" })
+hl(0, "@lsp.type.typeParameter", { fg = "#007866" })
+hl(0, "@lsp.type.enum", { fg = "#C02F68" })
+hl(0, "@lsp.type.enumMember", { fg = "#006C99" })
+hl(0, "@lsp.type.function", { fg = "#434213" })
+hl(0, "@lsp.type.method", { fg = "#434213" })
+hl(0, "@lsp.type.macro", { fg = "#434213" })
+hl(0, "@lsp.type.decorator", { fg = "#995D95" })
+hl(0, "@lsp.type.parameter", { fg = "#0E5471" })
+hl(0, "@lsp.type.variable", { fg = "#0E5471" })
+hl(0, "@lsp.type.property", { fg = "#0E5471" })
+hl(0, "@lsp.type.lifetime", { fg = "#006C99" })
+hl(0, "@lsp.type.selfKeyword", { fg = "#B93C38" })
+hl(0, "@lsp.type.comment", { fg = "#527F3C", italic = true })
+hl(0, "@lsp.type.string", { fg = "#9D634C" })
+hl(0, "@lsp.type.keyword", { fg = "#3178B0", bold = true })
+hl(0, "@lsp.type.number", { fg = "#3D5231" })
+hl(0, "@lsp.type.operator", { fg = "#19659E" })
+hl(0, "@lsp.mod.mutable", { underline = true })
+hl(0, "@lsp.mod.async", { italic = true })
+hl(0, "@lsp.mod.deprecated", { strikethrough = true })
+hl(0, "@lsp.mod.unsafe", { fg = "#B93C38", bold = true })
+hl(0, "DiagnosticError", { fg = "#AE4835" })
+hl(0, "DiagnosticWarn", { fg = "#5C4C00" })
+hl(0, "DiagnosticInfo", { fg = "#006C99" })
+hl(0, "DiagnosticHint", { fg = "#6D6D6D" })
+hl(0, "DiagnosticOk", { fg = "#6B8232" })
+hl(0, "DiagnosticUnderlineError", { sp = "#AE4835", undercurl = true })
+hl(0, "DiagnosticUnderlineWarn", { sp = "#5C4C00", undercurl = true })
+hl(0, "DiagnosticUnderlineInfo", { sp = "#006C99", undercurl = true })
+hl(0, "DiagnosticUnderlineHint", { sp = "#6D6D6D", undercurl = true })
+hl(0, "DiagnosticVirtualTextError", { fg = "#AE4835", bg = "#E4BCBB" })
+hl(0, "DiagnosticVirtualTextWarn", { fg = "#5C4C00", bg = "#B9BB87" })
+hl(0, "DiagnosticVirtualTextInfo", { fg = "#006C99", bg = "#9FD1F9" })
+hl(0, "DiagnosticVirtualTextHint", { fg = "#6D6D6D", bg = "#E7E7E7" })
+hl(0, "DiagnosticUnnecessary", { fg = "#6D6D6D" })
+hl(0, "LspReferenceText", { bg = "#9CA8B7" })
+hl(0, "LspReferenceRead", { bg = "#9CA8B7" })
+hl(0, "LspReferenceWrite", { bg = "#9CA8B7" })
+hl(0, "LspInlayHint", { fg = "#6D6D6D", bg = "#E7E7E7" })
+hl(0, "LspSignatureActiveParameter", { bg = "#9CA8B7", bold = true })
+hl(0, "GitSignsAdd", { fg = "#536B3D" })
+hl(0, "GitSignsChange", { fg = "#664A00" })
+hl(0, "GitSignsDelete", { fg = "#CE6F77" })
+hl(0, "TelescopeNormal", { link = "NormalFloat" })
+hl(0, "TelescopeBorder", { link = "FloatBorder" })
+hl(0, "TelescopeTitle", { fg = "#2E2E2E", bold = true })
+hl(0, "TelescopeSelection", { bg = "#9CC9F9" })
+hl(0, "TelescopeMatching", { fg = "#006C99", bold = true })
+hl(0, "TelescopePromptPrefix", { fg = "#006C99" })
+hl(0, "CmpItemAbbr", { fg = "#2E2E2E" })
+hl(0, "CmpItemAbbrMatch", { fg = "#006C99", bold = true })
+hl(0, "CmpItemAbbrMatchFuzzy", { fg = "#006C99" })
+hl(0, "CmpItemAbbrDeprecated", { fg = "#BABABA", strikethrough = true })
+hl(0, "CmpItemMenu", { fg = "#6D6D6D" })
+hl(0, "CmpItemKind", { fg = "#6D6D6D" })
+hl(0, "CmpItemKindFunction", { link = "@function" })
+hl(0, "CmpItemKindMethod", { link = "@function.method" })
+hl(0, "CmpItemKindVariable", { link = "@variable" })
+hl(0, "CmpItemKindField", { link = "@property" })
+hl(0, "CmpItemKindProperty", { link = "@property" })
+hl(0, "CmpItemKindKeyword", { link = "@keyword" })
+hl(0, "CmpItemKindClass", { link = "@type" })
+hl(0, "CmpItemKindStruct", { link = "@type" })
+hl(0, "CmpItemKindInterface", { link = "@type" })
+hl(0, "CmpItemKindEnum", { link = "@lsp.type.enum" })
+hl(0, "CmpItemKindEnumMember", { link = "@lsp.type.enumMember" })
+hl(0, "CmpItemKindModule", { link = "@module" })
+hl(0, "CmpItemKindConstant", { link = "@constant" })
+hl(0, "CmpItemKindSnippet", { fg = "#8257B8" })
+hl(0, "ZeroTrustRainbow1", { fg = "#836200" })
+hl(0, "ZeroTrustRainbow2", { fg = "#36793C" })
+hl(0, "ZeroTrustRainbow3", { fg = "#007680" })
+hl(0, "ZeroTrustRainbow4", { fg = "#4864A9" })
+hl(0, "ZeroTrustRainbow5", { fg = "#884E8B" })
+hl(0, "ZeroTrustRainbow6", { fg = "#9F4A45" })
+
+vim.g.terminal_color_0 = "#070707"
+vim.g.terminal_color_1 = "#AE4835"
+vim.g.terminal_color_2 = "#6B8232"
+vim.g.terminal_color_3 = "#5C4C00"
+vim.g.terminal_color_4 = "#006C99"
+vim.g.terminal_color_5 = "#8257B8"
+vim.g.terminal_color_6 = "#006458"
+vim.g.terminal_color_7 = "#E3E3E3"
+vim.g.terminal_color_8 = "#070707"
+vim.g.terminal_color_9 = "#C8373E"
+vim.g.terminal_color_10 = "#426526"
+vim.g.terminal_color_11 = "#4A3F00"
+vim.g.terminal_color_12 = "#096193"
+vim.g.terminal_color_13 = "#6C4B9A"
+vim.g.terminal_color_14 = "#004838"
+vim.g.terminal_color_15 = "#919191"
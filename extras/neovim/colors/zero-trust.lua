@@ -0,0 +1,2 @@
+-- Follows 'background': zero-trust-dark or zero-trust-light.
+vim.cmd.colorscheme(vim.o.background == "light" and "zero-trust-light" or "zero-trust-dark")
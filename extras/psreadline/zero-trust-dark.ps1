@@ -0,0 +1,29 @@
+# Zero Trust Dark 0.1.0
+# Author: Yannick Boog
+# Homepage: https://github.com/yannickboog/zero-trust-theme
+# License: MIT
+# Generated by zero-trust from theme.toml; do not edit.
+
+$esc = [char]27
+Set-PSReadLineOption -Colors @{
+    Default = "${esc}[38;2;204;204;204m"
+    Command = "${esc}[38;2;220;220;170m"
+    Comment = "${esc}[38;2;106;153;85m"
+    ContinuationPrompt = "${esc}[38;2;139;139;139m"
+    Emphasis = "${esc}[38;2;79;193;255m"
+    Error = "${esc}[38;2;244;135;113m"
+    InlinePrediction = "${esc}[38;2;183;140;242m"
+    Keyword = "${esc}[38;2;86;156;214m"
+    ListPrediction = "${esc}[38;2;79;193;255m"
+    ListPredictionSelected = "${esc}[48;2;38;79;120m"
+    ListPredictionTooltip = "${esc}[38;2;139;139;139m"
+    Member = "${esc}[38;2;156;220;254m"
+    Number = "${esc}[38;2;181;206;168m"
+    Operator = "${esc}[38;2;121;192;255m"
+    Parameter = "${esc}[38;2;197;134;192m"
+    Selection = "${esc}[48;2;26;74;107m"
+    String = "${esc}[38;2;206;145;120m"
+    Type = "${esc}[38;2;78;201;176m"
+    Variable = "${esc}[38;2;156;220;254m"
+}
+Remove-Variable esc
@@ -0,0 +1,29 @@
+# Zero Trust Light 0.1.0
+# Author: Yannick Boog
+# Homepage: https://github.com/yannickboog/zero-trust-theme
+# License: MIT
+# Generated by zero-trust from theme.toml; do not edit.
+
+$esc = [char]27
+Set-PSReadLineOption -Colors @{
+    Default = "${esc}[38;2;46;46;46m"
+    Command = "${esc}[38;2;67;66;19m"
+    Comment = "${esc}[38;2;82;127;60m"
+    ContinuationPrompt = "${esc}[38;2;109;109;109m"
+    Emphasis = "${esc}[38;2;0;108;153m"
+    Error = "${esc}[38;2;174;72;53m"
+    InlinePrediction = "${esc}[38;2;130;87;184m"
+    Keyword = "${esc}[38;2;49;120;176m"
+    ListPrediction = "${esc}[38;2;0;108;153m"
+    ListPredictionSelected = "${esc}[48;2;156;201;249m"
+    ListPredictionTooltip = "${esc}[38;2;109;109;109m"
+    Member = "${esc}[38;2;14;84;113m"
+    Number = "${esc}[38;2;61;82;49m"
+    Operator = "${esc}[38;2;25;101;158m"
+    Parameter = "${esc}[38;2;153;93;149m"
+    Selection = "${esc}[48;2;159;209;249m"
+    String = "${esc}[38;2;157;99;76m"
+    Type = "${esc}[38;2;0;120;102m"
+    Variable = "${esc}[38;2;14;84;113m"
+}
+Remove-Variable esc
@@ -0,0 +1,47 @@
+/*
+ * Zero Trust Dark 0.1.0
+ * Author: Yannick Boog
+ * Homepage: https://github.com/yannickboog/zero-trust-theme
+ * License: MIT
+ * Generated by zero-trust from theme.toml; do not edit.
+ */
+
+/* Terminal colors (16 first used in escape sequence) */
+static const char *colorname[] = {
+	/* 8 normal colors */
+	"#2D2D2D", /* 0: normal black, 256-color 236 */
+	"#F48771", /* 1: normal red, 256-color 209 */
+	"#90A959", /* 2: normal green, 256-color 107 */
+	"#F4D03F", /* 3: normal yellow, 256-color 221 */
+	"#4FC1FF", /* 4: normal blue, 256-color 75 */
+	"#B78CF2", /* 5: normal magenta, 256-color 141 */
+	"#7FDBCA", /* 6: normal cyan, 256-color 116 */
+	"#FFFFFF", /* 7: normal white, 256-color 231 */
+
+	/* 8 bright colors */
+	"#6B6B6B", /* 8: bright black, 256-color 242 */
+	"#FF6B6B", /* 9: bright red, 256-color 203 */
+	"#A8D08D", /* 10: bright green, 256-color 150 */
+	"#FFE66D", /* 11: bright yellow, 256-color 221 */
+	"#7DC8FF", /* 12: bright blue, 256-color 117 */
+	"#C9A8FF", /* 13: bright magenta, 256-color 183 */
+	"#9FFFE0", /* 14: bright cyan, 256-color 158 */
+	"#FFFFFF", /* 15: bright white, 256-color 231 */
+
+	[255] = 0,
+
+	/* more colors can be added after 255 to use with DefaultXX */
+	"#4FC1FF", /* 256: cursor, 256-color 75 */
+	"#1E1E1E", /* 257: reverse cursor, 256-color 234 */
+	"#CCCCCC", /* 258: foreground, 256-color 252 */
+	"#1E1E1E", /* 259: background, 256-color 234 */
+};
+
+/*
+ * Default colors (colorname index)
+ * foreground, background, cursor, reverse cursor
+ */
+unsigned int defaultfg = 258;
+unsigned int defaultbg = 259;
+unsigned int defaultcs = 256;
+static unsigned int defaultrcs = 257;
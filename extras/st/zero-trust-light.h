@@ -0,0 +1,47 @@
+/*
+ * Zero Trust Light 0.1.0
+ * Author: Yannick Boog
+ * Homepage: https://github.com/yannickboog/zero-trust-theme
+ * License: MIT
+ * Generated by zero-trust from theme.toml; do not edit.
+ */
+
+/* Terminal colors (16 first used in escape sequence) */
+static const char *colorname[] = {
+	/* 8 normal colors */
+	"#070707", /* 0: normal black, 256-color 232 */
+	"#AE4835", /* 1: normal red, 256-color 131 */
+	"#6B8232", /* 2: normal green, 256-color 64 */
+	"#5C4C00", /* 3: normal yellow, 256-color 58 */
+	"#006C99", /* 4: normal blue, 256-color 24 */
+	"#8257B8", /* 5: normal magenta, 256-color 97 */
+	"#006458", /* 6: normal cyan, 256-color 23 */
+	"#E3E3E3", /* 7: normal white, 256-color 254 */
+
+	/* 8 bright colors */
+	"#070707", /* 8: bright black, 256-color 232 */
+	"#C8373E", /* 9: bright red, 256-color 160 */
+	"#426526", /* 10: bright green, 256-color 58 */
+	"#4A3F00", /* 11: bright yellow, 256-color 238 */
+	"#096193", /* 12: bright blue, 256-color 24 */
+	"#6C4B9A", /* 13: bright magenta, 256-color 61 */
+	"#004838", /* 14: bright cyan, 256-color 237 */
+	"#919191", /* 15: bright white, 256-color 246 */
+
+	[255] = 0,
+
+	/* more colors can be added after 255 to use with DefaultXX */
+	"#006C99", /* 256: cursor, 256-color 24 */
+	"#FAFAFA", /* 257: reverse cursor, 256-color 231 */
+	"#2E2E2E", /* 258: foreground, 256-color 236 */
+	"#FAFAFA", /* 259: background, 256-color 231 */
+};
+
+/*
+ * Default colors (colorname index)
+ * foreground, background, cursor, reverse cursor
+ */
+unsigned int defaultfg = 258;
+unsigned int defaultbg = 259;
+unsigned int defaultcs = 256;
+static unsigned int defaultrcs = 257;
@@ -0,0 +1,98 @@
+" Zero Trust Dark 0.1.0
+" Author: Yannick Boog
+" Homepage: https://github.com/yannickboog/zero-trust-theme
+" License: MIT
+" Generated by zero-trust from theme.toml; do not edit.
+
+set background=dark
+hi clear
+if exists("syntax_on")
+  syntax reset
+endif
+let g:colors_name = "zero-trust-dark"
+
+hi Normal guifg=#CCCCCC ctermfg=252 guibg=#1E1E1E ctermbg=234 gui=NONE cterm=NONE
+hi NormalFloat guifg=#CCCCCC ctermfg=252 guibg=#2D2D2D ctermbg=236 gui=NONE cterm=NONE
+hi FloatBorder guifg=#3A3A3A ctermfg=237 guibg=#2D2D2D ctermbg=236 gui=NONE cterm=NONE
+hi Cursor guifg=#1E1E1E ctermfg=234 guibg=#4FC1FF ctermbg=75 gui=NONE cterm=NONE
+hi CursorLine guibg=#2A2A2A ctermbg=235 gui=NONE cterm=NONE
+hi CursorColumn guibg=#2A2A2A ctermbg=235 gui=NONE cterm=NONE
+hi ColorColumn guibg=#2A2A2A ctermbg=235 gui=NONE cterm=NONE
+hi LineNr guifg=#6B6B6B ctermfg=242 guibg=#252525 ctermbg=235 gui=NONE cterm=NONE
+hi CursorLineNr guifg=#FFFFFF ctermfg=231 guibg=#252525 ctermbg=235 gui=bold cterm=bold
+hi SignColumn guibg=#252525 ctermbg=235 gui=NONE cterm=NONE
+hi FoldColumn guifg=#6B6B6B ctermfg=242 guibg=#252525 ctermbg=235 gui=NONE cterm=NONE
+hi Folded guifg=#8B8B8B ctermfg=245 guibg=#2A2A2A ctermbg=235 gui=NONE cterm=NONE
+hi VertSplit guifg=#404040 ctermfg=238 gui=NONE cterm=NONE
+hi WinSeparator guifg=#404040 ctermfg=238 gui=NONE cterm=NONE
+hi StatusLine guifg=#8B8B8B ctermfg=245 guibg=#2D2D2D ctermbg=236 gui=NONE cterm=NONE
+hi StatusLineNC guifg=#6B6B6B ctermfg=242 guibg=#2D2D2D ctermbg=236 gui=NONE cterm=NONE
+hi TabLine guifg=#8B8B8B ctermfg=245 guibg=#2D2D2D ctermbg=236 gui=NONE cterm=NONE
+hi TabLineSel guifg=#CCCCCC ctermfg=252 guibg=#1E1E1E ctermbg=234 gui=NONE cterm=NONE
+hi TabLineFill guibg=#2D2D2D ctermbg=236 gui=NONE cterm=NONE
+hi Visual guibg=#1A4A6B ctermbg=24 gui=NONE cterm=NONE
+hi Search guibg=#515C6A ctermbg=240 gui=NONE cterm=NONE
+hi IncSearch guifg=#1E1E1E ctermfg=234 guibg=#4FC1FF ctermbg=75 gui=NONE cterm=NONE
+hi CurSearch guifg=#1E1E1E ctermfg=234 guibg=#4FC1FF ctermbg=75 gui=NONE cterm=NONE
+hi MatchParen guibg=#515C6A ctermbg=240 gui=bold cterm=bold
+hi Pmenu guifg=#CCCCCC ctermfg=252 guibg=#2D2D2D ctermbg=236 gui=NONE cterm=NONE
+hi PmenuSel guibg=#264F78 ctermbg=24 gui=NONE cterm=NONE
+hi PmenuSbar guibg=#2A2A2A ctermbg=235 gui=NONE cterm=NONE
+hi PmenuThumb guibg=#5A5A5A ctermbg=240 gui=NONE cterm=NONE
+hi WildMenu guibg=#264F78 ctermbg=24 gui=NONE cterm=NONE
+hi QuickFixLine guibg=#264F78 ctermbg=24 gui=NONE cterm=NONE
+hi NonText guifg=#404040 ctermfg=238 gui=NONE cterm=NONE
+hi Whitespace guifg=#404040 ctermfg=238 gui=NONE cterm=NONE
+hi SpecialKey guifg=#404040 ctermfg=238 gui=NONE cterm=NONE
+hi EndOfBuffer guifg=#1E1E1E ctermfg=234 gui=NONE cterm=NONE
+hi Directory guifg=#74B7FF ctermfg=111 gui=NONE cterm=NONE
+hi Conceal guifg=#8B8B8B ctermfg=245 gui=NONE cterm=NONE
+hi ErrorMsg guifg=#F48771 ctermfg=209 gui=NONE cterm=NONE
+hi WarningMsg guifg=#F4D03F ctermfg=221 gui=NONE cterm=NONE
+hi MoreMsg guifg=#90A959 ctermfg=107 gui=NONE cterm=NONE
+hi Question guifg=#4FC1FF ctermfg=75 gui=NONE cterm=NONE
+hi ModeMsg guifg=#CCCCCC ctermfg=252 gui=bold cterm=bold
+hi DiffAdd guibg=#3A5A3A ctermbg=23 gui=NONE cterm=NONE
+hi DiffChange guibg=#5A5A2A ctermbg=58 gui=NONE cterm=NONE
+hi DiffDelete guifg=#BF616A ctermfg=131 guibg=#5A3A3A ctermbg=238 gui=NONE cterm=NONE
+hi DiffText guibg=#1A4A6B ctermbg=24 gui=NONE cterm=NONE
+hi SpellBad guisp=#F48771 gui=undercurl cterm=underline
+hi SpellCap guisp=#F4D03F gui=undercurl cterm=underline
+hi SpellLocal guisp=#4FC1FF gui=undercurl cterm=underline
+hi SpellRare guisp=#8B8B8B gui=undercurl cterm=underline
+hi Underlined guifg=#74B7FF ctermfg=111 gui=underline cterm=underline
+hi Todo guifg=#F4D03F ctermfg=221 gui=bold cterm=bold
+hi Error guifg=#F48771 ctermfg=209 gui=NONE cterm=NONE
+hi Comment guifg=#6A9955 ctermfg=71 gui=italic cterm=italic
+hi SpecialComment guifg=#6A9955 ctermfg=71 gui=italic cterm=italic
+hi Constant guifg=#4FC1FF ctermfg=75 gui=NONE cterm=NONE
+hi String guifg=#CE9178 ctermfg=173 gui=NONE cterm=NONE
+hi Character guifg=#CE9178 ctermfg=173 gui=NONE cterm=NONE
+hi SpecialChar guifg=#D7BA7D ctermfg=180 gui=NONE cterm=NONE
+hi Number guifg=#B5CEA8 ctermfg=151 gui=NONE cterm=NONE
+hi Float guifg=#B5CEA8 ctermfg=151 gui=NONE cterm=NONE
+hi Boolean guifg=#569CD6 ctermfg=74 gui=NONE cterm=NONE
+hi Identifier guifg=#9CDCFE ctermfg=153 gui=NONE cterm=NONE
+hi Function guifg=#DCDCAA ctermfg=187 gui=NONE cterm=NONE
+hi Statement guifg=#569CD6 ctermfg=74 gui=bold cterm=bold
+hi Conditional guifg=#569CD6 ctermfg=74 gui=bold cterm=bold
+hi Repeat guifg=#569CD6 ctermfg=74 gui=bold cterm=bold
+hi Exception guifg=#569CD6 ctermfg=74 gui=bold cterm=bold
+hi Keyword guifg=#569CD6 ctermfg=74 gui=bold cterm=bold
+hi StorageClass guifg=#569CD6 ctermfg=74 gui=bold cterm=bold
+hi Label guifg=#4FC1FF ctermfg=75 gui=NONE cterm=NONE
+hi Operator guifg=#79C0FF ctermfg=111 gui=NONE cterm=NONE
+hi PreProc guifg=#CCCCCC ctermfg=252 gui=NONE cterm=NONE
+hi Include guifg=#CCCCCC ctermfg=252 gui=NONE cterm=NONE
+hi Define guifg=#CCCCCC ctermfg=252 gui=NONE cterm=NONE
+hi Macro guifg=#DCDCAA ctermfg=187 gui=NONE cterm=NONE
+hi PreCondit guifg=#CCCCCC ctermfg=252 gui=NONE cterm=NONE
+hi Type guifg=#4EC9B0 ctermfg=43 gui=NONE cterm=NONE
+hi Structure guifg=#4EC9B0 ctermfg=43 gui=NONE cterm=NONE
+hi Typedef guifg=#4EC9B0 ctermfg=43 gui=NONE cterm=NONE
+hi Special guifg=#F85149 ctermfg=203 gui=NONE cterm=NONE
+hi Tag guifg=#569CD6 ctermfg=74 gui=NONE cterm=NONE
+hi Delimiter guifg=#CCCCCC ctermfg=252 gui=NONE cterm=NONE
+hi Title guifg=#C586C0 ctermfg=175 gui=bold cterm=bold
+
+let g:terminal_ansi_colors = ["#2D2D2D", "#F48771", "#90A959", "#F4D03F", "#4FC1FF", "#B78CF2", "#7FDBCA", "#FFFFFF", "#6B6B6B", "#FF6B6B", "#A8D08D", "#FFE66D", "#7DC8FF", "#C9A8FF", "#9FFFE0", "#FFFFFF"]
@@ -0,0 +1,98 @@
+" Zero Trust Light 0.1.0
+" Author: Yannick Boog
+" Homepage: https://github.com/yannickboog/zero-trust-theme
+" License: MIT
+" Generated by zero-trust from theme.toml; do not edit.
+
+set background=light
+hi clear
+if exists("syntax_on")
+  syntax reset
+endif
+let g:colors_name = "zero-trust-light"
+
+hi Normal guifg=#2E2E2E ctermfg=236 guibg=#FAFAFA ctermbg=231 gui=NONE cterm=NONE
+hi NormalFloat guifg=#2E2E2E ctermfg=236 guibg=#E3E3E3 ctermbg=254 gui=NONE cterm=NONE
+hi FloatBorder guifg=#D0D0D0 ctermfg=252 guibg=#E3E3E3 ctermbg=254 gui=NONE cterm=NONE
+hi Cursor guifg=#FAFAFA ctermfg=231 guibg=#006C99 ctermbg=24 gui=NONE cterm=NONE
+hi CursorLine guibg=#E7E7E7 ctermbg=254 gui=NONE cterm=NONE
+hi CursorColumn guibg=#E7E7E7 ctermbg=254 gui=NONE cterm=NONE
+hi ColorColumn guibg=#E7E7E7 ctermbg=254 gui=NONE cterm=NONE
+hi LineNr guifg=#919191 ctermfg=246 guibg=#EFEFEF ctermbg=255 gui=NONE cterm=NONE
+hi CursorLineNr guifg=#070707 ctermfg=232 guibg=#EFEFEF ctermbg=255 gui=bold cterm=bold
+hi SignColumn guibg=#EFEFEF ctermbg=255 gui=NONE cterm=NONE
+hi FoldColumn guifg=#919191 ctermfg=246 guibg=#EFEFEF ctermbg=255 gui=NONE cterm=NONE
+hi Folded guifg=#6D6D6D ctermfg=242 guibg=#E7E7E7 ctermbg=254 gui=NONE cterm=NONE
+hi VertSplit guifg=#C8C8C8 ctermfg=251 gui=NONE cterm=NONE
+hi WinSeparator guifg=#C8C8C8 ctermfg=251 gui=NONE cterm=NONE
+hi StatusLine guifg=#6D6D6D ctermfg=242 guibg=#E3E3E3 ctermbg=254 gui=NONE cterm=NONE
+hi StatusLineNC guifg=#919191 ctermfg=246 guibg=#E3E3E3 ctermbg=254 gui=NONE cterm=NONE
+hi TabLine guifg=#6D6D6D ctermfg=242 guibg=#E3E3E3 ctermbg=254 gui=NONE cterm=NONE
+hi TabLineSel guifg=#2E2E2E ctermfg=236 guibg=#FAFAFA ctermbg=231 gui=NONE cterm=NONE
+hi TabLineFill guibg=#E3E3E3 ctermbg=254 gui=NONE cterm=NONE
+hi Visual guibg=#9FD1F9 ctermbg=153 gui=NONE cterm=NONE
+hi Search guibg=#9CA8B7 ctermbg=248 gui=NONE cterm=NONE
+hi IncSearch guifg=#FAFAFA ctermfg=231 guibg=#006C99 ctermbg=24 gui=NONE cterm=NONE
+hi CurSearch guifg=#FAFAFA ctermfg=231 guibg=#006C99 ctermbg=24 gui=NONE cterm=NONE
+hi MatchParen guibg=#9CA8B7 ctermbg=248 gui=bold cterm=bold
+hi Pmenu guifg=#2E2E2E ctermfg=236 guibg=#E3E3E3 ctermbg=254 gui=NONE cterm=NONE
+hi PmenuSel guibg=#9CC9F9 ctermbg=117 gui=NONE cterm=NONE
+hi PmenuSbar guibg=#E7E7E7 ctermbg=254 gui=NONE cterm=NONE
+hi PmenuThumb guibg=#A6A6A6 ctermbg=248 gui=NONE cterm=NONE
+hi WildMenu guibg=#9CC9F9 ctermbg=117 gui=NONE cterm=NONE
+hi QuickFixLine guibg=#9CC9F9 ctermbg=117 gui=NONE cterm=NONE
+hi NonText guifg=#C8C8C8 ctermfg=251 gui=NONE cterm=NONE
+hi Whitespace guifg=#C8C8C8 ctermfg=251 gui=NONE cterm=NONE
+hi SpecialKey guifg=#C8C8C8 ctermfg=251 gui=NONE cterm=NONE
+hi EndOfBuffer guifg=#FAFAFA ctermfg=231 gui=NONE cterm=NONE
+hi Directory guifg=#2568AA ctermfg=25 gui=NONE cterm=NONE
+hi Conceal guifg=#6D6D6D ctermfg=242 gui=NONE cterm=NONE
+hi ErrorMsg guifg=#AE4835 ctermfg=131 gui=NONE cterm=NONE
+hi WarningMsg guifg=#5C4C00 ctermfg=58 gui=NONE cterm=NONE
+hi MoreMsg guifg=#6B8232 ctermfg=64 gui=NONE cterm=NONE
+hi Question guifg=#006C99 ctermfg=24 gui=NONE cterm=NONE
+hi ModeMsg guifg=#2E2E2E ctermfg=236 gui=bold cterm=bold
+hi DiffAdd guibg=#A4C8A3 ctermbg=151 gui=NONE cterm=NONE
+hi DiffChange guibg=#B9BB87 ctermbg=144 gui=NONE cterm=NONE
+hi DiffDelete guifg=#CE6F77 ctermfg=167 guibg=#E4BCBB ctermbg=181 gui=NONE cterm=NONE
+hi DiffText guibg=#9FD1F9 ctermbg=153 gui=NONE cterm=NONE
+hi SpellBad guisp=#AE4835 gui=undercurl cterm=underline
+hi SpellCap guisp=#5C4C00 gui=undercurl cterm=underline
+hi SpellLocal guisp=#006C99 gui=undercurl cterm=underline
+hi SpellRare guisp=#6D6D6D gui=undercurl cterm=underline
+hi Underlined guifg=#2568AA ctermfg=25 gui=underline cterm=underline
+hi Todo guifg=#5C4C00 ctermfg=58 gui=bold cterm=bold
+hi Error guifg=#AE4835 ctermfg=131 gui=NONE cterm=NONE
+hi Comment guifg=#527F3C ctermfg=65 gui=italic cterm=italic
+hi SpecialComment guifg=#527F3C ctermfg=65 gui=italic cterm=italic
+hi Constant guifg=#006C99 ctermfg=24 gui=NONE cterm=NONE
+hi String guifg=#9D634C ctermfg=131 gui=NONE cterm=NONE
+hi Character guifg=#9D634C ctermfg=131 gui=NONE cterm=NONE
+hi SpecialChar guifg=#73591A ctermfg=94 gui=NONE cterm=NONE
+hi Number guifg=#3D5231 ctermfg=239 gui=NONE cterm=NONE
+hi Float guifg=#3D5231 ctermfg=239 gui=NONE cterm=NONE
+hi Boolean guifg=#3178B0 ctermfg=31 gui=NONE cterm=NONE
+hi Identifier guifg=#0E5471 ctermfg=24 gui=NONE cterm=NONE
+hi Function guifg=#434213 ctermfg=238 gui=NONE cterm=NONE
+hi Statement guifg=#3178B0 ctermfg=31 gui=bold cterm=bold
+hi Conditional guifg=#3178B0 ctermfg=31 gui=bold cterm=bold
+hi Repeat guifg=#3178B0 ctermfg=31 gui=bold cterm=bold
+hi Exception guifg=#3178B0 ctermfg=31 gui=bold cterm=bold
+hi Keyword guifg=#3178B0 ctermfg=31 gui=bold cterm=bold
+hi StorageClass guifg=#3178B0 ctermfg=31 gui=bold cterm=bold
+hi Label guifg=#006C99 ctermfg=24 gui=NONE cterm=NONE
+hi Operator guifg=#19659E ctermfg=25 gui=NONE cterm=NONE
+hi PreProc guifg=#2E2E2E ctermfg=236 gui=NONE cterm=NONE
+hi Include guifg=#2E2E2E ctermfg=236 gui=NONE cterm=NONE
+hi Define guifg=#2E2E2E ctermfg=236 gui=NONE cterm=NONE
+hi Macro guifg=#434213 ctermfg=238 gui=NONE cterm=NONE
+hi PreCondit guifg=#2E2E2E ctermfg=236 gui=NONE cterm=NONE
+hi Type guifg=#007866 ctermfg=29 gui=NONE cterm=NONE
+hi Structure guifg=#007866 ctermfg=29 gui=NONE cterm=NONE
+hi Typedef guifg=#007866 ctermfg=29 gui=NONE cterm=NONE
+hi Special guifg=#DA3130 ctermfg=160 gui=NONE cterm=NONE
+hi Tag guifg=#3178B0 ctermfg=31 gui=NONE cterm=NONE
+hi Delimiter guifg=#2E2E2E ctermfg=236 gui=NONE cterm=NONE
+hi Title guifg=#995D95 ctermfg=96 gui=bold cterm=bold
+
+let g:terminal_ansi_colors = ["#070707", "#AE4835", "#6B8232", "#5C4C00", "#006C99", "#8257B8", "#006458", "#E3E3E3", "#070707", "#C8373E", "#426526", "#4A3F00", "#096193", "#6C4B9A", "#004838", "#919191"]
@@ -0,0 +1,2 @@
+" Follows 'background': zero-trust-dark or zero-trust-light.
+execute "colorscheme" (&background ==# "light" ? "zero-trust-light" : "zero-trust-dark")